@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use sovereign::cpi::accounts::VerifyTier;
+use sovereign::program::Sovereign;
+
+declare_id!("3sgRsXzeKuxd4BL2id5v7h3TvoudFca8vfzE9FiHDqgD");
+
+// =============================================================================
+// SOVEREIGN CPI MOCK
+// =============================================================================
+//
+// Stands in for an integrating program (Dverse, Komon, DePINfinity) in the
+// tests: it asks SOVEREIGN for a tier over CPI and only reads the returned
+// data, never the identity account's layout.
+// =============================================================================
+
+#[program]
+pub mod sovereign_cpi_mock {
+    use super::*;
+
+    /// Succeed only if the user's own identity meets `min_tier`
+    pub fn gate_on_tier(ctx: Context<GateOnTier>, min_tier: u8) -> Result<()> {
+        let verification = sovereign::cpi::verify_tier(
+            CpiContext::new(
+                ctx.accounts.sovereign_program.to_account_info(),
+                VerifyTier {
+                    identity: ctx.accounts.identity.to_account_info(),
+                    tier_config: ctx.accounts.tier_config.as_ref().map(|config| config.to_account_info()),
+                    protocol_config: ctx.accounts.protocol_config.as_ref().map(|config| config.to_account_info()),
+                },
+            ),
+            min_tier,
+        )?
+        .get();
+
+        msg!(
+            "SOVEREIGN tier {} (composite {}), required {}",
+            verification.tier,
+            verification.composite_score,
+            min_tier
+        );
+        require_keys_eq!(
+            verification.owner,
+            ctx.accounts.user.key(),
+            MockError::NotIdentityOwner
+        );
+        require!(verification.meets_min_tier, MockError::TierTooLow);
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct GateOnTier<'info> {
+    pub user: Signer<'info>,
+    /// CHECK: Validated by SOVEREIGN's verify_tier
+    pub identity: UncheckedAccount<'info>,
+    /// CHECK: Validated by SOVEREIGN's verify_tier
+    pub tier_config: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by SOVEREIGN's verify_tier
+    pub protocol_config: Option<UncheckedAccount<'info>>,
+    pub sovereign_program: Program<'info, Sovereign>,
+}
+
+#[error_code]
+pub enum MockError {
+    #[msg("Identity is below the required tier")]
+    TierTooLow,
+    #[msg("Identity belongs to another wallet")]
+    NotIdentityOwner,
+}
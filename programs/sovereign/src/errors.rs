@@ -28,4 +28,43 @@ pub enum SovereignError {
 
     #[msg("Score details not initialized")]
     DetailsNotInitialized,
+
+    #[msg("Invalid grace period: must not be negative")]
+    InvalidGracePeriod,
+
+    #[msg("Invalid decay rate: must be between 0 and 10000 basis points per day")]
+    InvalidDecayRate,
+
+    #[msg("Invalid weights: dimension weights must sum to 100")]
+    InvalidWeights,
+
+    #[msg("Invalid metric: value out of range")]
+    InvalidMetric,
+
+    #[msg("Invalid attestation: hash must not be empty")]
+    InvalidAttestation,
+
+    #[msg("Invalid tier cutoffs: must be positive, strictly increasing and at most 10000")]
+    InvalidTierCutoffs,
+
+    #[msg("Invalid tier names: need exactly 5 names of at most 16 bytes")]
+    InvalidTierNames,
+
+    #[msg("Identity is frozen: the owner must unfreeze it before scores can change")]
+    IdentityFrozen,
+
+    #[msg("Identity already uses the current schema version")]
+    AlreadyMigrated,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Invalid voting period bound: must be at least 1 day (86400 seconds)")]
+    InvalidVotingPeriodBound,
+
+    #[msg("Stale update: update_seq must be greater than the last accepted sequence")]
+    StaleUpdate,
+
+    #[msg("Identity was transferred to a new owner")]
+    IdentityTransferred,
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{ContentType, Region, VoteChoice};
+use crate::state::{SovereignIdentity, TierChange};
+
+// =============================================================================
+// EVENTS
+// =============================================================================
+//
+// Emitted for state changes that off-chain indexers need to follow but that
+// can't be reconstructed cheaply from account snapshots alone.
+// =============================================================================
+
+/// A new creator DAO was created
+#[event]
+pub struct DAOCreated {
+    pub dao: Pubkey,
+    pub dao_id: u64,
+    pub founder: Pubkey,
+    pub content_type: ContentType,
+    /// Raw stored code, kept even when it isn't a known region
+    pub region_code: u16,
+    /// The known region for `region_code`, if any
+    pub region: Option<Region>,
+    pub timestamp: i64,
+}
+
+/// A DAO's founder role moved to a new key
+#[event]
+pub struct FounderTransferred {
+    pub dao: Pubkey,
+    pub old_founder: Pubkey,
+    pub new_founder: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A new admission market was opened, tagged with its DAO's niche so
+/// indexers can segment markets by content type and style
+#[event]
+pub struct MarketCreated {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub dao: Pubkey,
+    pub creator_identity: Pubkey,
+    pub market_creator: Pubkey,
+    pub content_type: ContentType,
+    pub style_tag: [u8; 32],
+    pub initial_liquidity: u64,
+    pub expires_at: i64,
+}
+
+/// A nomination opened for voting, whether by a member's nomination or a
+/// sponsored application
+#[event]
+pub struct NominationOpened {
+    pub nomination: Pubkey,
+    pub nomination_id: u64,
+    pub dao: Pubkey,
+    pub nominee_identity: Pubkey,
+    pub nominee_wallet: Pubkey,
+    pub nominator: Pubkey,
+    pub members_snapshot: u16,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+/// A vote was committed on a nomination. The tallies are the nomination's
+/// revealed totals at the time, which stay at zero until voting closes.
+#[event]
+pub struct VoteCast {
+    pub nomination: Pubkey,
+    /// keccak of the voter's wallet
+    pub voter_hash: [u8; 32],
+    /// The vote's commitment, keccak(choice || salt)
+    pub choice_committed: [u8; 32],
+    pub weight: u32,
+    pub voter_count: u16,
+    pub running_accept: u32,
+    pub running_reject: u32,
+    pub running_abstain: u32,
+    pub timestamp: i64,
+}
+
+/// A committed vote was revealed and added to the nomination's tallies
+#[event]
+pub struct VoteRevealed {
+    pub nomination: Pubkey,
+    pub choice: VoteChoice,
+    pub weight: u32,
+    pub revealed_count: u16,
+    pub running_accept: u32,
+    pub running_reject: u32,
+    pub running_abstain: u32,
+    pub timestamp: i64,
+}
+
+/// An identity's tier moved, up or down, so systems that gate perks on
+/// tiers can react without diffing every score update
+#[event]
+pub struct TierChanged {
+    pub identity: Pubkey,
+    pub old_tier: u8,
+    pub new_tier: u8,
+    pub composite_score: u16,
+    pub timestamp: i64,
+}
+
+/// Emit `TierChanged` if a recalculation moved `identity` to a new tier
+pub fn emit_tier_change(identity: &Account<SovereignIdentity>, change: Option<TierChange>, now: i64) {
+    if let Some(TierChange { old_tier, new_tier }) = change {
+        emit!(TierChanged {
+            identity: identity.key(),
+            old_tier,
+            new_tier,
+            composite_score: identity.composite_score,
+            timestamp: now,
+        });
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::incinerator;
+use crate::state::admission_market::{AdmissionMarket, MarketPosition, MarketStatus, SurfacingScore};
+use crate::state::creator_dao::CreatorScoreDetails;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+use crate::instructions::admission_market::claim_winnings::{settle_position, tracks_prediction, Prediction};
+
+// =============================================================================
+// CLAIM ALL WINNINGS INSTRUCTION
+// =============================================================================
+//
+// claim_winnings for up to MAX_CLAIM_BATCH markets in one transaction. Each
+// claim is passed in `remaining_accounts` as a (market, position) pair, both
+// writable. Positions that can't be claimed yet - unresolved markets, open
+// challenge periods - or were already claimed are skipped rather than
+// failing the batch. The predictor's prediction accuracy is updated once,
+// after every pair has settled.
+//
+// A scout's positions in markets they created credit their own
+// SurfacingScore, so those are only settled when it is passed and skipped
+// otherwise.
+// =============================================================================
+
+/// Most claims one batch can settle
+pub const MAX_CLAIM_BATCH: usize = 8;
+
+/// Accounts each claim takes up in `remaining_accounts`
+const ACCOUNTS_PER_CLAIM: usize = 2;
+
+#[derive(Accounts)]
+pub struct ClaimAllWinnings<'info> {
+    /// The predictor claiming winnings
+    #[account(mut)]
+    pub predictor: Signer<'info>,
+
+    /// The predictor's creator score details (for prediction accuracy update).
+    /// Required whenever a claimed position recorded a predictor identity.
+    #[account(mut)]
+    pub creator_score: Option<Account<'info, CreatorScoreDetails>>,
+
+    /// The predictor's own surfacing score, for markets they created
+    #[account(
+        mut,
+        constraint = surfacing_score.identity == predictor.key(),
+    )]
+    pub surfacing_score: Option<Account<'info, SurfacingScore>>,
+
+    /// CHECK: Solana's incinerator; a creator's self-position burn is sent here
+    #[account(mut, address = incinerator::ID)]
+    pub burn_treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimAllWinnings<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty()
+            && remaining.len().is_multiple_of(ACCOUNTS_PER_CLAIM)
+            && remaining.len() / ACCOUNTS_PER_CLAIM <= MAX_CLAIM_BATCH,
+        AdmissionMarketError::InvalidClaimBatch
+    );
+    let batch_size = remaining.len() / ACCOUNTS_PER_CLAIM;
+
+    let accounts = &mut *ctx.accounts;
+    let details_identity = accounts.creator_score.as_ref().map(|details| details.identity);
+    let predictor_key = accounts.predictor.key();
+    let predictor_info = accounts.predictor.to_account_info();
+    let burn_info = accounts.burn_treasury.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+    let (mut correct, mut incorrect, mut pnl_bps, mut settled) = (0u32, 0u32, 0i32, 0usize);
+
+    for claim in remaining.chunks(ACCOUNTS_PER_CLAIM) {
+        let mut market = Account::<AdmissionMarket>::try_from(&claim[0])?;
+        let mut position = Account::<MarketPosition>::try_from(&claim[1])?;
+        require_keys_eq!(position.market, market.key(), AdmissionMarketError::InvalidClaimBatch);
+        require_keys_eq!(position.predictor, predictor_key, AdmissionMarketError::PredictorMismatch);
+
+        if position.claimed {
+            msg!("Position in market {} already claimed; skipping", market.key());
+            continue;
+        }
+        let resolved = matches!(
+            market.status,
+            MarketStatus::Resolved | MarketStatus::Finalized | MarketStatus::Expired
+        );
+        if !resolved || !market.claims_open(now) {
+            msg!("Market {} can't be claimed yet; skipping", market.key());
+            continue;
+        }
+        if market.market_creator == predictor_key && accounts.surfacing_score.is_none() {
+            msg!("Market {} was created by the predictor; pass their surfacing score to claim it", market.key());
+            continue;
+        }
+
+        let tracked = tracks_prediction(position.predictor_identity, details_identity)?;
+        let surfacing_score = accounts.surfacing_score.as_deref_mut();
+        let prediction = settle_position(&mut market, &mut position, &predictor_info, &burn_info, surfacing_score, None)?;
+        match prediction.filter(|_| tracked) {
+            Some(Prediction::Correct { pnl_bps: position_pnl_bps }) => {
+                correct += 1;
+                pnl_bps = pnl_bps.saturating_add(position_pnl_bps);
+            }
+            Some(Prediction::Incorrect) => incorrect += 1,
+            None => {}
+        }
+        market.exit(&crate::ID)?;
+        position.exit(&crate::ID)?;
+        settled += 1;
+    }
+
+    // Update prediction accuracy once for the whole batch
+    match accounts.creator_score.as_deref_mut() {
+        Some(creator_score) => {
+            creator_score.record_predictions(correct, incorrect, pnl_bps, now);
+            msg!(
+                "{} correct, {} incorrect. Accuracy: {}%",
+                correct,
+                incorrect,
+                creator_score.prediction_accuracy_bps / 100
+            );
+        }
+        None => msg!("No position recorded a SOVEREIGN identity; prediction accuracy not tracked"),
+    }
+
+    msg!("Settled {} of {} claims for {}", settled, batch_size, predictor_key);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::incinerator;
 use crate::state::admission_market::{AdmissionMarket, MarketPosition, MarketStatus, MarketOutcome, SurfacingScore};
 use crate::state::creator_dao::CreatorScoreDetails;
 use crate::instructions::admission_market::create_market::AdmissionMarketError;
@@ -14,6 +15,9 @@ use crate::instructions::admission_market::create_market::AdmissionMarketError;
 //
 // The burn mechanism creates deflationary pressure tied to quality acceptance,
 // not speculative attention.
+//
+// Payouts and refunds are paid out of the lamports escrowed in the market
+// account by create_market and take_position.
 // =============================================================================
 
 #[derive(Accounts)]
@@ -22,10 +26,11 @@ pub struct ClaimWinnings<'info> {
     #[account(mut)]
     pub predictor: Signer<'info>,
 
-    /// The resolved market
+    /// The resolved (or expired and cancelled) market
     #[account(
         mut,
-        constraint = market.status == MarketStatus::Resolved @ AdmissionMarketError::MarketNotResolved,
+        constraint = matches!(market.status, MarketStatus::Resolved | MarketStatus::Finalized | MarketStatus::Expired)
+            @ AdmissionMarketError::MarketNotResolved,
     )]
     pub market: Account<'info, AdmissionMarket>,
 
@@ -38,11 +43,9 @@ pub struct ClaimWinnings<'info> {
     )]
     pub position: Account<'info, MarketPosition>,
 
-    /// The predictor's creator score details (for prediction accuracy update)
-    #[account(
-        mut,
-        constraint = creator_score.identity == predictor.key(),
-    )]
+    /// The predictor's creator score details (for prediction accuracy update).
+    /// Required whenever the position recorded a predictor identity.
+    #[account(mut)]
     pub creator_score: Option<Account<'info, CreatorScoreDetails>>,
 
     /// The market creator's surfacing score (for bonus if correct)
@@ -52,18 +55,147 @@ pub struct ClaimWinnings<'info> {
     )]
     pub surfacing_score: Option<Account<'info, SurfacingScore>>,
 
-    /// Treasury for receiving burned tokens
-    /// CHECK: Burn address
-    #[account(mut)]
+    /// CHECK: Solana's incinerator; a creator's self-position burn is sent here
+    #[account(mut, address = incinerator::ID)]
     pub burn_treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
+/// Decide whether settling a position updates prediction accuracy.
+///
+/// Goes by the identity the position recorded at take_position: that
+/// identity's creator details must be passed, so neither the predictor nor
+/// a keeper can leave a call untracked. A position taken without an
+/// identity has nothing to attribute the prediction to.
+pub(crate) fn tracks_prediction(position_identity: Option<Pubkey>, details_identity: Option<Pubkey>) -> Result<bool> {
+    let Some(identity) = position_identity else {
+        return Ok(false);
+    };
+    let details_identity = details_identity.ok_or(AdmissionMarketError::CreatorDetailsNotInitialized)?;
+    require_keys_eq!(identity, details_identity, AdmissionMarketError::CreatorDetailsMismatch);
+    Ok(true)
+}
+
+/// Move lamports out of a market's escrow
+pub fn pay_from_escrow(market: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+    let remaining = market
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AdmissionMarketError::InsufficientEscrow)?;
+    **market.try_borrow_mut_lamports()? = remaining;
+    **recipient.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Reject a claim whose payout fell below the claimant's `min_payout`
+fn check_min_payout(payout: u64, min_payout: Option<u64>) -> Result<()> {
+    if let Some(min_payout) = min_payout {
+        require!(payout >= min_payout, AdmissionMarketError::SlippageExceeded);
+    }
+    Ok(())
+}
+
+pub fn handler(ctx: Context<ClaimWinnings>, min_payout: Option<u64>) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let predictor_info = accounts.predictor.to_account_info();
+    let burn_info = accounts.burn_treasury.to_account_info();
+
+    settle(
+        ClaimParts {
+            market: &mut accounts.market,
+            position: &mut accounts.position,
+            predictor_info: &predictor_info,
+            burn_info: &burn_info,
+            creator_score: accounts.creator_score.as_deref_mut(),
+            surfacing_score: accounts.surfacing_score.as_deref_mut(),
+        },
+        min_payout,
+    )
+}
+
+/// What a settled position says about its predictor's judgment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Prediction {
+    Correct { pnl_bps: i32 },
+    Incorrect,
+}
+
+/// The accounts a single claim touches, shared by claim_winnings and
+/// claim_winnings_for
+pub(crate) struct ClaimParts<'a, 'info> {
+    pub market: &'a mut Account<'info, AdmissionMarket>,
+    pub position: &'a mut Account<'info, MarketPosition>,
+    pub predictor_info: &'a AccountInfo<'info>,
+    pub burn_info: &'a AccountInfo<'info>,
+    pub creator_score: Option<&'a mut CreatorScoreDetails>,
+    pub surfacing_score: Option<&'a mut SurfacingScore>,
+}
+
+/// Pay out (or close) a position against its market's outcome, crediting
+/// `predictor`. Shared by self-claims and keeper settlement.
+pub(crate) fn settle(parts: ClaimParts, min_payout: Option<u64>) -> Result<()> {
+    let ClaimParts {
+        market,
+        position,
+        predictor_info,
+        burn_info,
+        creator_score,
+        surfacing_score,
+    } = parts;
+    let tracked = tracks_prediction(
+        position.predictor_identity,
+        creator_score.as_ref().map(|details| details.identity),
+    )?;
+    if !tracked {
+        msg!("Position has no SOVEREIGN identity; prediction accuracy not tracked");
+    }
+
+    let prediction = settle_position(market, position, predictor_info, burn_info, surfacing_score, min_payout)?;
+
+    if let (true, Some(creator_score), Some(prediction)) = (tracked, creator_score, prediction) {
+        let now = Clock::get()?.unix_timestamp;
+        match prediction {
+            Prediction::Correct { pnl_bps } => {
+                creator_score.record_predictions(1, 0, pnl_bps, now);
+                msg!(
+                    "Prediction correct! Accuracy: {}%, P&L: {}%",
+                    creator_score.prediction_accuracy_bps / 100,
+                    pnl_bps / 100
+                );
+            }
+            Prediction::Incorrect => {
+                creator_score.record_predictions(0, 1, 0, now);
+                // Vitalik: Good predictors contribute to surfacing quality
+                msg!(
+                    "Prediction incorrect. Updated accuracy: {}%",
+                    creator_score.prediction_accuracy_bps / 100
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pay out (or close) a position without touching prediction accuracy,
+/// returning the call it made so the caller can record it. Cancelled
+/// markets make no call.
+pub(crate) fn settle_position<'info>(
+    market: &mut Account<'info, AdmissionMarket>,
+    position: &mut Account<'info, MarketPosition>,
+    predictor_info: &AccountInfo<'info>,
+    burn_info: &AccountInfo<'info>,
+    mut surfacing_score: Option<&mut SurfacingScore>,
+    min_payout: Option<u64>,
+) -> Result<Option<Prediction>> {
     let clock = Clock::get()?;
-    let market = &ctx.accounts.market;
-    let position = &mut ctx.accounts.position;
+    require!(
+        market.claims_open(clock.unix_timestamp),
+        AdmissionMarketError::ChallengePeriodActive
+    );
+
+    let market_info = market.to_account_info();
 
     // Determine if this position won
     let (is_winner, winning_tokens) = match market.outcome {
@@ -71,10 +203,25 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
         MarketOutcome::Rejected => (position.no_tokens > 0, position.no_tokens),
         MarketOutcome::Cancelled => {
             // Refund on cancellation
+            check_min_payout(position.total_staked, min_payout)?;
             position.claimed = true;
             position.payout = position.total_staked;
+            pay_from_escrow(&market_info, predictor_info, position.total_staked)?;
             msg!("Market cancelled. Refunding {} lamports", position.total_staked);
-            return Ok(());
+
+            // A market that never reached a vote says nothing about the
+            // scout's judgment; the creator's own refund takes it out of
+            // their accuracy, once
+            if predictor_info.key() == market.market_creator {
+                if let Some(surfacing_score) = surfacing_score.as_deref_mut() {
+                    surfacing_score.record_cancelled_market(clock.unix_timestamp);
+                    msg!(
+                        "Cancelled market excluded. Surfacing accuracy: {}%",
+                        surfacing_score.surfacing_accuracy_bps / 100
+                    );
+                }
+            }
+            return Ok(None);
         }
         MarketOutcome::Pending => {
             return err!(AdmissionMarketError::MarketNotResolved);
@@ -82,88 +229,59 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     };
 
     if !is_winner {
+        check_min_payout(0, min_payout)?;
+
         // Losing position - just mark as claimed
         position.claimed = true;
         position.payout = 0;
 
-        // Update prediction accuracy
-        if let Some(creator_score) = &mut ctx.accounts.creator_score {
-            creator_score.predictions_incorrect += 1;
-            let total = creator_score.predictions_correct + creator_score.predictions_incorrect;
-            creator_score.prediction_accuracy_bps = if total > 0 {
-                ((creator_score.predictions_correct as u32 * 10000) / total as u32) as u16
-            } else {
-                0
-            };
-            creator_score.last_updated = clock.unix_timestamp;
-
-            // Vitalik: Good predictors contribute to surfacing quality
-            msg!(
-                "Prediction incorrect. Updated accuracy: {}%",
-                creator_score.prediction_accuracy_bps / 100
-            );
-        }
-
-        return Ok(());
+        return Ok(Some(Prediction::Incorrect));
     }
 
     // === WINNING POSITION ===
 
     // Calculate payout
     let is_yes = market.outcome == MarketOutcome::Accepted;
-    let payout = market.calculate_payout(winning_tokens, is_yes);
+    let gross_payout = market.calculate_payout(winning_tokens, is_yes);
+
+    // The accepted creator betting on themselves pays an elevated burn
+    let self_burn = market.self_position_burn(&position.predictor, position.predictor_identity, gross_payout);
+    let payout = gross_payout - self_burn;
+    check_min_payout(payout, min_payout)?;
+    if self_burn > 0 {
+        market.amount_burned = market
+            .amount_burned
+            .checked_add(self_burn)
+            .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+        pay_from_escrow(&market_info, burn_info, self_burn)?;
+        msg!("Creator self-position: {} lamports burned from payout", self_burn);
+    }
 
     position.claimed = true;
     position.payout = payout;
+    pay_from_escrow(&market_info, predictor_info, payout)?;
 
-    // Update prediction accuracy
-    if let Some(creator_score) = &mut ctx.accounts.creator_score {
-        creator_score.predictions_correct += 1;
-        let total = creator_score.predictions_correct + creator_score.predictions_incorrect;
-        creator_score.prediction_accuracy_bps = if total > 0 {
-            ((creator_score.predictions_correct as u32 * 10000) / total as u32) as u16
-        } else {
-            0
-        };
-
-        // Calculate P&L in basis points
-        let pnl = payout as i64 - position.total_staked as i64;
-        let pnl_bps = if position.total_staked > 0 {
-            ((pnl * 10000) / position.total_staked as i64) as i32
-        } else {
-            0
-        };
-        creator_score.prediction_pnl_bps = creator_score.prediction_pnl_bps.saturating_add(pnl_bps);
-        creator_score.last_updated = clock.unix_timestamp;
-
-        msg!(
-            "Prediction correct! Accuracy: {}%, P&L: {}%",
-            creator_score.prediction_accuracy_bps / 100,
-            pnl_bps / 100
-        );
-    }
+    // Calculate P&L in basis points
+    let pnl = payout as i64 - position.total_staked as i64;
+    let pnl_bps = if position.total_staked > 0 {
+        ((pnl * 10000) / position.total_staked as i64) as i32
+    } else {
+        0
+    };
 
-    // Bonus for market creator if they predicted correctly
+    // Bonus for market creator if they predicted correctly. The scout opens a
+    // directional position when creating the market, so a winning claim on
+    // it is a correct call whichever side they took.
     // Vitalik: Talent scouts who surface accepted creators are rewarded
-    if ctx.accounts.predictor.key() == market.market_creator {
-        if let Some(surfacing_score) = &mut ctx.accounts.surfacing_score {
-            if market.outcome == MarketOutcome::Accepted {
-                surfacing_score.successful_surfaces += 1;
-                surfacing_score.surfacing_accuracy_bps = if surfacing_score.markets_created > 0 {
-                    ((surfacing_score.successful_surfaces as u32 * 10000)
-                        / surfacing_score.markets_created as u32) as u16
-                } else {
-                    0
-                };
-                surfacing_score.scout_score = surfacing_score.calculate_scout_score();
-                surfacing_score.last_updated = clock.unix_timestamp;
+    if predictor_info.key() == market.market_creator {
+        if let Some(surfacing_score) = surfacing_score {
+            surfacing_score.record_successful_surface(clock.unix_timestamp);
 
-                msg!(
-                    "Market creator bonus! Surfacing accuracy: {}%, Scout score: {}",
-                    surfacing_score.surfacing_accuracy_bps / 100,
-                    surfacing_score.scout_score
-                );
-            }
+            msg!(
+                "Market creator bonus! Surfacing accuracy: {}%, Scout score: {}",
+                surfacing_score.surfacing_accuracy_bps / 100,
+                surfacing_score.scout_score
+            );
         }
     }
 
@@ -183,5 +301,44 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     // their creator coins"
     msg!("Total burned from this market: {} lamports", market.amount_burned);
 
-    Ok(())
+    Ok(Some(Prediction::Correct { pnl_bps }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_payout_is_optional_and_inclusive() {
+        assert!(check_min_payout(0, None).is_ok());
+        assert!(check_min_payout(1_000, Some(1_000)).is_ok());
+
+        let err = check_min_payout(999, Some(1_000)).unwrap_err();
+        assert_eq!(err, AdmissionMarketError::SlippageExceeded.into());
+    }
+
+    #[test]
+    fn identityless_positions_are_not_tracked() {
+        assert!(!tracks_prediction(None, None).unwrap());
+        // Details passed for a position with no identity aren't credited
+        assert!(!tracks_prediction(None, Some(Pubkey::new_unique())).unwrap());
+    }
+
+    #[test]
+    fn matching_details_are_tracked() {
+        let identity = Pubkey::new_unique();
+        assert!(tracks_prediction(Some(identity), Some(identity)).unwrap());
+    }
+
+    #[test]
+    fn identity_without_details_is_rejected() {
+        let err = tracks_prediction(Some(Pubkey::new_unique()), None).unwrap_err();
+        assert_eq!(err, AdmissionMarketError::CreatorDetailsNotInitialized.into());
+    }
+
+    #[test]
+    fn mismatched_details_are_rejected() {
+        let err = tracks_prediction(Some(Pubkey::new_unique()), Some(Pubkey::new_unique())).unwrap_err();
+        assert_eq!(err, AdmissionMarketError::CreatorDetailsMismatch.into());
+    }
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::incinerator;
+use crate::state::admission_market::{AdmissionMarket, MarketPosition, MarketStatus, SurfacingScore};
+use crate::state::creator_dao::CreatorScoreDetails;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+use crate::instructions::admission_market::claim_winnings::{settle, ClaimParts};
+
+// =============================================================================
+// CLAIM WINNINGS FOR INSTRUCTION
+// =============================================================================
+//
+// Lets a keeper settle positions on behalf of their predictors, so a market
+// can be fully settled without every winner and loser showing up. The payout
+// always goes to the position's own predictor; the keeper only pays the fee,
+// and can batch several of these into one transaction. Positions that are
+// already claimed are skipped, so a batch never fails on a predictor who
+// claimed first.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimWinningsFor<'info> {
+    /// Anyone settling the market (pays the transaction fee)
+    pub keeper: Signer<'info>,
+
+    /// The position's predictor, who receives the payout
+    /// CHECK: Must be the position's predictor
+    #[account(
+        mut,
+        address = position.predictor @ AdmissionMarketError::PredictorMismatch,
+    )]
+    pub predictor: UncheckedAccount<'info>,
+
+    /// The resolved (or expired and cancelled) market
+    #[account(
+        mut,
+        constraint = matches!(market.status, MarketStatus::Resolved | MarketStatus::Finalized | MarketStatus::Expired)
+            @ AdmissionMarketError::MarketNotResolved,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+
+    /// The position being settled
+    #[account(
+        mut,
+        constraint = position.market == market.key(),
+    )]
+    pub position: Account<'info, MarketPosition>,
+
+    /// The predictor's creator score details.
+    /// Required whenever the position recorded a predictor identity.
+    #[account(mut)]
+    pub creator_score: Option<Account<'info, CreatorScoreDetails>>,
+
+    /// The market creator's surfacing score (for bonus if correct)
+    #[account(
+        mut,
+        constraint = surfacing_score.identity == market.market_creator,
+    )]
+    pub surfacing_score: Option<Account<'info, SurfacingScore>>,
+
+    /// CHECK: Solana's incinerator; a creator's self-position burn is sent here
+    #[account(mut, address = incinerator::ID)]
+    pub burn_treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimWinningsFor>) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+
+    if accounts.position.claimed {
+        msg!("Position for {} already settled", accounts.position.predictor);
+        return Ok(());
+    }
+
+    let predictor_info = accounts.predictor.to_account_info();
+    let burn_info = accounts.burn_treasury.to_account_info();
+    settle(
+        ClaimParts {
+            market: &mut accounts.market,
+            position: &mut accounts.position,
+            predictor_info: &predictor_info,
+            burn_info: &burn_info,
+            creator_score: accounts.creator_score.as_deref_mut(),
+            surfacing_score: accounts.surfacing_score.as_deref_mut(),
+        },
+        None,
+    )?;
+
+    msg!("Keeper {} settled position for {}", accounts.keeper.key(), accounts.predictor.key());
+
+    Ok(())
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::creator_dao::CreatorDAO;
-use crate::state::admission_market::{AdmissionMarket, MarketFactory, MarketStatus, MarketOutcome, SurfacingScore};
+use crate::events::MarketCreated;
+use crate::state::admission_market::{
+    AdmissionMarket, MarketFactory, MarketOutcome, MarketPosition, MarketStatus, PositionSide, SurfacingScore,
+    DEFAULT_INITIAL_YES_BPS, MAX_INITIAL_YES_BPS, MIN_INITIAL_YES_BPS,
+};
+use crate::state::{ProtocolConfig, SovereignIdentity};
+use crate::errors::SovereignError;
 
 // =============================================================================
 // CREATE ADMISSION MARKET INSTRUCTION
@@ -23,6 +30,17 @@ use crate::state::admission_market::{AdmissionMarket, MarketFactory, MarketStatu
 // The market creator is essentially saying: "I spotted this creator before
 // anyone else. I believe DAO X will accept them. I'm putting my money where
 // my prediction is."
+//
+// The scout also takes a directional position of at least the factory's
+// `min_creator_stake` at creation, so their surfacing record reflects a real
+// call rather than neutral liquidity.
+//
+// The scout's SurfacingScore must already exist: call `init_surfacing_score`
+// once before creating a first market.
+//
+// Markets are seeded by the factory's market_count as well as the (DAO,
+// creator) pair, so a creator whose earlier market expired or was rejected
+// can get a fresh one. Nominations link the specific market they lock.
 // =============================================================================
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -31,6 +49,12 @@ pub struct CreateMarketParams {
     pub initial_liquidity: u64,
     /// Days until market expires if no nomination
     pub expiry_days: u16,
+    /// Side of the scout's own opening position
+    pub creator_initial_side: PositionSide,
+    /// Scout's opening stake (in lamports, fee included)
+    pub creator_initial_stake: u64,
+    /// Starting YES price in basis points (1000-9000, default 5000)
+    pub initial_yes_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
@@ -40,9 +64,14 @@ pub struct CreateMarket<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    /// The creator's SOVEREIGN identity (for surfacing score tracking)
-    /// CHECK: Optional, validated if present
-    pub creator_identity: Option<UncheckedAccount<'info>>,
+    /// The creator's SOVEREIGN identity (optional, recorded on their opening
+    /// position for prediction accuracy tracking)
+    #[account(
+        seeds = [b"identity", creator.key().as_ref()],
+        bump = creator_identity.bump,
+        constraint = creator_identity.owner == creator.key() @ AdmissionMarketError::PredictorIdentityMismatch,
+    )]
+    pub creator_identity: Option<Account<'info, SovereignIdentity>>,
 
     /// The DAO this prediction is about
     #[account(
@@ -58,6 +87,14 @@ pub struct CreateMarket<'info> {
     /// CHECK: Used as identifier
     pub predicted_creator_wallet: UncheckedAccount<'info>,
 
+    /// Market factory for configuration
+    #[account(
+        mut,
+        seeds = [b"market_factory"],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, MarketFactory>,
+
     /// The market account to create
     #[account(
         init,
@@ -66,30 +103,45 @@ pub struct CreateMarket<'info> {
         seeds = [
             b"admission_market",
             dao.key().as_ref(),
-            predicted_creator_identity.key().as_ref()
+            predicted_creator_identity.key().as_ref(),
+            &factory.market_count.to_le_bytes()
         ],
         bump
     )]
     pub market: Account<'info, AdmissionMarket>,
 
-    /// Market factory for configuration
+    /// The scout's opening position
     #[account(
-        mut,
-        seeds = [b"market_factory"],
-        bump = factory.bump
+        init,
+        payer = creator,
+        space = MarketPosition::SIZE,
+        seeds = [
+            b"market_position",
+            market.key().as_ref(),
+            creator.key().as_ref()
+        ],
+        bump
     )]
-    pub factory: Account<'info, MarketFactory>,
+    pub creator_position: Account<'info, MarketPosition>,
 
-    /// Surfacing score for the market creator
+    /// Surfacing score for the market creator (see `init_surfacing_score`).
+    /// The bump is re-derived because scores created by older versions of
+    /// this instruction never stored one.
     #[account(
-        init_if_needed,
-        payer = creator,
-        space = SurfacingScore::SIZE,
+        mut,
         seeds = [b"surfacing_score", creator.key().as_ref()],
         bump
     )]
     pub surfacing_score: Account<'info, SurfacingScore>,
 
+    /// Program-wide pause switch
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ SovereignError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -99,32 +151,63 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     let surfacing_score = &mut ctx.accounts.surfacing_score;
     let clock = Clock::get()?;
 
+    // Only DAOs established enough to matter get speculation markets
+    require!(
+        ctx.accounts.dao.prestige_tier() >= factory.min_dao_prestige,
+        AdmissionMarketError::DAOPrestigeTooLow
+    );
+
     // Validate initial liquidity
     require!(
         params.initial_liquidity >= factory.min_initial_liquidity,
         AdmissionMarketError::InsufficientLiquidity
     );
 
+    let initial_yes_bps = params.initial_yes_bps.unwrap_or(DEFAULT_INITIAL_YES_BPS);
+    require!(
+        (MIN_INITIAL_YES_BPS..=MAX_INITIAL_YES_BPS).contains(&initial_yes_bps),
+        AdmissionMarketError::InvalidInitialPrice
+    );
+
+    // The scout must back their call with real stake
+    require!(
+        params.creator_initial_stake > 0 && params.creator_initial_stake >= factory.min_creator_stake,
+        AdmissionMarketError::CreatorStakeTooLow
+    );
+
     // Initialize market
     market.market_id = factory.market_count;
     market.dao = ctx.accounts.dao.key();
+    market.content_type = ctx.accounts.dao.content_type;
+    market.style_tag = ctx.accounts.dao.style_tag;
     market.creator_identity = ctx.accounts.predicted_creator_identity.key();
     market.creator_wallet = ctx.accounts.predicted_creator_wallet.key();
     market.market_creator = ctx.accounts.creator.key();
     market.creator_bonus_bps = factory.creator_bonus_bps;
+    market.seeder_fee_share_bps = factory.seeder_fee_share_bps;
 
-    // Initialize pools with 50/50 split (market starts at 50% probability)
+    // Initialize pools at the requested starting price (50/50 by default)
     // Vitalik: Prediction markets give real-time probability estimates
-    let half_liquidity = params.initial_liquidity / 2;
-    market.yes_pool = half_liquidity;
-    market.no_pool = half_liquidity;
+    let (yes_pool, no_pool) = AdmissionMarket::seed_pools(params.initial_liquidity, initial_yes_bps);
+    market.yes_pool = yes_pool;
+    market.no_pool = no_pool;
     market.predictor_count = 1; // Market creator counts
+    market.total_yes_tokens_issued = 0;
+    market.total_no_tokens_issued = 0;
+    market.total_net_stakes = 0;
 
     market.initial_liquidity = params.initial_liquidity;
     market.fee_bps = factory.default_fee_bps;
     market.accumulated_fees = 0;
+    market.seeder_fees_paid = 0;
+    market.seed_reclaimed = false;
+    market.lp_liquidity = 0;
+    market.lp_fees = 0;
+    market.lp_fee_per_share = 0;
 
     market.created_at = clock.unix_timestamp;
+    market.cumulative_yes_price = 0;
+    market.last_price_update_ts = clock.unix_timestamp;
     market.trading_ends_at = None;
     market.expires_at = clock.unix_timestamp + (params.expiry_days as i64 * 86400);
 
@@ -134,24 +217,98 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     market.resolved_at = None;
 
     market.burn_percentage_bps = factory.default_burn_bps;
+    market.protocol_fee_bps = factory.default_protocol_fee_bps;
+    market.self_position_burn_bps = factory.self_position_burn_bps;
     market.amount_burned = 0;
+    market.amount_to_protocol = 0;
+    market.max_position_bps = factory.max_position_bps;
+    market.min_pool_floor = factory.min_pool_floor;
+    market.challenge_period = factory.challenge_period;
 
     market.bump = ctx.bumps.market;
 
+    // Open the scout's directional position against the seeded pools
+    let side = params.creator_initial_side;
+    let stake = params.creator_initial_stake;
+    let tokens = match side {
+        PositionSide::Yes => market.calculate_yes_tokens(stake, market.fee_bps),
+        PositionSide::No => market.calculate_no_tokens(stake, market.fee_bps),
+    };
+    require!(tokens > 0, AdmissionMarketError::InvalidTradeAmount);
+    require!(
+        market.keeps_pool_floor(side, tokens),
+        AdmissionMarketError::InsufficientLiquidity
+    );
+
+    let stake_after_fee = AdmissionMarket::stake_after_fee(stake, market.fee_bps);
+    market
+        .apply_trade(side, stake_after_fee, tokens)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+    market.accumulated_fees = stake - stake_after_fee;
+    require!(
+        !market.exceeds_position_cap(stake),
+        AdmissionMarketError::PositionCapExceeded
+    );
+
+    // Already counted in predictor_count above
+    let position = &mut ctx.accounts.creator_position;
+    position.market = market.key();
+    position.predictor = ctx.accounts.creator.key();
+    position.predictor_identity = ctx.accounts.creator_identity
+        .as_ref()
+        .map(|a| a.key());
+    position.yes_tokens = if side == PositionSide::Yes { tokens } else { 0 };
+    position.no_tokens = if side == PositionSide::No { tokens } else { 0 };
+    position.total_staked = stake;
+    position.opened_at = clock.unix_timestamp;
+    position.claimed = false;
+    position.payout = 0;
+    position.last_modified = clock.unix_timestamp;
+    position.bump = ctx.bumps.creator_position;
+
+    // The seed liquidity and the scout's stake are escrowed in the market
+    // account itself
+    let escrow = params
+        .initial_liquidity
+        .checked_add(stake)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: market.to_account_info(),
+            },
+        ),
+        escrow,
+    )?;
+
     // Update factory stats
-    factory.market_count += 1;
-    factory.total_markets += 1;
-    factory.total_volume += params.initial_liquidity;
+    factory
+        .record_market(params.initial_liquidity)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
 
     // Update surfacing score
     // Vitalik: "they also provide a valuable service to the creator DAOs:
     // they are helping surface promising creators for the DAOs to choose from"
-    if surfacing_score.identity == Pubkey::default() {
-        surfacing_score.identity = ctx.accounts.creator.key();
-    }
-    surfacing_score.markets_created += 1;
+    surfacing_score.markets_created = surfacing_score
+        .markets_created
+        .checked_add(1)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
     surfacing_score.last_updated = clock.unix_timestamp;
 
+    emit!(MarketCreated {
+        market: market.key(),
+        market_id: market.market_id,
+        dao: market.dao,
+        creator_identity: market.creator_identity,
+        market_creator: market.market_creator,
+        content_type: market.content_type,
+        style_tag: market.style_tag,
+        initial_liquidity: market.initial_liquidity,
+        expires_at: market.expires_at,
+    });
+
     msg!(
         "Admission market #{} created: Will DAO '{}' accept creator {}?",
         market.market_id,
@@ -160,7 +317,11 @@ pub fn handler(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result
     );
 
     msg!(
-        "Initial probability: 50%. Market expires: {}",
+        "Scout opened {} {} tokens for {} lamports. Initial probability: {}% YES. Market expires: {}",
+        tokens,
+        match side { PositionSide::Yes => "YES", PositionSide::No => "NO" },
+        stake,
+        market.yes_price_bps() / 100,
         market.expires_at
     );
 
@@ -176,7 +337,7 @@ pub enum AdmissionMarketError {
     #[msg("DAO is not active")]
     DAONotActive,
 
-    #[msg("Initial liquidity below minimum")]
+    #[msg("Insufficient liquidity: below the initial minimum or the pool floor")]
     InsufficientLiquidity,
 
     #[msg("Market is not open for trading")]
@@ -199,4 +360,85 @@ pub enum AdmissionMarketError {
 
     #[msg("Slippage exceeded")]
     SlippageExceeded,
+
+    #[msg("Basis point settings must not exceed 10000")]
+    InvalidFeeConfig,
+
+    #[msg("DAO prestige tier is below the factory minimum")]
+    DAOPrestigeTooLow,
+
+    #[msg("Prestige tier out of range")]
+    InvalidPrestigeTier,
+
+    #[msg("Only the factory authority can perform this action")]
+    Unauthorized,
+
+    #[msg("Creator details not initialized; call init_all_details before claiming")]
+    CreatorDetailsNotInitialized,
+
+    #[msg("Creator details do not belong to the predictor's identity")]
+    CreatorDetailsMismatch,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Position would exceed the market's per-predictor cap")]
+    PositionCapExceeded,
+
+    #[msg("Market has not expired yet")]
+    MarketNotExpired,
+
+    #[msg("Market escrow cannot cover this payout")]
+    InsufficientEscrow,
+
+    #[msg("Fees can only be withdrawn from finalized or expired markets")]
+    MarketStillActive,
+
+    #[msg("No fees to withdraw")]
+    NoFeesToWithdraw,
+
+    #[msg("Comparison account is not a surfacing score")]
+    InvalidScoutAccount,
+
+    #[msg("Payout recipient must be the position's predictor")]
+    PredictorMismatch,
+
+    #[msg("Challenge period must not be negative")]
+    InvalidChallengePeriod,
+
+    #[msg("Outcome can still be disputed; claims open after the challenge period")]
+    ChallengePeriodActive,
+
+    #[msg("The challenge period for this outcome has ended")]
+    ChallengePeriodOver,
+
+    #[msg("Market outcome already matches its nomination")]
+    OutcomeNotDisputed,
+
+    #[msg("Nomination did not resolve this market")]
+    NominationMismatch,
+
+    #[msg("Market creator's opening stake is below the factory minimum")]
+    CreatorStakeTooLow,
+
+    #[msg("Initial YES price must be between 1000 and 9000 basis points")]
+    InvalidInitialPrice,
+
+    #[msg("Already holding the opposite side of this market")]
+    OpposingPositionHeld,
+
+    #[msg("Recipient must be the market's creator")]
+    MarketCreatorMismatch,
+
+    #[msg("Claim batch must be 1 to MAX_CLAIM_BATCH (market, position) pairs")]
+    InvalidClaimBatch,
+
+    #[msg("No liquidity or fees left to remove")]
+    NoLiquidityToRemove,
+
+    #[msg("Seed liquidity already reclaimed or not reclaimable")]
+    SeedNotReclaimable,
+
+    #[msg("Predictor identity does not belong to the predictor")]
+    PredictorIdentityMismatch,
 }
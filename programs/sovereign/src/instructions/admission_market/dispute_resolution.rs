@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
+use crate::state::creator_dao::Nomination;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// DISPUTE RESOLUTION INSTRUCTION
+// =============================================================================
+//
+// A resolved market holds claims for its challenge period. During that window
+// anyone can check the market's outcome against the nomination that resolved
+// it, and if they disagree, the outcome is corrected to match the
+// nomination's on-chain result before anyone is paid.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    /// Anyone can dispute
+    pub disputer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.status == MarketStatus::Resolved @ AdmissionMarketError::MarketNotResolved,
+        constraint = market.resolved_by_nomination == Some(nomination.key()) @ AdmissionMarketError::NominationMismatch,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+
+    /// The nomination the market was resolved on
+    #[account(
+        constraint = nomination.is_resolved @ AdmissionMarketError::MarketNotResolved,
+    )]
+    pub nomination: Account<'info, Nomination>,
+}
+
+pub fn handler(ctx: Context<DisputeResolution>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let market = &mut ctx.accounts.market;
+
+    require!(
+        market.in_challenge_period(now),
+        AdmissionMarketError::ChallengePeriodOver
+    );
+
+    let expected = AdmissionMarket::outcome_for(ctx.accounts.nomination.was_accepted);
+    require!(market.outcome != expected, AdmissionMarketError::OutcomeNotDisputed);
+
+    market.outcome = expected;
+
+    msg!(
+        "Market #{} outcome corrected to {} by {}",
+        market.market_id,
+        if ctx.accounts.nomination.was_accepted { "ACCEPTED" } else { "REJECTED" },
+        ctx.accounts.disputer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// EXPIRE STALE MARKET INSTRUCTION
+// =============================================================================
+//
+// A market whose creator is never nominated would otherwise stay open
+// forever with its predictors' stakes escrowed. Once it is past expires_at,
+// anyone can cancel it; claim_winnings then refunds every position its
+// full stake.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ExpireStaleMarket<'info> {
+    /// Anyone can expire a stale market
+    pub keeper: Signer<'info>,
+
+    /// The market to expire
+    #[account(
+        mut,
+        constraint = market.status == MarketStatus::Open @ AdmissionMarketError::MarketNotOpen,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+}
+
+pub fn handler(ctx: Context<ExpireStaleMarket>) -> Result<()> {
+    let clock = Clock::get()?;
+    let market = &mut ctx.accounts.market;
+
+    require!(
+        market.is_stale(clock.unix_timestamp),
+        AdmissionMarketError::MarketNotExpired
+    );
+
+    market.expire();
+
+    msg!(
+        "Market #{} expired without a nomination; {} predictor(s) can claim refunds",
+        market.market_id,
+        market.predictor_count.saturating_sub(1)
+    );
+
+    Ok(())
+}
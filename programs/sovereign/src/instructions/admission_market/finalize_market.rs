@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
+use crate::instructions::admission_market::claim_winnings::pay_from_escrow;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// FINALIZE MARKET INSTRUCTION
+// =============================================================================
+//
+// The market creator seeds the pools and carries their price risk, so they
+// earn a rebate on the trading fees: the factory's seeder_fee_share_bps of
+// the fees left once LPs took their share by liquidity. Once a resolved
+// market is past its challenge period anyone can finalize it, paying the
+// rebate out of accumulated_fees so withdraw_fees only ever sweeps what's
+// left.
+// Positions can still be claimed after finalization.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct FinalizeMarket<'info> {
+    /// Anyone can finalize a settled market
+    pub keeper: Signer<'info>,
+
+    /// The resolved market
+    #[account(
+        mut,
+        constraint = market.status == MarketStatus::Resolved @ AdmissionMarketError::MarketNotResolved,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+
+    /// The market creator, who receives the fee rebate
+    /// CHECK: Must match the market's creator
+    #[account(
+        mut,
+        constraint = market_creator.key() == market.market_creator @ AdmissionMarketError::MarketCreatorMismatch,
+    )]
+    pub market_creator: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<FinalizeMarket>) -> Result<()> {
+    let clock = Clock::get()?;
+    let market_info = ctx.accounts.market.to_account_info();
+    let market = &mut ctx.accounts.market;
+
+    require!(
+        market.claims_open(clock.unix_timestamp),
+        AdmissionMarketError::ChallengePeriodActive
+    );
+
+    let rebate = market.finalize();
+    if rebate > 0 {
+        pay_from_escrow(&market_info, &ctx.accounts.market_creator.to_account_info(), rebate)?;
+    }
+
+    msg!(
+        "Market #{} finalized; {} lamports of fees rebated to creator {}, {} left for the protocol",
+        market.market_id,
+        rebate,
+        market.market_creator,
+        market.accumulated_fees
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::SurfacingScore;
+
+// =============================================================================
+// INIT SURFACING SCORE INSTRUCTION
+// =============================================================================
+//
+// Creates the account that tracks a talent scout's surfacing record. Scouts
+// call this once before their first `create_market`, so market creation
+// costs the same every time and never allocates accounts on the side.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitSurfacingScore<'info> {
+    #[account(mut)]
+    pub scout: Signer<'info>,
+
+    #[account(
+        init,
+        payer = scout,
+        space = SurfacingScore::SIZE,
+        seeds = [b"surfacing_score", scout.key().as_ref()],
+        bump
+    )]
+    pub surfacing_score: Account<'info, SurfacingScore>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitSurfacingScore>) -> Result<()> {
+    let surfacing_score = &mut ctx.accounts.surfacing_score;
+    surfacing_score.identity = ctx.accounts.scout.key();
+    surfacing_score.successful_surfaces = 0;
+    surfacing_score.markets_created = 0;
+    surfacing_score.markets_cancelled = 0;
+    surfacing_score.surfacing_accuracy_bps = 0;
+    surfacing_score.total_profit = 0;
+    surfacing_score.scout_score = 0;
+    surfacing_score.last_updated = Clock::get()?.unix_timestamp;
+    surfacing_score.bump = ctx.bumps.surfacing_score;
+
+    msg!("Initialized surfacing score for scout {}", ctx.accounts.scout.key());
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::MarketFactory;
+use crate::state::creator_dao::MAX_PRESTIGE_TIER;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// INITIALIZE MARKET FACTORY INSTRUCTION
+// =============================================================================
+//
+// One-time setup of the global admission market configuration. The signer
+// becomes the factory authority. Must run before the first create_market.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeFactoryParams {
+    /// Default trading fee (basis points)
+    pub default_fee_bps: u16,
+    /// Default burn percentage (basis points)
+    pub default_burn_bps: u16,
+    /// Default share of a resolved pot sent to the protocol (basis points)
+    pub default_protocol_fee_bps: u16,
+    /// Minimum initial liquidity required
+    pub min_initial_liquidity: u64,
+    /// Minimum directional stake the market creator must take at creation
+    pub min_creator_stake: u64,
+    /// Smallest either AMM reserve may be drawn down to by a trade
+    pub min_pool_floor: u64,
+    /// Default expiry period (seconds from creation)
+    pub default_expiry_period: i64,
+    /// Creator bonus for correct prediction (basis points)
+    pub creator_bonus_bps: u16,
+    /// Share of trading fees rebated to market creators for seeding liquidity (basis points)
+    pub seeder_fee_share_bps: u16,
+    /// Extra burn on the predicted creator's own winning payout (basis points)
+    pub self_position_burn_bps: u16,
+    /// Minimum DAO prestige tier required to open a market on it
+    pub min_dao_prestige: u8,
+    /// Largest share of a market's pot one position may stake (basis points, 0 = uncapped)
+    pub max_position_bps: u16,
+    /// Seconds a resolved outcome stays disputable before claims open
+    pub challenge_period: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFactory<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = MarketFactory::SIZE,
+        seeds = [b"market_factory"],
+        bump
+    )]
+    pub factory: Account<'info, MarketFactory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Check the factory's basis-point settings and prestige tier are in range
+pub fn validate_factory_config(factory: &MarketFactory) -> Result<()> {
+    require!(
+        factory.default_fee_bps as u32 + factory.default_burn_bps as u32 <= 10000,
+        AdmissionMarketError::InvalidFeeConfig
+    );
+    // Burn and protocol shares come out of the pot; winners get the rest
+    require!(
+        factory.default_burn_bps as u32 + factory.default_protocol_fee_bps as u32 <= 10000,
+        AdmissionMarketError::InvalidFeeConfig
+    );
+    require!(
+        factory.creator_bonus_bps <= 10000
+            && factory.seeder_fee_share_bps <= 10000
+            && factory.self_position_burn_bps <= 10000
+            && factory.max_position_bps <= 10000,
+        AdmissionMarketError::InvalidFeeConfig
+    );
+    require!(
+        factory.min_dao_prestige <= MAX_PRESTIGE_TIER,
+        AdmissionMarketError::InvalidPrestigeTier
+    );
+    require!(
+        factory.challenge_period >= 0,
+        AdmissionMarketError::InvalidChallengePeriod
+    );
+    Ok(())
+}
+
+pub fn handler(ctx: Context<InitializeFactory>, params: InitializeFactoryParams) -> Result<()> {
+    let factory = &mut ctx.accounts.factory;
+    factory.authority = ctx.accounts.authority.key();
+    factory.market_count = 0;
+    factory.default_fee_bps = params.default_fee_bps;
+    factory.default_burn_bps = params.default_burn_bps;
+    factory.default_protocol_fee_bps = params.default_protocol_fee_bps;
+    factory.min_initial_liquidity = params.min_initial_liquidity;
+    factory.min_creator_stake = params.min_creator_stake;
+    factory.min_pool_floor = params.min_pool_floor;
+    factory.default_expiry_period = params.default_expiry_period;
+    factory.creator_bonus_bps = params.creator_bonus_bps;
+    factory.seeder_fee_share_bps = params.seeder_fee_share_bps;
+    factory.self_position_burn_bps = params.self_position_burn_bps;
+    factory.min_dao_prestige = params.min_dao_prestige;
+    factory.max_position_bps = params.max_position_bps;
+    factory.challenge_period = params.challenge_period;
+    factory.total_markets = 0;
+    factory.total_volume = 0;
+    factory.total_burned = 0;
+    factory.bump = ctx.bumps.factory;
+
+    validate_factory_config(factory)?;
+
+    msg!(
+        "Initialized market factory (fee: {} bps, burn: {} bps, protocol: {} bps, authority: {})",
+        factory.default_fee_bps,
+        factory.default_burn_bps,
+        factory.default_protocol_fee_bps,
+        factory.authority
+    );
+
+    Ok(())
+}
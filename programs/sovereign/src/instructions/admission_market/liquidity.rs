@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::admission_market::{AdmissionMarket, LiquidityPosition, MarketStatus};
+use crate::state::ProtocolConfig;
+use crate::errors::SovereignError;
+use crate::instructions::admission_market::claim_winnings::pay_from_escrow;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// LIQUIDITY PROVIDER INSTRUCTIONS
+// =============================================================================
+//
+// Beyond the seeding market creator, anyone can deepen a market's reserves
+// with matched YES/NO liquidity for tighter prices, earning a share of the
+// trading fees in return. add_liquidity splits the deposit across both
+// reserves in their current ratio, so it is not a bet and doesn't move the
+// price. remove_liquidity returns the deposit, pulling it back out of the
+// reserves while trading is still open, plus the fees earned once the market
+// is finalized.
+//
+// LP liquidity is tracked apart from the directional stakes: it never joins
+// the pot winners split, and the LPs' fees never join the fees the factory
+// sweeps.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.status == MarketStatus::Open @ AdmissionMarketError::MarketNotOpen,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = LiquidityPosition::SIZE,
+        seeds = [
+            b"liquidity_position",
+            market.key().as_ref(),
+            provider.key().as_ref()
+        ],
+        bump
+    )]
+    pub liquidity_position: Account<'info, LiquidityPosition>,
+
+    /// Program-wide pause switch
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ SovereignError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Account<'info, AdmissionMarket>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"liquidity_position",
+            market.key().as_ref(),
+            provider.key().as_ref()
+        ],
+        bump = liquidity_position.bump,
+    )]
+    pub liquidity_position: Account<'info, LiquidityPosition>,
+}
+
+pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.liquidity_position;
+
+    require!(now < market.expires_at, AdmissionMarketError::MarketExpired);
+    require!(amount > 0, AdmissionMarketError::InvalidTradeAmount);
+
+    if position.market == Pubkey::default() {
+        position.market = market.key();
+        position.provider = ctx.accounts.provider.key();
+        position.deposited = 0;
+        position.fees_owed = 0;
+        position.fees_paid = 0;
+        position.bump = ctx.bumps.liquidity_position;
+    }
+
+    // Fees earned so far were earned on the old deposit
+    position.settle_fees(market.lp_fee_per_share);
+    position.deposited = position
+        .deposited
+        .checked_add(amount)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+
+    market
+        .add_lp_liquidity(amount)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: market.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Added {} lamports of liquidity to market #{} (LP liquidity now {})",
+        amount,
+        market.market_id,
+        market.lp_liquidity
+    );
+
+    Ok(())
+}
+
+pub fn remove_liquidity(ctx: Context<RemoveLiquidity>) -> Result<()> {
+    let market_info = ctx.accounts.market.to_account_info();
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.liquidity_position;
+
+    position.settle_fees(market.lp_fee_per_share);
+
+    let deposit = position.deposited;
+    if deposit > 0 {
+        require!(
+            market.remove_lp_liquidity(deposit),
+            AdmissionMarketError::InsufficientLiquidity
+        );
+        position.deposited = 0;
+    }
+
+    // Fees wait for finalization; an expired market refunded them to traders
+    let fees = match market.status {
+        MarketStatus::Finalized => position.fees_owed.min(market.lp_fees),
+        MarketStatus::Expired => {
+            position.fees_owed = 0;
+            0
+        }
+        _ => 0,
+    };
+    if fees > 0 {
+        market.lp_fees -= fees;
+        position.fees_owed -= fees;
+        position.fees_paid += fees;
+    }
+
+    let payout = deposit + fees;
+    require!(payout > 0, AdmissionMarketError::NoLiquidityToRemove);
+    pay_from_escrow(&market_info, &ctx.accounts.provider.to_account_info(), payout)?;
+
+    msg!(
+        "Removed {} lamports of liquidity and {} in fees from market #{} ({} in fees still owed)",
+        deposit,
+        fees,
+        market.market_id,
+        position.fees_owed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, PositionSide};
+
+// =============================================================================
+// MARKET VIEW INSTRUCTIONS
+// =============================================================================
+//
+// Read-only helpers for trading UIs. Results are returned through Anchor's
+// return data, so clients can simulate these instead of recomputing the AMM
+// math off-chain.
+// =============================================================================
+
+/// Snapshot of a market's odds and AMM depth
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MarketState {
+    /// Current YES probability (basis points)
+    pub yes_price_bps: u16,
+    /// Current NO probability (basis points)
+    pub no_price_bps: u16,
+    /// YES virtual reserve
+    pub yes_pool: u64,
+    /// NO virtual reserve
+    pub no_pool: u64,
+    /// Number of positions opened
+    pub predictor_count: u32,
+    /// Trading fees collected so far
+    pub accumulated_fees: u64,
+    /// The stake the estimates below are quoted for
+    pub sample_stake: u64,
+    /// YES tokens `sample_stake` would buy now, fee included
+    pub sample_yes_tokens: u64,
+    /// NO tokens `sample_stake` would buy now, fee included
+    pub sample_no_tokens: u64,
+}
+
+impl MarketState {
+    pub fn new(market: &AdmissionMarket, sample_stake: u64) -> Self {
+        Self {
+            yes_price_bps: market.yes_price_bps(),
+            no_price_bps: market.no_price_bps(),
+            yes_pool: market.yes_pool,
+            no_pool: market.no_pool,
+            predictor_count: market.predictor_count,
+            accumulated_fees: market.accumulated_fees,
+            sample_stake,
+            sample_yes_tokens: market.calculate_yes_tokens(sample_stake, market.fee_bps),
+            sample_no_tokens: market.calculate_no_tokens(sample_stake, market.fee_bps),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct MarketView<'info> {
+    pub market: Account<'info, AdmissionMarket>,
+}
+
+/// Largest stake that moves the chosen side's probability by at most `max_impact_bps`
+pub fn get_max_stake_for_impact(
+    ctx: Context<MarketView>,
+    side: PositionSide,
+    max_impact_bps: u16,
+) -> Result<u64> {
+    Ok(ctx.accounts.market.max_stake_for_impact(side, max_impact_bps))
+}
+
+/// YES probability a buyer of `stake` effectively pays for, fee included
+pub fn get_yes_price_after_fee(ctx: Context<MarketView>, stake: u64) -> Result<u16> {
+    Ok(ctx.accounts.market.yes_price_after_fee_bps(stake))
+}
+
+/// Current odds, pool depth and the tokens a `sample_stake` would buy
+pub fn get_market_state(ctx: Context<MarketView>, sample_stake: u64) -> Result<MarketState> {
+    Ok(MarketState::new(&ctx.accounts.market, sample_stake))
+}
+
+/// Time-weighted YES probability over the last `window` seconds
+pub fn get_market_twap(ctx: Context<MarketView>, window: i64) -> Result<u16> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ctx.accounts.market.twap_bps(now, window))
+}
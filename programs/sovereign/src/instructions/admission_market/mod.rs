@@ -1,7 +1,33 @@
+pub mod initialize_factory;
+pub mod init_surfacing_score;
 pub mod create_market;
 pub mod take_position;
+pub mod liquidity;
 pub mod claim_winnings;
+pub mod claim_winnings_for;
+pub mod claim_all_winnings;
+pub mod dispute_resolution;
+pub mod expire_stale_market;
+pub mod reclaim_seed_liquidity;
+pub mod withdraw_fees;
+pub mod finalize_market;
+pub mod market_views;
+pub mod scout_rank;
+pub mod update_factory_config;
 
+pub use initialize_factory::*;
+pub use init_surfacing_score::*;
 pub use create_market::*;
 pub use take_position::*;
+pub use liquidity::*;
 pub use claim_winnings::*;
+pub use claim_winnings_for::*;
+pub use claim_all_winnings::*;
+pub use dispute_resolution::*;
+pub use expire_stale_market::*;
+pub use reclaim_seed_liquidity::*;
+pub use withdraw_fees::*;
+pub use finalize_market::*;
+pub use market_views::*;
+pub use scout_rank::*;
+pub use update_factory_config::*;
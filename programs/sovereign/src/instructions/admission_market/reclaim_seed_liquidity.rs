@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, MarketOutcome};
+use crate::instructions::admission_market::claim_winnings::pay_from_escrow;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// RECLAIM SEED LIQUIDITY INSTRUCTION
+// =============================================================================
+//
+// A cancelled market (expired, or cancelled when its nomination fell
+// through) refunds each position its stake, but the liquidity the scout
+// seeded the pools with belongs to no position. Once the market is
+// cancelled the scout can take that seed back out of the escrow, once.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ReclaimSeedLiquidity<'info> {
+    /// The market creator who seeded the pools
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The cancelled market
+    #[account(
+        mut,
+        constraint = market.market_creator == creator.key() @ AdmissionMarketError::MarketCreatorMismatch,
+        constraint = market.outcome == MarketOutcome::Cancelled @ AdmissionMarketError::MarketNotResolved,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+}
+
+pub fn handler(ctx: Context<ReclaimSeedLiquidity>) -> Result<()> {
+    let market_info = ctx.accounts.market.to_account_info();
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.reclaimable_seed();
+    require!(amount > 0, AdmissionMarketError::SeedNotReclaimable);
+
+    market.seed_reclaimed = true;
+    pay_from_escrow(&market_info, &ctx.accounts.creator.to_account_info(), amount)?;
+
+    msg!(
+        "Returned {} lamports of seed liquidity from cancelled market #{} to {}",
+        amount,
+        market.market_id,
+        market.market_creator
+    );
+
+    Ok(())
+}
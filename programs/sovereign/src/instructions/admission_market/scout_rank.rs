@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::SurfacingScore;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// SCOUT RANK VIEW
+// =============================================================================
+//
+// Ranks a scout against the SurfacingScore accounts passed in
+// `remaining_accounts`, so leaderboards don't need every account fetched and
+// sorted client-side. Scout scores are only refreshed on some writes, so every
+// score is recomputed with `calculate_scout_score` before comparing.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ScoutRank {
+    /// The scout's freshly computed score
+    pub scout_score: u16,
+    /// 1 plus the number of compared scouts scoring strictly higher
+    pub rank: u32,
+    /// Number of other scouts compared against
+    pub compared: u32,
+    /// Share of compared scouts the scout matches or beats (basis points)
+    pub percentile_bps: u16,
+    /// Whether `rank` is within the requested top N
+    pub is_top_n: bool,
+}
+
+#[derive(Accounts)]
+pub struct GetScoutRank<'info> {
+    pub surfacing_score: Account<'info, SurfacingScore>,
+}
+
+/// Rank `score` among `others`
+fn rank_among(score: u16, others: &[u16], top_n: u32) -> ScoutRank {
+    let compared = others.len() as u32;
+    let above = others.iter().filter(|&&other| other > score).count() as u32;
+    let rank = above + 1;
+
+    ScoutRank {
+        scout_score: score,
+        rank,
+        compared,
+        percentile_bps: if compared == 0 {
+            10000
+        } else {
+            ((compared - above) as u64 * 10000 / compared as u64) as u16
+        },
+        is_top_n: rank <= top_n,
+    }
+}
+
+pub fn get_scout_rank(ctx: Context<GetScoutRank>, top_n: u32) -> Result<ScoutRank> {
+    let scout_key = ctx.accounts.surfacing_score.key();
+    let score = ctx.accounts.surfacing_score.calculate_scout_score();
+
+    let mut others = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        if info.key() == scout_key {
+            continue;
+        }
+        require_keys_eq!(*info.owner, crate::ID, AdmissionMarketError::InvalidScoutAccount);
+        let other = SurfacingScore::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        others.push(other.calculate_scout_score());
+    }
+
+    Ok(rank_among(score, &others, top_n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scout(successful_surfaces: u32, markets_created: u32, total_profit: i64) -> SurfacingScore {
+        SurfacingScore {
+            identity: Pubkey::default(),
+            successful_surfaces,
+            markets_created,
+            markets_cancelled: 0,
+            surfacing_accuracy_bps: (successful_surfaces * 10000 / markets_created.max(1)) as u16,
+            total_profit,
+            scout_score: 0,
+            last_updated: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn ranks_three_scouts_by_their_fresh_scores() {
+        let strong = scout(8, 10, 2_000_000_000).calculate_scout_score();
+        let middling = scout(2, 4, 0).calculate_scout_score();
+        let idle = scout(0, 0, 0).calculate_scout_score();
+        assert!(strong > middling && middling > idle);
+
+        let top = rank_among(strong, &[middling, idle], 1);
+        assert_eq!((top.rank, top.compared, top.percentile_bps, top.is_top_n), (1, 2, 10000, true));
+
+        let mid = rank_among(middling, &[strong, idle], 1);
+        assert_eq!((mid.rank, mid.percentile_bps, mid.is_top_n), (2, 5000, false));
+
+        let last = rank_among(idle, &[strong, middling], 2);
+        assert_eq!((last.rank, last.percentile_bps, last.is_top_n), (3, 0, false));
+    }
+
+    #[test]
+    fn ties_share_a_rank_and_no_comparison_is_the_top() {
+        let tied = rank_among(1000, &[1000, 1000], 1);
+        assert_eq!((tied.rank, tied.percentile_bps, tied.is_top_n), (1, 10000, true));
+
+        let alone = rank_among(0, &[], 0);
+        assert_eq!((alone.rank, alone.compared, alone.percentile_bps, alone.is_top_n), (1, 0, 10000, false));
+    }
+}
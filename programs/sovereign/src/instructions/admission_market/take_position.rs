@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::admission_market::{AdmissionMarket, MarketPosition, MarketStatus};
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::admission_market::{AdmissionMarket, MarketPosition, MarketStatus, PositionSide};
+use crate::state::{ProtocolConfig, SovereignIdentity};
+use crate::errors::SovereignError;
 use crate::instructions::admission_market::create_market::AdmissionMarketError;
 
 // =============================================================================
@@ -19,12 +22,6 @@ use crate::instructions::admission_market::create_market::AdmissionMarketError;
 // It's the actual vote of the DAO members (quality judges).
 // =============================================================================
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
-pub enum PositionSide {
-    Yes,
-    No,
-}
-
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct TakePositionParams {
     /// Amount to stake (in lamports)
@@ -42,9 +39,14 @@ pub struct TakePosition<'info> {
     #[account(mut)]
     pub predictor: Signer<'info>,
 
-    /// The predictor's SOVEREIGN identity (for prediction accuracy tracking)
-    /// CHECK: Optional
-    pub predictor_identity: Option<UncheckedAccount<'info>>,
+    /// The predictor's SOVEREIGN identity (optional, for prediction accuracy
+    /// tracking)
+    #[account(
+        seeds = [b"identity", predictor.key().as_ref()],
+        bump = predictor_identity.bump,
+        constraint = predictor_identity.owner == predictor.key() @ AdmissionMarketError::PredictorIdentityMismatch,
+    )]
+    pub predictor_identity: Option<Account<'info, SovereignIdentity>>,
 
     /// The market
     #[account(
@@ -67,6 +69,14 @@ pub struct TakePosition<'info> {
     )]
     pub position: Account<'info, MarketPosition>,
 
+    /// Program-wide pause switch
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ SovereignError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -87,12 +97,27 @@ pub fn handler(ctx: Context<TakePosition>, params: TakePositionParams) -> Result
         AdmissionMarketError::InvalidTradeAmount
     );
 
+    // A predictor can't hedge by holding both sides of the same market
+    require!(
+        !position.holds_opposite(params.side),
+        AdmissionMarketError::OpposingPositionHeld
+    );
+
     // Calculate tokens based on constant product AMM
     let tokens = match params.side {
         PositionSide::Yes => market.calculate_yes_tokens(params.amount, market.fee_bps),
         PositionSide::No => market.calculate_no_tokens(params.amount, market.fee_bps),
     };
 
+    // A stake too small to buy any tokens would only pay fees
+    require!(tokens > 0, AdmissionMarketError::InvalidTradeAmount);
+
+    // A stake so large it drains a reserve would leave the pricing degenerate
+    require!(
+        market.keeps_pool_floor(params.side, tokens),
+        AdmissionMarketError::InsufficientLiquidity
+    );
+
     // Slippage check
     require!(
         tokens >= params.min_tokens,
@@ -100,21 +125,22 @@ pub fn handler(ctx: Context<TakePosition>, params: TakePositionParams) -> Result
     );
 
     // Calculate fee
-    let fee = params.amount * market.fee_bps as u64 / 10000;
-    let amount_after_fee = params.amount - fee;
-
-    // Update market pools
-    match params.side {
-        PositionSide::Yes => {
-            market.no_pool += amount_after_fee;
-            market.yes_pool = market.yes_pool.saturating_sub(tokens);
-        }
-        PositionSide::No => {
-            market.yes_pool += amount_after_fee;
-            market.no_pool = market.no_pool.saturating_sub(tokens);
-        }
-    }
-    market.accumulated_fees += fee;
+    let amount_after_fee = AdmissionMarket::stake_after_fee(params.amount, market.fee_bps);
+    let fee = params.amount - amount_after_fee;
+
+    // Weight the outgoing price by how long it stood, then update market
+    // pools and issuance
+    market.accumulate_price(clock.unix_timestamp);
+    market
+        .apply_trade(params.side, amount_after_fee, tokens)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+    let lp_fee = market
+        .accrue_lp_fees(fee)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+    market.accumulated_fees = market
+        .accumulated_fees
+        .checked_add(fee - lp_fee)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
 
     // Initialize or update position
     if position.market == Pubkey::default() {
@@ -132,17 +158,47 @@ pub fn handler(ctx: Context<TakePosition>, params: TakePositionParams) -> Result
         position.bump = ctx.bumps.position;
 
         // New predictor
-        market.predictor_count += 1;
+        market.predictor_count = market.predictor_count
+            .checked_add(1)
+            .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
     }
 
     // Add tokens to position
     match params.side {
-        PositionSide::Yes => position.yes_tokens += tokens,
-        PositionSide::No => position.no_tokens += tokens,
+        PositionSide::Yes => {
+            position.yes_tokens = position.yes_tokens
+                .checked_add(tokens)
+                .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+        }
+        PositionSide::No => {
+            position.no_tokens = position.no_tokens
+                .checked_add(tokens)
+                .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+        }
     }
-    position.total_staked += params.amount;
+    position.total_staked = position.total_staked
+        .checked_add(params.amount)
+        .ok_or(AdmissionMarketError::ArithmeticOverflow)?;
+
+    // No single predictor may dominate the price
+    require!(
+        !market.exceeds_position_cap(position.total_staked),
+        AdmissionMarketError::PositionCapExceeded
+    );
     position.last_modified = clock.unix_timestamp;
 
+    // Escrow the full stake (fee included) in the market account
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.predictor.to_account_info(),
+                to: market.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
     // Log current market state
     let yes_prob = market.yes_price_bps();
     let no_prob = market.no_price_bps();
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::MarketFactory;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+use crate::instructions::admission_market::initialize_factory::validate_factory_config;
+
+// =============================================================================
+// UPDATE FACTORY CONFIG INSTRUCTION
+// =============================================================================
+//
+// Lets the factory authority change the defaults applied to new markets.
+// Values are validated with the same rules as initialize_market_factory.
+// Existing markets keep the values they were created with.
+//
+// set_min_dao_prestige and set_max_position_bps remain as single-value
+// shorthands for this instruction.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct UpdateFactoryConfigParams {
+    /// New default trading fee (basis points)
+    pub default_fee_bps: Option<u16>,
+    /// New default burn percentage (basis points)
+    pub default_burn_bps: Option<u16>,
+    /// New default share of a resolved pot sent to the protocol (basis points)
+    pub default_protocol_fee_bps: Option<u16>,
+    /// New minimum initial liquidity
+    pub min_initial_liquidity: Option<u64>,
+    /// New minimum directional stake for market creators
+    pub min_creator_stake: Option<u64>,
+    /// New floor on either AMM reserve after a trade
+    pub min_pool_floor: Option<u64>,
+    /// New default expiry period (seconds from creation)
+    pub default_expiry_period: Option<i64>,
+    /// New creator bonus for correct prediction (basis points)
+    pub creator_bonus_bps: Option<u16>,
+    /// New share of trading fees rebated to market creators (basis points)
+    pub seeder_fee_share_bps: Option<u16>,
+    /// New extra burn on the predicted creator's own winning payout (basis points)
+    pub self_position_burn_bps: Option<u16>,
+    /// New minimum DAO prestige tier required to open a market
+    pub min_dao_prestige: Option<u8>,
+    /// New per-position cap (basis points of the pot, 0 = uncapped)
+    pub max_position_bps: Option<u16>,
+    /// New dispute window after resolution (seconds)
+    pub challenge_period: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFactoryConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"market_factory"],
+        bump = factory.bump,
+        constraint = factory.authority == authority.key() @ AdmissionMarketError::Unauthorized,
+    )]
+    pub factory: Account<'info, MarketFactory>,
+}
+
+pub fn handler(ctx: Context<UpdateFactoryConfig>, params: UpdateFactoryConfigParams) -> Result<()> {
+    let factory = &mut ctx.accounts.factory;
+
+    if let Some(default_fee_bps) = params.default_fee_bps {
+        factory.default_fee_bps = default_fee_bps;
+    }
+    if let Some(default_burn_bps) = params.default_burn_bps {
+        factory.default_burn_bps = default_burn_bps;
+    }
+    if let Some(default_protocol_fee_bps) = params.default_protocol_fee_bps {
+        factory.default_protocol_fee_bps = default_protocol_fee_bps;
+    }
+    if let Some(min_initial_liquidity) = params.min_initial_liquidity {
+        factory.min_initial_liquidity = min_initial_liquidity;
+    }
+    if let Some(min_creator_stake) = params.min_creator_stake {
+        factory.min_creator_stake = min_creator_stake;
+    }
+    if let Some(min_pool_floor) = params.min_pool_floor {
+        factory.min_pool_floor = min_pool_floor;
+    }
+    if let Some(default_expiry_period) = params.default_expiry_period {
+        factory.default_expiry_period = default_expiry_period;
+    }
+    if let Some(creator_bonus_bps) = params.creator_bonus_bps {
+        factory.creator_bonus_bps = creator_bonus_bps;
+    }
+    if let Some(seeder_fee_share_bps) = params.seeder_fee_share_bps {
+        factory.seeder_fee_share_bps = seeder_fee_share_bps;
+    }
+    if let Some(self_position_burn_bps) = params.self_position_burn_bps {
+        factory.self_position_burn_bps = self_position_burn_bps;
+    }
+    if let Some(min_dao_prestige) = params.min_dao_prestige {
+        factory.min_dao_prestige = min_dao_prestige;
+    }
+    if let Some(max_position_bps) = params.max_position_bps {
+        factory.max_position_bps = max_position_bps;
+    }
+    if let Some(challenge_period) = params.challenge_period {
+        factory.challenge_period = challenge_period;
+    }
+
+    // A rejected update reverts the whole transaction, so nothing is persisted
+    validate_factory_config(factory)?;
+
+    msg!(
+        "Updated market factory (fee: {} bps, burn: {} bps, protocol: {} bps, min liquidity: {}, min prestige: {}, position cap: {} bps)",
+        factory.default_fee_bps,
+        factory.default_burn_bps,
+        factory.default_protocol_fee_bps,
+        factory.min_initial_liquidity,
+        factory.min_dao_prestige,
+        factory.max_position_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::admission_market::{AdmissionMarket, MarketFactory};
+use crate::instructions::admission_market::claim_winnings::pay_from_escrow;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// WITHDRAW FEES INSTRUCTION
+// =============================================================================
+//
+// Sweeps a market's accumulated trading fees out of its escrow to a
+// destination of the factory authority's choosing. Only settled markets can
+// be swept, so fees never compete with live positions for the escrow.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"market_factory"],
+        bump = factory.bump,
+        constraint = factory.authority == authority.key() @ AdmissionMarketError::Unauthorized,
+    )]
+    pub factory: Account<'info, MarketFactory>,
+
+    /// The settled market to sweep
+    #[account(
+        mut,
+        constraint = market.fees_withdrawable() @ AdmissionMarketError::MarketStillActive,
+    )]
+    pub market: Account<'info, AdmissionMarket>,
+
+    /// Where the fees go
+    /// CHECK: Any account chosen by the factory authority
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawFees>) -> Result<()> {
+    let market_info = ctx.accounts.market.to_account_info();
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.take_withdrawable_fees();
+    require!(amount > 0, AdmissionMarketError::NoFeesToWithdraw);
+
+    pay_from_escrow(&market_info, &ctx.accounts.destination.to_account_info(), amount)?;
+
+    msg!(
+        "Withdrew {} lamports of fees from market #{} to {}",
+        amount,
+        market.market_id,
+        ctx.accounts.destination.key()
+    );
+
+    Ok(())
+}
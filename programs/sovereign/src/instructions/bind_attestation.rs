@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::SovereignIdentity;
+use crate::errors::SovereignError;
+
+// =============================================================================
+// BIND ATTESTATION INSTRUCTION
+// =============================================================================
+//
+// Identities are one-per-wallet, but wallets are free. Binding a credential
+// that an attestation authority verified off-chain (and co-signs here) marks
+// the identity as verified, which DAOs can require of their nominees.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct BindAttestation<'info> {
+    pub owner: Signer<'info>,
+
+    /// The authority vouching for the credential
+    pub attestation_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+}
+
+pub fn handler(ctx: Context<BindAttestation>, attestation_hash: [u8; 32]) -> Result<()> {
+    require!(attestation_hash != [0u8; 32], SovereignError::InvalidAttestation);
+
+    let identity = &mut ctx.accounts.identity;
+    identity.attestation_hash = attestation_hash;
+    identity.attestation_authority = ctx.accounts.attestation_authority.key();
+    identity.is_verified = true;
+
+    msg!(
+        "Bound attestation for {} (attested by {})",
+        identity.owner,
+        identity.attestation_authority
+    );
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, CivicScoreDetails, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::errors::SovereignError;
+use crate::events::emit_tier_change;
+
+// =============================================================================
+// CIVIC DETAILS INSTRUCTIONS
+// =============================================================================
+//
+// Mirrors the trading-details flow: the civic authority records raw civic
+// metrics and the civic score is derived on-chain via
+// CivicScoreDetails::calculate_score.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCivicDetailsParams {
+    /// Number of problems solved
+    pub problems_solved: u64,
+    /// Prediction accuracy in basis points (0-10000)
+    pub prediction_accuracy_bps: u16,
+    /// Number of directions proposed
+    pub directions_proposed: u64,
+    /// Number of directions that won
+    pub directions_won: u64,
+    /// Current winning streak
+    pub current_streak: u16,
+    /// Community trust score (0-10000)
+    pub community_trust: u16,
+}
+
+#[derive(Accounts)]
+pub struct InitCivicDetails<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = identity.civic_authority == authority.key() @ SovereignError::Unauthorized,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CivicScoreDetails::SIZE,
+        seeds = [b"civic_details", identity.key().as_ref()],
+        bump
+    )]
+    pub civic_details: Account<'info, CivicScoreDetails>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCivicDetails<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = identity.civic_authority == authority.key() @ SovereignError::Unauthorized,
+        constraint = !identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        mut,
+        seeds = [b"civic_details", identity.key().as_ref()],
+        bump = civic_details.bump,
+        constraint = civic_details.identity == identity.key() @ SovereignError::DetailsNotInitialized,
+    )]
+    pub civic_details: Account<'info, CivicScoreDetails>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn init_civic_details(ctx: Context<InitCivicDetails>) -> Result<()> {
+    let details = &mut ctx.accounts.civic_details;
+
+    details.identity = ctx.accounts.identity.key();
+    details.problems_solved = 0;
+    details.prediction_accuracy_bps = 0;
+    details.directions_proposed = 0;
+    details.directions_won = 0;
+    details.current_streak = 0;
+    details.community_trust = 0;
+    details.last_updated = Clock::get()?.unix_timestamp;
+    details.bump = ctx.bumps.civic_details;
+
+    msg!("Initialized civic details for identity {}", details.identity);
+    Ok(())
+}
+
+pub fn update_civic_details(
+    ctx: Context<UpdateCivicDetails>,
+    params: UpdateCivicDetailsParams,
+) -> Result<()> {
+    require!(params.prediction_accuracy_bps <= 10000, SovereignError::InvalidMetric);
+    require!(params.community_trust <= 10000, SovereignError::InvalidMetric);
+    require!(
+        params.directions_won <= params.directions_proposed,
+        SovereignError::InvalidMetric
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let identity = &mut ctx.accounts.identity;
+    let details = &mut ctx.accounts.civic_details;
+
+    details.problems_solved = params.problems_solved;
+    details.prediction_accuracy_bps = params.prediction_accuracy_bps;
+    details.directions_proposed = params.directions_proposed;
+    details.directions_won = params.directions_won;
+    details.current_streak = params.current_streak;
+    details.community_trust = params.community_trust;
+    details.last_updated = now;
+
+    let score = details.calculate_score();
+
+    identity.apply_decay(now);
+    identity.civic_score = score;
+    identity.civic_updated_at = now;
+    identity.last_updated = now;
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!(
+        "Derived civic score {} from details (composite: {}, tier: {})",
+        score,
+        identity.composite_score,
+        identity.tier
+    );
+
+    Ok(())
+}
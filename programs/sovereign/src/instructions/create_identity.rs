@@ -1,5 +1,35 @@
 use anchor_lang::prelude::*;
-use crate::state::SovereignIdentity;
+use crate::state::{
+    ScoreHistory, SovereignIdentity, DEFAULT_SCORE_WEIGHTS, DEFAULT_TIER_GRACE_PERIOD,
+    SCHEMA_VERSION_CREATOR,
+};
+use crate::errors::SovereignError;
+use crate::instructions::score_history::open_history;
+
+/// Dimension authorities to delegate at creation, saving a `set_*_authority`
+/// call per oracle afterwards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitAuthorities {
+    /// Trading oracle (None = owner)
+    pub trading: Option<Pubkey>,
+    /// Civic program (None = owner)
+    pub civic: Option<Pubkey>,
+    /// Developer oracle (None = owner)
+    pub developer: Option<Pubkey>,
+    /// Infra program (None = owner)
+    pub infra: Option<Pubkey>,
+    /// Creator authority (None = left unset until the DAO side is chosen)
+    pub creator: Option<Pubkey>,
+}
+
+impl InitAuthorities {
+    /// Whether any passed authority is the default (all-zero) key
+    pub fn has_default_key(&self) -> bool {
+        [self.trading, self.civic, self.developer, self.infra, self.creator]
+            .iter()
+            .any(|authority| *authority == Some(Pubkey::default()))
+    }
+}
 
 #[derive(Accounts)]
 pub struct CreateIdentity<'info> {
@@ -15,21 +45,39 @@ pub struct CreateIdentity<'info> {
     )]
     pub identity: Account<'info, SovereignIdentity>,
 
+    /// The identity's score history, which score updates append to
+    #[account(
+        init,
+        payer = owner,
+        space = ScoreHistory::SIZE,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump
+    )]
+    pub score_history: Account<'info, ScoreHistory>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateIdentity>) -> Result<()> {
+pub fn handler(ctx: Context<CreateIdentity>, authorities: Option<InitAuthorities>) -> Result<()> {
+    let authorities = authorities.unwrap_or_default();
+    require!(!authorities.has_default_key(), SovereignError::InvalidAuthority);
+
     let identity = &mut ctx.accounts.identity;
     let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
 
     identity.owner = ctx.accounts.owner.key();
+    identity.schema_version = SCHEMA_VERSION_CREATOR;
     identity.created_at = clock.unix_timestamp;
 
-    // Initialize authorities to owner (can be changed later)
-    identity.trading_authority = ctx.accounts.owner.key();
-    identity.civic_authority = ctx.accounts.owner.key();
-    identity.developer_authority = ctx.accounts.owner.key();
-    identity.infra_authority = ctx.accounts.owner.key();
+    // Authorities default to owner (can be changed later)
+    identity.trading_authority = authorities.trading.unwrap_or(owner);
+    identity.civic_authority = authorities.civic.unwrap_or(owner);
+    identity.developer_authority = authorities.developer.unwrap_or(owner);
+    identity.infra_authority = authorities.infra.unwrap_or(owner);
+    if let Some(creator) = authorities.creator {
+        identity.creator_authority = creator;
+    }
 
     // Initialize all scores to 0
     identity.trading_score = 0;
@@ -37,14 +85,44 @@ pub fn handler(ctx: Context<CreateIdentity>) -> Result<()> {
     identity.developer_score = 0;
     identity.infra_score = 0;
 
-    // Initial composite and tier
+    // No dimension has been scored yet
+    identity.trading_updated_at = 0;
+    identity.civic_updated_at = 0;
+    identity.developer_updated_at = 0;
+    identity.infra_updated_at = 0;
+    identity.creator_updated_at = 0;
+
+    // No oracle has sequenced an update yet
+    identity.trading_update_seq = 0;
+    identity.civic_update_seq = 0;
+    identity.developer_update_seq = 0;
+    identity.infra_update_seq = 0;
+    identity.creator_update_seq = 0;
+
+    // Initial weights, composite and tier
+    identity.weights = DEFAULT_SCORE_WEIGHTS;
     identity.composite_score = 0;
     identity.tier = 1;
+    identity.tier_demotion_pending_since = None;
+    identity.tier_grace_period = DEFAULT_TIER_GRACE_PERIOD;
+    identity.decay_bps_per_day = 0;
+
+    // Unverified until an attestation authority co-signs a binding
+    identity.attestation_hash = [0; 32];
+    identity.attestation_authority = Pubkey::default();
+    identity.is_verified = false;
+    identity.display_name = [0; 32];
+    identity.metadata_uri = [0; 96];
+    identity.is_frozen = false;
+    identity.transferred_to = None;
 
     identity.last_updated = clock.unix_timestamp;
     identity.bump = ctx.bumps.identity;
 
-    msg!("Created SOVEREIGN identity for {}", ctx.accounts.owner.key());
+    let identity_key = identity.key();
+    open_history(&mut ctx.accounts.score_history, identity_key, ctx.bumps.score_history);
+
+    msg!("Created SOVEREIGN identity for {}", owner);
 
     Ok(())
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::creator_dao::{CreatorDAO, DAOMembership, MAX_DAO_MEMBERS};
+use crate::state::creator_dao::{CreatorDAO, DAOMembership, MemberDirectory, MAX_DAO_MEMBERS};
+use crate::state::SovereignIdentity;
 use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+use crate::errors::SovereignError;
 
 // =============================================================================
 // ADD FOUNDER MEMBER INSTRUCTION
@@ -10,7 +12,11 @@ use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 // alignment with the desired style."
 //
 // Only the founder can add initial members. This is the "hand-picking" phase.
-// After initial setup, members can only be added via nomination/voting.
+// Once the founder calls close_founder_phase, members can only be added via
+// nomination/voting.
+//
+// The founder must add themselves first, so every DAO's member #1 is its
+// founder; the membership PDA keeps them from being added twice.
 // =============================================================================
 
 #[derive(Accounts)]
@@ -26,16 +32,26 @@ pub struct AddFounderMember<'info> {
     #[account(
         mut,
         constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+        constraint = dao.founder_phase_open @ CreatorDAOError::FounderPhaseClosed,
         constraint = (dao.member_count as usize) < MAX_DAO_MEMBERS @ CreatorDAOError::MaxMembersReached,
     )]
     pub dao: Account<'info, CreatorDAO>,
 
-    /// The new member's SOVEREIGN identity
-    /// CHECK: Will be validated exists
-    pub member_identity: UncheckedAccount<'info>,
+    /// The new member's SOVEREIGN identity, whose creator score counts
+    /// towards the DAO's quality
+    #[account(
+        seeds = [b"identity", member_wallet.key().as_ref()],
+        bump = member_identity.bump,
+        constraint = !member_identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub member_identity: Account<'info, SovereignIdentity>,
 
     /// The new member's wallet
     /// CHECK: Used as identifier
+    #[account(
+        constraint = dao.member_count > 0 || member_wallet.key() == founder.key()
+            @ CreatorDAOError::FounderMustJoinFirst,
+    )]
     pub member_wallet: UncheckedAccount<'info>,
 
     /// New membership account
@@ -52,6 +68,16 @@ pub struct AddFounderMember<'info> {
     )]
     pub membership: Account<'info, DAOMembership>,
 
+    /// The new member's directory of DAOs
+    #[account(
+        init_if_needed,
+        payer = founder,
+        space = MemberDirectory::SIZE,
+        seeds = [b"member_directory", member_wallet.key().as_ref()],
+        bump
+    )]
+    pub member_directory: Account<'info, MemberDirectory>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -68,12 +94,27 @@ pub fn handler(ctx: Context<AddFounderMember>) -> Result<()> {
     membership.nominated_by = None; // Founder-added, no nominator
     membership.successful_nominations = 0;
     membership.votes_cast = 0;
+    membership.last_nominated_at = 0;
     membership.is_active = true;
+    membership.pending_consent = false;
+    membership.consent_deadline = 0;
+    membership.delegate = None;
+    membership.delegation_scope = None;
+    membership.counted_creator_score = ctx.accounts.member_identity.creator_score;
     membership.bump = ctx.bumps.membership;
 
-    // Update DAO member count
+    // Update DAO member count and quality
+    dao.add_member_score(membership.counted_creator_score)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
     dao.member_count += 1;
 
+    let directory = &mut ctx.accounts.member_directory;
+    directory.wallet = membership.member_wallet;
+    directory.bump = ctx.bumps.member_directory;
+    if !directory.add(dao.key()) {
+        msg!("Member directory full; DAO not recorded");
+    }
+
     msg!(
         "Founder added member {} to DAO '{}'. Total members: {}",
         ctx.accounts.member_wallet.key(),
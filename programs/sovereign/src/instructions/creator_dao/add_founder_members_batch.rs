@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use crate::state::creator_dao::{CreatorDAO, DAOMembership, MemberDirectory, MAX_DAO_MEMBERS};
+use crate::state::SovereignIdentity;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+use crate::errors::SovereignError;
+
+// =============================================================================
+// ADD FOUNDER MEMBERS BATCH INSTRUCTION
+// =============================================================================
+//
+// Vitalik: "Hand-pick the initial membership set, in order to maximize its
+// alignment with the desired style."
+//
+// add_founder_member for up to MAX_FOUNDER_BATCH members in one transaction.
+// Each member is passed in `remaining_accounts` as a triple:
+//
+//   (member_identity, membership PDA, member_directory PDA)
+//
+// The wallet is read from the identity, which is enough to derive both PDAs,
+// so it isn't passed separately; that keeps a full batch inside the
+// transaction size limit. The same rules as add_founder_member apply: the
+// founder joins first, nobody joins twice, and the DAO stays within
+// MAX_DAO_MEMBERS.
+// =============================================================================
+
+/// Most members one batch can add
+pub const MAX_FOUNDER_BATCH: usize = 10;
+
+/// Accounts each member takes up in `remaining_accounts`
+const ACCOUNTS_PER_MEMBER: usize = 3;
+
+#[derive(Accounts)]
+pub struct AddFounderMembersBatch<'info> {
+    /// The founder (must match DAO founder), paying for every new account
+    #[account(
+        mut,
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub founder: Signer<'info>,
+
+    /// The DAO
+    #[account(
+        mut,
+        constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+        constraint = dao.founder_phase_open @ CreatorDAOError::FounderPhaseClosed,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a program-owned PDA of `space` bytes, paid for by `payer`
+pub(crate) fn create_pda<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: target.clone(),
+            },
+            &[seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, AddFounderMembersBatch<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty()
+            && remaining.len().is_multiple_of(ACCOUNTS_PER_MEMBER)
+            && remaining.len() / ACCOUNTS_PER_MEMBER <= MAX_FOUNDER_BATCH,
+        CreatorDAOError::InvalidMemberBatch
+    );
+    let batch_size = remaining.len() / ACCOUNTS_PER_MEMBER;
+
+    let dao = &mut ctx.accounts.dao;
+    require!(
+        dao.member_count as usize + batch_size <= MAX_DAO_MEMBERS,
+        CreatorDAOError::MaxMembersReached
+    );
+
+    let founder = ctx.accounts.founder.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let dao_key = dao.key();
+    let clock = Clock::get()?;
+    let mut member_count = dao.member_count;
+
+    for member in remaining.chunks(ACCOUNTS_PER_MEMBER) {
+        let (identity_info, membership_info, directory_info) = (&member[0], &member[1], &member[2]);
+
+        // The identity must be a real SOVEREIGN identity at its own PDA
+        require_keys_eq!(*identity_info.owner, crate::ID, CreatorDAOError::MemberAccountMismatch);
+        let identity = SovereignIdentity::try_deserialize(&mut &identity_info.try_borrow_data()?[..])?;
+        let wallet = identity.owner;
+        let identity_pda = Pubkey::create_program_address(
+            &[b"identity", wallet.as_ref(), &[identity.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| error!(CreatorDAOError::MemberAccountMismatch))?;
+        require_keys_eq!(identity_pda, identity_info.key(), CreatorDAOError::MemberAccountMismatch);
+        require!(!identity.is_frozen, SovereignError::IdentityFrozen);
+
+        require!(
+            member_count > 0 || wallet == ctx.accounts.founder.key(),
+            CreatorDAOError::FounderMustJoinFirst
+        );
+
+        // Membership: a fresh PDA, like add_founder_member's `init`
+        let (membership_pda, membership_bump) = Pubkey::find_program_address(
+            &[b"dao_membership", dao_key.as_ref(), wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(membership_pda, membership_info.key(), CreatorDAOError::MemberAccountMismatch);
+        require!(membership_info.data_is_empty(), CreatorDAOError::AlreadyMember);
+        create_pda(
+            &founder,
+            membership_info,
+            &system_program,
+            DAOMembership::SIZE,
+            &[b"dao_membership", dao_key.as_ref(), wallet.as_ref(), &[membership_bump]],
+        )?;
+        let membership = DAOMembership {
+            dao: dao_key,
+            member_identity: identity_info.key(),
+            member_wallet: wallet,
+            admitted_at: clock.unix_timestamp,
+            nominated_by: None, // Founder-added, no nominator
+            successful_nominations: 0,
+            votes_cast: 0,
+            last_nominated_at: 0,
+            is_active: true,
+            pending_consent: false,
+            consent_deadline: 0,
+            delegate: None,
+            delegation_scope: None,
+            counted_creator_score: identity.creator_score,
+            bump: membership_bump,
+        };
+        membership.try_serialize(&mut &mut membership_info.try_borrow_mut_data()?[..])?;
+
+        // Directory: created on first use, like add_founder_member's
+        // `init_if_needed`
+        let (directory_pda, directory_bump) = Pubkey::find_program_address(
+            &[b"member_directory", wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(directory_pda, directory_info.key(), CreatorDAOError::MemberAccountMismatch);
+        let mut directory = if directory_info.data_is_empty() {
+            create_pda(
+                &founder,
+                directory_info,
+                &system_program,
+                MemberDirectory::SIZE,
+                &[b"member_directory", wallet.as_ref(), &[directory_bump]],
+            )?;
+            MemberDirectory {
+                wallet,
+                daos: Vec::new(),
+                bump: directory_bump,
+            }
+        } else {
+            require_keys_eq!(*directory_info.owner, crate::ID, CreatorDAOError::MemberAccountMismatch);
+            MemberDirectory::try_deserialize(&mut &directory_info.try_borrow_data()?[..])?
+        };
+        if !directory.add(dao_key) {
+            msg!("Member directory full for {}; DAO not recorded", wallet);
+        }
+        directory.try_serialize(&mut &mut directory_info.try_borrow_mut_data()?[..])?;
+
+        dao.add_member_score(identity.creator_score)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+        member_count += 1;
+    }
+
+    // Update DAO member count once for the whole batch
+    dao.member_count = member_count;
+
+    msg!(
+        "Founder added {} members to DAO '{}'. Total members: {}",
+        batch_size,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        dao.member_count
+    );
+
+    Ok(())
+}
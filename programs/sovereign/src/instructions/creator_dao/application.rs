@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{
+    Application, CreatorDAO, DAOMembership, DAOTreasury, Nomination, NomineeLock,
+    MAX_PENDING_APPLICATIONS, MAX_PENDING_NOMINATIONS,
+};
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
+use crate::state::{ProtocolConfig, SovereignIdentity};
+use crate::errors::SovereignError;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+use crate::instructions::creator_dao::nominate_creator::{open_nomination, NominationParts};
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
+
+// =============================================================================
+// APPLICATION INSTRUCTIONS
+// =============================================================================
+//
+// Vitalik: "anyone can become a creator"
+//
+// Only members can nominate, so apply_for_admission gives creators a way to
+// ask for themselves. An application does nothing on its own: a member either
+// sponsors it, which opens a regular nomination with the member as nominator
+// (cooldown, fee and all), or rejects it. Both close the application and
+// return its rent to the applicant.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApplyForAdmissionParams {
+    /// Why the applicant fits the DAO (max 256 chars)
+    pub pitch: String,
+}
+
+#[derive(Accounts)]
+pub struct ApplyForAdmission<'info> {
+    /// The creator applying
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    /// The applicant's SOVEREIGN identity (must exist)
+    #[account(
+        seeds = [b"identity", applicant.key().as_ref()],
+        bump = applicant_identity.bump,
+        constraint = !applicant_identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub applicant_identity: Account<'info, SovereignIdentity>,
+
+    /// The DAO applied to
+    #[account(
+        mut,
+        constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+        constraint = dao.pending_applications < MAX_PENDING_APPLICATIONS as u8 @ CreatorDAOError::MaxPendingApplications,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The applicant's membership PDA in this DAO (usually not created yet)
+    /// CHECK: Only deserialized in the handler if it already exists
+    #[account(
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            applicant.key().as_ref()
+        ],
+        bump
+    )]
+    pub applicant_membership: UncheckedAccount<'info>,
+
+    /// One open application per (DAO, applicant)
+    #[account(
+        init,
+        payer = applicant,
+        space = Application::SIZE,
+        seeds = [
+            b"application",
+            dao.key().as_ref(),
+            applicant.key().as_ref()
+        ],
+        bump
+    )]
+    pub application: Account<'info, Application>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SponsorApplication<'info> {
+    /// The sponsoring member, who becomes the nominator
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    /// The sponsor's membership account
+    #[account(
+        mut,
+        constraint = sponsor_membership.dao == dao.key() @ CreatorDAOError::NotMember,
+        constraint = sponsor_membership.member_wallet == sponsor.key() @ CreatorDAOError::NotMember,
+        constraint = sponsor_membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub sponsor_membership: Account<'info, DAOMembership>,
+
+    /// The sponsor's SOVEREIGN identity, checked against the DAO's minimum
+    #[account(
+        seeds = [b"identity", sponsor.key().as_ref()],
+        bump = sponsor_identity.bump,
+        constraint = !sponsor_identity.is_frozen @ SovereignError::IdentityFrozen,
+        constraint = sponsor_identity.creator_score >= dao.min_nominator_creator_score @ CreatorDAOError::NominatorScoreTooLow,
+    )]
+    pub sponsor_identity: Account<'info, SovereignIdentity>,
+
+    /// The DAO
+    #[account(
+        mut,
+        constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+        constraint = dao.pending_nominations < MAX_PENDING_NOMINATIONS as u8 @ CreatorDAOError::MaxPendingNominations,
+        constraint = dao.can_open_nominations() @ CreatorDAOError::DAOTooSmall,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The application being sponsored, closed back to the applicant
+    #[account(
+        mut,
+        close = applicant_wallet,
+        seeds = [
+            b"application",
+            dao.key().as_ref(),
+            applicant_wallet.key().as_ref()
+        ],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+
+    /// The applicant's SOVEREIGN identity, which must belong to applicant_wallet
+    #[account(
+        seeds = [b"identity", applicant_wallet.key().as_ref()],
+        bump = applicant_identity.bump,
+        constraint = !applicant_identity.is_frozen @ SovereignError::IdentityFrozen,
+        constraint = applicant_identity.owner == applicant_wallet.key() @ CreatorDAOError::NomineeIdentityMismatch,
+    )]
+    pub applicant_identity: Account<'info, SovereignIdentity>,
+
+    /// The applicant's wallet (receives the application's rent)
+    /// CHECK: Must be the application's applicant
+    #[account(
+        mut,
+        address = application.applicant_wallet @ CreatorDAOError::ApplicationMismatch,
+    )]
+    pub applicant_wallet: UncheckedAccount<'info>,
+
+    /// The applicant's membership PDA in this DAO (usually not created yet)
+    /// CHECK: Only deserialized in the handler if it already exists
+    #[account(
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            applicant_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub applicant_membership: UncheckedAccount<'info>,
+
+    /// The nomination account to create
+    #[account(
+        init,
+        payer = sponsor,
+        space = Nomination::SIZE,
+        seeds = [
+            b"nomination",
+            dao.key().as_ref(),
+            &dao.nomination_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// One open nomination per (DAO, nominee)
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = NomineeLock::SIZE,
+        seeds = [
+            b"nominee_lock",
+            dao.key().as_ref(),
+            applicant_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub nominee_lock: Account<'info, NomineeLock>,
+
+    /// When the nominee was last rejected by this DAO (usually not created)
+    /// CHECK: Only deserialized in the handler if it already exists
+    #[account(
+        seeds = [
+            b"rejection_record",
+            dao.key().as_ref(),
+            applicant_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub rejection_record: UncheckedAccount<'info>,
+
+    /// Optional: Prediction market on the applicant, linked to the
+    /// nomination and locked for the vote
+    #[account(
+        mut,
+        constraint = prediction_market.dao == dao.key() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.creator_identity == applicant_identity.key() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.status == MarketStatus::Open @ AdmissionMarketError::MarketNotOpen,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// The DAO treasury, required when the DAO charges a nomination fee
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DAOTreasury>>,
+
+    /// Program-wide pause switch
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ SovereignError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectApplication<'info> {
+    /// Any active member can turn an application down
+    pub member: Signer<'info>,
+
+    #[account(
+        constraint = member_membership.dao == dao.key() @ CreatorDAOError::NotMember,
+        constraint = member_membership.member_wallet == member.key() @ CreatorDAOError::NotMember,
+        constraint = member_membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub member_membership: Account<'info, DAOMembership>,
+
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The application being rejected, closed back to the applicant
+    #[account(
+        mut,
+        close = applicant_wallet,
+        seeds = [
+            b"application",
+            dao.key().as_ref(),
+            applicant_wallet.key().as_ref()
+        ],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+
+    /// CHECK: Must be the application's applicant
+    #[account(
+        mut,
+        address = application.applicant_wallet @ CreatorDAOError::ApplicationMismatch,
+    )]
+    pub applicant_wallet: UncheckedAccount<'info>,
+}
+
+pub fn apply_for_admission(ctx: Context<ApplyForAdmission>, params: ApplyForAdmissionParams) -> Result<()> {
+    // Active members have nothing to apply for
+    let membership_info = ctx.accounts.applicant_membership.to_account_info();
+    if membership_info.owner == &crate::ID && !membership_info.data_is_empty() {
+        let membership = DAOMembership::try_deserialize(&mut &membership_info.data.borrow()[..])?;
+        require!(!membership.is_active, CreatorDAOError::AlreadyMember);
+    }
+
+    let mut pitch = [0u8; 256];
+    let pitch_slice = params.pitch.as_bytes();
+    let pitch_len = pitch_slice.len().min(256);
+    pitch[..pitch_len].copy_from_slice(&pitch_slice[..pitch_len]);
+
+    let dao = &mut ctx.accounts.dao;
+    let application = &mut ctx.accounts.application;
+    application.dao = dao.key();
+    application.applicant_identity = ctx.accounts.applicant_identity.key();
+    application.applicant_wallet = ctx.accounts.applicant.key();
+    application.pitch = pitch;
+    application.created_at = Clock::get()?.unix_timestamp;
+    application.bump = ctx.bumps.application;
+
+    dao.pending_applications += 1;
+
+    msg!(
+        "Creator {} applied to DAO '{}' ({} open applications)",
+        application.applicant_wallet,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        dao.pending_applications
+    );
+
+    Ok(())
+}
+
+pub fn sponsor_application(ctx: Context<SponsorApplication>) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let pitch = accounts.application.pitch;
+
+    accounts.dao.pending_applications = accounts.dao.pending_applications.saturating_sub(1);
+
+    open_nomination(
+        NominationParts {
+            nominator: &accounts.sponsor,
+            nominator_membership: &mut accounts.sponsor_membership,
+            dao: &mut accounts.dao,
+            nominee_identity: &accounts.applicant_identity,
+            nominee_wallet: accounts.applicant_wallet.key(),
+            nominee_membership: &accounts.applicant_membership,
+            nomination: &mut accounts.nomination,
+            nomination_bump: ctx.bumps.nomination,
+            nominee_lock: &mut accounts.nominee_lock,
+            nominee_lock_bump: ctx.bumps.nominee_lock,
+            rejection_record: &accounts.rejection_record,
+            prediction_market: accounts.prediction_market.as_mut(),
+            treasury: accounts.treasury.as_mut(),
+            system_program: &accounts.system_program,
+        },
+        &pitch,
+    )?;
+
+    msg!("Application by {} sponsored by {}", accounts.applicant_wallet.key(), accounts.sponsor.key());
+
+    Ok(())
+}
+
+pub fn reject_application(ctx: Context<RejectApplication>) -> Result<()> {
+    let dao = &mut ctx.accounts.dao;
+    dao.pending_applications = dao.pending_applications.saturating_sub(1);
+
+    msg!(
+        "Application by {} to DAO '{}' rejected by {}",
+        ctx.accounts.applicant_wallet.key(),
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        ctx.accounts.member.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOTreasury, Nomination, NomineeLock};
+use crate::state::admission_market::AdmissionMarket;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// CANCEL EXPIRED NOMINATION INSTRUCTION
+// =============================================================================
+//
+// resolve_nomination requires quorum, so a nomination nobody votes on could
+// otherwise stay pending forever and eventually exhaust the DAO's pending
+// nomination slots. Once voting has ended without quorum, anyone can close it
+// out as rejected-by-timeout. The nominator's record is not touched. A linked
+// prediction market reopens for trading if it hasn't expired yet, so a
+// re-nomination can still be priced; otherwise it is cancelled so
+// predictors are refunded.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CancelExpiredNomination<'info> {
+    /// Anyone can cancel a nomination that timed out
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The DAO
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The nomination that never reached quorum
+    #[account(
+        mut,
+        constraint = nomination.dao == dao.key(),
+        constraint = !nomination.is_resolved @ CreatorDAOError::AlreadyResolved,
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// The nominee's pending-nomination lock, released on cancellation
+    #[account(
+        mut,
+        close = caller,
+        seeds = [
+            b"nominee_lock",
+            dao.key().as_ref(),
+            nomination.nominee_wallet.as_ref()
+        ],
+        bump = nominee_lock.bump,
+    )]
+    pub nominee_lock: Account<'info, NomineeLock>,
+
+    /// The prediction market linked to this nomination, required if it has one
+    #[account(
+        mut,
+        address = nomination.linked_market.unwrap_or_default() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.is_unsettled() @ CreatorDAOError::MarketAlreadySettled,
+        constraint = prediction_market.nomination == Some(nomination.key()) @ CreatorDAOError::MarketMismatch,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// The DAO treasury, required if a nomination fee was paid
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DAOTreasury>>,
+}
+
+pub fn handler(ctx: Context<CancelExpiredNomination>) -> Result<()> {
+    let clock = Clock::get()?;
+    let dao = &mut ctx.accounts.dao;
+    let nomination = &mut ctx.accounts.nomination;
+
+    // A linked market must be settled along with its nomination
+    require!(
+        nomination.links_market(ctx.accounts.prediction_market.as_ref().map(|market| market.key())),
+        CreatorDAOError::LinkedMarketRequired
+    );
+
+    require!(
+        !nomination.voting_open(clock.unix_timestamp),
+        CreatorDAOError::VotingNotEnded
+    );
+    require!(
+        nomination.tally_final(clock.unix_timestamp),
+        CreatorDAOError::RevealStillOpen
+    );
+    require!(
+        nomination.can_time_out(clock.unix_timestamp, Some(dao.member_count)),
+        CreatorDAOError::QuorumReached
+    );
+
+    nomination.is_resolved = true;
+    nomination.was_accepted = false;
+    nomination.timed_out = true;
+    nomination.resolved_at = Some(clock.unix_timestamp);
+
+    dao.pending_nominations = dao.pending_nominations.saturating_sub(1);
+
+    // The forfeited fee stays in the treasury, no longer held for a refund
+    if nomination.fee_paid > 0 {
+        ctx.accounts
+            .treasury
+            .as_mut()
+            .ok_or(CreatorDAOError::TreasuryRequired)?
+            .release_fee(nomination.fee_paid);
+    }
+
+    if let Some(market) = &mut ctx.accounts.prediction_market {
+        if market.unlock_after_nomination(clock.unix_timestamp) {
+            msg!("Linked prediction market reopened for trading");
+        } else {
+            market.resolved_at = Some(clock.unix_timestamp);
+            msg!("Linked prediction market cancelled; predictors can claim refunds");
+        }
+    }
+
+    msg!(
+        "Nomination #{} in DAO '{}' timed out without quorum ({} of {} members revealed a vote)",
+        nomination.nomination_id,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        nomination.revealed_count,
+        nomination.quorum_base(Some(dao.member_count))
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::keccak;
-use crate::state::creator_dao::{CreatorDAO, DAOMembership, Nomination, VoteRecord, VoteChoice};
+use crate::state::creator_dao::{CreatorDAO, CreatorScoreDetails, DAOMembership, Nomination, VoteRecord};
+use crate::state::admission_market::AdmissionMarket;
+use crate::events::VoteCast;
 use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 
 // =============================================================================
@@ -10,21 +11,33 @@ use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 // Vitalik: "there are N members, and they can (anonymously) vote new members
 // in and out"
 //
-// Current implementation: Semi-anonymous (hides vote choice but not participation)
-// For full anonymity: Integrate with VEIL's ZK infrastructure
+// Votes are commit-reveal: the voter submits keccak(choice || salt) and
+// reveals it with reveal_vote once voting closes. Until then nobody can see
+// how anyone voted, or the running tally.
+//
+// That is all commit-reveal hides. Votes are not yet anonymous as quoted
+// above: the voter posts their own commitment, so whoever later opens it,
+// the choice can be matched back to them. Counting choices apart from the
+// members who made them needs a proof that a ballot comes from some member
+// without saying which one (VEIL's ZK infrastructure), and is not
+// implemented. See VoteRecord for details.
+//
+// A member's delegate (see delegate_vote) may cast on their behalf. The vote
+// record is seeded by the member, not the signer, so a member and their
+// delegate share a single vote: whichever of them commits first.
 // =============================================================================
 
 #[derive(Accounts)]
 pub struct CastVote<'info> {
-    /// The voter (must be existing DAO member)
+    /// The voter (must be existing DAO member), or their delegate
     #[account(mut)]
     pub voter: Signer<'info>,
 
-    /// The voter's membership account
+    /// The membership of the member whose vote this is
     #[account(
         mut,
         constraint = voter_membership.dao == dao.key() @ CreatorDAOError::NotMember,
-        constraint = voter_membership.member_wallet == voter.key() @ CreatorDAOError::NotMember,
+        constraint = voter_membership.can_cast_for(&voter.key(), &nomination.key()) @ CreatorDAOError::NotDelegate,
         constraint = voter_membership.is_active @ CreatorDAOError::NotMember,
     )]
     pub voter_membership: Account<'info, DAOMembership>,
@@ -40,10 +53,12 @@ pub struct CastVote<'info> {
         mut,
         constraint = nomination.dao == dao.key(),
         constraint = !nomination.is_resolved @ CreatorDAOError::AlreadyResolved,
+        constraint = nomination.nominee_wallet != voter.key() @ CreatorDAOError::CannotVoteOnSelf,
+        constraint = nomination.nominee_wallet != voter_membership.member_wallet @ CreatorDAOError::CannotVoteOnSelf,
     )]
     pub nomination: Account<'info, Nomination>,
 
-    /// Vote record (prevents double voting)
+    /// Vote record (prevents double voting, including by member and delegate)
     #[account(
         init,
         payer = voter,
@@ -51,67 +66,102 @@ pub struct CastVote<'info> {
         seeds = [
             b"vote_record",
             nomination.key().as_ref(),
-            voter.key().as_ref()
+            voter_membership.member_wallet.as_ref()
         ],
         bump
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    /// The nomination's linked prediction market (required if it has one),
+    /// read to record the implied probability at vote time (informational only)
+    #[account(
+        address = nomination.linked_market.unwrap_or_default() @ CreatorDAOError::MarketMismatch,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// Optional: The voter's creator details, read for ByCreatorScore weighting
+    /// (votes count at the minimum weight without it)
+    #[account(
+        constraint = voter_creator_details.identity == voter_membership.member_identity @ CreatorDAOError::NotMember,
+    )]
+    pub voter_creator_details: Option<Account<'info, CreatorScoreDetails>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CastVote>, vote: VoteChoice, salt: [u8; 32]) -> Result<()> {
+pub fn handler(ctx: Context<CastVote>, commitment: [u8; 32]) -> Result<()> {
     let clock = Clock::get()?;
     let nomination = &mut ctx.accounts.nomination;
     let vote_record = &mut ctx.accounts.vote_record;
     let voter_membership = &mut ctx.accounts.voter_membership;
+    let caster = ctx.accounts.voter.key();
 
     // Check voting period
     require!(
-        clock.unix_timestamp <= nomination.voting_ends_at,
+        nomination.voting_open(clock.unix_timestamp),
         CreatorDAOError::VotingEnded
     );
+    require!(
+        nomination.links_market(ctx.accounts.prediction_market.as_ref().map(|market| market.key())),
+        CreatorDAOError::LinkedMarketRequired
+    );
 
-    // Create voter hash for semi-anonymity
-    // Vitalik: "(anonymously) vote"
-    // This hides WHO voted HOW, but not that they voted
-    // For full ZK anonymity, integrate VEIL
-    let voter_hash = keccak::hashv(&[
-        ctx.accounts.voter.key().as_ref(),
-        &nomination.nomination_id.to_le_bytes(),
-        &salt,
-    ]);
-
-    // Record vote
+    // Record the commitment only; the choice stays hidden until reveal
     vote_record.nomination = nomination.key();
-    vote_record.voter_hash = voter_hash.0;
-    vote_record.vote = vote;
+    vote_record.voter = voter_membership.member_wallet;
+    vote_record.delegate = (caster != voter_membership.member_wallet).then_some(caster);
+    vote_record.commitment = commitment;
+    vote_record.revealed = false;
     vote_record.voted_at = clock.unix_timestamp;
+    vote_record.market_prob_at_vote_bps = ctx.accounts.prediction_market
+        .as_ref()
+        .map(|market| market.yes_price_bps());
+
+    // Weight the vote per the DAO's weighting mode
+    let weight = ctx.accounts.dao.vote_weighting.weight(
+        clock.unix_timestamp - voter_membership.admitted_at,
+        ctx.accounts.voter_creator_details
+            .as_ref()
+            .map(|details| details.calculate_score(clock.unix_timestamp)),
+    );
+    vote_record.weight = weight;
     vote_record.bump = ctx.bumps.vote_record;
 
-    // Update nomination tallies
-    match vote {
-        VoteChoice::Accept => nomination.votes_accept += 1,
-        VoteChoice::Reject => nomination.votes_reject += 1,
-        VoteChoice::Abstain => nomination.votes_abstain += 1,
-    }
+    // Tallies are only updated on reveal
+    nomination.voter_count = nomination
+        .voter_count
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
 
     // Update voter stats
-    voter_membership.votes_cast += 1;
+    voter_membership.votes_cast = voter_membership
+        .votes_cast
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+    emit!(VoteCast {
+        nomination: nomination.key(),
+        voter_hash: VoteRecord::voter_hash(&voter_membership.member_wallet),
+        choice_committed: commitment,
+        weight,
+        voter_count: nomination.voter_count,
+        running_accept: nomination.votes_accept,
+        running_reject: nomination.votes_reject,
+        running_abstain: nomination.votes_abstain,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
-        "Vote cast on nomination #{}. Current tally: {} accept, {} reject, {} abstain",
+        "Vote (weight {}) committed on nomination #{}; reveal it after voting ends at {}",
+        weight,
         nomination.nomination_id,
-        nomination.votes_accept,
-        nomination.votes_reject,
-        nomination.votes_abstain
+        nomination.voting_ends_at
     );
 
     // Vitalik: "the ultimate decider of who rises and falls is not speculators,
     // but high-value content creators"
-    let total_votes = nomination.votes_accept + nomination.votes_reject + nomination.votes_abstain;
     let participation_pct = if nomination.total_members_snapshot > 0 {
-        (total_votes as u32 * 100) / nomination.total_members_snapshot as u32
+        (nomination.voter_count as u32 * 100) / nomination.total_members_snapshot as u32
     } else {
         0
     };
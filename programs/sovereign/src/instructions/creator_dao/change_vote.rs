@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOMembership, Nomination, VoteRecord};
+use crate::events::VoteCast;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// CHANGE VOTE INSTRUCTION
+// =============================================================================
+//
+// Members can revise their vote while voting is open, e.g. after new
+// information surfaces about the nominee. The vote keeps the weight it was
+// cast with; only the commitment changes, and a fresh salt keeps the new
+// commitment unlinkable to the old one.
+//
+// A delegate may change a vote they can cast; a member changing a vote their
+// delegate cast takes it back, and then reveals it themselves.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    /// The voter (must still be an active DAO member), or their delegate
+    pub voter: Signer<'info>,
+
+    /// The membership of the member whose vote this is
+    #[account(
+        constraint = voter_membership.dao == dao.key() @ CreatorDAOError::NotMember,
+        constraint = voter_membership.can_cast_for(&voter.key(), &nomination.key()) @ CreatorDAOError::NotDelegate,
+        constraint = voter_membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub voter_membership: Account<'info, DAOMembership>,
+
+    /// The DAO
+    #[account(
+        constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The nomination being voted on
+    #[account(
+        constraint = nomination.dao == dao.key(),
+        constraint = !nomination.is_resolved @ CreatorDAOError::AlreadyResolved,
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// The voter's existing vote record
+    #[account(
+        mut,
+        seeds = [
+            b"vote_record",
+            nomination.key().as_ref(),
+            voter_membership.member_wallet.as_ref()
+        ],
+        bump = vote_record.bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+pub fn handler(ctx: Context<ChangeVote>, commitment: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let nomination = &ctx.accounts.nomination;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    require!(
+        nomination.voting_open(clock.unix_timestamp),
+        CreatorDAOError::VotingEnded
+    );
+
+    let caster = ctx.accounts.voter.key();
+    let member = ctx.accounts.voter_membership.member_wallet;
+    vote_record.commitment = commitment;
+    vote_record.delegate = (caster != member).then_some(caster);
+    vote_record.voted_at = clock.unix_timestamp;
+
+    emit!(VoteCast {
+        nomination: nomination.key(),
+        voter_hash: VoteRecord::voter_hash(&member),
+        choice_committed: commitment,
+        weight: vote_record.weight,
+        voter_count: nomination.voter_count,
+        running_accept: nomination.votes_accept,
+        running_reject: nomination.votes_reject,
+        running_abstain: nomination.votes_abstain,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vote commitment changed on nomination #{}", nomination.nomination_id);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::CreatorDAO;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// CLOSE FOUNDER PHASE INSTRUCTION
+// =============================================================================
+//
+// Vitalik: "Hand-pick the initial membership set, in order to maximize its
+// alignment with the desired style."
+//
+// Ends the hand-picking phase for good. After this the founder has no more
+// power to add members than anyone else; growth happens through nominations.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CloseFounderPhase<'info> {
+    /// The founder (must match DAO founder)
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub founder: Signer<'info>,
+
+    /// The DAO
+    #[account(
+        mut,
+        constraint = dao.founder_phase_open @ CreatorDAOError::FounderPhaseClosed,
+        constraint = dao.member_count > 0 @ CreatorDAOError::NoMembers,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+}
+
+pub fn handler(ctx: Context<CloseFounderPhase>) -> Result<()> {
+    let dao = &mut ctx.accounts.dao;
+    dao.founder_phase_open = false;
+
+    msg!(
+        "Founder phase closed for DAO '{}' with {} hand-picked members",
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        dao.member_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{
+    CreatorDAO, CreatorScoreDetails, DAOMembership, ContentRecord, ContentUpvote,
+};
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// CONTENT INSTRUCTIONS
+// =============================================================================
+//
+// Vitalik: "the ultimate decider of who rises and falls is not speculators,
+// but high-value content creators"
+//
+// Fellow members of a DAO record the pieces a creator publishes and upvote
+// them. Both feed CreatorScoreDetails (content_count, peer_upvotes).
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SubmitContent<'info> {
+    /// The member recording the piece
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    /// A DAO both the submitter and the creator belong to
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The submitter's membership
+    #[account(
+        constraint = submitter_membership.dao == dao.key() @ CreatorDAOError::NotMember,
+        constraint = submitter_membership.member_wallet == submitter.key() @ CreatorDAOError::NotMember,
+        constraint = submitter_membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub submitter_membership: Account<'info, DAOMembership>,
+
+    /// The creator's membership in the same DAO
+    #[account(
+        constraint = creator_membership.dao == dao.key() @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.member_identity == creator_details.identity @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.is_active @ CreatorDAOError::CreatorNotMember,
+    )]
+    pub creator_membership: Account<'info, DAOMembership>,
+
+    /// The creator's score details
+    #[account(
+        mut,
+        seeds = [b"creator_details", creator_details.identity.as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    /// The record for this piece
+    #[account(
+        init,
+        payer = submitter,
+        space = ContentRecord::SIZE,
+        seeds = [
+            b"content",
+            creator_details.identity.as_ref(),
+            &creator_details.content_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub content: Account<'info, ContentRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpvoteContent<'info> {
+    /// The member upvoting
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// A DAO both the voter and the creator belong to
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The voter's membership
+    #[account(
+        constraint = voter_membership.dao == dao.key() @ CreatorDAOError::NotMember,
+        constraint = voter_membership.member_wallet == voter.key() @ CreatorDAOError::NotMember,
+        constraint = voter_membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub voter_membership: Account<'info, DAOMembership>,
+
+    /// The creator's membership in the same DAO
+    #[account(
+        constraint = creator_membership.dao == dao.key() @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.member_identity == content.creator_identity @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.is_active @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.member_wallet != voter.key() @ CreatorDAOError::CannotUpvoteOwnContent,
+    )]
+    pub creator_membership: Account<'info, DAOMembership>,
+
+    /// The creator's score details
+    #[account(
+        mut,
+        seeds = [b"creator_details", content.creator_identity.as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    /// The piece being upvoted
+    #[account(mut)]
+    pub content: Account<'info, ContentRecord>,
+
+    /// Upvote marker (prevents double upvotes)
+    #[account(
+        init,
+        payer = voter,
+        space = ContentUpvote::SIZE,
+        seeds = [
+            b"content_upvote",
+            content.key().as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub upvote: Account<'info, ContentUpvote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn submit_content(ctx: Context<SubmitContent>, content_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator_details = &mut ctx.accounts.creator_details;
+    let content = &mut ctx.accounts.content;
+
+    content.creator_identity = creator_details.identity;
+    content.index = creator_details.content_count;
+    content.content_hash = content_hash;
+    content.submitted_by = ctx.accounts.submitter.key();
+    content.submitted_at = clock.unix_timestamp;
+    content.upvotes = 0;
+    content.bump = ctx.bumps.content;
+
+    creator_details.content_count += 1;
+    creator_details.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Content #{} recorded for creator {} ({} total)",
+        content.index,
+        creator_details.identity,
+        creator_details.content_count
+    );
+
+    Ok(())
+}
+
+pub fn upvote_content(ctx: Context<UpvoteContent>) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator_details = &mut ctx.accounts.creator_details;
+    let content = &mut ctx.accounts.content;
+    let upvote = &mut ctx.accounts.upvote;
+
+    upvote.content = content.key();
+    upvote.voter = ctx.accounts.voter.key();
+    upvote.upvoted_at = clock.unix_timestamp;
+    upvote.bump = ctx.bumps.upvote;
+
+    content.upvotes += 1;
+    creator_details.peer_upvotes += 1;
+    creator_details.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Content #{} upvoted ({} upvotes, {} peer upvotes total)",
+        content.index,
+        content.upvotes,
+        creator_details.peer_upvotes
+    );
+
+    Ok(())
+}
@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::creator_dao::{CreatorDAO, ContentType, MAX_DAO_MEMBERS};
+use crate::state::creator_dao::{
+    CreatorDAO, ContentType, DAORegistryEntry, DAORegistryPage, Region, VoteWeighting,
+    DEFAULT_MIN_MEMBERS_FOR_NOMINATIONS, MAX_DAO_MEMBERS,
+};
+use crate::state::{ProtocolConfig, SovereignIdentity, MIN_VOTING_PERIOD};
+use crate::events::DAOCreated;
+use crate::errors::SovereignError;
 
 // =============================================================================
 // CREATE DAO INSTRUCTION
@@ -9,7 +15,14 @@ use crate::state::creator_dao::{CreatorDAO, ContentType, MAX_DAO_MEMBERS};
 // alignment with the desired style."
 //
 // The founder creates the DAO with specific opinionated parameters.
-// They then add initial members using add_founder_member instruction.
+// They then add themselves and the initial members using the
+// add_founder_member instruction.
+//
+// To keep out spam DAOs the founder's identity must carry at least the
+// protocol's min_founder_composite score.
+//
+// The new DAO is listed on the registry page for its ID (see DAORegistryPage)
+// so clients can discover it by content type or style tag.
 // =============================================================================
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,14 +35,33 @@ pub struct CreateDAOParams {
     pub content_type: ContentType,
     /// Style tag (opinionated niche)
     pub style_tag: String,
-    /// Region code (0 for global)
+    /// Region code (0 for global, see `Region`)
     pub region_code: u16,
+    /// Reject region codes that aren't a known `Region`
+    pub strict_region: bool,
     /// Percentage needed to admit (e.g., 60)
     pub admission_threshold: u8,
     /// Voting period in seconds
     pub voting_period: i64,
     /// Quorum percentage
     pub quorum: u8,
+    /// Distinct accept voters needed to admit, on top of quorum and
+    /// threshold (at least 1)
+    pub min_accept_votes: u16,
+    /// How member votes are weighted
+    pub vote_weighting: VoteWeighting,
+    /// Attestation authority nominees must be verified by (None = no requirement)
+    pub required_attestor: Option<Pubkey>,
+    /// Creator score members need before they can nominate (0 = anyone)
+    pub min_nominator_creator_score: u16,
+    /// Seconds between a member's nominations (0 = no cooldown)
+    pub nomination_cooldown: i64,
+    /// Lamports charged per nomination into the DAO treasury (0 = free)
+    pub nomination_fee: u64,
+    /// Seconds before a rejected creator can be nominated again (0 = none)
+    pub rejection_cooldown: i64,
+    /// Members needed before nominations open (None = the default of 3)
+    pub min_members_for_nominations: Option<u8>,
 }
 
 #[derive(Accounts)]
@@ -38,9 +70,14 @@ pub struct CreateDAO<'info> {
     #[account(mut)]
     pub founder: Signer<'info>,
 
-    /// The founder's SOVEREIGN identity (must exist)
-    /// CHECK: Validated in handler
-    pub founder_identity: UncheckedAccount<'info>,
+    /// The founder's SOVEREIGN identity, checked against the protocol minimum
+    #[account(
+        seeds = [b"identity", founder.key().as_ref()],
+        bump = founder_identity.bump,
+        constraint = !founder_identity.is_frozen @ SovereignError::IdentityFrozen,
+        constraint = founder_identity.composite_score >= protocol_config.min_founder_composite @ CreatorDAOError::FounderScoreTooLow,
+    )]
+    pub founder_identity: Account<'info, SovereignIdentity>,
 
     #[account(
         init,
@@ -62,6 +99,26 @@ pub struct CreateDAO<'info> {
     )]
     pub dao_counter: Account<'info, DAOCounter>,
 
+    /// Registry page the new DAO is listed on
+    #[account(
+        init_if_needed,
+        payer = founder,
+        space = DAORegistryPage::SIZE,
+        seeds = [
+            b"dao_registry".as_ref(),
+            DAORegistryPage::page_for(dao_counter.count).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub registry_page: Account<'info, DAORegistryPage>,
+
+    /// Holds the composite score founders need and the voting period cap
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -76,20 +133,52 @@ impl DAOCounter {
     pub const SIZE: usize = 8 + 8 + 1;
 }
 
-pub fn handler(ctx: Context<CreateDAO>, params: CreateDAOParams) -> Result<()> {
-    // Validate parameters
+/// Governance rules shared by create_dao and update_dao_governance
+pub fn validate_governance(
+    admission_threshold: u8,
+    quorum: u8,
+    voting_period: i64,
+    max_voting_period: i64,
+) -> Result<()> {
     require!(
-        params.admission_threshold > 0 && params.admission_threshold <= 100,
+        admission_threshold > 0 && admission_threshold <= 100,
         CreatorDAOError::InvalidThreshold
     );
     require!(
-        params.quorum > 0 && params.quorum <= 100,
+        quorum > 0 && quorum <= 100,
         CreatorDAOError::InvalidQuorum
     );
     require!(
-        params.voting_period >= 86400, // Minimum 1 day
+        voting_period >= MIN_VOTING_PERIOD,
         CreatorDAOError::VotingPeriodTooShort
     );
+    require!(
+        voting_period <= max_voting_period,
+        CreatorDAOError::VotingPeriodTooLong
+    );
+    Ok(())
+}
+
+pub fn handler(ctx: Context<CreateDAO>, params: CreateDAOParams) -> Result<()> {
+    // Validate parameters
+    validate_governance(
+        params.admission_threshold,
+        params.quorum,
+        params.voting_period,
+        ctx.accounts.protocol_config.max_voting_period,
+    )?;
+    require!(
+        params.min_nominator_creator_score <= 10000,
+        CreatorDAOError::InvalidMinNominatorScore
+    );
+    require!(params.nomination_cooldown >= 0, CreatorDAOError::InvalidNominationCooldown);
+    require!(params.rejection_cooldown >= 0, CreatorDAOError::InvalidRejectionCooldown);
+    require!(params.min_accept_votes >= 1, CreatorDAOError::InvalidMinAcceptVotes);
+    let region = Region::from_code(params.region_code);
+    require!(
+        region.is_some() || !params.strict_region,
+        CreatorDAOError::UnknownRegion
+    );
 
     // Convert strings to fixed arrays
     let mut name_bytes = [0u8; 32];
@@ -123,18 +212,54 @@ pub fn handler(ctx: Context<CreateDAO>, params: CreateDAOParams) -> Result<()> {
     dao.admission_threshold = params.admission_threshold;
     dao.voting_period = params.voting_period;
     dao.quorum = params.quorum;
+    dao.min_accept_votes = params.min_accept_votes;
+    dao.vote_weighting = params.vote_weighting;
+    dao.required_attestor = params.required_attestor;
+    dao.min_nominator_creator_score = params.min_nominator_creator_score;
+    dao.nomination_cooldown = params.nomination_cooldown;
+    dao.nomination_fee = params.nomination_fee;
+    dao.rejection_cooldown = params.rejection_cooldown;
+    dao.min_members_for_nominations = params
+        .min_members_for_nominations
+        .unwrap_or(DEFAULT_MIN_MEMBERS_FOR_NOMINATIONS);
     dao.pending_nominations = 0;
+    dao.pending_applications = 0;
     dao.total_admitted = 0;
     dao.total_removed = 0;
+    dao.total_nominations = 0;
+    dao.total_accepted = 0;
+    dao.total_rejected = 0;
+    dao.total_member_creator_score = 0;
     dao.is_active = true;
     dao.nomination_nonce = 0;
+    dao.founder_phase_open = true;
     dao.parent_dao = None;
     dao.split_count = 0;
     dao.bump = ctx.bumps.dao;
 
+    // List the DAO for discovery
+    let registry_page = &mut ctx.accounts.registry_page;
+    registry_page.page = DAORegistryPage::page_for(dao.dao_id);
+    registry_page.bump = ctx.bumps.registry_page;
+    registry_page.entries.push(DAORegistryEntry {
+        dao: dao.key(),
+        content_type: dao.content_type,
+        style_tag: dao.style_tag,
+    });
+
     // Increment counter
     counter.count += 1;
 
+    emit!(DAOCreated {
+        dao: dao.key(),
+        dao_id: dao.dao_id,
+        founder: dao.founder,
+        content_type: dao.content_type,
+        region_code: dao.region_code,
+        region,
+        timestamp: dao.created_at,
+    });
+
     msg!(
         "Created CreatorDAO '{}' with ID {} (type: {:?}, style: {})",
         params.name,
@@ -190,6 +315,9 @@ pub enum CreatorDAOError {
     #[msg("Quorum not reached")]
     QuorumNotReached,
 
+    #[msg("Quorum was reached; resolve the nomination instead")]
+    QuorumReached,
+
     #[msg("DAO is not active")]
     DAONotActive,
 
@@ -198,4 +326,157 @@ pub enum CreatorDAOError {
 
     #[msg("Maximum pending nominations reached")]
     MaxPendingNominations,
+
+    #[msg("Prediction market does not match this nomination")]
+    MarketMismatch,
+
+    #[msg("Prediction market is already resolved or expired")]
+    MarketAlreadySettled,
+
+    #[msg("This creator already has a pending nomination in this DAO")]
+    NominationAlreadyPending,
+
+    #[msg("Only the nominator can withdraw this nomination")]
+    NotNominator,
+
+    #[msg("Founder phase is closed; members can only join by nomination")]
+    FounderPhaseClosed,
+
+    #[msg("Cannot close the founder phase of a DAO with no members")]
+    NoMembers,
+
+    #[msg("New founder cannot be the default public key")]
+    InvalidFounder,
+
+    #[msg("Members cannot vote on their own nomination")]
+    CannotVoteOnSelf,
+
+    #[msg("Creator is not an active member of this DAO")]
+    CreatorNotMember,
+
+    #[msg("Creators cannot upvote their own content")]
+    CannotUpvoteOwnContent,
+
+    #[msg("Nominee identity is not verified by this DAO's attestation authority")]
+    NomineeNotVerified,
+
+    #[msg("Minimum nominator creator score must not exceed 10000")]
+    InvalidMinNominatorScore,
+
+    #[msg("Nominator's creator score is below this DAO's minimum")]
+    NominatorScoreTooLow,
+
+    #[msg("Nomination cooldown must not be negative")]
+    InvalidNominationCooldown,
+
+    #[msg("Nominator must wait out the DAO's cooldown before nominating again")]
+    NominationCooldownActive,
+
+    #[msg("Votes can only be revealed during the reveal period after voting ends")]
+    RevealNotOpen,
+
+    #[msg("Vote has already been revealed")]
+    VoteAlreadyRevealed,
+
+    #[msg("Choice and salt do not match the committed vote")]
+    CommitmentMismatch,
+
+    #[msg("Committed votes are still being revealed")]
+    RevealStillOpen,
+
+    #[msg("Treasury burn share must not exceed 10000 basis points")]
+    InvalidTreasuryBurn,
+
+    #[msg("Deposit amount must be greater than zero")]
+    InvalidDepositAmount,
+
+    #[msg("Treasury does not hold enough lamports above rent")]
+    InsufficientTreasury,
+
+    #[msg("Only the mint authority can register a mint as their creator coin")]
+    NotMintAuthority,
+
+    #[msg("Mint is not the creator's registered creator coin")]
+    CreatorMintMismatch,
+
+    #[msg("Treasury does not hold enough of the creator's coins")]
+    InsufficientCreatorCoins,
+
+    #[msg("This DAO charges a nomination fee: pass its treasury")]
+    TreasuryRequired,
+
+    #[msg("Refunding the nomination fee needs the DAO treasury and the nominator's wallet")]
+    FeeRefundAccountsRequired,
+
+    #[msg("Region code is not a known region")]
+    UnknownRegion,
+
+    #[msg("Minimum accept votes must be at least 1")]
+    InvalidMinAcceptVotes,
+
+    #[msg("The founder must be added as the DAO's first member")]
+    FounderMustJoinFirst,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Too many open applications, wait for some to be sponsored or rejected")]
+    MaxPendingApplications,
+
+    #[msg("Wallet is not the application's applicant")]
+    ApplicationMismatch,
+
+    #[msg("Nominee was rejected too recently to be nominated again")]
+    RejectionCooldownActive,
+
+    #[msg("Rejection cooldown must not be negative")]
+    InvalidRejectionCooldown,
+
+    #[msg("Founder's composite score is below the protocol minimum")]
+    FounderScoreTooLow,
+
+    #[msg("Nominee identity does not belong to the nominee wallet")]
+    NomineeIdentityMismatch,
+
+    #[msg("Member batch must hold 1 to 10 (identity, membership, directory) triples")]
+    InvalidMemberBatch,
+
+    #[msg("Member account is not the expected identity or PDA")]
+    MemberAccountMismatch,
+
+    #[msg("Membership is not awaiting the creator's consent")]
+    NotPendingConsent,
+
+    #[msg("The window to accept this membership has closed")]
+    ConsentWindowClosed,
+
+    #[msg("DAO has too few members to open nominations")]
+    DAOTooSmall,
+
+    #[msg("Voting period exceeds the protocol maximum")]
+    VotingPeriodTooLong,
+
+    #[msg("Signer is neither this member nor their delegate for this nomination")]
+    NotDelegate,
+
+    #[msg("Votes can only be delegated to another active member of the same DAO")]
+    InvalidDelegate,
+
+    #[msg("No vote delegation to revoke")]
+    NoDelegation,
+
+    #[msg("The nomination's linked prediction market must be passed")]
+    LinkedMarketRequired,
+
+    #[msg("Unrevealed votes can only be penalized once the reveal period has ended")]
+    RevealNotClosed,
+
+    #[msg("The founder can't leave the DAO; transfer it first")]
+    FounderCannotLeave,
+
+    #[msg("Votes on a withdrawn nomination can't be penalized")]
+    NominationWithdrawn,
+
+    #[msg("Unrevealed votes can only be penalized once the nomination has resolved or timed out")]
+    NominationNotSettled,
 }
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::SovereignIdentity;
+use crate::state::creator_dao::{CreatorDAO, CreatorScoreDetails, DAOMembership, DAOTreasury};
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// CREATOR COIN INSTRUCTIONS
+// =============================================================================
+//
+// Vitalik: "if they get admitted to a creator DAO, a portion of their proceeds
+// from the DAO are used to burn their creator coins"
+//
+// A creator registers their coin's SPL mint (as its mint authority). Once
+// they are a member of a DAO, the founder can settle proceeds held in the
+// DAO treasury's token account: the treasury's burn share of them is burned
+// with a real SPL Burn, signed by the treasury PDA, and added to the
+// creator's total_burned.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetCreatorMint<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_details", identity.key().as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    #[account(
+        constraint = mint.mint_authority == Some(owner.key()).into() @ CreatorDAOError::NotMintAuthority,
+    )]
+    pub mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct BurnCreatorCoins<'info> {
+    pub founder: Signer<'info>,
+
+    /// The DAO whose acceptance triggers the burn
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    #[account(
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DAOTreasury>,
+
+    /// The accepted creator
+    #[account(
+        constraint = creator_membership.dao == dao.key() @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.member_identity == creator_details.identity @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.is_active @ CreatorDAOError::CreatorNotMember,
+    )]
+    pub creator_membership: Account<'info, DAOMembership>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_details", creator_details.identity.as_ref()],
+        bump = creator_details.bump,
+        constraint = creator_details.creator_mint == Some(mint.key()) @ CreatorDAOError::CreatorMintMismatch,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    /// The creator's coin
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// The treasury's holding of the creator's coin
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn set_creator_mint(ctx: Context<SetCreatorMint>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    ctx.accounts.creator_details.creator_mint = Some(mint);
+
+    msg!("Registered creator coin mint {}", mint);
+
+    Ok(())
+}
+
+pub fn burn_creator_coins(ctx: Context<BurnCreatorCoins>, proceeds: u64) -> Result<()> {
+    require!(
+        proceeds <= ctx.accounts.treasury_token_account.amount,
+        CreatorDAOError::InsufficientCreatorCoins
+    );
+
+    let burn_amount = ctx.accounts.treasury.burn_share(proceeds);
+
+    let dao_key = ctx.accounts.dao.key();
+    let treasury_seeds: &[&[u8]] = &[
+        b"dao_treasury",
+        dao_key.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        burn_amount,
+    )?;
+
+    let creator_details = &mut ctx.accounts.creator_details;
+    creator_details.total_burned = creator_details.total_burned.saturating_add(burn_amount);
+    creator_details.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Burned {} of {} creator coins in proceeds (creator total burned: {})",
+        burn_amount,
+        proceeds,
+        creator_details.total_burned
+    );
+
+    Ok(())
+}
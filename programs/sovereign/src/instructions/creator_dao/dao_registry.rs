@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{ContentType, DAORegistryEntry, DAORegistryPage};
+
+// =============================================================================
+// DAO REGISTRY VIEW
+// =============================================================================
+//
+// Returns the DAOs on one registry page, optionally filtered by content type
+// and style tag. Clients page from 0 up to the DAO counter's count divided by
+// DAO_REGISTRY_PAGE_SIZE.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetDaoRegistryPage<'info> {
+    #[account(
+        seeds = [
+            b"dao_registry".as_ref(),
+            registry_page.page.to_le_bytes().as_ref()
+        ],
+        bump = registry_page.bump,
+    )]
+    pub registry_page: Account<'info, DAORegistryPage>,
+}
+
+pub fn handler(
+    ctx: Context<GetDaoRegistryPage>,
+    content_type: Option<ContentType>,
+    style_tag: Option<String>,
+) -> Result<Vec<DAORegistryEntry>> {
+    // Match create_dao's fixed-width encoding of the tag
+    let style_tag = style_tag.map(|tag| {
+        let mut style_bytes = [0u8; 32];
+        let style_slice = tag.as_bytes();
+        let style_len = style_slice.len().min(32);
+        style_bytes[..style_len].copy_from_slice(&style_slice[..style_len]);
+        style_bytes
+    });
+
+    Ok(ctx.accounts.registry_page.matching(content_type, style_tag))
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::DAOMembership;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// VOTE DELEGATION INSTRUCTIONS
+// =============================================================================
+//
+// Busy members can hand their vote to a trusted peer: delegate_vote lets a
+// fellow active member of the same DAO cast (and change, and reveal) the
+// member's votes, either on one nomination or on every nomination. Both
+// share the member's single vote record, so only one of them gets to vote;
+// the member can still vote first themselves. revoke_delegation takes the
+// vote back; votes the delegate already committed stand.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    /// The member handing over their vote
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"dao_membership",
+            membership.dao.as_ref(),
+            member.key().as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+
+    /// The delegate's membership in the same DAO
+    #[account(
+        constraint = delegate_membership.dao == membership.dao @ CreatorDAOError::InvalidDelegate,
+        constraint = delegate_membership.is_active @ CreatorDAOError::InvalidDelegate,
+        constraint = delegate_membership.member_wallet != member.key() @ CreatorDAOError::InvalidDelegate,
+    )]
+    pub delegate_membership: Account<'info, DAOMembership>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"dao_membership",
+            membership.dao.as_ref(),
+            member.key().as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.delegate.is_some() @ CreatorDAOError::NoDelegation,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+}
+
+pub fn delegate_vote(ctx: Context<DelegateVote>, nomination: Option<Pubkey>) -> Result<()> {
+    let delegate = ctx.accounts.delegate_membership.member_wallet;
+    let membership = &mut ctx.accounts.membership;
+    membership.delegate = Some(delegate);
+    membership.delegation_scope = nomination;
+
+    match nomination {
+        Some(nomination) => msg!("Vote on nomination {} delegated to {}", nomination, delegate),
+        None => msg!("All votes delegated to {}", delegate),
+    }
+    Ok(())
+}
+
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let membership = &mut ctx.accounts.membership;
+    membership.delegate = None;
+    membership.delegation_scope = None;
+
+    msg!("Vote delegation revoked");
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::instructions::creator_dao::create_dao::DAOCounter;
+
+// =============================================================================
+// INITIALIZE DAO COUNTER INSTRUCTION
+// =============================================================================
+//
+// One-time setup of the global counter that hands out unique DAO IDs.
+// Must run before the first create_dao.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeDAOCounter<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = DAOCounter::SIZE,
+        seeds = [b"dao_counter"],
+        bump
+    )]
+    pub dao_counter: Account<'info, DAOCounter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeDAOCounter>) -> Result<()> {
+    let counter = &mut ctx.accounts.dao_counter;
+    counter.count = 0;
+    counter.bump = ctx.bumps.dao_counter;
+
+    msg!("Initialized DAO counter");
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOMembership, MemberDirectory};
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// LEAVE DAO INSTRUCTION
+// =============================================================================
+//
+// An active member walks away from a DAO. The membership is deactivated
+// rather than closed, so the member can be nominated again later, and the
+// creator score it counted towards the DAO's quality is taken back out. Any
+// vote delegation lapses with it; votes already cast stand. The founder
+// can't leave - hand the DAO over with transfer_founder first.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct LeaveDao<'info> {
+    /// The departing member
+    pub member: Signer<'info>,
+
+    /// The DAO being left
+    #[account(
+        mut,
+        constraint = dao.founder != member.key() @ CreatorDAOError::FounderCannotLeave,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The member's membership, deactivated
+    #[account(
+        mut,
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            member.key().as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+
+    /// The member's directory of DAOs
+    #[account(
+        mut,
+        seeds = [b"member_directory", member.key().as_ref()],
+        bump = member_directory.bump,
+    )]
+    pub member_directory: Account<'info, MemberDirectory>,
+}
+
+pub fn handler(ctx: Context<LeaveDao>) -> Result<()> {
+    let dao = &mut ctx.accounts.dao;
+    let membership = &mut ctx.accounts.membership;
+
+    dao.member_count = dao.member_count.saturating_sub(1);
+    dao.remove_member_score(membership.counted_creator_score);
+
+    membership.is_active = false;
+    membership.counted_creator_score = 0;
+    membership.delegate = None;
+    membership.delegation_scope = None;
+
+    ctx.accounts.member_directory.remove(&dao.key());
+
+    msg!(
+        "Member {} left DAO '{}'. Members remaining: {}",
+        membership.member_wallet,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        dao.member_count
+    );
+
+    Ok(())
+}
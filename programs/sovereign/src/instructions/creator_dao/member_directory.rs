@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::MemberDirectory;
+
+// =============================================================================
+// MEMBER DIRECTORY VIEW
+// =============================================================================
+//
+// Returns every DAO a wallet has been admitted to, for cross-DAO reputation.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetMemberDaos<'info> {
+    /// The wallet to look up
+    /// CHECK: Used as identifier
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"member_directory", wallet.key().as_ref()],
+        bump = member_directory.bump,
+    )]
+    pub member_directory: Account<'info, MemberDirectory>,
+}
+
+pub fn handler(ctx: Context<GetMemberDaos>) -> Result<Vec<Pubkey>> {
+    Ok(ctx.accounts.member_directory.daos.clone())
+}
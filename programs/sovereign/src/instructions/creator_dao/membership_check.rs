@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOMembership};
+
+// =============================================================================
+// ACTIVE MEMBERSHIP CHECK
+// =============================================================================
+//
+// Lets external programs (content platforms and the like) gate features on
+// DAO membership without re-deriving and deserializing DAOMembership. The
+// answer comes back as return data, so a CPI caller can branch on it: a
+// wallet with no membership, or an inactive one, is `false`, never an error.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct AssertActiveMember<'info> {
+    /// The DAO to check
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The wallet to check
+    /// CHECK: Used as identifier
+    pub wallet: UncheckedAccount<'info>,
+
+    /// The wallet's membership PDA in this DAO (may not exist)
+    /// CHECK: Only deserialized if it exists
+    #[account(
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub membership: UncheckedAccount<'info>,
+}
+
+/// Whether `info` holds an active membership (false if it was never created)
+fn is_active_membership(info: &AccountInfo) -> Result<bool> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(false);
+    }
+    let membership = DAOMembership::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    Ok(membership.is_active)
+}
+
+pub fn handler(ctx: Context<AssertActiveMember>) -> Result<bool> {
+    is_active_membership(&ctx.accounts.membership.to_account_info())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn membership_data(is_active: bool) -> Vec<u8> {
+        let membership = DAOMembership {
+            dao: Pubkey::default(),
+            member_identity: Pubkey::default(),
+            member_wallet: Pubkey::default(),
+            admitted_at: 0,
+            nominated_by: None,
+            successful_nominations: 0,
+            votes_cast: 0,
+            last_nominated_at: 0,
+            is_active,
+            pending_consent: false,
+            consent_deadline: 0,
+            delegate: None,
+            delegation_scope: None,
+            counted_creator_score: 0,
+            bump: 0,
+        };
+        let mut data = Vec::new();
+        membership.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn check(owner: Pubkey, mut data: Vec<u8>) -> bool {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        is_active_membership(&info).unwrap()
+    }
+
+    #[test]
+    fn active_members_pass() {
+        assert!(check(crate::ID, membership_data(true)));
+    }
+
+    #[test]
+    fn removed_members_fail_without_erroring() {
+        assert!(!check(crate::ID, membership_data(false)));
+    }
+
+    #[test]
+    fn non_members_fail_without_erroring() {
+        assert!(!check(anchor_lang::system_program::ID, Vec::new()));
+    }
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, CreatorScoreDetails, DAOMembership, MemberDirectory};
+use crate::state::SovereignIdentity;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+use crate::errors::SovereignError;
+
+// =============================================================================
+// MEMBERSHIP CONSENT INSTRUCTIONS
+// =============================================================================
+//
+// A DAO vote can admit a creator, but it can't make them a member. When a
+// nomination is accepted, resolve_nomination leaves the membership pending;
+// the creator then has MEMBERSHIP_CONSENT_WINDOW to sign accept_membership,
+// which activates it and credits their creator score. decline_membership
+// closes it instead. Once the window passes the membership can't be
+// accepted and stays inactive until the creator declines it or is
+// nominated again. Declining costs nothing, just as letting the window pass
+// doesn't.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct AcceptMembership<'info> {
+    /// The admitted creator
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The DAO that accepted them
+    #[account(
+        mut,
+        constraint = dao.is_active @ CreatorDAOError::DAONotActive,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The pending membership
+    #[account(
+        mut,
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            creator.key().as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.pending_consent @ CreatorDAOError::NotPendingConsent,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+
+    /// The creator's SOVEREIGN identity
+    #[account(
+        seeds = [b"identity", creator.key().as_ref()],
+        bump = creator_identity.bump,
+        constraint = !creator_identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub creator_identity: Account<'info, SovereignIdentity>,
+
+    /// The creator's score details, credited with the admission
+    #[account(
+        mut,
+        constraint = creator_score.identity == creator_identity.key(),
+    )]
+    pub creator_score: Account<'info, CreatorScoreDetails>,
+
+    /// The creator's directory of DAOs
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = MemberDirectory::SIZE,
+        seeds = [b"member_directory", creator.key().as_ref()],
+        bump
+    )]
+    pub member_directory: Account<'info, MemberDirectory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn accept_membership(ctx: Context<AcceptMembership>) -> Result<()> {
+    let clock = Clock::get()?;
+    let dao = &mut ctx.accounts.dao;
+    let membership = &mut ctx.accounts.membership;
+    let creator_score = &mut ctx.accounts.creator_score;
+
+    require!(
+        membership.can_accept(clock.unix_timestamp),
+        CreatorDAOError::ConsentWindowClosed
+    );
+
+    // The bonus reflects the DAO the creator is joining, before they join it
+    let admission_bonus = dao.admission_bonus();
+
+    dao.total_admitted = dao
+        .total_admitted
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    dao.member_count = dao
+        .member_count
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    dao.add_member_score(ctx.accounts.creator_identity.creator_score)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+    membership.counted_creator_score = ctx.accounts.creator_identity.creator_score;
+    membership.is_active = true;
+    membership.pending_consent = false;
+    membership.admitted_at = clock.unix_timestamp;
+
+    let directory = &mut ctx.accounts.member_directory;
+    directory.wallet = ctx.accounts.creator.key();
+    directory.bump = ctx.bumps.member_directory;
+    if !directory.add(dao.key()) {
+        msg!("Member directory full; DAO not recorded");
+    }
+
+    // === UPDATE SOVEREIGN CREATOR SCORE ===
+    // This is the core value proposition
+
+    // Award reputation points based on the quality of the DAO's members,
+    // not its size
+    creator_score
+        .record_acceptance(admission_bonus, clock.unix_timestamp)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    let new_score = creator_score.calculate_score(clock.unix_timestamp);
+
+    msg!(
+        "Creator {} joined DAO '{}'. New creator score: {}",
+        membership.member_wallet,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        new_score
+    );
+
+    // Vitalik: "If N gets above ~200, consider auto-splitting it"
+    if dao.should_consider_split() {
+        msg!(
+            "WARNING: DAO now has {} members. Consider splitting.",
+            dao.member_count
+        );
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeclineMembership<'info> {
+    /// The admitted creator, who gets the membership's rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The DAO that accepted them
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The pending membership, closed on decline
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            creator.key().as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.pending_consent @ CreatorDAOError::NotPendingConsent,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+}
+
+pub fn decline_membership(ctx: Context<DeclineMembership>) -> Result<()> {
+    msg!(
+        "Creator {} declined membership in DAO '{}'",
+        ctx.accounts.creator.key(),
+        String::from_utf8_lossy(&ctx.accounts.dao.name).trim_end_matches('\0')
+    );
+
+    Ok(())
+}
@@ -1,11 +1,51 @@
+pub mod initialize_dao_counter;
 pub mod create_dao;
 pub mod add_founder_member;
+pub mod add_founder_members_batch;
+pub mod close_founder_phase;
+pub mod update_dao_governance;
+pub mod transfer_founder;
+pub mod content;
+pub mod treasury;
+pub mod creator_coin;
 pub mod nominate_creator;
+pub mod application;
 pub mod cast_vote;
+pub mod change_vote;
+pub mod reveal_vote;
+pub mod delegate_vote;
 pub mod resolve_nomination;
+pub mod withdraw_nomination;
+pub mod cancel_expired_nomination;
+pub mod member_directory;
+pub mod dao_registry;
+pub mod membership_check;
+pub mod membership_consent;
+pub mod leave_dao;
+pub mod sync_member_score;
 
+pub use initialize_dao_counter::*;
 pub use create_dao::*;
 pub use add_founder_member::*;
+pub use add_founder_members_batch::*;
+pub use close_founder_phase::*;
+pub use update_dao_governance::*;
+pub use transfer_founder::*;
+pub use content::*;
+pub use treasury::*;
+pub use creator_coin::*;
 pub use nominate_creator::*;
+pub use application::*;
 pub use cast_vote::*;
+pub use change_vote::*;
+pub use reveal_vote::*;
+pub use delegate_vote::*;
 pub use resolve_nomination::*;
+pub use withdraw_nomination::*;
+pub use cancel_expired_nomination::*;
+pub use member_directory::*;
+pub use dao_registry::*;
+pub use membership_check::*;
+pub use membership_consent::*;
+pub use leave_dao::*;
+pub use sync_member_score::*;
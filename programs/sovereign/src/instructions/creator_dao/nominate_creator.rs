@@ -1,6 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::creator_dao::{CreatorDAO, DAOMembership, Nomination, MAX_PENDING_NOMINATIONS};
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::creator_dao::{
+    CreatorDAO, DAOMembership, DAOTreasury, Nomination, NomineeLock, RejectionRecord, MAX_PENDING_NOMINATIONS,
+};
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
+use crate::state::{ProtocolConfig, SovereignIdentity};
+use crate::errors::SovereignError;
+use crate::events::NominationOpened;
 use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+use crate::instructions::admission_market::create_market::AdmissionMarketError;
 
 // =============================================================================
 // NOMINATE CREATOR INSTRUCTION
@@ -10,7 +18,15 @@ use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 // if they get admitted to a creator DAO..."
 //
 // Any existing DAO member can nominate a creator for admission.
-// This triggers the voting process.
+// This triggers the voting process, and closes trading on the nominee's
+// prediction market so nobody can trade on a near-certain outcome.
+//
+// DAOs with a nomination fee charge it into their treasury here to deter
+// spam; resolve_nomination refunds it if the nominee is accepted.
+//
+// A nominee the DAO rejected can't be nominated again until the DAO's
+// rejection_cooldown has passed, and a DAO can't nominate at all until it
+// has min_members_for_nominations members.
 // =============================================================================
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -28,28 +44,57 @@ pub struct NominateCreator<'info> {
 
     /// The nominator's membership account
     #[account(
+        mut,
         constraint = nominator_membership.dao == dao.key() @ CreatorDAOError::NotMember,
         constraint = nominator_membership.member_wallet == nominator.key() @ CreatorDAOError::NotMember,
         constraint = nominator_membership.is_active @ CreatorDAOError::NotMember,
     )]
     pub nominator_membership: Account<'info, DAOMembership>,
 
+    /// The nominator's SOVEREIGN identity, checked against the DAO's minimum
+    #[account(
+        seeds = [b"identity", nominator.key().as_ref()],
+        bump = nominator_identity.bump,
+        constraint = !nominator_identity.is_frozen @ SovereignError::IdentityFrozen,
+        constraint = nominator_identity.creator_score >= dao.min_nominator_creator_score @ CreatorDAOError::NominatorScoreTooLow,
+    )]
+    pub nominator_identity: Account<'info, SovereignIdentity>,
+
     /// The DAO
     #[account(
         mut,
         constraint = dao.is_active @ CreatorDAOError::DAONotActive,
         constraint = dao.pending_nominations < MAX_PENDING_NOMINATIONS as u8 @ CreatorDAOError::MaxPendingNominations,
+        constraint = dao.can_open_nominations() @ CreatorDAOError::DAOTooSmall,
     )]
     pub dao: Account<'info, CreatorDAO>,
 
-    /// The nominee's SOVEREIGN identity
-    /// CHECK: Will be validated
-    pub nominee_identity: UncheckedAccount<'info>,
+    /// The nominee's SOVEREIGN identity, which must belong to nominee_wallet
+    /// so resolve_nomination can match the two later
+    #[account(
+        seeds = [b"identity", nominee_wallet.key().as_ref()],
+        bump = nominee_identity.bump,
+        constraint = !nominee_identity.is_frozen @ SovereignError::IdentityFrozen,
+        constraint = nominee_identity.owner == nominee_wallet.key() @ CreatorDAOError::NomineeIdentityMismatch,
+    )]
+    pub nominee_identity: Account<'info, SovereignIdentity>,
 
     /// The nominee's wallet
     /// CHECK: Used as identifier
     pub nominee_wallet: UncheckedAccount<'info>,
 
+    /// The nominee's membership PDA in this DAO (usually not created yet)
+    /// CHECK: Only deserialized in the handler if it already exists
+    #[account(
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            nominee_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub nominee_membership: UncheckedAccount<'info>,
+
     /// The nomination account to create
     #[account(
         init,
@@ -64,41 +109,227 @@ pub struct NominateCreator<'info> {
     )]
     pub nomination: Account<'info, Nomination>,
 
+    /// One open nomination per (DAO, nominee)
+    #[account(
+        init_if_needed,
+        payer = nominator,
+        space = NomineeLock::SIZE,
+        seeds = [
+            b"nominee_lock",
+            dao.key().as_ref(),
+            nominee_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub nominee_lock: Account<'info, NomineeLock>,
+
+    /// When the nominee was last rejected by this DAO (usually not created)
+    /// CHECK: Only deserialized in the handler if it already exists
+    #[account(
+        seeds = [
+            b"rejection_record",
+            dao.key().as_ref(),
+            nominee_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub rejection_record: UncheckedAccount<'info>,
+
+    /// Optional: Prediction market on this nominee, linked to this
+    /// nomination and locked for the vote
+    #[account(
+        mut,
+        constraint = prediction_market.dao == dao.key() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.creator_identity == nominee_identity.key() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.status == MarketStatus::Open @ AdmissionMarketError::MarketNotOpen,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// The DAO treasury, required when the DAO charges a nomination fee
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DAOTreasury>>,
+
+    /// Program-wide pause switch
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ SovereignError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<NominateCreator>, params: NominateCreatorParams) -> Result<()> {
-    let dao = &mut ctx.accounts.dao;
-    let nomination = &mut ctx.accounts.nomination;
+/// The accounts a new nomination touches, shared by nominate_creator and
+/// sponsor_application
+pub(crate) struct NominationParts<'a, 'info> {
+    pub nominator: &'a Signer<'info>,
+    pub nominator_membership: &'a mut Account<'info, DAOMembership>,
+    pub dao: &'a mut Account<'info, CreatorDAO>,
+    pub nominee_identity: &'a Account<'info, SovereignIdentity>,
+    pub nominee_wallet: Pubkey,
+    pub nominee_membership: &'a UncheckedAccount<'info>,
+    pub nomination: &'a mut Account<'info, Nomination>,
+    pub nomination_bump: u8,
+    pub nominee_lock: &'a mut Account<'info, NomineeLock>,
+    pub nominee_lock_bump: u8,
+    pub rejection_record: &'a UncheckedAccount<'info>,
+    pub prediction_market: Option<&'a mut Account<'info, AdmissionMarket>>,
+    pub treasury: Option<&'a mut Account<'info, DAOTreasury>>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Validate the nominee and nominator, charge the fee, and open the
+/// nomination (locking the nominee and any linked market)
+pub(crate) fn open_nomination(parts: NominationParts, reason: &[u8]) -> Result<()> {
+    let NominationParts {
+        nominator,
+        nominator_membership,
+        dao,
+        nominee_identity,
+        nominee_wallet,
+        nominee_membership,
+        nomination,
+        nomination_bump,
+        nominee_lock,
+        nominee_lock_bump,
+        rejection_record,
+        prediction_market,
+        treasury,
+        system_program,
+    } = parts;
+
+    // Active members can't be nominated into a DAO they're already in
+    let membership_info = nominee_membership.to_account_info();
+    if membership_info.owner == &crate::ID && !membership_info.data_is_empty() {
+        let membership = DAOMembership::try_deserialize(&mut &membership_info.data.borrow()[..])?;
+        require!(!membership.is_active, CreatorDAOError::AlreadyMember);
+    }
+
+    // DAOs can insist on nominees with an attested (sybil-resistant) identity
+    if let Some(attestor) = dao.required_attestor {
+        require!(
+            nominee_identity.is_verified && nominee_identity.attestation_authority == attestor,
+            CreatorDAOError::NomineeNotVerified
+        );
+    }
+
     let clock = Clock::get()?;
 
+    // A rejected nominee waits out the DAO's rejection cooldown
+    let record_info = rejection_record.to_account_info();
+    if record_info.owner == &crate::ID && !record_info.data_is_empty() {
+        let record = RejectionRecord::try_deserialize(&mut &record_info.data.borrow()[..])?;
+        require!(
+            record.cooldown_elapsed(dao.rejection_cooldown, clock.unix_timestamp),
+            CreatorDAOError::RejectionCooldownActive
+        );
+    }
+
+    // Members can't fire off nominations faster than the DAO's cooldown
+    require!(
+        clock.unix_timestamp - nominator_membership.last_nominated_at >= dao.nomination_cooldown,
+        CreatorDAOError::NominationCooldownActive
+    );
+    nominator_membership.last_nominated_at = clock.unix_timestamp;
+
+    let fee = dao.nomination_fee;
+    if fee > 0 {
+        let treasury = treasury.ok_or(CreatorDAOError::TreasuryRequired)?;
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: nominator.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+        treasury.total_deposited = treasury.total_deposited.saturating_add(fee);
+        // Held until the nomination closes, in case it is refunded
+        treasury.reserved_fees = treasury
+            .reserved_fees
+            .checked_add(fee)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+        msg!("Charged nomination fee of {} lamports", fee);
+    }
+
+    // A live lock means another nomination for this creator is still open
+    require!(
+        nominee_lock.nomination == Pubkey::default(),
+        CreatorDAOError::NominationAlreadyPending
+    );
+
     // Convert reason to fixed array
     let mut reason_bytes = [0u8; 256];
-    let reason_slice = params.reason.as_bytes();
-    let reason_len = reason_slice.len().min(256);
-    reason_bytes[..reason_len].copy_from_slice(&reason_slice[..reason_len]);
+    let reason_len = reason.len().min(256);
+    reason_bytes[..reason_len].copy_from_slice(&reason[..reason_len]);
 
     // Initialize nomination
     nomination.dao = dao.key();
     nomination.nomination_id = dao.nomination_nonce;
-    nomination.nominee_identity = ctx.accounts.nominee_identity.key();
-    nomination.nominee_wallet = ctx.accounts.nominee_wallet.key();
-    nomination.nominator = ctx.accounts.nominator.key();
+    nomination.nominee_identity = nominee_identity.key();
+    nomination.nominee_wallet = nominee_wallet;
+    nomination.nominator = nominator.key();
     nomination.reason = reason_bytes;
     nomination.created_at = clock.unix_timestamp;
+    // Governance is fixed here; cast_vote/resolve_nomination only ever read
+    // the nomination's snapshot, never the DAO's current parameters
     nomination.voting_ends_at = clock.unix_timestamp + dao.voting_period;
     nomination.votes_accept = 0;
     nomination.votes_reject = 0;
     nomination.votes_abstain = 0;
+    nomination.voter_count = 0;
+    nomination.revealed_count = 0;
+    nomination.accept_voter_count = 0;
     nomination.total_members_snapshot = dao.member_count;
+    nomination.admission_threshold_snapshot = dao.admission_threshold;
+    nomination.quorum_snapshot = dao.quorum;
+    nomination.min_accept_votes_snapshot = dao.min_accept_votes;
     nomination.is_resolved = false;
     nomination.was_accepted = false;
+    nomination.was_withdrawn = false;
+    nomination.timed_out = false;
     nomination.resolved_at = None;
-    nomination.bump = ctx.bumps.nomination;
+    nomination.fee_paid = fee;
+    nomination.linked_market = prediction_market.as_ref().map(|market| market.key());
+    nomination.bump = nomination_bump;
+
+    nominee_lock.dao = dao.key();
+    nominee_lock.nominee_wallet = nomination.nominee_wallet;
+    nominee_lock.nomination = nomination.key();
+    nominee_lock.bump = nominee_lock_bump;
+
+    // The market now resolves on this nomination, and trading stops
+    if let Some(market) = prediction_market {
+        market.lock_for_voting(nomination.key(), clock.unix_timestamp);
+        msg!("Prediction market {} closed for trading", market.key());
+    }
 
     // Update DAO state
     dao.nomination_nonce += 1;
     dao.pending_nominations += 1;
+    dao.total_nominations = dao
+        .total_nominations
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+    emit!(NominationOpened {
+        nomination: nomination.key(),
+        nomination_id: nomination.nomination_id,
+        dao: dao.key(),
+        nominee_identity: nomination.nominee_identity,
+        nominee_wallet: nomination.nominee_wallet,
+        nominator: nomination.nominator,
+        members_snapshot: nomination.total_members_snapshot,
+        voting_ends_at: nomination.voting_ends_at,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
         "Nomination #{} created for admission to DAO '{}'. Voting ends at {}",
@@ -107,6 +338,31 @@ pub fn handler(ctx: Context<NominateCreator>, params: NominateCreatorParams) ->
         nomination.voting_ends_at
     );
 
+    Ok(())
+}
+
+pub fn handler(ctx: Context<NominateCreator>, params: NominateCreatorParams) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    open_nomination(
+        NominationParts {
+            nominator: &accounts.nominator,
+            nominator_membership: &mut accounts.nominator_membership,
+            dao: &mut accounts.dao,
+            nominee_identity: &accounts.nominee_identity,
+            nominee_wallet: accounts.nominee_wallet.key(),
+            nominee_membership: &accounts.nominee_membership,
+            nomination: &mut accounts.nomination,
+            nomination_bump: ctx.bumps.nomination,
+            nominee_lock: &mut accounts.nominee_lock,
+            nominee_lock_bump: ctx.bumps.nominee_lock,
+            rejection_record: &accounts.rejection_record,
+            prediction_market: accounts.prediction_market.as_mut(),
+            treasury: accounts.treasury.as_mut(),
+            system_program: &accounts.system_program,
+        },
+        params.reason.as_bytes(),
+    )?;
+
     // Vitalik: "they are helping surface promising creators for the DAOs to choose from"
     // This nomination may have been influenced by prediction market activity
     msg!("Prediction markets can now lock in positions for this nomination");
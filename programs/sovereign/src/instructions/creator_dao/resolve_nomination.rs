@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::state::creator_dao::{CreatorDAO, DAOMembership, Nomination, CreatorScoreDetails};
-use crate::state::admission_market::{AdmissionMarket, MarketStatus, MarketOutcome};
+use anchor_lang::solana_program::incinerator;
+use crate::state::creator_dao::{
+    CreatorDAO, DAOMembership, DAOTreasury, Nomination, NomineeLock, RejectionRecord,
+    CreatorScoreDetails, MEMBERSHIP_CONSENT_WINDOW,
+};
+use crate::state::admission_market::{AdmissionMarket, MarketStatus};
 use crate::state::SovereignIdentity;
+use crate::instructions::admission_market::claim_winnings::pay_from_escrow;
+use crate::instructions::creator_dao::add_founder_members_batch::create_pda;
 use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 
 // =============================================================================
@@ -11,7 +17,8 @@ use crate::instructions::creator_dao::create_dao::CreatorDAOError;
 // This is where Vitalik's vision comes together:
 //
 // 1. The DAO vote resolves → determines if creator is accepted
-// 2. If accepted → creator's SOVEREIGN score increases
+// 2. If accepted → creator is offered membership, and their SOVEREIGN score
+//    increases once they sign accept_membership
 // 3. Linked prediction markets resolve → speculators get paid/lose
 // 4. A portion of winnings → burned (deflationary pressure)
 //
@@ -41,9 +48,8 @@ pub struct ResolveNomination<'info> {
     )]
     pub nomination: Account<'info, Nomination>,
 
-    /// The nominee's SOVEREIGN identity (to update creator score)
+    /// The nominee's SOVEREIGN identity
     #[account(
-        mut,
         constraint = nominee_identity.owner == nomination.nominee_wallet,
     )]
     pub nominee_identity: Account<'info, SovereignIdentity>,
@@ -63,15 +69,31 @@ pub struct ResolveNomination<'info> {
     )]
     pub nominator_membership: Account<'info, DAOMembership>,
 
-    /// Optional: Linked prediction market to resolve
-    /// CHECK: Validated in handler
-    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
-
-    /// New membership account if accepted
+    /// The nominator's creator score details (for judgment accuracy),
+    /// created here if the nominator never initialized them
     #[account(
         init_if_needed,
         payer = resolver,
-        space = DAOMembership::SIZE,
+        space = CreatorScoreDetails::SIZE,
+        seeds = [b"creator_details", nominator_membership.member_identity.as_ref()],
+        bump
+    )]
+    pub nominator_creator_score: Account<'info, CreatorScoreDetails>,
+
+    /// The prediction market linked to this nomination, required if it has one
+    #[account(
+        mut,
+        address = nomination.linked_market.unwrap_or_default() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.is_unsettled() @ CreatorDAOError::MarketAlreadySettled,
+        constraint = prediction_market.nomination == Some(nomination.key()) @ CreatorDAOError::MarketMismatch,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// New membership account, pending the nominee's consent. Only created
+    /// if the nominee is accepted.
+    /// CHECK: PDA checked by seeds; created or reset by the handler
+    #[account(
+        mut,
         seeds = [
             b"dao_membership",
             dao.key().as_ref(),
@@ -79,7 +101,53 @@ pub struct ResolveNomination<'info> {
         ],
         bump
     )]
-    pub new_membership: Account<'info, DAOMembership>,
+    pub new_membership: UncheckedAccount<'info>,
+
+    /// The nominee's pending-nomination lock, released on resolution
+    #[account(
+        mut,
+        close = resolver,
+        seeds = [
+            b"nominee_lock",
+            dao.key().as_ref(),
+            nomination.nominee_wallet.as_ref()
+        ],
+        bump = nominee_lock.bump,
+    )]
+    pub nominee_lock: Account<'info, NomineeLock>,
+
+    /// When this nominee was last rejected by the DAO, stamped on rejection
+    /// to start the DAO's rejection cooldown. Only created if the nominee is
+    /// rejected.
+    /// CHECK: PDA checked by seeds; created or updated by the handler
+    #[account(
+        mut,
+        seeds = [
+            b"rejection_record",
+            dao.key().as_ref(),
+            nomination.nominee_wallet.as_ref()
+        ],
+        bump
+    )]
+    pub rejection_record: UncheckedAccount<'info>,
+
+    /// The DAO treasury, required if a nomination fee was paid
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DAOTreasury>>,
+
+    /// The nominator's wallet, which receives the refund
+    /// CHECK: Must be the nomination's nominator
+    #[account(mut, address = nomination.nominator)]
+    pub nominator_wallet: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Solana's incinerator; a linked market's creator-coin burn
+    /// share is sent here
+    #[account(mut, address = incinerator::ID)]
+    pub burn_address: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
@@ -90,22 +158,33 @@ pub fn handler(ctx: Context<ResolveNomination>) -> Result<()> {
     let nomination = &mut ctx.accounts.nomination;
     let creator_score = &mut ctx.accounts.creator_score;
     let nominator_membership = &mut ctx.accounts.nominator_membership;
-    let nominee_identity = &mut ctx.accounts.nominee_identity;
 
-    // Verify voting period has ended
+    // A linked market must be settled along with its nomination
+    require!(
+        nomination.links_market(ctx.accounts.prediction_market.as_ref().map(|market| market.key())),
+        CreatorDAOError::LinkedMarketRequired
+    );
+
+    // Verify voting period has ended and every vote was revealed (or had
+    // its chance to be)
     require!(
         clock.unix_timestamp > nomination.voting_ends_at,
         CreatorDAOError::VotingNotEnded
     );
+    require!(
+        nomination.tally_final(clock.unix_timestamp),
+        CreatorDAOError::RevealStillOpen
+    );
 
-    // Check quorum
+    // Check quorum against the snapshot or the current membership, whichever is larger
     require!(
-        nomination.has_quorum(dao.quorum),
+        nomination.has_quorum(nomination.quorum_snapshot, Some(dao.member_count)),
         CreatorDAOError::QuorumNotReached
     );
 
-    // Determine outcome
-    let was_accepted = nomination.meets_threshold(dao.admission_threshold);
+    // Determine outcome: the threshold and the minimum accept votes must
+    // both be met
+    let was_accepted = nomination.admits();
 
     // Update nomination
     nomination.is_resolved = true;
@@ -118,73 +197,94 @@ pub fn handler(ctx: Context<ResolveNomination>) -> Result<()> {
     if was_accepted {
         // === CREATOR ACCEPTED ===
         // Vitalik: "if they get admitted to a creator DAO..."
-
-        dao.total_admitted += 1;
-        dao.member_count += 1;
-
-        // Initialize new membership
-        let new_membership = &mut ctx.accounts.new_membership;
-        new_membership.dao = dao.key();
-        new_membership.member_identity = nomination.nominee_identity;
-        new_membership.member_wallet = nomination.nominee_wallet;
-        new_membership.admitted_at = clock.unix_timestamp;
-        new_membership.nominated_by = Some(nomination.nominator);
-        new_membership.successful_nominations = 0;
-        new_membership.votes_cast = 0;
-        new_membership.is_active = true;
-        new_membership.bump = ctx.bumps.new_membership;
-
-        // Update nominator stats (they made a successful nomination)
-        nominator_membership.successful_nominations += 1;
-
-        // === UPDATE SOVEREIGN CREATOR SCORE ===
-        // This is the core value proposition
-
-        creator_score.daos_accepted += 1;
-        if creator_score.first_dao_acceptance.is_none() {
-            creator_score.first_dao_acceptance = Some(clock.unix_timestamp);
+        dao.total_accepted = dao
+            .total_accepted
+            .checked_add(1)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+        // The membership only becomes active, and the creator score only
+        // moves, once the nominee signs accept_membership
+        let membership_info = ctx.accounts.new_membership.to_account_info();
+        let bump = ctx.bumps.new_membership;
+        if membership_info.data_is_empty() {
+            create_pda(
+                &ctx.accounts.resolver.to_account_info(),
+                &membership_info,
+                &ctx.accounts.system_program.to_account_info(),
+                DAOMembership::SIZE,
+                &[b"dao_membership", dao.key().as_ref(), nomination.nominee_wallet.as_ref(), &[bump]],
+            )?;
+        } else {
+            // A membership left over from an earlier admission is reset, but
+            // never one the nominee still holds
+            require_keys_eq!(*membership_info.owner, crate::ID, CreatorDAOError::MemberAccountMismatch);
+            let existing = DAOMembership::try_deserialize(&mut &membership_info.try_borrow_data()?[..])?;
+            require!(!existing.is_active, CreatorDAOError::AlreadyMember);
         }
-
-        // Award reputation points based on DAO tier/prestige
-        // Higher member count = more established = more prestige
-        let prestige_bonus = match dao.member_count {
-            0..=10 => 100,      // New DAO
-            11..=50 => 200,     // Growing DAO
-            51..=100 => 300,    // Established DAO
-            101..=150 => 400,   // Prestigious DAO
-            _ => 500,           // Elite DAO
+        let new_membership = DAOMembership {
+            dao: dao.key(),
+            member_identity: nomination.nominee_identity,
+            member_wallet: nomination.nominee_wallet,
+            admitted_at: 0,
+            nominated_by: Some(nomination.nominator),
+            successful_nominations: 0,
+            votes_cast: 0,
+            last_nominated_at: 0,
+            is_active: false,
+            pending_consent: true,
+            consent_deadline: clock
+                .unix_timestamp
+                .checked_add(MEMBERSHIP_CONSENT_WINDOW)
+                .ok_or(CreatorDAOError::ArithmeticOverflow)?,
+            delegate: None,
+            delegation_scope: None,
+            counted_creator_score: 0,
+            bump,
         };
-        creator_score.dao_reputation_points += prestige_bonus;
-        creator_score.last_updated = clock.unix_timestamp;
+        new_membership.try_serialize(&mut &mut membership_info.try_borrow_mut_data()?[..])?;
 
-        // Recalculate creator score
-        let new_score = creator_score.calculate_score();
+        // Update nominator's per-DAO stats (they made a successful nomination)
+        nominator_membership.successful_nominations = nominator_membership
+            .successful_nominations
+            .checked_add(1)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
 
-        // Update SOVEREIGN identity with new creator score
-        // Note: This requires adding creator_score and creator_authority to SovereignIdentity
-        // For now, we log the score
         msg!(
-            "Creator {} accepted into DAO '{}'. New creator score: {}",
+            "Creator {} accepted by DAO '{}'. Awaiting their consent until {}",
             nomination.nominee_wallet,
             String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
-            new_score
+            new_membership.consent_deadline
         );
 
-        // Vitalik: "If N gets above ~200, consider auto-splitting it"
-        if dao.should_consider_split() {
-            msg!(
-                "WARNING: DAO now has {} members. Consider splitting.",
-                dao.member_count
-            );
-        }
-
     } else {
         // === CREATOR REJECTED ===
-        dao.total_removed += 1;
-
-        // Update nominator stats (failed nomination)
-        // This affects their judgment quality score
-        creator_score.failed_nominations += 1;
+        dao.total_rejected = dao
+            .total_rejected
+            .checked_add(1)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+        // The nominee can't be put straight back up for a vote
+        let record_info = ctx.accounts.rejection_record.to_account_info();
+        let bump = ctx.bumps.rejection_record;
+        if record_info.data_is_empty() {
+            create_pda(
+                &ctx.accounts.resolver.to_account_info(),
+                &record_info,
+                &ctx.accounts.system_program.to_account_info(),
+                RejectionRecord::SIZE,
+                &[b"rejection_record", dao.key().as_ref(), nomination.nominee_wallet.as_ref(), &[bump]],
+            )?;
+        } else {
+            require_keys_eq!(*record_info.owner, crate::ID, CreatorDAOError::MemberAccountMismatch);
+            RejectionRecord::try_deserialize(&mut &record_info.try_borrow_data()?[..])?;
+        }
+        let rejection_record = RejectionRecord {
+            dao: dao.key(),
+            nominee_wallet: nomination.nominee_wallet,
+            last_rejected_at: clock.unix_timestamp,
+            bump,
+        };
+        rejection_record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
 
         msg!(
             "Creator {} rejected by DAO '{}'. Votes: {} accept, {} reject",
@@ -202,43 +302,88 @@ pub fn handler(ctx: Context<ResolveNomination>) -> Result<()> {
     // creator DAOs will be willing to accept"
 
     if let Some(market) = &mut ctx.accounts.prediction_market {
-        // Verify market is for this nomination
-        if market.dao == dao.key() && market.creator_identity == nomination.nominee_identity {
-            market.status = MarketStatus::Resolved;
-            market.outcome = if was_accepted {
-                MarketOutcome::Accepted
-            } else {
-                MarketOutcome::Rejected
-            };
-            market.resolved_by_nomination = Some(nomination.key());
-            market.resolved_at = Some(clock.unix_timestamp);
-
-            // Calculate burn amount
-            // Vitalik: "a portion of their proceeds from the DAO are used to
-            // burn their creator coins"
-            let total_pool = market.yes_pool + market.no_pool;
-            let burn_amount = (total_pool as u128 * market.burn_percentage_bps as u128 / 10000) as u64;
-            market.amount_burned = burn_amount;
-
-            msg!(
-                "Prediction market resolved: {}. {} tokens will be burned.",
-                if was_accepted { "ACCEPTED" } else { "REJECTED" },
-                burn_amount
-            );
-
-            // Update creator's burn metrics
-            creator_score.total_burned += burn_amount;
-        }
+        market.status = MarketStatus::Resolved;
+        market.outcome = AdmissionMarket::outcome_for(was_accepted);
+        market.resolved_by_nomination = Some(nomination.key());
+        market.resolved_at = Some(clock.unix_timestamp);
+
+        // Split the pot: winners / creator-coin burn / protocol treasury
+        // Vitalik: "a portion of their proceeds from the DAO are used to
+        // burn their creator coins"
+        let (_, burn_amount, protocol_amount) = market.pot_split();
+        market.amount_burned = burn_amount;
+        market.amount_to_protocol = protocol_amount;
+
+        // The protocol share is swept with the trading fees by withdraw_fees
+        market.accumulated_fees = market
+            .accumulated_fees
+            .checked_add(protocol_amount)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+        // The burn share leaves the escrow for good
+        pay_from_escrow(
+            &market.to_account_info(),
+            &ctx.accounts.burn_address.to_account_info(),
+            burn_amount,
+        )?;
+
+        msg!(
+            "Prediction market resolved: {}. {} lamports burned, {} to protocol.",
+            if was_accepted { "ACCEPTED" } else { "REJECTED" },
+            burn_amount,
+            protocol_amount
+        );
+
+        // Update creator's burn metrics
+        creator_score.total_burned = creator_score
+            .total_burned
+            .checked_add(burn_amount)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    }
+
+    // Accepting the nominee earns the nominator their fee back; a rejected
+    // nomination's fee stays in the treasury. Either way the treasury stops
+    // holding it.
+    if nomination.fee_paid > 0 {
+        ctx.accounts
+            .treasury
+            .as_mut()
+            .ok_or(CreatorDAOError::TreasuryRequired)?
+            .release_fee(nomination.fee_paid);
+    }
+    let refund = nomination.fee_refund();
+    if refund > 0 {
+        let (treasury, nominator_wallet) = match (&ctx.accounts.treasury, &ctx.accounts.nominator_wallet) {
+            (Some(treasury), Some(nominator_wallet)) => (treasury.to_account_info(), nominator_wallet.to_account_info()),
+            _ => return err!(CreatorDAOError::FeeRefundAccountsRequired),
+        };
+        let remaining = treasury
+            .lamports()
+            .checked_sub(refund)
+            .ok_or(CreatorDAOError::InsufficientTreasury)?;
+        **treasury.try_borrow_mut_lamports()? = remaining;
+        let credited = nominator_wallet
+            .lamports()
+            .checked_add(refund)
+            .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+        **nominator_wallet.try_borrow_mut_lamports()? = credited;
+        msg!("Refunded nomination fee of {} lamports to {}", refund, nomination.nominator);
     }
 
     // Update nominator's judgment accuracy
-    let total_nominations = nominator_membership.successful_nominations as u32 +
-        creator_score.failed_nominations as u32;
-    if total_nominations > 0 {
-        let accuracy = (nominator_membership.successful_nominations as u32 * 10000) / total_nominations;
-        // This accuracy feeds back into the nominator's own creator score
-        msg!("Nominator judgment accuracy: {}%", accuracy / 100);
+    // This feeds back into the nominator's own creator score
+    let nominator_score = &mut ctx.accounts.nominator_creator_score;
+    if nominator_score.identity == Pubkey::default() {
+        nominator_score.identity = nominator_membership.member_identity;
+        nominator_score.first_dao_acceptance = None;
+        nominator_score.bump = ctx.bumps.nominator_creator_score;
     }
+    nominator_score.record_nomination(was_accepted);
+    nominator_score.last_updated = clock.unix_timestamp;
+    msg!(
+        "Nominator judgment accuracy: {}%",
+        nominator_score.nomination_accuracy_bps / 100
+    );
 
     Ok(())
 }
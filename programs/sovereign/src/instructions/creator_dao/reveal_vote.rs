@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorScoreDetails, Nomination, VoteRecord, VoteChoice};
+use crate::state::SovereignIdentity;
+use crate::events::VoteRevealed;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// REVEAL VOTE INSTRUCTIONS
+// =============================================================================
+//
+// Second half of commit-reveal voting. Once voting closes, each voter opens
+// their commitment with the choice and salt they committed to, and only then
+// is their weight added to the tallies. A vote a delegate committed is
+// revealed by that delegate, who holds its salt.
+//
+// Commit-reveal keeps a vote hidden only until it is revealed: it stops
+// voters from following the early tally, not from being identified. The
+// reveal opens the voter's own vote record, so anyone watching the chain can
+// tie the choice back to the member. Unlinkable reveals are not implemented
+// (see cast_vote).
+//
+// A commitment counts toward quorum whether or not it is revealed, so a
+// voter can't sink quorum by watching early reveals and withholding theirs;
+// left unrevealed, it is an abstention. Once the reveal period ends and the
+// nomination has resolved or timed out, anyone can call
+// penalize_unrevealed_vote on such a vote: it closes the record back to the
+// member and charges their creator score a small penalty, even if a delegate
+// committed it. Votes on a withdrawn nomination, which could never be
+// revealed, are not penalized.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    /// Whoever committed the vote: the voter or their delegate
+    pub voter: Signer<'info>,
+
+    /// The nomination the vote was cast on
+    #[account(
+        mut,
+        constraint = !nomination.is_resolved @ CreatorDAOError::AlreadyResolved,
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// The voter's committed vote
+    #[account(
+        mut,
+        seeds = [
+            b"vote_record",
+            nomination.key().as_ref(),
+            vote_record.voter.as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = vote_record.can_reveal(&voter.key()) @ CreatorDAOError::NotDelegate,
+        constraint = !vote_record.revealed @ CreatorDAOError::VoteAlreadyRevealed,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+pub fn handler(ctx: Context<RevealVote>, vote: VoteChoice, salt: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let nomination = &mut ctx.accounts.nomination;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    require!(
+        nomination.reveal_open(clock.unix_timestamp),
+        CreatorDAOError::RevealNotOpen
+    );
+    require!(
+        VoteRecord::commitment_for(vote, &salt) == vote_record.commitment,
+        CreatorDAOError::CommitmentMismatch
+    );
+
+    vote_record.revealed = true;
+    nomination
+        .add_vote(vote, vote_record.weight)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    nomination.revealed_count = nomination
+        .revealed_count
+        .checked_add(1)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+
+    emit!(VoteRevealed {
+        nomination: nomination.key(),
+        choice: vote,
+        weight: vote_record.weight,
+        revealed_count: nomination.revealed_count,
+        running_accept: nomination.votes_accept,
+        running_reject: nomination.votes_reject,
+        running_abstain: nomination.votes_abstain,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vote revealed on nomination #{} ({} of {} revealed). Tally: {} accept, {} reject, {} abstain",
+        nomination.nomination_id,
+        nomination.revealed_count,
+        nomination.voter_count,
+        nomination.votes_accept,
+        nomination.votes_reject,
+        nomination.votes_abstain
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PenalizeUnrevealedVote<'info> {
+    /// Anyone may report an unrevealed vote
+    pub caller: Signer<'info>,
+
+    /// The nomination the vote was cast on
+    #[account(
+        constraint = !nomination.was_withdrawn @ CreatorDAOError::NominationWithdrawn,
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// The unrevealed vote, closed back to the member
+    #[account(
+        mut,
+        close = voter,
+        seeds = [
+            b"vote_record",
+            nomination.key().as_ref(),
+            vote_record.voter.as_ref()
+        ],
+        bump = vote_record.bump,
+        constraint = !vote_record.revealed @ CreatorDAOError::VoteAlreadyRevealed,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// CHECK: The member the vote belongs to, receiving the record's rent
+    #[account(mut, address = vote_record.voter)]
+    pub voter: UncheckedAccount<'info>,
+
+    /// The member's SOVEREIGN identity
+    #[account(
+        seeds = [b"identity", vote_record.voter.as_ref()],
+        bump = voter_identity.bump,
+    )]
+    pub voter_identity: Account<'info, SovereignIdentity>,
+
+    /// The member's creator details, charged the penalty
+    #[account(
+        mut,
+        seeds = [b"creator_details", voter_identity.key().as_ref()],
+        bump = voter_creator_details.bump,
+    )]
+    pub voter_creator_details: Account<'info, CreatorScoreDetails>,
+}
+
+pub fn penalize_unrevealed_vote(ctx: Context<PenalizeUnrevealedVote>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let nomination = &ctx.accounts.nomination;
+
+    require!(
+        !nomination.voting_open(now) && !nomination.reveal_open(now),
+        CreatorDAOError::RevealNotClosed
+    );
+    require!(nomination.settled_by_vote(), CreatorDAOError::NominationNotSettled);
+
+    let details = &mut ctx.accounts.voter_creator_details;
+    details.record_unrevealed_vote();
+    let new_score = details.calculate_score(now);
+
+    msg!(
+        "Unrevealed vote on nomination #{} penalized. New creator score: {}",
+        nomination.nomination_id,
+        new_score
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOMembership};
+use crate::state::SovereignIdentity;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// SYNC MEMBER SCORE INSTRUCTION
+// =============================================================================
+//
+// A member's creator score is counted towards their DAO's quality when they
+// join, and keeps moving afterwards. Anyone can call sync_member_score to
+// swap the recorded score for the member's current one, so the average
+// behind the admission bonus tracks the members as they are now.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SyncMemberScore<'info> {
+    /// The DAO whose quality is refreshed
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The member's active membership
+    #[account(
+        mut,
+        seeds = [
+            b"dao_membership",
+            dao.key().as_ref(),
+            membership.member_wallet.as_ref()
+        ],
+        bump = membership.bump,
+        constraint = membership.is_active @ CreatorDAOError::NotMember,
+    )]
+    pub membership: Account<'info, DAOMembership>,
+
+    /// The member's SOVEREIGN identity
+    #[account(
+        address = membership.member_identity @ CreatorDAOError::MemberAccountMismatch,
+    )]
+    pub member_identity: Account<'info, SovereignIdentity>,
+}
+
+pub fn handler(ctx: Context<SyncMemberScore>) -> Result<()> {
+    let dao = &mut ctx.accounts.dao;
+    let membership = &mut ctx.accounts.membership;
+    let current = ctx.accounts.member_identity.creator_score;
+
+    dao.resync_member_score(membership.counted_creator_score, current)
+        .ok_or(CreatorDAOError::ArithmeticOverflow)?;
+    let recorded = membership.counted_creator_score;
+    membership.counted_creator_score = current;
+
+    msg!(
+        "Member {} creator score resynced: {} -> {}. DAO average: {}",
+        membership.member_wallet,
+        recorded,
+        current,
+        dao.average_member_creator_score()
+    );
+
+    Ok(())
+}
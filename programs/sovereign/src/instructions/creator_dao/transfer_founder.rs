@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::CreatorDAO;
+use crate::events::FounderTransferred;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// TRANSFER FOUNDER INSTRUCTION
+// =============================================================================
+//
+// Hands every founder-only power (hand-picking, governance updates) to a new
+// key, so a DAO isn't bricked if its founder rotates or loses a wallet.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct TransferFounder<'info> {
+    /// The current founder (must match DAO founder)
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub founder: Signer<'info>,
+
+    /// The DAO
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+}
+
+pub fn handler(ctx: Context<TransferFounder>, new_founder: Pubkey) -> Result<()> {
+    require!(new_founder != Pubkey::default(), CreatorDAOError::InvalidFounder);
+
+    let dao = &mut ctx.accounts.dao;
+    let old_founder = dao.founder;
+    dao.founder = new_founder;
+
+    emit!(FounderTransferred {
+        dao: dao.key(),
+        old_founder,
+        new_founder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Founder of DAO '{}' transferred from {} to {}",
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        old_founder,
+        new_founder
+    );
+
+    Ok(())
+}
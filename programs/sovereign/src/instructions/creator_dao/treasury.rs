@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::incinerator;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::creator_dao::{CreatorDAO, CreatorScoreDetails, DAOMembership, DAOTreasury};
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// DAO TREASURY INSTRUCTIONS
+// =============================================================================
+//
+// Vitalik: "a portion of their proceeds from the DAO are used to burn their
+// creator coins"
+//
+// Anyone can pay into a DAO's treasury (membership dues, content revenue
+// shares). When the founder settles a creator's proceeds, the treasury's
+// burn share of them is sent to the incinerator and recorded against the
+// creator's CreatorScoreDetails.total_burned.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitDAOTreasury<'info> {
+    #[account(mut)]
+    pub founder: Signer<'info>,
+
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    #[account(
+        init,
+        payer = founder,
+        space = DAOTreasury::SIZE,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, DAOTreasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositToTreasury<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", treasury.dao.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DAOTreasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnFromTreasury<'info> {
+    pub founder: Signer<'info>,
+
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub dao: Account<'info, CreatorDAO>,
+
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DAOTreasury>,
+
+    /// The creator whose proceeds are being settled
+    #[account(
+        constraint = creator_membership.dao == dao.key() @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.member_identity == creator_details.identity @ CreatorDAOError::CreatorNotMember,
+        constraint = creator_membership.is_active @ CreatorDAOError::CreatorNotMember,
+    )]
+    pub creator_membership: Account<'info, DAOMembership>,
+
+    /// The creator's score details
+    #[account(
+        mut,
+        seeds = [b"creator_details", creator_details.identity.as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    /// CHECK: Solana's incinerator; lamports sent here are burned
+    #[account(mut, address = incinerator::ID)]
+    pub burn_address: UncheckedAccount<'info>,
+}
+
+pub fn init_dao_treasury(ctx: Context<InitDAOTreasury>, burn_bps: u16) -> Result<()> {
+    require!(burn_bps <= 10000, CreatorDAOError::InvalidTreasuryBurn);
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.dao = ctx.accounts.dao.key();
+    treasury.burn_bps = burn_bps;
+    treasury.total_deposited = 0;
+    treasury.total_burned = 0;
+    treasury.reserved_fees = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    msg!("Initialized DAO treasury (burn: {} bps)", burn_bps);
+
+    Ok(())
+}
+
+pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, CreatorDAOError::InvalidDepositAmount);
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_deposited = treasury.total_deposited.saturating_add(amount);
+
+    msg!("Deposited {} lamports (total: {})", amount, treasury.total_deposited);
+
+    Ok(())
+}
+
+pub fn burn_from_treasury(ctx: Context<BurnFromTreasury>, proceeds: u64) -> Result<()> {
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let treasury = &mut ctx.accounts.treasury;
+    let available = treasury.spendable(treasury_info.lamports(), rent_floor);
+    require!(proceeds <= available, CreatorDAOError::InsufficientTreasury);
+
+    let burn_amount = treasury.burn_share(proceeds);
+
+    **treasury_info.try_borrow_mut_lamports()? -= burn_amount;
+    **ctx.accounts.burn_address.try_borrow_mut_lamports()? += burn_amount;
+
+    treasury.total_burned = treasury.total_burned.saturating_add(burn_amount);
+
+    let creator_details = &mut ctx.accounts.creator_details;
+    creator_details.total_burned = creator_details.total_burned.saturating_add(burn_amount);
+    creator_details.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Burned {} of {} lamports in proceeds (creator total burned: {})",
+        burn_amount,
+        proceeds,
+        creator_details.total_burned
+    );
+
+    Ok(())
+}
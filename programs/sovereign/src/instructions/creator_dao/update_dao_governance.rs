@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::CreatorDAO;
+use crate::state::ProtocolConfig;
+use crate::instructions::creator_dao::create_dao::{validate_governance, CreatorDAOError};
+
+// =============================================================================
+// UPDATE DAO GOVERNANCE INSTRUCTION
+// =============================================================================
+//
+// Lets the founder tune admission_threshold, voting_period, quorum, the
+// nominator score minimum, the nomination and rejection cooldowns and the
+// member count needed to open nominations as the DAO matures. Values are validated with the same rules as create_dao.
+// Nominations already in flight keep the parameters they snapshotted.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateDAOGovernanceParams {
+    /// New percentage of votes needed to admit (1-100)
+    pub admission_threshold: Option<u8>,
+    /// New voting period in seconds (1 day up to the protocol maximum)
+    pub voting_period: Option<i64>,
+    /// New quorum percentage (1-100)
+    pub quorum: Option<u8>,
+    /// New creator score required to nominate (0-10000)
+    pub min_nominator_creator_score: Option<u16>,
+    /// New seconds between a member's nominations (non-negative)
+    pub nomination_cooldown: Option<i64>,
+    /// New lamports charged per nomination (0 = free)
+    pub nomination_fee: Option<u64>,
+    /// New number of distinct accept voters needed to admit (at least 1)
+    pub min_accept_votes: Option<u16>,
+    /// New seconds before a rejected creator can be nominated again (non-negative)
+    pub rejection_cooldown: Option<i64>,
+    /// New member count needed before nominations open
+    pub min_members_for_nominations: Option<u8>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDAOGovernance<'info> {
+    /// The founder (must match DAO founder)
+    #[account(
+        constraint = dao.founder == founder.key() @ CreatorDAOError::NotFounder,
+    )]
+    pub founder: Signer<'info>,
+
+    /// The DAO
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// Holds the voting period cap
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateDAOGovernance>, params: UpdateDAOGovernanceParams) -> Result<()> {
+    let dao = &mut ctx.accounts.dao;
+
+    let admission_threshold = params.admission_threshold.unwrap_or(dao.admission_threshold);
+    let voting_period = params.voting_period.unwrap_or(dao.voting_period);
+    let quorum = params.quorum.unwrap_or(dao.quorum);
+    let min_nominator_creator_score = params
+        .min_nominator_creator_score
+        .unwrap_or(dao.min_nominator_creator_score);
+    let nomination_cooldown = params.nomination_cooldown.unwrap_or(dao.nomination_cooldown);
+    let min_accept_votes = params.min_accept_votes.unwrap_or(dao.min_accept_votes);
+    let rejection_cooldown = params.rejection_cooldown.unwrap_or(dao.rejection_cooldown);
+
+    validate_governance(
+        admission_threshold,
+        quorum,
+        voting_period,
+        ctx.accounts.protocol_config.max_voting_period,
+    )?;
+    require!(
+        min_nominator_creator_score <= 10000,
+        CreatorDAOError::InvalidMinNominatorScore
+    );
+    require!(nomination_cooldown >= 0, CreatorDAOError::InvalidNominationCooldown);
+    require!(min_accept_votes >= 1, CreatorDAOError::InvalidMinAcceptVotes);
+    require!(rejection_cooldown >= 0, CreatorDAOError::InvalidRejectionCooldown);
+
+    dao.admission_threshold = admission_threshold;
+    dao.voting_period = voting_period;
+    dao.quorum = quorum;
+    dao.min_nominator_creator_score = min_nominator_creator_score;
+    dao.nomination_cooldown = nomination_cooldown;
+    dao.min_accept_votes = min_accept_votes;
+    dao.rejection_cooldown = rejection_cooldown;
+    if let Some(nomination_fee) = params.nomination_fee {
+        dao.nomination_fee = nomination_fee;
+    }
+    if let Some(min_members_for_nominations) = params.min_members_for_nominations {
+        dao.min_members_for_nominations = min_members_for_nominations;
+    }
+
+    msg!(
+        "Updated governance for DAO '{}' (threshold: {}%, quorum: {}%, voting period: {}s)",
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0'),
+        dao.admission_threshold,
+        dao.quorum,
+        dao.voting_period
+    );
+
+    Ok(())
+}
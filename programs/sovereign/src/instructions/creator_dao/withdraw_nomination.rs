@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::state::creator_dao::{CreatorDAO, DAOTreasury, Nomination, NomineeLock};
+use crate::state::admission_market::AdmissionMarket;
+use crate::instructions::creator_dao::create_dao::CreatorDAOError;
+
+// =============================================================================
+// WITHDRAW NOMINATION INSTRUCTION
+// =============================================================================
+//
+// The nominator can pull a nomination while voting is still open. The
+// nomination is closed out without an accept/reject outcome and the nominee
+// lock is released so the creator can be nominated again later. A linked
+// prediction market reopens for trading (or is cancelled if it has already
+// expired) instead of staying locked.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawNomination<'info> {
+    /// The member who made the nomination
+    #[account(mut)]
+    pub nominator: Signer<'info>,
+
+    /// The DAO
+    #[account(mut)]
+    pub dao: Account<'info, CreatorDAO>,
+
+    /// The nomination to withdraw
+    #[account(
+        mut,
+        constraint = nomination.dao == dao.key(),
+        constraint = nomination.nominator == nominator.key() @ CreatorDAOError::NotNominator,
+        constraint = !nomination.is_resolved @ CreatorDAOError::AlreadyResolved,
+    )]
+    pub nomination: Account<'info, Nomination>,
+
+    /// The nominee's pending-nomination lock, released to the nominator
+    #[account(
+        mut,
+        close = nominator,
+        seeds = [
+            b"nominee_lock",
+            dao.key().as_ref(),
+            nomination.nominee_wallet.as_ref()
+        ],
+        bump = nominee_lock.bump,
+    )]
+    pub nominee_lock: Account<'info, NomineeLock>,
+
+    /// The prediction market linked to this nomination, required if it has one
+    #[account(
+        mut,
+        address = nomination.linked_market.unwrap_or_default() @ CreatorDAOError::MarketMismatch,
+        constraint = prediction_market.is_unsettled() @ CreatorDAOError::MarketAlreadySettled,
+        constraint = prediction_market.nomination == Some(nomination.key()) @ CreatorDAOError::MarketMismatch,
+    )]
+    pub prediction_market: Option<Account<'info, AdmissionMarket>>,
+
+    /// The DAO treasury, required if a nomination fee was paid
+    #[account(
+        mut,
+        seeds = [b"dao_treasury", dao.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DAOTreasury>>,
+}
+
+pub fn handler(ctx: Context<WithdrawNomination>) -> Result<()> {
+    let clock = Clock::get()?;
+    let dao = &mut ctx.accounts.dao;
+    let nomination = &mut ctx.accounts.nomination;
+
+    // A linked market must be settled along with its nomination
+    require!(
+        nomination.links_market(ctx.accounts.prediction_market.as_ref().map(|market| market.key())),
+        CreatorDAOError::LinkedMarketRequired
+    );
+
+    require!(
+        clock.unix_timestamp <= nomination.voting_ends_at,
+        CreatorDAOError::VotingEnded
+    );
+
+    nomination.is_resolved = true;
+    nomination.was_accepted = false;
+    nomination.was_withdrawn = true;
+    nomination.resolved_at = Some(clock.unix_timestamp);
+
+    dao.pending_nominations = dao.pending_nominations.saturating_sub(1);
+
+    // The forfeited fee stays in the treasury, no longer held for a refund
+    if nomination.fee_paid > 0 {
+        ctx.accounts
+            .treasury
+            .as_mut()
+            .ok_or(CreatorDAOError::TreasuryRequired)?
+            .release_fee(nomination.fee_paid);
+    }
+
+    if let Some(market) = &mut ctx.accounts.prediction_market {
+        if market.unlock_after_nomination(clock.unix_timestamp) {
+            msg!("Linked prediction market reopened for trading");
+        } else {
+            market.resolved_at = Some(clock.unix_timestamp);
+            msg!("Linked prediction market cancelled; predictors can claim refunds");
+        }
+    }
+
+    msg!(
+        "Nomination #{} withdrawn from DAO '{}'",
+        nomination.nomination_id,
+        String::from_utf8_lossy(&dao.name).trim_end_matches('\0')
+    );
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::events::emit_tier_change;
+
+// =============================================================================
+// DECAY SCORE INSTRUCTION
+// =============================================================================
+//
+// Reputation earned long ago shouldn't count the same as recent activity.
+// Decay is applied lazily on every score update; this permissionless
+// instruction lets anyone settle it for an idle identity so its composite
+// and tier reflect the elapsed time.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct DecayScore<'info> {
+    #[account(mut)]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn handler(ctx: Context<DecayScore>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let identity = &mut ctx.accounts.identity;
+
+    identity.apply_decay(now);
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!(
+        "Applied decay (composite: {}, tier: {})",
+        identity.composite_score,
+        identity.tier
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::SovereignIdentity;
+use crate::errors::SovereignError;
+
+// =============================================================================
+// FREEZE IDENTITY INSTRUCTIONS
+// =============================================================================
+//
+// If a key holding a dimension authority is compromised, the owner can freeze
+// the identity so no score can be written until they unfreeze it (typically
+// after rotating the affected authority). An identity left behind by
+// transfer_identity stays frozen for good.
+//
+// Freezing is not a way to sit out bad score updates while keeping access: a
+// frozen identity fails verify_tier and can't found, join, sponsor or
+// nominate into a DAO until it is unfrozen.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetFrozen<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+}
+
+pub fn freeze_identity(ctx: Context<SetFrozen>) -> Result<()> {
+    ctx.accounts.identity.is_frozen = true;
+
+    msg!("Froze identity {}", ctx.accounts.identity.key());
+    Ok(())
+}
+
+pub fn unfreeze_identity(ctx: Context<SetFrozen>) -> Result<()> {
+    require!(
+        ctx.accounts.identity.transferred_to.is_none(),
+        SovereignError::IdentityTransferred
+    );
+    ctx.accounts.identity.is_frozen = false;
+
+    msg!("Unfroze identity {}", ctx.accounts.identity.key());
+    Ok(())
+}
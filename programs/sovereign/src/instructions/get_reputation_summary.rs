@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, CreatorScoreDetails, SurfacingScore, TierConfig, ProtocolConfig};
+
+// =============================================================================
+// GET REPUTATION SUMMARY INSTRUCTION
+// =============================================================================
+//
+// Read-only aggregate of an identity's reputation, returned through Anchor's
+// return data so clients can simulate one instruction instead of fetching
+// and recombining several accounts. Pending decay is applied to a copy, so
+// the summary reflects `now` without writing to the identity.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReputationSummary {
+    pub owner: Pubkey,
+    pub composite_score: u16,
+    pub tier: u8,
+    pub trading_score: u16,
+    pub civic_score: u16,
+    pub developer_score: u16,
+    pub infra_score: u16,
+    pub creator_score: u16,
+    /// DAOs the identity has been accepted into (0 without creator details)
+    pub daos_accepted: u16,
+    /// Prediction accuracy in basis points (0 without creator details)
+    pub prediction_accuracy_bps: u16,
+    /// Talent scout score (0 if the owner never created a market)
+    pub scout_score: u16,
+}
+
+#[derive(Accounts)]
+pub struct GetReputationSummary<'info> {
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        seeds = [b"creator_details", identity.key().as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Option<Account<'info, CreatorScoreDetails>>,
+
+    #[account(
+        seeds = [b"surfacing_score", identity.owner.as_ref()],
+        bump,
+    )]
+    pub surfacing_score: Option<Account<'info, SurfacingScore>>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(ctx: Context<GetReputationSummary>) -> Result<ReputationSummary> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let mut identity = (*ctx.accounts.identity).clone();
+    identity.apply_decay(now);
+    identity.recalculate_with(now, &cutoffs, balanced_tier5);
+
+    let creator_details = ctx.accounts.creator_details.as_ref();
+    let surfacing_score = ctx.accounts.surfacing_score.as_ref();
+
+    Ok(ReputationSummary {
+        owner: identity.owner,
+        composite_score: identity.composite_score,
+        tier: identity.tier,
+        trading_score: identity.trading_score,
+        civic_score: identity.civic_score,
+        developer_score: identity.developer_score,
+        infra_score: identity.infra_score,
+        creator_score: identity.creator_score,
+        daos_accepted: creator_details.map_or(0, |details| details.daos_accepted),
+        prediction_accuracy_bps: creator_details.map_or(0, |details| details.prediction_accuracy_bps),
+        scout_score: surfacing_score.map_or(0, |score| score.scout_score),
+    })
+}
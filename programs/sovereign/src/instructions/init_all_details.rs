@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    SovereignIdentity, TradingScoreDetails, CivicScoreDetails, DeveloperScoreDetails,
+    InfraScoreDetails, CreatorScoreDetails,
+};
+use crate::errors::SovereignError;
+
+// =============================================================================
+// INIT ALL DETAILS INSTRUCTION
+// =============================================================================
+//
+// Creates every per-dimension detail account for an identity in a single
+// transaction. Accounts that already exist are left untouched, so this is
+// safe to call again after only some of the details were initialized.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitAllDetails<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = TradingScoreDetails::SIZE,
+        seeds = [b"trading_details", identity.key().as_ref()],
+        bump
+    )]
+    pub trading_details: Account<'info, TradingScoreDetails>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CivicScoreDetails::SIZE,
+        seeds = [b"civic_details", identity.key().as_ref()],
+        bump
+    )]
+    pub civic_details: Account<'info, CivicScoreDetails>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = DeveloperScoreDetails::SIZE,
+        seeds = [b"developer_details", identity.key().as_ref()],
+        bump
+    )]
+    pub developer_details: Account<'info, DeveloperScoreDetails>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = InfraScoreDetails::SIZE,
+        seeds = [b"infra_details", identity.key().as_ref()],
+        bump
+    )]
+    pub infra_details: Account<'info, InfraScoreDetails>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CreatorScoreDetails::SIZE,
+        seeds = [b"creator_details", identity.key().as_ref()],
+        bump
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAllDetails>) -> Result<()> {
+    let identity_key = ctx.accounts.identity.key();
+    let now = Clock::get()?.unix_timestamp;
+    let mut initialized = 0u8;
+
+    // Freshly created accounts are zeroed, so only the identity link,
+    // timestamp and bump need to be written.
+    let trading = &mut ctx.accounts.trading_details;
+    if trading.identity == Pubkey::default() {
+        trading.identity = identity_key;
+        trading.last_updated = now;
+        trading.bump = ctx.bumps.trading_details;
+        initialized += 1;
+    }
+
+    let civic = &mut ctx.accounts.civic_details;
+    if civic.identity == Pubkey::default() {
+        civic.identity = identity_key;
+        civic.last_updated = now;
+        civic.bump = ctx.bumps.civic_details;
+        initialized += 1;
+    }
+
+    let developer = &mut ctx.accounts.developer_details;
+    if developer.identity == Pubkey::default() {
+        developer.identity = identity_key;
+        developer.last_updated = now;
+        developer.bump = ctx.bumps.developer_details;
+        initialized += 1;
+    }
+
+    let infra = &mut ctx.accounts.infra_details;
+    if infra.identity == Pubkey::default() {
+        infra.identity = identity_key;
+        infra.last_updated = now;
+        infra.bump = ctx.bumps.infra_details;
+        initialized += 1;
+    }
+
+    let creator = &mut ctx.accounts.creator_details;
+    if creator.identity == Pubkey::default() {
+        creator.identity = identity_key;
+        creator.first_dao_acceptance = None;
+        creator.last_updated = now;
+        creator.bump = ctx.bumps.creator_details;
+        initialized += 1;
+    }
+
+    msg!(
+        "Initialized {} detail account(s) for identity {}",
+        initialized,
+        identity_key
+    );
+
+    Ok(())
+}
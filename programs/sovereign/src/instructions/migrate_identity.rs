@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{LegacyIdentity, SovereignIdentity, TierChange, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::instructions::score_history::open_history;
+use crate::errors::SovereignError;
+use crate::events::TierChanged;
+
+// =============================================================================
+// MIGRATE IDENTITY INSTRUCTION
+// =============================================================================
+//
+// Identities created before the creator extension are `schema_version` 1:
+// they still have the original 230-byte layout (see `LegacyIdentity`), which
+// predates the version field itself, and were scored with the legacy
+// 4-dimension weighting. The owner migrates them once: the account is grown
+// to the current layout (the owner pays the extra rent), the creator
+// dimension starts unscored under the given creator authority, the default
+// 5-dimension weights are adopted and the composite is recalculated with
+// them. Its score history is opened here too, since legacy identities never
+// had one. New identities start on version 2.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct MigrateToV2<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The legacy identity, rewritten in the current layout
+    /// CHECK: Legacy layout; parsed and owner-checked by the handler
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub identity: UncheckedAccount<'info>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// History that records the recalculated composite and tier. Legacy
+    /// identities never had one; a current identity's existing history is
+    /// only loaded so the handler can refuse it with `AlreadyMigrated`.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ScoreHistory::SIZE,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump
+    )]
+    pub score_history: Account<'info, ScoreHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateToV2>, creator_authority: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let identity_info = ctx.accounts.identity.to_account_info();
+
+    let legacy = LegacyIdentity::try_from_account_data(&identity_info.try_borrow_data()?)?;
+    require_keys_eq!(legacy.owner, ctx.accounts.owner.key(), SovereignError::OwnerMismatch);
+
+    // Grow the account to the current layout
+    let rent_due = Rent::get()?
+        .minimum_balance(SovereignIdentity::SIZE)
+        .saturating_sub(identity_info.lamports());
+    if rent_due > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: identity_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    identity_info.realloc(SovereignIdentity::SIZE, true)?;
+
+    let (migrated, tier_change) = SovereignIdentity::migrate_to_v2(
+        &legacy,
+        creator_authority,
+        now,
+        &TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref()),
+        ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref()),
+    );
+    migrated.try_serialize(&mut &mut identity_info.try_borrow_mut_data()?[..])?;
+
+    let history = &mut ctx.accounts.score_history;
+    open_history(history, identity_info.key(), ctx.bumps.score_history);
+    history.push(&migrated, now);
+    if let Some(TierChange { old_tier, new_tier }) = tier_change {
+        emit!(TierChanged {
+            identity: identity_info.key(),
+            old_tier,
+            new_tier,
+            composite_score: migrated.composite_score,
+            timestamp: now,
+        });
+    }
+
+    msg!(
+        "Migrated identity to schema v{} (composite: {} -> {}, tier: {})",
+        migrated.schema_version,
+        legacy.composite_score,
+        migrated.composite_score,
+        migrated.tier
+    );
+
+    Ok(())
+}
@@ -1,10 +1,29 @@
 pub mod create_identity;
 pub mod set_authority;
+pub mod freeze_identity;
+pub mod set_identity_metadata;
+pub mod transfer_identity;
+pub mod migrate_identity;
 pub mod update_trading;
+pub mod update_trading_details;
 pub mod update_civic;
+pub mod civic_details;
 pub mod update_developer;
 pub mod update_infra;
 pub mod update_creator;
+pub mod sync_creator_score;
+pub mod set_tier_grace_period;
+pub mod init_all_details;
+pub mod set_decay_rate;
+pub mod decay_score;
+pub mod recompute_composite;
+pub mod set_score_weights;
+pub mod get_reputation_summary;
+pub mod bind_attestation;
+pub mod verify_tier;
+pub mod tier_config;
+pub mod score_history;
+pub mod protocol_config;
 
 // Vitalik's Creator Coin Extension
 pub mod creator_dao;
@@ -12,10 +31,29 @@ pub mod admission_market;
 
 pub use create_identity::*;
 pub use set_authority::*;
+pub use freeze_identity::*;
+pub use set_identity_metadata::*;
+pub use transfer_identity::*;
+pub use migrate_identity::*;
 pub use update_trading::*;
+pub use update_trading_details::*;
 pub use update_civic::*;
+pub use civic_details::*;
 pub use update_developer::*;
 pub use update_infra::*;
 pub use update_creator::*;
+pub use sync_creator_score::*;
+pub use set_tier_grace_period::*;
+pub use init_all_details::*;
+pub use set_decay_rate::*;
+pub use decay_score::*;
+pub use recompute_composite::*;
+pub use set_score_weights::*;
+pub use get_reputation_summary::*;
+pub use bind_attestation::*;
+pub use verify_tier::*;
+pub use tier_config::*;
+pub use score_history::*;
+pub use protocol_config::*;
 pub use creator_dao::*;
 pub use admission_market::*;
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, DEFAULT_MAX_VOTING_PERIOD, MIN_VOTING_PERIOD};
+use crate::errors::SovereignError;
+
+// =============================================================================
+// PROTOCOL CONFIG INSTRUCTIONS
+// =============================================================================
+//
+// initialize_protocol_config is one-time setup of the program-wide pause
+// switch; the signer becomes its admin. set_paused lets the admin halt new
+// markets, positions and nominations if something goes wrong, and resume
+// them once it is fixed. set_min_founder_composite sets the reputation a
+// founder needs before create_dao, set_max_voting_period caps how long a
+// DAO's votes may stay open, and set_require_balanced_tier5 holds tier 5
+// back from identities that are strong in only some dimensions.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::SIZE,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ SovereignError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    config.admin = ctx.accounts.admin.key();
+    config.paused = false;
+    config.min_founder_composite = 0;
+    config.max_voting_period = DEFAULT_MAX_VOTING_PERIOD;
+    config.require_balanced_tier5 = false;
+    config.bump = ctx.bumps.protocol_config;
+
+    msg!("Initialized protocol config with admin {}", config.admin);
+    Ok(())
+}
+
+pub fn set_paused(ctx: Context<SetProtocolConfig>, paused: bool) -> Result<()> {
+    ctx.accounts.protocol_config.paused = paused;
+
+    msg!("Protocol {}", if paused { "paused" } else { "unpaused" });
+    Ok(())
+}
+
+pub fn set_min_founder_composite(ctx: Context<SetProtocolConfig>, min_founder_composite: u16) -> Result<()> {
+    require!(min_founder_composite <= 10000, SovereignError::InvalidScore);
+    ctx.accounts.protocol_config.min_founder_composite = min_founder_composite;
+
+    msg!("Founders now need a composite score of at least {}", min_founder_composite);
+    Ok(())
+}
+
+pub fn set_max_voting_period(ctx: Context<SetProtocolConfig>, max_voting_period: i64) -> Result<()> {
+    require!(max_voting_period >= MIN_VOTING_PERIOD, SovereignError::InvalidVotingPeriodBound);
+    ctx.accounts.protocol_config.max_voting_period = max_voting_period;
+
+    msg!("DAO voting periods are now capped at {}s", max_voting_period);
+    Ok(())
+}
+
+pub fn set_require_balanced_tier5(ctx: Context<SetProtocolConfig>, require_balanced_tier5: bool) -> Result<()> {
+    ctx.accounts.protocol_config.require_balanced_tier5 = require_balanced_tier5;
+
+    msg!(
+        "Balanced tier 5 {}",
+        if require_balanced_tier5 { "required" } else { "not required" }
+    );
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::events::emit_tier_change;
+
+// =============================================================================
+// RECOMPUTE COMPOSITE INSTRUCTION
+// =============================================================================
+//
+// Composite and tier are only refreshed when a dimension is written, so a
+// change to the tier curve or weighting logic leaves existing identities
+// stale until their next update. This permissionless instruction recomputes
+// them from the current scores. Decay is not applied (see decay_score), and
+// nothing is changed or recorded when the result matches what is stored.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct RecomputeComposite<'info> {
+    #[account(mut)]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn handler(ctx: Context<RecomputeComposite>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+
+    let mut recomputed = (*ctx.accounts.identity).clone();
+    let tier_change = recomputed.recalculate_with(now, &cutoffs, balanced_tier5);
+
+    let identity = &mut ctx.accounts.identity;
+    if recomputed.composite_score == identity.composite_score
+        && recomputed.tier == identity.tier
+        && recomputed.tier_demotion_pending_since == identity.tier_demotion_pending_since
+    {
+        msg!("Composite already current ({}, tier {})", identity.composite_score, identity.tier);
+        return Ok(());
+    }
+
+    identity.composite_score = recomputed.composite_score;
+    identity.tier = recomputed.tier;
+    identity.tier_demotion_pending_since = recomputed.tier_demotion_pending_since;
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!(
+        "Recomputed composite: {} (tier: {})",
+        identity.composite_score,
+        identity.tier
+    );
+
+    Ok(())
+}
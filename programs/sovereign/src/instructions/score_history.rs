@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, ScoreHistory, ScoreSnapshot, SCORE_HISTORY_LEN};
+
+// =============================================================================
+// SCORE HISTORY INSTRUCTIONS
+// =============================================================================
+//
+// create_identity opens the identity's snapshot ring buffer, and
+// init_score_history opens one for identities created before it did. Anyone
+// may open it and pay the rent, so an oracle or keeper can start recording an
+// older identity without its owner. Score updates take it as an optional
+// account and append the recalculated composite and tier when it is passed.
+// get_score_history returns the snapshots oldest first so clients can chart
+// whether a reputation is rising or falling.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitScoreHistory<'info> {
+    /// Whoever opens the history pays its rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"identity", identity.owner.as_ref()],
+        bump = identity.bump,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ScoreHistory::SIZE,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump
+    )]
+    pub score_history: Account<'info, ScoreHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetScoreHistory<'info> {
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Account<'info, ScoreHistory>,
+}
+
+/// Start an empty history for `identity`
+pub(crate) fn open_history(history: &mut ScoreHistory, identity: Pubkey, bump: u8) {
+    history.identity = identity;
+    history.entries = [ScoreSnapshot::default(); SCORE_HISTORY_LEN];
+    history.next = 0;
+    history.count = 0;
+    history.bump = bump;
+}
+
+pub fn init_score_history(ctx: Context<InitScoreHistory>) -> Result<()> {
+    let history = &mut ctx.accounts.score_history;
+    open_history(history, ctx.accounts.identity.key(), ctx.bumps.score_history);
+
+    msg!("Initialized score history for identity {}", history.identity);
+    Ok(())
+}
+
+pub fn get_score_history(ctx: Context<GetScoreHistory>) -> Result<Vec<ScoreSnapshot>> {
+    Ok(ctx.accounts.score_history.ordered())
+}
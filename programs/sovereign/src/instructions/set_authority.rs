@@ -55,3 +55,27 @@ pub fn set_infra_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) ->
     msg!("Set infra authority to {}", new_authority);
     Ok(())
 }
+
+pub fn set_creator_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
+    require!(new_authority != Pubkey::default(), SovereignError::InvalidAuthority);
+
+    let identity = &mut ctx.accounts.identity;
+    identity.creator_authority = new_authority;
+
+    msg!("Set creator authority to {}", new_authority);
+    Ok(())
+}
+
+/// Emergency reset: hand every dimension authority back to the owner at once
+pub fn revoke_all_authorities(ctx: Context<SetAuthority>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let identity = &mut ctx.accounts.identity;
+    identity.trading_authority = owner;
+    identity.civic_authority = owner;
+    identity.developer_authority = owner;
+    identity.infra_authority = owner;
+    identity.creator_authority = owner;
+
+    msg!("Revoked all dimension authorities back to owner {}", owner);
+    Ok(())
+}
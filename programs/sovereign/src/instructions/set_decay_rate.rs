@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::errors::SovereignError;
+use crate::events::emit_tier_change;
+
+#[derive(Accounts)]
+pub struct SetDecayRate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn handler(ctx: Context<SetDecayRate>, decay_bps_per_day: u16) -> Result<()> {
+    require!(decay_bps_per_day <= 10000, SovereignError::InvalidDecayRate);
+
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let identity = &mut ctx.accounts.identity;
+
+    // Settle decay accrued under the old rate before switching
+    identity.apply_decay(now);
+    identity.decay_bps_per_day = decay_bps_per_day;
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!("Set score decay to {} bps per day", decay_bps_per_day);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::SovereignIdentity;
+use crate::errors::SovereignError;
+
+// =============================================================================
+// SET IDENTITY METADATA INSTRUCTION
+// =============================================================================
+//
+// Lets the owner attach a display name and a metadata URI (avatar, bio, ...)
+// to their identity, so apps don't each keep their own off-chain mapping.
+// Both are stored as fixed, zero-padded byte arrays; longer input is
+// truncated, like a DAO's name. Passing an empty string clears the field.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetIdentityMetadata<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+}
+
+/// Copy `value` into a zero-padded array, truncating at `N` bytes
+fn padded<const N: usize>(value: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let value = value.as_bytes();
+    let len = value.len().min(N);
+    bytes[..len].copy_from_slice(&value[..len]);
+    bytes
+}
+
+pub fn handler(ctx: Context<SetIdentityMetadata>, display_name: String, metadata_uri: String) -> Result<()> {
+    let identity = &mut ctx.accounts.identity;
+    identity.display_name = padded(&display_name);
+    identity.metadata_uri = padded(&metadata_uri);
+
+    msg!(
+        "Set metadata for identity {}: '{}'",
+        identity.key(),
+        String::from_utf8_lossy(&identity.display_name).trim_end_matches('\0')
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::SovereignIdentity;
+use crate::errors::SovereignError;
+
+#[derive(Accounts)]
+pub struct SetTierGracePeriod<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.owner == owner.key() @ SovereignError::OwnerMismatch,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+}
+
+pub fn handler(ctx: Context<SetTierGracePeriod>, grace_period: i64) -> Result<()> {
+    require!(grace_period >= 0, SovereignError::InvalidGracePeriod);
+
+    let identity = &mut ctx.accounts.identity;
+    identity.tier_grace_period = grace_period;
+
+    msg!("Set tier demotion grace period to {} seconds", grace_period);
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::state::creator_dao::CreatorScoreDetails;
+use crate::errors::SovereignError;
+use crate::events::emit_tier_change;
+
+// =============================================================================
+// SYNC CREATOR SCORE INSTRUCTION
+// =============================================================================
+//
+// CreatorScoreDetails accrues continuously (content, peer upvotes, judgment
+// and prediction accuracy), but none of that reaches the identity's creator
+// dimension on its own. This instruction recomputes the creator score from
+// the details and writes it through, under the same creator_authority gate
+// as update_creator_score.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SyncCreatorScore<'info> {
+    /// The identity's creator authority (CreatorDAO program or delegate)
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = identity.creator_authority == authority.key() @ SovereignError::Unauthorized,
+        constraint = !identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    /// The identity's creator score details
+    #[account(
+        seeds = [b"creator_details", identity.key().as_ref()],
+        bump = creator_details.bump,
+    )]
+    pub creator_details: Account<'info, CreatorScoreDetails>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn handler(ctx: Context<SyncCreatorScore>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let score = ctx.accounts.creator_details.calculate_score(now);
+
+    let identity = &mut ctx.accounts.identity;
+    identity.apply_decay(now);
+    identity.creator_score = score;
+    identity.creator_updated_at = now;
+    identity.last_updated = now;
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!(
+        "Synced creator score to {} (composite: {}, tier: {})",
+        score,
+        identity.composite_score,
+        identity.tier
+    );
+
+    Ok(())
+}
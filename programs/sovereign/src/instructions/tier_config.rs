@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, TierConfig};
+use crate::errors::SovereignError;
+
+// =============================================================================
+// TIER CONFIG INSTRUCTIONS
+// =============================================================================
+//
+// initialize_tier_config is one-time setup of the protocol-wide tier curve;
+// only the protocol admin may run it, and it becomes the curve's authority.
+// set_tier_config lets that authority move the cutoffs and rename the tiers.
+// Identities pick up a new curve the next time they are recalculated with
+// the config passed in; until it is initialized, recalculations use the
+// default cutoffs.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TierConfigParams {
+    /// Minimum composite for tiers 2-5 (strictly increasing)
+    pub cutoffs: [u16; 4],
+    /// Display names for tiers 1-5 (at most 16 bytes each)
+    pub names: Vec<String>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTierConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Protocol config; only its admin may set up the tier curve
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == authority.key() @ SovereignError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TierConfig::SIZE,
+        seeds = [b"tier_config"],
+        bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTierConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tier_config"],
+        bump = tier_config.bump,
+        constraint = tier_config.authority == authority.key() @ SovereignError::Unauthorized,
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+}
+
+/// Validate the params and write them into `config`
+fn apply_params(config: &mut TierConfig, params: TierConfigParams) -> Result<()> {
+    require!(TierConfig::cutoffs_valid(&params.cutoffs), SovereignError::InvalidTierCutoffs);
+    require!(
+        params.names.len() == 5 && params.names.iter().all(|name| name.len() <= 16),
+        SovereignError::InvalidTierNames
+    );
+
+    config.cutoffs = params.cutoffs;
+    for (slot, name) in config.names.iter_mut().zip(params.names.iter()) {
+        *slot = [0u8; 16];
+        slot[..name.len()].copy_from_slice(name.as_bytes());
+    }
+    Ok(())
+}
+
+pub fn initialize_tier_config(ctx: Context<InitializeTierConfig>, params: TierConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.tier_config;
+    config.authority = ctx.accounts.authority.key();
+    config.bump = ctx.bumps.tier_config;
+    apply_params(config, params)?;
+
+    msg!("Initialized tier config with cutoffs {:?}", config.cutoffs);
+    Ok(())
+}
+
+pub fn set_tier_config(ctx: Context<SetTierConfig>, params: TierConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.tier_config;
+    apply_params(config, params)?;
+
+    msg!("Updated tier cutoffs to {:?}", config.cutoffs);
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{ScoreHistory, SovereignIdentity};
+use crate::errors::SovereignError;
+
+// =============================================================================
+// TRANSFER IDENTITY INSTRUCTION
+// =============================================================================
+//
+// Identities are PDAs seeded by their owner, so rotating to a new wallet means
+// moving the account: a new one is created under the new owner's seeds with
+// every field copied. Both wallets sign so an identity can't be pushed onto,
+// or pulled from, someone who didn't agree to it.
+//
+// The old PDA is not closed. Detail accounts, DAO memberships and the member
+// directory are seeded by the old identity or wallet, so a re-created
+// identity at the same address would pick them all up again alongside the
+// copy. Instead it is left as a tombstone: frozen for good, unverified, with
+// its scores cleared and pointing at its successor. The score history moves
+// with the identity; detail accounts stay with the tombstone and the new
+// identity starts its own.
+//
+// The trading, civic, developer and infra authorities are reset to the new
+// owner unless `keep_authorities` is set; the creator authority belongs to
+// the DAO side and always carries over.
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct TransferIdentity<'info> {
+    #[account(mut)]
+    pub old_owner: Signer<'info>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"identity", old_owner.key().as_ref()],
+        bump = old_identity.bump,
+        constraint = old_identity.owner == old_owner.key() @ SovereignError::OwnerMismatch,
+        constraint = old_identity.transferred_to.is_none() @ SovereignError::IdentityTransferred,
+    )]
+    pub old_identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = SovereignIdentity::SIZE,
+        seeds = [b"identity", new_owner.key().as_ref()],
+        bump,
+    )]
+    pub new_identity: Account<'info, SovereignIdentity>,
+
+    /// The old identity's score history, closed once copied
+    #[account(
+        mut,
+        close = old_owner,
+        seeds = [b"score_history", old_identity.key().as_ref()],
+        bump = old_score_history.bump,
+    )]
+    pub old_score_history: Account<'info, ScoreHistory>,
+
+    /// The new identity's score history, carrying the old snapshots over
+    #[account(
+        init,
+        payer = new_owner,
+        space = ScoreHistory::SIZE,
+        seeds = [b"score_history", new_identity.key().as_ref()],
+        bump,
+    )]
+    pub new_score_history: Account<'info, ScoreHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TransferIdentity>, keep_authorities: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let new_owner = ctx.accounts.new_owner.key();
+    let new_identity_key = ctx.accounts.new_identity.key();
+    let old = &ctx.accounts.old_identity;
+
+    let mut migrated = SovereignIdentity {
+        owner: new_owner,
+        transferred_to: None,
+        bump: ctx.bumps.new_identity,
+        ..(**old).clone()
+    };
+
+    if !keep_authorities {
+        migrated.trading_authority = new_owner;
+        migrated.civic_authority = new_owner;
+        migrated.developer_authority = new_owner;
+        migrated.infra_authority = new_owner;
+    }
+
+    ctx.accounts.new_identity.set_inner(migrated);
+    ctx.accounts.old_identity.tombstone(new_identity_key, now);
+
+    let history = ScoreHistory {
+        identity: new_identity_key,
+        bump: ctx.bumps.new_score_history,
+        ..(*ctx.accounts.old_score_history).clone()
+    };
+    ctx.accounts.new_score_history.set_inner(history);
+
+    msg!(
+        "Transferred SOVEREIGN identity from {} to {} (authorities {})",
+        ctx.accounts.old_owner.key(),
+        new_owner,
+        if keep_authorities { "kept" } else { "reset" }
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::SovereignIdentity;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig, ScoreHistory};
 use crate::errors::SovereignError;
+use crate::events::emit_tier_change;
 
 // =============================================================================
 // UPDATE CREATOR SCORE INSTRUCTION
@@ -27,17 +28,47 @@ pub struct UpdateCreatorScore<'info> {
     #[account(
         mut,
         constraint = identity.creator_authority == authority.key() @ SovereignError::Unauthorized,
+        constraint = !identity.is_frozen @ SovereignError::IdentityFrozen,
     )]
     pub identity: Account<'info, SovereignIdentity>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
 }
 
-pub fn handler(ctx: Context<UpdateCreatorScore>, score: u16) -> Result<()> {
+pub fn handler(ctx: Context<UpdateCreatorScore>, score: u16, update_seq: Option<u64>) -> Result<()> {
     require!(score <= 10000, SovereignError::InvalidScore);
 
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
     let identity = &mut ctx.accounts.identity;
+    require!(
+        SovereignIdentity::advance_update_seq(&mut identity.creator_update_seq, update_seq),
+        SovereignError::StaleUpdate
+    );
+    identity.apply_decay(now);
     identity.creator_score = score;
-    identity.last_updated = Clock::get()?.unix_timestamp;
-    identity.recalculate();
+    identity.creator_updated_at = now;
+    identity.last_updated = now;
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
 
     msg!(
         "Updated creator score to {} (composite: {}, tier: {})",
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TradingScoreDetails, TierConfig, ProtocolConfig, ScoreHistory};
+use crate::errors::SovereignError;
+use crate::events::emit_tier_change;
+
+// =============================================================================
+// UPDATE TRADING DETAILS INSTRUCTION
+// =============================================================================
+//
+// Records raw trading metrics and derives the trading score on-chain via
+// TradingScoreDetails::calculate_score, instead of trusting a precomputed
+// score from the oracle.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTradingDetailsParams {
+    /// Win rate in basis points (0-10000)
+    pub win_rate_bps: u16,
+    /// Profit factor in basis points (0-50000 = 0-5.0x)
+    pub profit_factor_bps: u16,
+    /// Total number of trades
+    pub total_trades: u64,
+    /// Total trading volume in USDC (6 decimals)
+    pub total_volume: u64,
+    /// Maximum drawdown in basis points (0-10000)
+    pub max_drawdown_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTradingDetails<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = identity.trading_authority == authority.key() @ SovereignError::Unauthorized,
+        constraint = !identity.is_frozen @ SovereignError::IdentityFrozen,
+    )]
+    pub identity: Account<'info, SovereignIdentity>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TradingScoreDetails::SIZE,
+        seeds = [b"trading_details", identity.key().as_ref()],
+        bump
+    )]
+    pub trading_details: Account<'info, TradingScoreDetails>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Optional: history that records the recalculated composite and tier
+    #[account(
+        mut,
+        seeds = [b"score_history", identity.key().as_ref()],
+        bump = score_history.bump,
+    )]
+    pub score_history: Option<Account<'info, ScoreHistory>>,
+}
+
+pub fn handler(ctx: Context<UpdateTradingDetails>, params: UpdateTradingDetailsParams) -> Result<()> {
+    require!(params.win_rate_bps <= 10000, SovereignError::InvalidMetric);
+    require!(params.profit_factor_bps <= 50000, SovereignError::InvalidMetric);
+    require!(params.max_drawdown_bps <= 10000, SovereignError::InvalidMetric);
+
+    let now = Clock::get()?.unix_timestamp;
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let identity = &mut ctx.accounts.identity;
+    let details = &mut ctx.accounts.trading_details;
+
+    details.identity = identity.key();
+    details.win_rate_bps = params.win_rate_bps;
+    details.profit_factor_bps = params.profit_factor_bps;
+    details.total_trades = params.total_trades;
+    details.total_volume = params.total_volume;
+    details.max_drawdown_bps = params.max_drawdown_bps;
+    details.last_updated = now;
+    details.bump = ctx.bumps.trading_details;
+
+    let score = details.calculate_score();
+
+    identity.apply_decay(now);
+    identity.trading_score = score;
+    identity.trading_updated_at = now;
+    identity.last_updated = now;
+    let tier_change = identity.recalculate_with(now, &cutoffs, balanced_tier5);
+    if let Some(history) = ctx.accounts.score_history.as_deref_mut() {
+        history.push(identity, now);
+    }
+    emit_tier_change(identity, tier_change, now);
+
+    msg!(
+        "Derived trading score {} from details (composite: {}, tier: {})",
+        score,
+        identity.composite_score,
+        identity.tier
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{SovereignIdentity, TierConfig, ProtocolConfig};
+
+// =============================================================================
+// VERIFY TIER INSTRUCTION
+// =============================================================================
+//
+// Stable CPI entry point for integrating programs that only need to gate on
+// a SOVEREIGN tier. The answer comes back through return data, so callers
+// don't have to deserialize (and couple themselves to) the identity layout.
+// Pending decay is applied to a copy, as in get_reputation_summary.
+//
+// A frozen identity (including one left behind by transfer_identity) never
+// meets a minimum tier, so freezing can't be used to dodge score updates
+// while keeping access.
+//
+// Any identity account can be passed in, so the result carries the owning
+// wallet; callers must check it against whoever they are gating.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TierVerification {
+    /// Wallet that owns the identity; callers must match it to their user
+    pub owner: Pubkey,
+    /// Whether the identity is unfrozen and its tier is at least the
+    /// requested minimum
+    pub meets_min_tier: bool,
+    pub composite_score: u16,
+    pub tier: u8,
+}
+
+#[derive(Accounts)]
+pub struct VerifyTier<'info> {
+    pub identity: Account<'info, SovereignIdentity>,
+
+    /// Optional: protocol tier curve (default cutoffs apply when omitted)
+    #[account(seeds = [b"tier_config"], bump = tier_config.bump)]
+    pub tier_config: Option<Account<'info, TierConfig>>,
+
+    /// Optional: protocol config (the balanced tier-5 rule applies only when passed)
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(ctx: Context<VerifyTier>, min_tier: u8) -> Result<TierVerification> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let cutoffs = TierConfig::cutoffs_or_default(ctx.accounts.tier_config.as_deref());
+    let balanced_tier5 = ProtocolConfig::requires_balanced_tier5(ctx.accounts.protocol_config.as_deref());
+    let mut identity = (*ctx.accounts.identity).clone();
+    identity.apply_decay(now);
+    identity.recalculate_with(now, &cutoffs, balanced_tier5);
+
+    Ok(TierVerification {
+        owner: identity.owner,
+        meets_min_tier: !identity.is_frozen && identity.tier >= min_tier,
+        composite_score: identity.composite_score,
+        tier: identity.tier,
+    })
+}
@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::creator_dao::{ContentType, DAORegistryEntry, VoteChoice};
+use state::admission_market::PositionSide;
+use state::ScoreSnapshot;
 
 declare_id!("2UAZc1jj4QTSkgrC8U9d4a7EM9AQunxMvW5g7rX7Af9T");
 
@@ -14,9 +18,116 @@ pub mod sovereign {
 
     // === Identity Management ===
 
-    /// Create a new SOVEREIGN identity for the signer
-    pub fn create_identity(ctx: Context<CreateIdentity>) -> Result<()> {
-        instructions::create_identity::handler(ctx)
+    /// Create a new SOVEREIGN identity for the signer, optionally delegating
+    /// dimension authorities up front
+    pub fn create_identity(ctx: Context<CreateIdentity>, authorities: Option<InitAuthorities>) -> Result<()> {
+        instructions::create_identity::handler(ctx, authorities)
+    }
+
+    /// Move the identity to a new owner's PDA (both wallets sign), resetting
+    /// dimension authorities to the new owner unless `keep_authorities` is set
+    pub fn transfer_identity(
+        ctx: Context<TransferIdentity>,
+        keep_authorities: bool,
+    ) -> Result<()> {
+        instructions::transfer_identity::handler(ctx, keep_authorities)
+    }
+
+    /// Set how long a composite must stay below a tier boundary before demotion
+    pub fn set_tier_grace_period(
+        ctx: Context<SetTierGracePeriod>,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::set_tier_grace_period::handler(ctx, grace_period)
+    }
+
+    /// Initialize every per-dimension detail account for the signer's identity
+    pub fn init_all_details(ctx: Context<InitAllDetails>) -> Result<()> {
+        instructions::init_all_details::handler(ctx)
+    }
+
+    /// Set the per-day decay rate applied to every dimension score
+    pub fn set_decay_rate(
+        ctx: Context<SetDecayRate>,
+        decay_bps_per_day: u16,
+    ) -> Result<()> {
+        instructions::set_decay_rate::handler(ctx, decay_bps_per_day)
+    }
+
+    /// Set the composite weights (trading, civic, developer, infra, creator)
+    pub fn set_score_weights(
+        ctx: Context<SetScoreWeights>,
+        weights: [u8; 5],
+    ) -> Result<()> {
+        instructions::set_score_weights::handler(ctx, weights)
+    }
+
+    /// View: composite, tier, dimension scores and creator stats in one struct
+    pub fn get_reputation_summary(
+        ctx: Context<GetReputationSummary>,
+    ) -> Result<ReputationSummary> {
+        instructions::get_reputation_summary::handler(ctx)
+    }
+
+    /// Bind an off-chain verified credential, co-signed by its attestation authority
+    pub fn bind_attestation(
+        ctx: Context<BindAttestation>,
+        attestation_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::bind_attestation::handler(ctx, attestation_hash)
+    }
+
+    /// View (CPI-friendly): whether an identity meets `min_tier`, plus its composite
+    pub fn verify_tier(ctx: Context<VerifyTier>, min_tier: u8) -> Result<TierVerification> {
+        instructions::verify_tier::handler(ctx, min_tier)
+    }
+
+    /// Create the protocol-wide tier curve (protocol admin only); the admin becomes its authority
+    pub fn initialize_tier_config(
+        ctx: Context<InitializeTierConfig>,
+        params: TierConfigParams,
+    ) -> Result<()> {
+        instructions::tier_config::initialize_tier_config(ctx, params)
+    }
+
+    /// Change the tier cutoffs and names (tier config authority only)
+    pub fn set_tier_config(ctx: Context<SetTierConfig>, params: TierConfigParams) -> Result<()> {
+        instructions::tier_config::set_tier_config(ctx, params)
+    }
+
+    /// Create an identity's score history ring buffer (anyone may pay for it)
+    pub fn init_score_history(ctx: Context<InitScoreHistory>) -> Result<()> {
+        instructions::score_history::init_score_history(ctx)
+    }
+
+    /// View: recorded (composite, tier, timestamp) snapshots, oldest first
+    pub fn get_score_history(ctx: Context<GetScoreHistory>) -> Result<Vec<ScoreSnapshot>> {
+        instructions::score_history::get_score_history(ctx)
+    }
+
+    /// Create the program-wide pause switch; the signer becomes its admin
+    pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+        instructions::protocol_config::initialize_protocol_config(ctx)
+    }
+
+    /// Halt or resume new markets, positions and nominations (admin only)
+    pub fn set_paused(ctx: Context<SetProtocolConfig>, paused: bool) -> Result<()> {
+        instructions::protocol_config::set_paused(ctx, paused)
+    }
+
+    /// Set the composite score a founder needs to create a DAO (admin only)
+    pub fn set_min_founder_composite(ctx: Context<SetProtocolConfig>, min_founder_composite: u16) -> Result<()> {
+        instructions::protocol_config::set_min_founder_composite(ctx, min_founder_composite)
+    }
+
+    /// Cap the voting period DAOs may set (admin only)
+    pub fn set_max_voting_period(ctx: Context<SetProtocolConfig>, max_voting_period: i64) -> Result<()> {
+        instructions::protocol_config::set_max_voting_period(ctx, max_voting_period)
+    }
+
+    /// Require every dimension at the floor before an identity reaches tier 5 (admin only)
+    pub fn set_require_balanced_tier5(ctx: Context<SetProtocolConfig>, require_balanced_tier5: bool) -> Result<()> {
+        instructions::protocol_config::set_require_balanced_tier5(ctx, require_balanced_tier5)
     }
 
     // === Authority Management ===
@@ -53,37 +164,474 @@ pub mod sovereign {
         instructions::set_authority::set_infra_authority(ctx, new_authority)
     }
 
+    /// Set the authority that can sync creator scores
+    pub fn set_creator_authority(
+        ctx: Context<SetAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_authority::set_creator_authority(ctx, new_authority)
+    }
+
+    /// Reset every dimension authority back to the owner in one go
+    pub fn revoke_all_authorities(ctx: Context<SetAuthority>) -> Result<()> {
+        instructions::set_authority::revoke_all_authorities(ctx)
+    }
+
+    /// Block all score updates, e.g. after an authority key is compromised (owner only)
+    pub fn freeze_identity(ctx: Context<SetFrozen>) -> Result<()> {
+        instructions::freeze_identity::freeze_identity(ctx)
+    }
+
+    /// Allow score updates again (owner only)
+    pub fn unfreeze_identity(ctx: Context<SetFrozen>) -> Result<()> {
+        instructions::freeze_identity::unfreeze_identity(ctx)
+    }
+
+    /// Set the display name and metadata URI shown for an identity (owner only)
+    pub fn set_identity_metadata(
+        ctx: Context<SetIdentityMetadata>,
+        display_name: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        instructions::set_identity_metadata::handler(ctx, display_name, metadata_uri)
+    }
+
+    /// Move a legacy 4-dimension identity onto the creator-dimension schema (owner only)
+    pub fn migrate_to_v2(ctx: Context<MigrateToV2>, creator_authority: Pubkey) -> Result<()> {
+        instructions::migrate_identity::handler(ctx, creator_authority)
+    }
+
     // === Score Updates ===
 
-    /// Update trading score
+    /// Update trading score, optionally ordered by a monotonic `update_seq`
     pub fn update_trading_score(
         ctx: Context<UpdateTradingScore>,
         score: u16,
+        update_seq: Option<u64>,
     ) -> Result<()> {
-        instructions::update_trading::handler(ctx, score)
+        instructions::update_trading::handler(ctx, score, update_seq)
     }
 
-    /// Update civic score
+    /// Update trading metrics and derive the trading score from them
+    pub fn update_trading_details(
+        ctx: Context<UpdateTradingDetails>,
+        params: UpdateTradingDetailsParams,
+    ) -> Result<()> {
+        instructions::update_trading_details::handler(ctx, params)
+    }
+
+    /// Update civic score, optionally ordered by a monotonic `update_seq`
     pub fn update_civic_score(
         ctx: Context<UpdateCivicScore>,
         score: u16,
+        update_seq: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_civic::handler(ctx, score, update_seq)
+    }
+
+    /// Create the civic details account for an identity
+    pub fn init_civic_details(ctx: Context<InitCivicDetails>) -> Result<()> {
+        instructions::civic_details::init_civic_details(ctx)
+    }
+
+    /// Update civic metrics and derive the civic score from them
+    pub fn update_civic_details(
+        ctx: Context<UpdateCivicDetails>,
+        params: UpdateCivicDetailsParams,
     ) -> Result<()> {
-        instructions::update_civic::handler(ctx, score)
+        instructions::civic_details::update_civic_details(ctx, params)
     }
 
-    /// Update developer score
+    /// Update developer score, optionally ordered by a monotonic `update_seq`
     pub fn update_developer_score(
         ctx: Context<UpdateDeveloperScore>,
         score: u16,
+        update_seq: Option<u64>,
     ) -> Result<()> {
-        instructions::update_developer::handler(ctx, score)
+        instructions::update_developer::handler(ctx, score, update_seq)
     }
 
-    /// Update infrastructure score
+    /// Update infrastructure score, optionally ordered by a monotonic `update_seq`
     pub fn update_infra_score(
         ctx: Context<UpdateInfraScore>,
         score: u16,
+        update_seq: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_infra::handler(ctx, score, update_seq)
+    }
+
+    /// Update creator score, optionally ordered by a monotonic `update_seq`
+    pub fn update_creator_score(
+        ctx: Context<UpdateCreatorScore>,
+        score: u16,
+        update_seq: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_creator::handler(ctx, score, update_seq)
+    }
+
+    /// Write the creator score derived from an identity's creator details
+    /// into its creator dimension (creator authority only)
+    pub fn sync_creator_score(ctx: Context<SyncCreatorScore>) -> Result<()> {
+        instructions::sync_creator_score::handler(ctx)
+    }
+
+    /// Apply elapsed decay to an identity (permissionless)
+    pub fn decay_score(ctx: Context<DecayScore>) -> Result<()> {
+        instructions::decay_score::handler(ctx)
+    }
+
+    /// Refresh composite and tier from the current scores, e.g. after a tier
+    /// config change (permissionless)
+    pub fn recompute_composite(ctx: Context<RecomputeComposite>) -> Result<()> {
+        instructions::recompute_composite::handler(ctx)
+    }
+
+    // === Creator DAOs (Vitalik's Creator Coin Extension) ===
+
+    /// Initialize the global DAO counter (one-time setup)
+    pub fn initialize_dao_counter(ctx: Context<InitializeDAOCounter>) -> Result<()> {
+        instructions::creator_dao::initialize_dao_counter::handler(ctx)
+    }
+
+    /// Create a new Creator DAO
+    pub fn create_dao(ctx: Context<CreateDAO>, params: CreateDAOParams) -> Result<()> {
+        instructions::creator_dao::create_dao::handler(ctx, params)
+    }
+
+    /// Add a hand-picked initial member (founder only)
+    pub fn add_founder_member(ctx: Context<AddFounderMember>) -> Result<()> {
+        instructions::creator_dao::add_founder_member::handler(ctx)
+    }
+
+    /// Add up to MAX_FOUNDER_BATCH hand-picked members at once (founder only)
+    pub fn add_founder_members_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddFounderMembersBatch<'info>>,
+    ) -> Result<()> {
+        instructions::creator_dao::add_founder_members_batch::handler(ctx)
+    }
+
+    /// Permanently end the founder's hand-picking phase (founder only)
+    pub fn close_founder_phase(ctx: Context<CloseFounderPhase>) -> Result<()> {
+        instructions::creator_dao::close_founder_phase::handler(ctx)
+    }
+
+    /// Update a DAO's admission threshold, voting period and quorum (founder only)
+    pub fn update_dao_governance(
+        ctx: Context<UpdateDAOGovernance>,
+        params: UpdateDAOGovernanceParams,
     ) -> Result<()> {
-        instructions::update_infra::handler(ctx, score)
+        instructions::creator_dao::update_dao_governance::handler(ctx, params)
+    }
+
+    /// Hand the founder role to a new key (founder only)
+    pub fn transfer_founder(ctx: Context<TransferFounder>, new_founder: Pubkey) -> Result<()> {
+        instructions::creator_dao::transfer_founder::handler(ctx, new_founder)
+    }
+
+    /// Record a piece of content published by a fellow DAO member
+    pub fn submit_content(ctx: Context<SubmitContent>, content_hash: [u8; 32]) -> Result<()> {
+        instructions::creator_dao::content::submit_content(ctx, content_hash)
+    }
+
+    /// Upvote a fellow DAO member's content (once per member per piece)
+    pub fn upvote_content(ctx: Context<UpvoteContent>) -> Result<()> {
+        instructions::creator_dao::content::upvote_content(ctx)
+    }
+
+    /// Create a DAO's treasury with the share of proceeds it burns (founder only)
+    pub fn init_dao_treasury(ctx: Context<InitDAOTreasury>, burn_bps: u16) -> Result<()> {
+        instructions::creator_dao::treasury::init_dao_treasury(ctx, burn_bps)
+    }
+
+    /// Pay lamports into a DAO's treasury (dues, revenue shares)
+    pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+        instructions::creator_dao::treasury::deposit_to_treasury(ctx, amount)
+    }
+
+    /// Burn the treasury's share of a creator's proceeds and credit it to
+    /// their creator score (founder only)
+    pub fn burn_from_treasury(ctx: Context<BurnFromTreasury>, proceeds: u64) -> Result<()> {
+        instructions::creator_dao::treasury::burn_from_treasury(ctx, proceeds)
+    }
+
+    /// Register the SPL mint of the caller's creator coin (mint authority only)
+    pub fn set_creator_mint(ctx: Context<SetCreatorMint>) -> Result<()> {
+        instructions::creator_dao::creator_coin::set_creator_mint(ctx)
+    }
+
+    /// Burn the treasury's share of a member creator's coin proceeds (founder only)
+    pub fn burn_creator_coins(ctx: Context<BurnCreatorCoins>, proceeds: u64) -> Result<()> {
+        instructions::creator_dao::creator_coin::burn_creator_coins(ctx, proceeds)
+    }
+
+    /// Nominate a creator for admission to a DAO
+    pub fn nominate_creator(
+        ctx: Context<NominateCreator>,
+        params: NominateCreatorParams,
+    ) -> Result<()> {
+        instructions::creator_dao::nominate_creator::handler(ctx, params)
+    }
+
+    /// Apply to join a DAO as a non-member creator
+    pub fn apply_for_admission(
+        ctx: Context<ApplyForAdmission>,
+        params: ApplyForAdmissionParams,
+    ) -> Result<()> {
+        instructions::creator_dao::application::apply_for_admission(ctx, params)
+    }
+
+    /// Sponsor an application, opening a nomination with the sponsor as nominator
+    pub fn sponsor_application(ctx: Context<SponsorApplication>) -> Result<()> {
+        instructions::creator_dao::application::sponsor_application(ctx)
+    }
+
+    /// Turn an application down (any active member)
+    pub fn reject_application(ctx: Context<RejectApplication>) -> Result<()> {
+        instructions::creator_dao::application::reject_application(ctx)
+    }
+
+    /// Commit a vote, keccak(choice || salt), on a pending nomination
+    pub fn cast_vote(ctx: Context<CastVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::creator_dao::cast_vote::handler(ctx, commitment)
+    }
+
+    /// Replace a vote commitment while voting is still open
+    pub fn change_vote(ctx: Context<ChangeVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::creator_dao::change_vote::handler(ctx, commitment)
+    }
+
+    /// Reveal a committed vote after voting ends so it counts toward the tallies
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        vote: VoteChoice,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::creator_dao::reveal_vote::handler(ctx, vote, salt)
+    }
+
+    /// Charge a creator score penalty for a vote left unrevealed past the
+    /// reveal period (permissionless)
+    pub fn penalize_unrevealed_vote(ctx: Context<PenalizeUnrevealedVote>) -> Result<()> {
+        instructions::creator_dao::reveal_vote::penalize_unrevealed_vote(ctx)
+    }
+
+    /// Let a fellow member cast your votes, on one nomination or on all of them
+    pub fn delegate_vote(ctx: Context<DelegateVote>, nomination: Option<Pubkey>) -> Result<()> {
+        instructions::creator_dao::delegate_vote::delegate_vote(ctx, nomination)
+    }
+
+    /// Take back a vote delegation
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::creator_dao::delegate_vote::revoke_delegation(ctx)
+    }
+
+    /// Resolve a nomination after voting ends
+    pub fn resolve_nomination(ctx: Context<ResolveNomination>) -> Result<()> {
+        instructions::creator_dao::resolve_nomination::handler(ctx)
+    }
+
+    /// Accept a membership the DAO voted to admit you to
+    pub fn accept_membership(ctx: Context<AcceptMembership>) -> Result<()> {
+        instructions::creator_dao::membership_consent::accept_membership(ctx)
+    }
+
+    /// Decline a membership the DAO voted to admit you to
+    pub fn decline_membership(ctx: Context<DeclineMembership>) -> Result<()> {
+        instructions::creator_dao::membership_consent::decline_membership(ctx)
+    }
+
+    /// Leave a DAO you are an active member of (not the founder)
+    pub fn leave_dao(ctx: Context<LeaveDao>) -> Result<()> {
+        instructions::creator_dao::leave_dao::handler(ctx)
+    }
+
+    /// Refresh the creator score a member counts towards their DAO's
+    /// quality (anyone)
+    pub fn sync_member_score(ctx: Context<SyncMemberScore>) -> Result<()> {
+        instructions::creator_dao::sync_member_score::handler(ctx)
+    }
+
+    /// Withdraw an open nomination (nominator only)
+    pub fn withdraw_nomination(ctx: Context<WithdrawNomination>) -> Result<()> {
+        instructions::creator_dao::withdraw_nomination::handler(ctx)
+    }
+
+    /// Cancel a nomination whose voting ended without quorum (anyone)
+    pub fn cancel_expired_nomination(ctx: Context<CancelExpiredNomination>) -> Result<()> {
+        instructions::creator_dao::cancel_expired_nomination::handler(ctx)
+    }
+
+    /// View: every DAO a wallet has been admitted to
+    pub fn get_member_daos(ctx: Context<GetMemberDaos>) -> Result<Vec<Pubkey>> {
+        instructions::creator_dao::member_directory::handler(ctx)
+    }
+
+    /// View: the DAOs on one registry page, optionally filtered by content
+    /// type and style tag
+    pub fn get_dao_registry_page(
+        ctx: Context<GetDaoRegistryPage>,
+        content_type: Option<ContentType>,
+        style_tag: Option<String>,
+    ) -> Result<Vec<DAORegistryEntry>> {
+        instructions::creator_dao::dao_registry::handler(ctx, content_type, style_tag)
+    }
+
+    /// Whether a wallet is an active member of a DAO, as return data for
+    /// CPI callers (false rather than an error for non-members)
+    pub fn assert_active_member(ctx: Context<AssertActiveMember>) -> Result<bool> {
+        instructions::creator_dao::membership_check::handler(ctx)
+    }
+
+    // === Admission Markets ===
+
+    /// Initialize the global market factory (one-time setup)
+    pub fn initialize_market_factory(
+        ctx: Context<InitializeFactory>,
+        params: InitializeFactoryParams,
+    ) -> Result<()> {
+        instructions::admission_market::initialize_factory::handler(ctx, params)
+    }
+
+    /// Update the defaults applied to new markets (factory authority only)
+    pub fn update_factory_config(
+        ctx: Context<UpdateFactoryConfig>,
+        params: UpdateFactoryConfigParams,
+    ) -> Result<()> {
+        instructions::admission_market::update_factory_config::handler(ctx, params)
+    }
+
+    /// Set the minimum DAO prestige tier required to open a market (factory authority only)
+    pub fn set_min_dao_prestige(
+        ctx: Context<UpdateFactoryConfig>,
+        min_dao_prestige: u8,
+    ) -> Result<()> {
+        let params = UpdateFactoryConfigParams {
+            min_dao_prestige: Some(min_dao_prestige),
+            ..Default::default()
+        };
+        instructions::admission_market::update_factory_config::handler(ctx, params)
+    }
+
+    /// Set the per-position whale cap copied into new markets (factory authority only)
+    pub fn set_max_position_bps(
+        ctx: Context<UpdateFactoryConfig>,
+        max_position_bps: u16,
+    ) -> Result<()> {
+        let params = UpdateFactoryConfigParams {
+            max_position_bps: Some(max_position_bps),
+            ..Default::default()
+        };
+        instructions::admission_market::update_factory_config::handler(ctx, params)
+    }
+
+    /// Create a talent scout's surfacing score (required before their first market)
+    pub fn init_surfacing_score(ctx: Context<InitSurfacingScore>) -> Result<()> {
+        instructions::admission_market::init_surfacing_score::handler(ctx)
+    }
+
+    /// Create a prediction market on a creator's admission to a DAO
+    pub fn create_market(
+        ctx: Context<CreateMarket>,
+        params: CreateMarketParams,
+    ) -> Result<()> {
+        instructions::admission_market::create_market::handler(ctx, params)
+    }
+
+    /// Take a YES or NO position in an admission market
+    pub fn take_position(
+        ctx: Context<TakePosition>,
+        params: TakePositionParams,
+    ) -> Result<()> {
+        instructions::admission_market::take_position::handler(ctx, params)
+    }
+
+    /// Add matched YES/NO liquidity to an open market, earning a share of its fees
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        instructions::admission_market::liquidity::add_liquidity(ctx, amount)
+    }
+
+    /// Withdraw LP liquidity, plus the fees it earned once the market is finalized
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>) -> Result<()> {
+        instructions::admission_market::liquidity::remove_liquidity(ctx)
+    }
+
+    /// Claim winnings (or refund) from a resolved market, optionally
+    /// requiring at least `min_payout` lamports
+    pub fn claim_winnings(
+        ctx: Context<ClaimWinnings>,
+        min_payout: Option<u64>,
+    ) -> Result<()> {
+        instructions::admission_market::claim_winnings::handler(ctx, min_payout)
+    }
+
+    /// Settle a position on its predictor's behalf, paying them directly
+    /// (anyone; already-claimed positions are skipped)
+    pub fn claim_winnings_for(ctx: Context<ClaimWinningsFor>) -> Result<()> {
+        instructions::admission_market::claim_winnings_for::handler(ctx)
+    }
+
+    /// Claim winnings from up to MAX_CLAIM_BATCH markets at once, skipping
+    /// positions that aren't claimable yet
+    pub fn claim_all_winnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAllWinnings<'info>>,
+    ) -> Result<()> {
+        instructions::admission_market::claim_all_winnings::handler(ctx)
+    }
+
+    /// Correct a resolved market's outcome to match its nomination during
+    /// the challenge period (anyone)
+    pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+        instructions::admission_market::dispute_resolution::handler(ctx)
+    }
+
+    /// Cancel an open market past its expiry so predictors can be refunded (anyone)
+    pub fn expire_stale_market(ctx: Context<ExpireStaleMarket>) -> Result<()> {
+        instructions::admission_market::expire_stale_market::handler(ctx)
+    }
+
+    /// Return a cancelled market's seed liquidity to its creator (market creator only)
+    pub fn reclaim_seed_liquidity(ctx: Context<ReclaimSeedLiquidity>) -> Result<()> {
+        instructions::admission_market::reclaim_seed_liquidity::handler(ctx)
+    }
+
+    /// Close out a resolved market once disputes are over, rebating the
+    /// market creator's share of trading fees (anyone)
+    pub fn finalize_market(ctx: Context<FinalizeMarket>) -> Result<()> {
+        instructions::admission_market::finalize_market::handler(ctx)
+    }
+
+    /// Sweep a settled market's trading fees (factory authority only)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        instructions::admission_market::withdraw_fees::handler(ctx)
+    }
+
+    /// View: largest stake that moves a side's probability by at most `max_impact_bps`
+    pub fn get_max_stake_for_impact(
+        ctx: Context<MarketView>,
+        side: PositionSide,
+        max_impact_bps: u16,
+    ) -> Result<u64> {
+        instructions::admission_market::market_views::get_max_stake_for_impact(ctx, side, max_impact_bps)
+    }
+
+    /// View: fee-adjusted YES probability for a buy of `stake` (0 = marginal)
+    pub fn get_yes_price_after_fee(ctx: Context<MarketView>, stake: u64) -> Result<u16> {
+        instructions::admission_market::market_views::get_yes_price_after_fee(ctx, stake)
+    }
+
+    /// View: current odds, pool depth and the tokens a `sample_stake` would buy
+    pub fn get_market_state(ctx: Context<MarketView>, sample_stake: u64) -> Result<MarketState> {
+        instructions::admission_market::market_views::get_market_state(ctx, sample_stake)
+    }
+
+    /// View: time-weighted YES probability over the last `window` seconds
+    pub fn get_market_twap(ctx: Context<MarketView>, window: i64) -> Result<u16> {
+        instructions::admission_market::market_views::get_market_twap(ctx, window)
+    }
+
+    /// View: a scout's rank and percentile among the surfacing scores passed
+    /// as remaining accounts
+    pub fn get_scout_rank(ctx: Context<GetScoutRank>, top_n: u32) -> Result<ScoutRank> {
+        instructions::admission_market::scout_rank::get_scout_rank(ctx, top_n)
     }
 }
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::SovereignError;
 
 // =============================================================================
 // SOVEREIGN STATE - Multi-Dimensional Reputation Protocol
@@ -8,6 +10,23 @@ use anchor_lang::prelude::*;
 // See: https://vitalik.eth.limo/general/2025/01/23/creatorcoins.html
 // =============================================================================
 
+/// Default composite weights (percent), ordered trading, civic, developer, infra, creator
+pub const DEFAULT_SCORE_WEIGHTS: [u8; 5] = [30, 20, 15, 10, 25];
+
+/// Default time a composite must stay below a tier boundary before demotion (7 days)
+pub const DEFAULT_TIER_GRACE_PERIOD: i64 = 7 * 86400;
+
+/// Default minimum composite for Silver, Gold, Platinum and Diamond
+pub const DEFAULT_TIER_CUTOFFS: [u16; 4] = [2000, 4000, 6000, 8000];
+
+/// Identity schema using the legacy 4-dimension weighting (no creator
+/// dimension); these accounts predate `schema_version` and use the
+/// `LegacyIdentity` layout
+pub const SCHEMA_VERSION_LEGACY: u8 = 1;
+
+/// Identity schema with the creator dimension and configurable weights
+pub const SCHEMA_VERSION_CREATOR: u8 = 2;
+
 // Vitalik's Creator Coin Extension - new state modules
 pub mod creator_dao;
 pub mod admission_market;
@@ -28,12 +47,21 @@ pub use admission_market::*;
 // are also good judges of quality, which seems often true)"
 // =============================================================================
 
+/// A tier transition produced by recalculating an identity
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TierChange {
+    pub old_tier: u8,
+    pub new_tier: u8,
+}
+
 /// Main identity account that stores user's multi-dimensional reputation
 #[account]
 pub struct SovereignIdentity {
     // === Identity ===
     /// The wallet that owns this identity
     pub owner: Pubkey,
+    /// Layout and weighting generation (see `SCHEMA_VERSION_*`)
+    pub schema_version: u8,
     /// When this identity was created
     pub created_at: i64,
 
@@ -63,12 +91,76 @@ pub struct SovereignIdentity {
     /// Derived from: DAO acceptances, judgment quality, prediction accuracy
     pub creator_score: u16,
 
+    // === Freshness (0 until the dimension is first scored) ===
+    /// When the trading score was last written
+    pub trading_updated_at: i64,
+    /// When the civic score was last written
+    pub civic_updated_at: i64,
+    /// When the developer score was last written
+    pub developer_updated_at: i64,
+    /// When the infrastructure score was last written
+    pub infra_updated_at: i64,
+    /// When the creator score was last written
+    pub creator_updated_at: i64,
+
+    // === Oracle Ordering (0 until an oracle first sends an update_seq) ===
+    /// Highest update_seq accepted for the trading score
+    pub trading_update_seq: u64,
+    /// Highest update_seq accepted for the civic score
+    pub civic_update_seq: u64,
+    /// Highest update_seq accepted for the developer score
+    pub developer_update_seq: u64,
+    /// Highest update_seq accepted for the infrastructure score
+    pub infra_update_seq: u64,
+    /// Highest update_seq accepted for the creator score
+    pub creator_update_seq: u64,
+
     // === Computed ===
+    /// Composite weights in percent (sum to 100), ordered
+    /// trading, civic, developer, infra, creator
+    pub weights: [u8; 5],
     /// Weighted composite score
     pub composite_score: u16,
     /// Tier level (1-5)
     pub tier: u8,
 
+    // === Tier Grace ===
+    /// When the composite first dropped below the current tier's boundary
+    /// (None while the composite still supports the current tier)
+    pub tier_demotion_pending_since: Option<i64>,
+    /// Seconds the composite must stay below the boundary before `tier` is lowered
+    pub tier_grace_period: i64,
+
+    // === Decay ===
+    /// Fraction of every dimension score lost per idle day, in basis points
+    /// (0 disables decay)
+    pub decay_bps_per_day: u16,
+
+    // === Attestation ===
+    /// Hash of an off-chain verified credential (e.g. social proof)
+    pub attestation_hash: [u8; 32],
+    /// Authority that verified the credential and co-signed the binding
+    pub attestation_authority: Pubkey,
+    /// Whether a credential has been bound by an attestation authority
+    pub is_verified: bool,
+
+    // === Display Metadata (zero-padded, empty until the owner sets them) ===
+    /// Human-readable name for apps to show
+    pub display_name: [u8; 32],
+    /// URI of off-chain metadata such as an avatar and bio
+    pub metadata_uri: [u8; 96],
+
+    // === Freeze ===
+    /// Set by the owner if a key is compromised; score updates are rejected
+    /// while frozen
+    pub is_frozen: bool,
+
+    // === Transfer ===
+    /// The identity this one was moved to by transfer_identity. The old PDA
+    /// stays behind as a frozen, zeroed tombstone so it can't be re-created
+    /// and picked up by the details, memberships and history seeded by it.
+    pub transferred_to: Option<Pubkey>,
+
     // === Metadata ===
     /// Last time any score was updated
     pub last_updated: i64,
@@ -79,6 +171,7 @@ pub struct SovereignIdentity {
 impl SovereignIdentity {
     pub const SIZE: usize = 8 +  // discriminator
         32 +                     // owner
+        1 +                      // schema_version
         8 +                      // created_at
         32 +                     // trading_authority
         32 +                     // civic_authority
@@ -90,15 +183,28 @@ impl SovereignIdentity {
         2 +                      // developer_score
         2 +                      // infra_score
         2 +                      // creator_score (NEW - Vitalik extension)
+        8 * 5 +                  // per-dimension updated_at
+        8 * 5 +                  // per-oracle update_seq
+        5 +                      // weights
         2 +                      // composite_score
         1 +                      // tier
+        9 +                      // tier_demotion_pending_since
+        8 +                      // tier_grace_period
+        2 +                      // decay_bps_per_day
+        32 +                     // attestation_hash
+        32 +                     // attestation_authority
+        1 +                      // is_verified
+        32 +                     // display_name
+        96 +                     // metadata_uri
+        1 +                      // is_frozen
+        33 +                     // transferred_to
         8 +                      // last_updated
         1;                       // bump
-    // Total: 236 bytes
+    // Total: 554 bytes
 
     /// Recalculate composite score and tier based on dimension scores
     ///
-    /// Updated weighting to include Creator dimension:
+    /// Uses the identity's stored `weights`. Defaults include the Creator dimension:
     /// - Trading: 30% (reduced from 40%)
     /// - Civic: 20% (reduced from 25%)
     /// - Developer: 15% (reduced from 20%)
@@ -107,43 +213,299 @@ impl SovereignIdentity {
     ///
     /// Vitalik: "the ultimate decider of who rises and falls is not speculators,
     /// but high-value content creators"
-    pub fn recalculate(&mut self) {
-        // Weighted average with Creator dimension
-        let weighted = self.trading_score as u32 * 30
-            + self.civic_score as u32 * 20
-            + self.developer_score as u32 * 15
-            + self.infra_score as u32 * 10
-            + self.creator_score as u32 * 25;  // Creator has significant weight
-
-        self.composite_score = (weighted / 100) as u16;
-
-        // Calculate tier from composite score
-        self.tier = match self.composite_score {
-            0..=1999 => 1,      // Bronze
-            2000..=3999 => 2,   // Silver
-            4000..=5999 => 3,   // Gold
-            6000..=7999 => 4,   // Platinum
-            _ => 5,             // Diamond
-        };
+    ///
+    /// Promotions apply immediately; demotions only apply once the composite
+    /// has stayed below the tier boundary for `tier_grace_period` seconds.
+    ///
+    /// Returns the tier transition, if the tier moved.
+    pub fn recalculate(&mut self, now: i64) -> Option<TierChange> {
+        self.recalculate_with(now, &DEFAULT_TIER_CUTOFFS, false)
+    }
+
+    /// `recalculate` against a custom tier curve (see `TierConfig`)
+    ///
+    /// With `balanced_tier5` set, tier 5 additionally needs every dimension at
+    /// `BALANCED_TIER5_FLOOR` or above; otherwise the tier is capped at 4.
+    pub fn recalculate_with(&mut self, now: i64, cutoffs: &[u16; 4], balanced_tier5: bool) -> Option<TierChange> {
+        self.debug_assert_dimensions_in_range();
+        let old_tier = self.tier;
+        self.composite_score = self.weighted_composite();
+
+        self.apply_tier(now, cutoffs, balanced_tier5);
+        (self.tier != old_tier).then_some(TierChange { old_tier, new_tier: self.tier })
     }
 
     /// Recalculate using legacy weights (without Creator dimension)
     /// For backwards compatibility during migration
-    pub fn recalculate_legacy(&mut self) {
+    pub fn recalculate_legacy(&mut self, now: i64) {
+        self.debug_assert_dimensions_in_range();
+        self.composite_score = self.legacy_composite();
+
+        self.apply_tier(now, &DEFAULT_TIER_CUTOFFS, false);
+    }
+
+    /// Rebuild a legacy identity on the creator-dimension schema
+    ///
+    /// Keeps the legacy scores and authorities, starts the creator dimension
+    /// unscored under `creator_authority`, fills every later field with the
+    /// defaults create_identity uses, and recalculates with the default
+    /// weights.
+    pub fn migrate_to_v2(
+        legacy: &LegacyIdentity,
+        creator_authority: Pubkey,
+        now: i64,
+        cutoffs: &[u16; 4],
+        balanced_tier5: bool,
+    ) -> (Self, Option<TierChange>) {
+        // Legacy identities only tracked one update time for every dimension
+        let scored_at = |score: u16| if score > 0 { legacy.last_updated } else { 0 };
+        let mut identity = Self {
+            owner: legacy.owner,
+            schema_version: SCHEMA_VERSION_CREATOR,
+            created_at: legacy.created_at,
+            trading_authority: legacy.trading_authority,
+            civic_authority: legacy.civic_authority,
+            developer_authority: legacy.developer_authority,
+            infra_authority: legacy.infra_authority,
+            creator_authority,
+            trading_score: legacy.trading_score,
+            civic_score: legacy.civic_score,
+            developer_score: legacy.developer_score,
+            infra_score: legacy.infra_score,
+            creator_score: 0,
+            trading_updated_at: scored_at(legacy.trading_score),
+            civic_updated_at: scored_at(legacy.civic_score),
+            developer_updated_at: scored_at(legacy.developer_score),
+            infra_updated_at: scored_at(legacy.infra_score),
+            creator_updated_at: 0,
+            trading_update_seq: 0,
+            civic_update_seq: 0,
+            developer_update_seq: 0,
+            infra_update_seq: 0,
+            creator_update_seq: 0,
+            weights: DEFAULT_SCORE_WEIGHTS,
+            composite_score: legacy.composite_score,
+            tier: legacy.tier,
+            tier_demotion_pending_since: None,
+            tier_grace_period: DEFAULT_TIER_GRACE_PERIOD,
+            decay_bps_per_day: 0,
+            attestation_hash: [0; 32],
+            attestation_authority: Pubkey::default(),
+            is_verified: false,
+            display_name: [0; 32],
+            metadata_uri: [0; 96],
+            is_frozen: false,
+            transferred_to: None,
+            last_updated: legacy.last_updated,
+            bump: legacy.bump,
+        };
+        let tier_change = identity.recalculate_with(now, cutoffs, balanced_tier5);
+        (identity, tier_change)
+    }
+
+    /// Weighted average with Creator dimension
+    fn weighted_composite(&self) -> u16 {
+        let [trading_w, civic_w, developer_w, infra_w, creator_w] = self.weights;
+        let weighted = self.trading_score as u32 * trading_w as u32
+            + self.civic_score as u32 * civic_w as u32
+            + self.developer_score as u32 * developer_w as u32
+            + self.infra_score as u32 * infra_w as u32
+            + self.creator_score as u32 * creator_w as u32;
+
+        (weighted / 100).min(10000) as u16
+    }
+
+    /// Fixed 40/25/20/15 average of the original four dimensions
+    fn legacy_composite(&self) -> u16 {
         let weighted = self.trading_score as u32 * 40
             + self.civic_score as u32 * 25
             + self.developer_score as u32 * 20
             + self.infra_score as u32 * 15;
 
-        self.composite_score = (weighted / 100) as u16;
+        (weighted / 100).min(10000) as u16
+    }
 
-        self.tier = match self.composite_score {
-            0..=1999 => 1,
-            2000..=3999 => 2,
-            4000..=5999 => 3,
-            6000..=7999 => 4,
-            _ => 5,
-        };
+    /// Score updates cap every dimension at 10000; anything above that is a
+    /// bug upstream. Release builds still clamp the composite.
+    fn debug_assert_dimensions_in_range(&self) {
+        for score in [
+            self.trading_score,
+            self.civic_score,
+            self.developer_score,
+            self.infra_score,
+            self.creator_score,
+        ] {
+            debug_assert!(score <= 10000, "dimension score {} exceeds 10000", score);
+        }
+    }
+
+    /// Advance a dimension's stored oracle sequence to `update_seq`
+    ///
+    /// Returns false (leaving `stored` alone) when the sequence doesn't move
+    /// past the last accepted one, i.e. the update is replayed or out of
+    /// order. Updates without a sequence are always accepted.
+    pub fn advance_update_seq(stored: &mut u64, update_seq: Option<u64>) -> bool {
+        match update_seq {
+            None => true,
+            Some(seq) if seq > *stored => {
+                *stored = seq;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    /// Decay every dimension score for the whole days elapsed since `last_updated`
+    ///
+    /// Applies `score * (1 - decay)^days` lazily and advances `last_updated` by
+    /// the days consumed, so partial days keep accruing until the next call.
+    pub fn apply_decay(&mut self, now: i64) {
+        if self.decay_bps_per_day == 0 || now <= self.last_updated {
+            return;
+        }
+
+        let days = ((now - self.last_updated) / 86400) as u64;
+        if days == 0 {
+            return;
+        }
+
+        let factor = Self::decay_factor_bps(self.decay_bps_per_day, days);
+        let decay = |score: u16| (score as u64 * factor / 10000) as u16;
+
+        self.trading_score = decay(self.trading_score);
+        self.civic_score = decay(self.civic_score);
+        self.developer_score = decay(self.developer_score);
+        self.infra_score = decay(self.infra_score);
+        self.creator_score = decay(self.creator_score);
+
+        self.last_updated += days as i64 * 86400;
+    }
+
+    /// Remaining fraction after `days` of decay, in basis points: (1 - rate)^days
+    pub fn decay_factor_bps(decay_bps_per_day: u16, days: u64) -> u64 {
+        let mut result: u64 = 10000;
+        let mut base: u64 = 10000 - decay_bps_per_day.min(10000) as u64;
+        let mut exp = days;
+
+        // Fixed-point exponentiation by squaring
+        while exp > 0 && result > 0 {
+            if exp & 1 == 1 {
+                result = result * base / 10000;
+            }
+            base = base * base / 10000;
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    /// Weights are valid when they sum to exactly 100
+    pub fn weights_valid(weights: &[u8; 5]) -> bool {
+        weights.iter().map(|w| *w as u32).sum::<u32>() == 100
+    }
+
+    /// Tier implied by a composite score under the default curve
+    pub fn tier_for(composite_score: u16) -> u8 {
+        Self::tier_for_cutoffs(composite_score, &DEFAULT_TIER_CUTOFFS)
+    }
+
+    /// Tier implied by a composite score: 1 (Bronze) plus one per cutoff reached
+    pub fn tier_for_cutoffs(composite_score: u16, cutoffs: &[u16; 4]) -> u8 {
+        1 + cutoffs.iter().filter(|&&cutoff| composite_score >= cutoff).count() as u8
+    }
+
+    /// Leave this identity behind as a tombstone for `successor`: frozen for
+    /// good, unverified and with every score cleared
+    pub fn tombstone(&mut self, successor: Pubkey, now: i64) {
+        self.trading_score = 0;
+        self.civic_score = 0;
+        self.developer_score = 0;
+        self.infra_score = 0;
+        self.creator_score = 0;
+        self.composite_score = 0;
+        self.tier = 1;
+        self.tier_demotion_pending_since = None;
+        self.is_verified = false;
+        self.is_frozen = true;
+        self.transferred_to = Some(successor);
+        self.last_updated = now;
+    }
+
+    /// Whether every dimension reaches `BALANCED_TIER5_FLOOR`
+    pub fn is_balanced(&self) -> bool {
+        [self.trading_score, self.civic_score, self.developer_score, self.infra_score, self.creator_score]
+            .iter()
+            .all(|&score| score >= BALANCED_TIER5_FLOOR)
+    }
+
+    /// Move `tier` towards the composite's tier, holding demotions for the grace period
+    fn apply_tier(&mut self, now: i64, cutoffs: &[u16; 4], balanced_tier5: bool) {
+        let mut target = Self::tier_for_cutoffs(self.composite_score, cutoffs);
+        if balanced_tier5 && target == 5 && !self.is_balanced() {
+            target = 4;
+        }
+
+        if target >= self.tier {
+            self.tier = target;
+            self.tier_demotion_pending_since = None;
+            return;
+        }
+
+        match self.tier_demotion_pending_since {
+            Some(since) if now - since >= self.tier_grace_period => {
+                self.tier = target;
+                self.tier_demotion_pending_since = None;
+            }
+            Some(_) => {}
+            None if self.tier_grace_period <= 0 => self.tier = target,
+            None => self.tier_demotion_pending_since = Some(now),
+        }
+    }
+}
+
+// =============================================================================
+// LEGACY IDENTITY
+// =============================================================================
+// The identity layout from before `schema_version` and every field after
+// `creator_score` existed. Those accounts can't be read as SovereignIdentity,
+// so migrate_to_v2 parses them with this layout and rewrites them in place.
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LegacyIdentity {
+    pub owner: Pubkey,
+    pub created_at: i64,
+    pub trading_authority: Pubkey,
+    pub civic_authority: Pubkey,
+    pub developer_authority: Pubkey,
+    pub infra_authority: Pubkey,
+    pub creator_authority: Pubkey,
+    pub trading_score: u16,
+    pub civic_score: u16,
+    pub developer_score: u16,
+    pub infra_score: u16,
+    pub creator_score: u16,
+    pub composite_score: u16,
+    pub tier: u8,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+impl LegacyIdentity {
+    /// Account size, discriminator included
+    pub const SIZE: usize = 8 + 32 + 8 + 32 * 5 + 2 * 5 + 2 + 1 + 8 + 1;
+
+    /// Parse a legacy identity account's data
+    ///
+    /// Fails with `AlreadyMigrated` for an account already on the current
+    /// layout.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(data.len() != SovereignIdentity::SIZE, SovereignError::AlreadyMigrated);
+        require!(
+            data.len() == Self::SIZE && data[..8] == SovereignIdentity::DISCRIMINATOR,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        Self::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
     }
 }
 
@@ -265,3 +627,673 @@ impl CivicScoreDetails {
         }
     }
 }
+
+/// Optional: Detailed developer score breakdown
+#[account]
+pub struct DeveloperScoreDetails {
+    /// Reference to the identity
+    pub identity: Pubkey,
+    /// Number of merged contributions
+    pub merged_contributions: u64,
+    /// Number of distinct repositories contributed to
+    pub repos_contributed: u32,
+    /// Number of bounties completed
+    pub bounties_completed: u32,
+    /// Code review quality in basis points (0-10000)
+    pub review_quality_bps: u16,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DeveloperScoreDetails {
+    pub const SIZE: usize = 8 + 32 + 8 + 4 + 4 + 2 + 8 + 1;
+
+    /// Calculate developer score from detailed metrics
+    pub fn calculate_score(&self) -> u16 {
+        // Weighted formula:
+        // Review quality: 40%, Bounties: 30%, Contributions: 20%, Breadth: 10%
+        let review_component = self.review_quality_bps as u32 * 40 / 100;
+        let bounty_component = Self::bounty_tier(self.bounties_completed) as u32 * 30 / 100;
+        let contribution_component = Self::contribution_tier(self.merged_contributions) as u32 * 20 / 100;
+        let breadth_component = Self::breadth_tier(self.repos_contributed) as u32 * 10 / 100;
+
+        (review_component + bounty_component + contribution_component + breadth_component)
+            .min(10000) as u16
+    }
+
+    fn bounty_tier(bounties: u32) -> u16 {
+        match bounties {
+            0..=2 => 2000,
+            3..=10 => 4000,
+            11..=30 => 6000,
+            31..=100 => 8000,
+            _ => 10000,
+        }
+    }
+
+    fn contribution_tier(contributions: u64) -> u16 {
+        match contributions {
+            0..=10 => 2000,
+            11..=100 => 4000,
+            101..=500 => 6000,
+            501..=2000 => 8000,
+            _ => 10000,
+        }
+    }
+
+    fn breadth_tier(repos: u32) -> u16 {
+        match repos {
+            0..=1 => 2000,
+            2..=5 => 4000,
+            6..=15 => 6000,
+            16..=40 => 8000,
+            _ => 10000,
+        }
+    }
+}
+
+/// Optional: Detailed infrastructure score breakdown
+#[account]
+pub struct InfraScoreDetails {
+    /// Reference to the identity
+    pub identity: Pubkey,
+    /// Number of nodes operated
+    pub nodes_operated: u32,
+    /// Average uptime in basis points (0-10000)
+    pub uptime_bps: u16,
+    /// Total bandwidth contributed in GB
+    pub bandwidth_gb: u64,
+    /// Days of continuous operation
+    pub days_active: u32,
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InfraScoreDetails {
+    pub const SIZE: usize = 8 + 32 + 4 + 2 + 8 + 4 + 8 + 1;
+
+    /// Calculate infrastructure score from detailed metrics
+    pub fn calculate_score(&self) -> u16 {
+        // Weighted formula:
+        // Uptime: 40%, Nodes: 25%, Bandwidth: 20%, Tenure: 15%
+        let uptime_component = self.uptime_bps as u32 * 40 / 100;
+        let nodes_component = Self::nodes_tier(self.nodes_operated) as u32 * 25 / 100;
+        let bandwidth_component = Self::bandwidth_tier(self.bandwidth_gb) as u32 * 20 / 100;
+        let tenure_component = Self::tenure_tier(self.days_active) as u32 * 15 / 100;
+
+        (uptime_component + nodes_component + bandwidth_component + tenure_component)
+            .min(10000) as u16
+    }
+
+    fn nodes_tier(nodes: u32) -> u16 {
+        match nodes {
+            0..=1 => 2000,
+            2..=5 => 4000,
+            6..=20 => 6000,
+            21..=100 => 8000,
+            _ => 10000,
+        }
+    }
+
+    fn bandwidth_tier(gb: u64) -> u16 {
+        match gb {
+            0..=100 => 2000,
+            101..=1_000 => 4000,
+            1_001..=10_000 => 6000,
+            10_001..=100_000 => 8000,
+            _ => 10000,
+        }
+    }
+
+    fn tenure_tier(days: u32) -> u16 {
+        match days {
+            0..=30 => 2000,
+            31..=90 => 4000,
+            91..=180 => 6000,
+            181..=365 => 8000,
+            _ => 10000,
+        }
+    }
+}
+
+// =============================================================================
+// TIER CONFIG
+// =============================================================================
+// Protocol-wide tier curve, so the cutoffs can move without a redeploy.
+// Instructions that recalculate an identity take it as an optional account
+// and fall back to DEFAULT_TIER_CUTOFFS when it is omitted.
+// =============================================================================
+
+#[account]
+pub struct TierConfig {
+    /// Protocol authority that can change the curve
+    pub authority: Pubkey,
+    /// Minimum composite for tiers 2-5, strictly increasing
+    pub cutoffs: [u16; 4],
+    /// Display names for tiers 1-5 (UTF-8, zero-padded)
+    pub names: [[u8; 16]; 5],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TierConfig {
+    pub const SIZE: usize = 8 + 32 + 2 * 4 + 16 * 5 + 1;
+
+    /// Cutoffs must be positive, strictly increasing and within 0-10000
+    pub fn cutoffs_valid(cutoffs: &[u16; 4]) -> bool {
+        cutoffs[0] > 0
+            && cutoffs.windows(2).all(|pair| pair[0] < pair[1])
+            && cutoffs[3] <= 10000
+    }
+
+    /// The configured cutoffs, or the defaults if no config was passed
+    pub fn cutoffs_or_default(config: Option<&TierConfig>) -> [u16; 4] {
+        config.map_or(DEFAULT_TIER_CUTOFFS, |config| config.cutoffs)
+    }
+}
+
+// =============================================================================
+// PROTOCOL CONFIG
+// =============================================================================
+// Program-wide emergency stop. While paused, instructions that move funds into
+// markets or start new admissions (create_market, take_position,
+// nominate_creator) are rejected; settling what already exists still works.
+// It also holds the composite score founders need before create_dao, to keep
+// out spam DAOs.
+// =============================================================================
+
+#[account]
+pub struct ProtocolConfig {
+    /// Protocol admin who can pause and unpause
+    pub admin: Pubkey,
+    /// Whether guarded instructions are currently halted
+    pub paused: bool,
+    /// Composite score a founder's identity needs to create a DAO (0 = anyone)
+    pub min_founder_composite: u16,
+    /// Longest voting period a DAO may set, in seconds
+    pub max_voting_period: i64,
+    /// Whether tier 5 needs every dimension at `BALANCED_TIER5_FLOOR`
+    pub require_balanced_tier5: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const SIZE: usize = 8 + 32 + 1 + 2 + 8 + 1 + 1;
+
+    /// Whether the balanced tier-5 rule is on; off when no config was passed
+    pub fn requires_balanced_tier5(config: Option<&ProtocolConfig>) -> bool {
+        config.is_some_and(|config| config.require_balanced_tier5)
+    }
+}
+
+/// Shortest voting period any DAO may set (1 day)
+pub const MIN_VOTING_PERIOD: i64 = 86400;
+
+/// Voting period cap a fresh protocol config starts with (30 days)
+pub const DEFAULT_MAX_VOTING_PERIOD: i64 = 30 * 86400;
+
+/// Minimum score in every dimension for tier 5 under the balanced rule
+pub const BALANCED_TIER5_FLOOR: u16 = 3000;
+
+// =============================================================================
+// SCORE HISTORY
+// =============================================================================
+// Ring buffer of an identity's recent (composite, tier) snapshots, so DAOs can
+// tell a rising reputation from a falling one. Score updates that recalculate
+// the identity append to it when it is passed in.
+// =============================================================================
+
+/// Number of snapshots kept before the oldest is overwritten
+pub const SCORE_HISTORY_LEN: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScoreSnapshot {
+    pub composite_score: u16,
+    pub tier: u8,
+    pub timestamp: i64,
+}
+
+#[account]
+pub struct ScoreHistory {
+    /// Identity these snapshots belong to
+    pub identity: Pubkey,
+    /// Snapshot slots; `next` is overwritten by the following push
+    pub entries: [ScoreSnapshot; SCORE_HISTORY_LEN],
+    /// Index of the slot the next snapshot is written to
+    pub next: u8,
+    /// Number of slots in use (at most SCORE_HISTORY_LEN)
+    pub count: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ScoreHistory {
+    pub const SIZE: usize = 8 + 32 + (2 + 1 + 8) * SCORE_HISTORY_LEN + 1 + 1 + 1;
+
+    /// Record the identity's current composite and tier, evicting the oldest
+    /// snapshot once the buffer is full
+    pub fn push(&mut self, identity: &SovereignIdentity, now: i64) {
+        self.entries[self.next as usize] = ScoreSnapshot {
+            composite_score: identity.composite_score,
+            tier: identity.tier,
+            timestamp: now,
+        };
+        self.next = ((self.next as usize + 1) % SCORE_HISTORY_LEN) as u8;
+        self.count = (self.count as usize + 1).min(SCORE_HISTORY_LEN) as u8;
+    }
+
+    /// Snapshots in the order they were taken, oldest first
+    pub fn ordered(&self) -> Vec<ScoreSnapshot> {
+        let count = self.count as usize;
+        let start = (self.next as usize + SCORE_HISTORY_LEN - count) % SCORE_HISTORY_LEN;
+        (0..count)
+            .map(|offset| self.entries[(start + offset) % SCORE_HISTORY_LEN])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const TIER_BOUNDARIES: [u16; 4] = [2000, 4000, 6000, 8000];
+
+    fn identity(scores: [u16; 5], weights: [u8; 5], tier: u8, grace: i64) -> SovereignIdentity {
+        SovereignIdentity {
+            owner: Pubkey::default(),
+            schema_version: SCHEMA_VERSION_CREATOR,
+            created_at: 0,
+            trading_authority: Pubkey::default(),
+            civic_authority: Pubkey::default(),
+            developer_authority: Pubkey::default(),
+            infra_authority: Pubkey::default(),
+            creator_authority: Pubkey::default(),
+            trading_score: scores[0],
+            civic_score: scores[1],
+            developer_score: scores[2],
+            infra_score: scores[3],
+            creator_score: scores[4],
+            trading_updated_at: 0,
+            civic_updated_at: 0,
+            developer_updated_at: 0,
+            infra_updated_at: 0,
+            creator_updated_at: 0,
+            trading_update_seq: 0,
+            civic_update_seq: 0,
+            developer_update_seq: 0,
+            infra_update_seq: 0,
+            creator_update_seq: 0,
+            weights,
+            composite_score: 0,
+            tier,
+            tier_demotion_pending_since: None,
+            tier_grace_period: grace,
+            decay_bps_per_day: 0,
+            attestation_hash: [0; 32],
+            attestation_authority: Pubkey::default(),
+            is_verified: false,
+            display_name: [0; 32],
+            metadata_uri: [0; 96],
+            is_frozen: false,
+            transferred_to: None,
+            last_updated: 0,
+            bump: 0,
+        }
+    }
+
+    fn scores() -> impl Strategy<Value = [u16; 5]> {
+        prop::array::uniform5(0u16..=10000)
+    }
+
+    /// Any split of 100 into five parts, from four sorted cut points
+    fn weights() -> impl Strategy<Value = [u8; 5]> {
+        prop::array::uniform4(0u8..=100).prop_map(|mut cuts| {
+            cuts.sort_unstable();
+            [
+                cuts[0],
+                cuts[1] - cuts[0],
+                cuts[2] - cuts[1],
+                cuts[3] - cuts[2],
+                100 - cuts[3],
+            ]
+        })
+    }
+
+    #[test]
+    fn recalculate_reports_only_actual_tier_transitions() {
+        // Composite 2500 sits in tier 2; staying there reports nothing
+        let mut id = identity([2500; 5], DEFAULT_SCORE_WEIGHTS, 2, 0);
+        assert_eq!(id.recalculate(0), None);
+
+        id.trading_score = 10000;
+        assert_eq!(id.recalculate(0), Some(TierChange { old_tier: 2, new_tier: 3 }));
+        assert_eq!(id.recalculate(0), None);
+
+        // A demotion held back by the grace period isn't a transition yet
+        let mut id = identity([2500; 5], DEFAULT_SCORE_WEIGHTS, 3, 100);
+        assert_eq!(id.recalculate(0), None);
+        assert_eq!(id.tier, 3);
+        assert_eq!(id.recalculate(100), Some(TierChange { old_tier: 3, new_tier: 2 }));
+    }
+
+    #[test]
+    fn a_custom_curve_changes_the_tier_for_the_same_composite() {
+        let steep = [1000, 2000, 3000, 4000];
+        assert!(TierConfig::cutoffs_valid(&steep));
+
+        assert_eq!(SovereignIdentity::tier_for(3500), 2);
+        assert_eq!(SovereignIdentity::tier_for_cutoffs(3500, &steep), 4);
+
+        let mut id = identity([3500; 5], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        id.recalculate_with(0, &steep, false);
+        assert_eq!(id.composite_score, 3500);
+        assert_eq!(id.tier, 4);
+    }
+
+    #[test]
+    fn the_balanced_rule_caps_a_lopsided_identity_at_tier_4() {
+        let trading_only = [100, 0, 0, 0, 0];
+        let mut id = identity([10000, 0, 0, 0, 0], trading_only, 1, 0);
+        id.recalculate_with(0, &DEFAULT_TIER_CUTOFFS, false);
+        assert_eq!(id.composite_score, 10000);
+        assert_eq!(id.tier, 5);
+
+        let mut id = identity([10000, 0, 0, 0, 0], trading_only, 1, 0);
+        id.recalculate_with(0, &DEFAULT_TIER_CUTOFFS, true);
+        assert_eq!(id.composite_score, 10000);
+        assert_eq!(id.tier, 4);
+
+        // One dimension just under the floor is enough to hold it back
+        let mut id = identity([10000, 10000, 10000, 10000, BALANCED_TIER5_FLOOR - 1], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        id.recalculate_with(0, &DEFAULT_TIER_CUTOFFS, true);
+        assert_eq!(id.tier, 4);
+        // Without a protocol config the rule is off
+        assert!(!ProtocolConfig::requires_balanced_tier5(None));
+    }
+
+    #[test]
+    fn the_balanced_rule_lets_a_balanced_identity_reach_tier_5() {
+        let mut id = identity([9000; 5], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        assert!(id.is_balanced());
+        id.recalculate_with(0, &DEFAULT_TIER_CUTOFFS, true);
+        assert_eq!(id.tier, 5);
+
+        // An unscored creator dimension keeps the identity unbalanced
+        let id = identity([9000, 9000, 9000, 9000, 0], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        assert!(!id.is_balanced());
+    }
+
+    #[test]
+    fn a_tombstone_keeps_no_reputation() {
+        let mut id = identity([9000; 5], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        id.is_verified = true;
+        id.recalculate(0);
+        assert_eq!(id.tier, 5);
+
+        let successor = Pubkey::new_unique();
+        id.tombstone(successor, 10);
+        assert_eq!(id.transferred_to, Some(successor));
+        assert!(id.is_frozen && !id.is_verified);
+        assert_eq!((id.composite_score, id.tier), (0, 1));
+
+        // Nothing is left for a recalculation to bring back
+        id.recalculate(20);
+        assert_eq!((id.composite_score, id.tier), (0, 1));
+    }
+
+    /// A v1 identity account as the original program wrote it: trading at
+    /// 8000 under the legacy weighting (40% -> composite 3200, tier 2)
+    fn legacy_account_data(owner: Pubkey) -> Vec<u8> {
+        let legacy = LegacyIdentity {
+            owner,
+            created_at: 100,
+            trading_authority: owner,
+            civic_authority: owner,
+            developer_authority: owner,
+            infra_authority: owner,
+            creator_authority: Pubkey::default(),
+            trading_score: 8000,
+            civic_score: 0,
+            developer_score: 0,
+            infra_score: 0,
+            creator_score: 0,
+            composite_score: 3200,
+            tier: 2,
+            last_updated: 500,
+            bump: 254,
+        };
+        let mut data = SovereignIdentity::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn a_v1_account_migrates_to_v2_weighting() {
+        let owner = Pubkey::new_unique();
+        let data = legacy_account_data(owner);
+        assert_eq!(data.len(), 230);
+        assert_eq!(data.len(), LegacyIdentity::SIZE);
+
+        let legacy = LegacyIdentity::try_from_account_data(&data).unwrap();
+        let dao = Pubkey::new_unique();
+        let (id, change) = SovereignIdentity::migrate_to_v2(&legacy, dao, 1_000, &DEFAULT_TIER_CUTOFFS, false);
+
+        assert_eq!(id.schema_version, SCHEMA_VERSION_CREATOR);
+        assert_eq!((id.owner, id.created_at, id.bump), (owner, 100, 254));
+        assert_eq!(id.trading_authority, owner);
+        assert_eq!(id.creator_authority, dao);
+        assert_eq!((id.trading_score, id.trading_updated_at), (8000, 500));
+        assert_eq!((id.creator_score, id.creator_updated_at), (0, 0));
+        assert_eq!(id.weights, DEFAULT_SCORE_WEIGHTS);
+
+        // 30% of 8000 under the 5-dimension weighting, down from 3200
+        assert_eq!(id.composite_score, 2400);
+        assert_eq!(id.tier, 2);
+        assert_eq!(change, None);
+
+        // The rewritten account fits the grown allocation and reads back
+        let mut rewritten = Vec::new();
+        id.try_serialize(&mut rewritten).unwrap();
+        assert!(rewritten.len() <= SovereignIdentity::SIZE);
+        let reread = SovereignIdentity::try_deserialize(&mut &rewritten[..]).unwrap();
+        assert_eq!(reread.composite_score, 2400);
+    }
+
+    #[test]
+    fn only_legacy_accounts_can_be_migrated() {
+        let mut current = Vec::new();
+        identity([0; 5], DEFAULT_SCORE_WEIGHTS, 1, 0).try_serialize(&mut current).unwrap();
+        current.resize(SovereignIdentity::SIZE, 0);
+        let err = LegacyIdentity::try_from_account_data(&current).unwrap_err();
+        assert_eq!(err, SovereignError::AlreadyMigrated.into());
+
+        // Another account type of the legacy size
+        let mut other = legacy_account_data(Pubkey::new_unique());
+        other[..8].copy_from_slice(&TierConfig::DISCRIMINATOR);
+        let err = LegacyIdentity::try_from_account_data(&other).unwrap_err();
+        assert_eq!(err, ErrorCode::AccountDidNotDeserialize.into());
+    }
+
+    #[test]
+    fn an_out_of_range_dimension_clamps_the_composite_instead_of_wrapping() {
+        // A maxed-out dimension at full weight would otherwise surface as a
+        // composite of 65535
+        let id = identity([u16::MAX, 0, 0, 0, 0], [100, 0, 0, 0, 0], 1, 0);
+        assert_eq!(id.weighted_composite(), 10000);
+
+        let id = identity([u16::MAX, u16::MAX, u16::MAX, u16::MAX, 0], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        assert_eq!(id.legacy_composite(), 10000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exceeds 10000")]
+    fn recalculate_flags_an_out_of_range_dimension_in_debug_builds() {
+        let mut id = identity([10001, 0, 0, 0, 0], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        id.recalculate(0);
+    }
+
+    #[test]
+    fn update_seq_only_moves_forward() {
+        let mut stored = 0;
+        assert!(SovereignIdentity::advance_update_seq(&mut stored, None));
+        assert_eq!(stored, 0);
+
+        assert!(SovereignIdentity::advance_update_seq(&mut stored, Some(5)));
+        assert_eq!(stored, 5);
+
+        // Replays and out-of-order sequences are refused
+        assert!(!SovereignIdentity::advance_update_seq(&mut stored, Some(5)));
+        assert!(!SovereignIdentity::advance_update_seq(&mut stored, Some(3)));
+        assert_eq!(stored, 5);
+
+        // Unsequenced updates still go through without resetting it
+        assert!(SovereignIdentity::advance_update_seq(&mut stored, None));
+        assert!(SovereignIdentity::advance_update_seq(&mut stored, Some(6)));
+        assert_eq!(stored, 6);
+    }
+
+    #[test]
+    fn tier_cutoffs_must_increase_within_range() {
+        assert!(TierConfig::cutoffs_valid(&DEFAULT_TIER_CUTOFFS));
+        assert!(!TierConfig::cutoffs_valid(&[0, 4000, 6000, 8000]));
+        assert!(!TierConfig::cutoffs_valid(&[2000, 2000, 6000, 8000]));
+        assert!(!TierConfig::cutoffs_valid(&[2000, 4000, 6000, 10001]));
+        assert_eq!(TierConfig::cutoffs_or_default(None), DEFAULT_TIER_CUTOFFS);
+    }
+
+    #[test]
+    fn score_history_wraps_and_keeps_the_newest_entries_in_order() {
+        let mut history = ScoreHistory {
+            identity: Pubkey::default(),
+            entries: [ScoreSnapshot::default(); SCORE_HISTORY_LEN],
+            next: 0,
+            count: 0,
+            bump: 0,
+        };
+        assert!(history.ordered().is_empty());
+
+        let mut id = identity([0; 5], DEFAULT_SCORE_WEIGHTS, 1, 0);
+        let pushes = SCORE_HISTORY_LEN as i64 + 5;
+        for now in 0..pushes {
+            id.composite_score = now as u16;
+            history.push(&id, now);
+        }
+
+        let ordered = history.ordered();
+        assert_eq!(ordered.len(), SCORE_HISTORY_LEN);
+        assert_eq!(ordered[0].timestamp, 5);
+        assert_eq!(ordered[SCORE_HISTORY_LEN - 1].timestamp, pushes - 1);
+        assert!(ordered.windows(2).all(|pair| pair[0].timestamp + 1 == pair[1].timestamp));
+        assert!(ordered.iter().all(|snapshot| snapshot.composite_score as i64 == snapshot.timestamp));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(256))]
+
+        #[test]
+        fn composite_never_exceeds_max(scores in scores(), weights in weights(), now in 0i64..1_000_000_000) {
+            prop_assert!(SovereignIdentity::weights_valid(&weights));
+
+            let mut id = identity(scores, weights, 1, 0);
+            id.recalculate(now);
+            prop_assert!(id.composite_score <= 10000);
+
+            id.recalculate_legacy(now);
+            prop_assert!(id.composite_score <= 10000);
+        }
+
+        #[test]
+        fn composite_lies_between_min_and_max_dimension(scores in scores(), weights in weights()) {
+            let mut id = identity(scores, weights, 1, 0);
+            id.recalculate(0);
+
+            let lo = *scores.iter().min().unwrap();
+            let hi = *scores.iter().max().unwrap();
+            prop_assert!(id.composite_score >= lo);
+            prop_assert!(id.composite_score <= hi);
+        }
+
+        #[test]
+        fn tier_matches_boundary_table(composite in 0u16..=u16::MAX) {
+            let expected = 1 + TIER_BOUNDARIES.iter().filter(|b| composite >= **b).count() as u8;
+            prop_assert_eq!(SovereignIdentity::tier_for(composite), expected);
+        }
+
+        #[test]
+        fn tier_is_monotonic_in_composite(a in 0u16..=10000, b in 0u16..=10000) {
+            let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+            prop_assert!(SovereignIdentity::tier_for(lo) <= SovereignIdentity::tier_for(hi));
+        }
+
+        #[test]
+        fn tier_is_consistent_with_composite_without_grace(
+            scores in scores(),
+            weights in weights(),
+            start_tier in 1u8..=5,
+        ) {
+            let mut id = identity(scores, weights, start_tier, 0);
+            id.recalculate(0);
+            prop_assert_eq!(id.tier, SovereignIdentity::tier_for(id.composite_score));
+            prop_assert!(id.tier_demotion_pending_since.is_none());
+        }
+
+        #[test]
+        fn tier_never_trails_composite_with_grace(
+            scores in scores(),
+            weights in weights(),
+            start_tier in 1u8..=5,
+            grace in 1i64..=30 * 86400,
+            now in 0i64..1_000_000_000,
+        ) {
+            let mut id = identity(scores, weights, start_tier, grace);
+            id.recalculate(now);
+
+            let target = SovereignIdentity::tier_for(id.composite_score);
+            prop_assert!(id.tier >= target);
+            // A tier held above its composite is always a pending demotion
+            prop_assert_eq!(id.tier > target, id.tier_demotion_pending_since.is_some());
+        }
+
+        #[test]
+        fn recalculate_is_idempotent(
+            scores in scores(),
+            weights in weights(),
+            start_tier in 1u8..=5,
+            grace in 0i64..=30 * 86400,
+            now in 0i64..1_000_000_000,
+        ) {
+            let mut id = identity(scores, weights, start_tier, grace);
+            id.recalculate(now);
+            let (composite, tier, pending) = (id.composite_score, id.tier, id.tier_demotion_pending_since);
+
+            id.recalculate(now);
+            prop_assert_eq!(id.composite_score, composite);
+            prop_assert_eq!(id.tier, tier);
+            prop_assert_eq!(id.tier_demotion_pending_since, pending);
+        }
+
+        #[test]
+        fn decay_never_increases_scores(
+            scores in scores(),
+            rate in 0u16..=10000,
+            elapsed in 0i64..=3650 * 86400,
+        ) {
+            let mut id = identity(scores, DEFAULT_SCORE_WEIGHTS, 1, 0);
+            id.decay_bps_per_day = rate;
+            id.apply_decay(elapsed);
+
+            let decayed = [id.trading_score, id.civic_score, id.developer_score, id.infra_score, id.creator_score];
+            for (after, before) in decayed.iter().zip(scores.iter()) {
+                prop_assert!(after <= before);
+            }
+            prop_assert!(SovereignIdentity::decay_factor_bps(rate, (elapsed / 86400) as u64) <= 10000);
+        }
+    }
+}
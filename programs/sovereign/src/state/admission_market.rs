@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::creator_dao::ContentType;
 
 // =============================================================================
 // ADMISSION PREDICTION MARKET
@@ -22,8 +23,18 @@ use anchor_lang::prelude::*;
 // This grounds speculation in quality judgment rather than attention metrics.
 // =============================================================================
 
+/// Starting YES price of a market when its creator doesn't pick one
+pub const DEFAULT_INITIAL_YES_BPS: u16 = 5000;
+
+/// Most skewed starting YES prices a market creator may pick
+pub const MIN_INITIAL_YES_BPS: u16 = 1000;
+pub const MAX_INITIAL_YES_BPS: u16 = 9000;
+
+/// Fixed-point scale of `lp_fee_per_share`
+pub const LP_FEE_SCALE: u128 = 1_000_000_000_000;
+
 /// Market status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum MarketStatus {
     /// Market is open for trading
     Open,
@@ -38,7 +49,7 @@ pub enum MarketStatus {
 }
 
 /// Outcome of the market
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum MarketOutcome {
     /// Not yet determined
     Pending,
@@ -50,6 +61,15 @@ pub enum MarketOutcome {
     Cancelled,
 }
 
+/// Side of a position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSide {
+    /// "I predict this creator WILL be accepted"
+    Yes,
+    /// "I predict this creator will NOT be accepted"
+    No,
+}
+
 // =============================================================================
 // ADMISSION MARKET ACCOUNT
 // =============================================================================
@@ -59,22 +79,34 @@ pub enum MarketOutcome {
 
 #[account]
 pub struct AdmissionMarket {
-    /// Unique market ID
+    /// Unique market ID (the factory's market_count at creation, also a
+    /// PDA seed so a (DAO, creator) pair can have more than one market)
     pub market_id: u64,
 
     // === The Prediction ===
     /// Which DAO is this prediction about?
     pub dao: Pubkey,
+    /// The DAO's content type, copied at creation so markets can be
+    /// filtered without fetching the DAO
+    pub content_type: ContentType,
+    /// The DAO's style tag, copied at creation
+    pub style_tag: [u8; 32],
     /// Which creator are we predicting will be accepted?
     pub creator_identity: Pubkey,
     /// The creator's wallet (for potential nomination)
     pub creator_wallet: Pubkey,
+    /// The nomination this market resolves on, set once when the creator
+    /// is nominated with this market attached
+    pub nomination: Option<Pubkey>,
 
     // === Market Creator ===
     /// Who created this market (the speculator who spotted talent first)
     pub market_creator: Pubkey,
     /// Bonus for market creator if prediction is correct (basis points of pool)
     pub creator_bonus_bps: u16,
+    /// Share of the seed's trading fees (those left after the LPs' share)
+    /// rebated to the market creator at finalization (basis points)
+    pub seeder_fee_share_bps: u16,
 
     // === Pool State ===
     /// Total staked on "will be accepted" (YES)
@@ -83,6 +115,13 @@ pub struct AdmissionMarket {
     pub no_pool: u64,
     /// Total unique predictors
     pub predictor_count: u32,
+    /// YES tokens issued to predictors (the AMM reserve shrinks as these are
+    /// bought, so payouts are proportioned on this instead of `yes_pool`)
+    pub total_yes_tokens_issued: u64,
+    /// NO tokens issued to predictors
+    pub total_no_tokens_issued: u64,
+    /// Stakes paid in, net of fees
+    pub total_net_stakes: u64,
 
     // === Pricing (Constant Product AMM) ===
     // Vitalik references prediction markets extensively; using proven AMM model
@@ -95,6 +134,26 @@ pub struct AdmissionMarket {
     pub fee_bps: u16,
     /// Accumulated fees
     pub accumulated_fees: u64,
+    /// Fees rebated to the market creator by finalize_market
+    pub seeder_fees_paid: u64,
+    /// Whether the market creator took back the seed liquidity of a
+    /// cancelled market
+    pub seed_reclaimed: bool,
+
+    // === Liquidity Providers ===
+    /// Matched liquidity added by LPs and not yet removed. It deepens the AMM
+    /// reserves but stays out of `total_pot`, so winners are never paid from it.
+    pub lp_liquidity: u64,
+    /// Trading fees set aside for LPs and not yet paid out (kept out of
+    /// `accumulated_fees`)
+    pub lp_fees: u64,
+    /// LP fees earned per lamport of LP liquidity, scaled by LP_FEE_SCALE
+    pub lp_fee_per_share: u128,
+    /// Running sum of the YES price (basis points) times the seconds it
+    /// stood, for `twap_bps`
+    pub cumulative_yes_price: u128,
+    /// When `cumulative_yes_price` was last brought up to date
+    pub last_price_update_ts: i64,
 
     // === Timing ===
     /// When market was created
@@ -124,8 +183,27 @@ pub struct AdmissionMarket {
 
     /// Percentage of winning pool that goes to burn (basis points)
     pub burn_percentage_bps: u16,
+    /// Percentage of the pot routed to the protocol treasury (basis points);
+    /// winners share whatever the burn and protocol leave
+    pub protocol_fee_bps: u16,
+    /// Extra burn applied to the predicted creator's own winning payout
+    /// (basis points), discouraging pure self-promotion profit
+    pub self_position_burn_bps: u16,
     /// Amount sent to burn
     pub amount_burned: u64,
+    /// Amount set aside for the protocol treasury at resolution
+    pub amount_to_protocol: u64,
+
+    /// Largest share of the pot one position's stake may reach (basis points,
+    /// 0 = uncapped), copied from the factory at creation
+    pub max_position_bps: u16,
+    /// Smallest either AMM reserve may be drawn down to by a trade, copied
+    /// from the factory at creation
+    pub min_pool_floor: u64,
+
+    /// Seconds after resolution during which the outcome can be disputed and
+    /// claims are held, copied from the factory at creation
+    pub challenge_period: i64,
 
     /// PDA bump seed
     pub bump: u8,
@@ -135,16 +213,30 @@ impl AdmissionMarket {
     pub const SIZE: usize = 8 +     // discriminator
         8 +                          // market_id
         32 +                         // dao
+        1 +                          // content_type
+        32 +                         // style_tag
         32 +                         // creator_identity
         32 +                         // creator_wallet
+        33 +                         // nomination
         32 +                         // market_creator
         2 +                          // creator_bonus_bps
+        2 +                          // seeder_fee_share_bps
         8 +                          // yes_pool
         8 +                          // no_pool
         4 +                          // predictor_count
+        8 +                          // total_yes_tokens_issued
+        8 +                          // total_no_tokens_issued
+        8 +                          // total_net_stakes
         8 +                          // initial_liquidity
         2 +                          // fee_bps
         8 +                          // accumulated_fees
+        8 +                          // seeder_fees_paid
+        1 +                          // seed_reclaimed
+        8 +                          // lp_liquidity
+        8 +                          // lp_fees
+        16 +                         // lp_fee_per_share
+        16 +                         // cumulative_yes_price
+        8 +                          // last_price_update_ts
         8 +                          // created_at
         9 +                          // trading_ends_at
         8 +                          // expires_at
@@ -153,7 +245,13 @@ impl AdmissionMarket {
         33 +                         // resolved_by_nomination
         9 +                          // resolved_at
         2 +                          // burn_percentage_bps
+        2 +                          // protocol_fee_bps
+        2 +                          // self_position_burn_bps
         8 +                          // amount_burned
+        8 +                          // amount_to_protocol
+        2 +                          // max_position_bps
+        8 +                          // min_pool_floor
+        8 +                          // challenge_period
         1;                           // bump
 
     /// Calculate current YES price (probability of acceptance)
@@ -171,53 +269,445 @@ impl AdmissionMarket {
         10000 - self.yes_price_bps()
     }
 
+    /// Fold the current YES price into the accumulator for the time it has
+    /// stood since the last update. Called before every trade, so each price
+    /// is weighted by how long it held.
+    pub fn accumulate_price(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.last_price_update_ts).max(0) as u128;
+        self.cumulative_yes_price = self
+            .cumulative_yes_price
+            .saturating_add(self.yes_price_bps() as u128 * elapsed);
+        self.last_price_update_ts = self.last_price_update_ts.max(now);
+    }
+
+    /// Time-weighted YES price over the last `window` seconds (basis points)
+    ///
+    /// The price since the last trade is known exactly; anything older is
+    /// taken at the accumulator's average up to that trade. A price that only
+    /// stood for a moment carries only a moment's weight, so a large trade
+    /// just before voting locks barely moves it. Falls back to the spot price
+    /// before any time has passed.
+    pub fn twap_bps(&self, now: i64, window: i64) -> u16 {
+        let spot = self.yes_price_bps() as u128;
+        let since_update = now.saturating_sub(self.last_price_update_ts).max(0);
+        let history = self.last_price_update_ts.saturating_sub(self.created_at).max(0);
+        let window = window.min(since_update.saturating_add(history));
+        if window <= 0 {
+            return spot as u16;
+        }
+
+        let recent = since_update.min(window);
+        let older = window - recent;
+        let older_average = if history > 0 {
+            self.cumulative_yes_price / history as u128
+        } else {
+            spot
+        };
+        ((spot * recent as u128 + older_average * older as u128) / window as u128) as u16
+    }
+
+    /// An open market past its expiry that no nomination ever locked
+    pub fn is_stale(&self, now: i64) -> bool {
+        self.status == MarketStatus::Open && now > self.expires_at
+    }
+
+    /// Cancel a stale market so every position can be refunded its stake
+    pub fn expire(&mut self) {
+        self.status = MarketStatus::Expired;
+        self.outcome = MarketOutcome::Cancelled;
+        // Refunds return each full stake, fees included, so neither the
+        // factory nor the LPs keep a fee share
+        self.accumulated_fees = 0;
+        self.lp_fees = 0;
+    }
+
+    /// Seed liquidity the market creator can take back: all of it once the
+    /// market is cancelled (there are no winners to share it), and only once
+    pub fn reclaimable_seed(&self) -> u64 {
+        if self.outcome == MarketOutcome::Cancelled && !self.seed_reclaimed {
+            self.initial_liquidity
+        } else {
+            0
+        }
+    }
+
+    /// Take the sweepable trading fees out of the market's books, returning
+    /// how much to pay out (0 once they have been swept)
+    pub fn take_withdrawable_fees(&mut self) -> u64 {
+        if !self.fees_withdrawable() {
+            return 0;
+        }
+        std::mem::take(&mut self.accumulated_fees)
+    }
+
+    /// Whether the market still awaits its outcome (neither resolved nor expired)
+    pub fn is_unsettled(&self) -> bool {
+        matches!(self.status, MarketStatus::Open | MarketStatus::VotingInProgress)
+    }
+
+    /// Whether `now` is inside the dispute window that follows resolution
+    pub fn in_challenge_period(&self, now: i64) -> bool {
+        self.status == MarketStatus::Resolved
+            && self
+                .resolved_at
+                .is_some_and(|resolved_at| now < resolved_at.saturating_add(self.challenge_period))
+    }
+
+    /// Claims open once the outcome is settled and no longer disputable
+    pub fn claims_open(&self, now: i64) -> bool {
+        match self.status {
+            MarketStatus::Resolved => !self.in_challenge_period(now),
+            MarketStatus::Finalized | MarketStatus::Expired => true,
+            _ => false,
+        }
+    }
+
+    /// Trading fees collected, excluding the protocol's share of the pot
+    pub fn trading_fees(&self) -> u64 {
+        self.accumulated_fees.saturating_sub(self.amount_to_protocol)
+    }
+
+    /// The market creator's rebate: `seeder_fee_share_bps` of the trading
+    /// fees. LPs already took their share of each fee as it was paid, so what
+    /// is left was earned by the seed, in proportion to
+    /// initial_liquidity / (initial_liquidity + lp_liquidity).
+    pub fn seeder_fee_rebate(&self) -> u64 {
+        (self.trading_fees() as u128 * self.seeder_fee_share_bps as u128 / 10000) as u64
+    }
+
+    /// Close out a resolved market, setting the seeder's rebate aside from
+    /// the fees so withdraw_fees only sweeps the remainder. Returns the rebate.
+    pub fn finalize(&mut self) -> u64 {
+        let rebate = self.seeder_fee_rebate();
+        self.accumulated_fees -= rebate;
+        self.seeder_fees_paid = rebate;
+        self.status = MarketStatus::Finalized;
+        rebate
+    }
+
+    /// Outcome a resolved market should carry for its nomination's result
+    pub fn outcome_for(was_accepted: bool) -> MarketOutcome {
+        if was_accepted {
+            MarketOutcome::Accepted
+        } else {
+            MarketOutcome::Rejected
+        }
+    }
+
+    /// Fees may only leave the escrow once the market is settled
+    pub fn fees_withdrawable(&self) -> bool {
+        matches!(self.status, MarketStatus::Finalized | MarketStatus::Expired)
+    }
+
+    /// Link the market to its nomination and stop trading for the vote
+    pub fn lock_for_voting(&mut self, nomination: Pubkey, now: i64) {
+        self.nomination = Some(nomination);
+        self.trading_ends_at = Some(now);
+        self.status = MarketStatus::VotingInProgress;
+    }
+
+    /// Undo `lock_for_voting` after its nomination was withdrawn or timed out
+    ///
+    /// A market still before `expires_at` reopens for trading so speculators
+    /// can keep pricing a re-nomination; one past it is cancelled instead.
+    /// Returns whether the market reopened.
+    pub fn unlock_after_nomination(&mut self, now: i64) -> bool {
+        self.nomination = None;
+        self.trading_ends_at = None;
+        if now < self.expires_at {
+            self.status = MarketStatus::Open;
+            true
+        } else {
+            self.expire();
+            false
+        }
+    }
+
+    /// Split seed liquidity into (yes_pool, no_pool) so the market opens at
+    /// `yes_bps`. The YES price is the NO pool's share, so a skewed market
+    /// holds more NO reserves; every lamport lands in one pool or the other.
+    pub fn seed_pools(initial_liquidity: u64, yes_bps: u16) -> (u64, u64) {
+        let no_pool = (initial_liquidity as u128 * yes_bps.min(10000) as u128 / 10000) as u64;
+        (initial_liquidity - no_pool, no_pool)
+    }
+
+    /// Stake left after the trading fee (never overflows)
+    pub fn stake_after_fee(stake: u64, fee_bps: u16) -> u64 {
+        stake - (stake as u128 * fee_bps.min(10000) as u128 / 10000) as u64
+    }
+
     /// Calculate amount of YES tokens for a given stake
     /// Using constant product formula: x * y = k
+    ///
+    /// However large the stake, at least one unit stays in the YES reserve.
     pub fn calculate_yes_tokens(&self, stake: u64, fee_bps: u16) -> u64 {
-        let stake_after_fee = stake - (stake * fee_bps as u64 / 10000);
+        let stake_after_fee = Self::stake_after_fee(stake, fee_bps);
         if self.yes_pool == 0 {
             return stake_after_fee;
         }
         // dy = y - (k / (x + dx))
         let k = self.yes_pool as u128 * self.no_pool as u128;
-        let new_no_pool = self.no_pool + stake_after_fee;
-        let new_yes_pool = (k / new_no_pool as u128) as u64;
-        self.yes_pool - new_yes_pool
+        let new_no_pool = self.no_pool as u128 + stake_after_fee as u128;
+        let new_yes_pool = ((k / new_no_pool) as u64).max(1);
+        self.yes_pool.saturating_sub(new_yes_pool)
     }
 
     /// Calculate amount of NO tokens for a given stake
+    ///
+    /// However large the stake, at least one unit stays in the NO reserve.
     pub fn calculate_no_tokens(&self, stake: u64, fee_bps: u16) -> u64 {
-        let stake_after_fee = stake - (stake * fee_bps as u64 / 10000);
+        let stake_after_fee = Self::stake_after_fee(stake, fee_bps);
         if self.no_pool == 0 {
             return stake_after_fee;
         }
         let k = self.yes_pool as u128 * self.no_pool as u128;
-        let new_yes_pool = self.yes_pool + stake_after_fee;
-        let new_no_pool = (k / new_yes_pool as u128) as u64;
-        self.no_pool - new_no_pool
+        let new_yes_pool = self.yes_pool as u128 + stake_after_fee as u128;
+        let new_no_pool = ((k / new_yes_pool) as u64).max(1);
+        self.no_pool.saturating_sub(new_no_pool)
+    }
+
+    /// Apply a trade to the AMM reserves and issuance counters
+    ///
+    /// Returns `None` (leaving the market untouched) if any counter would overflow.
+    pub fn apply_trade(&mut self, side: PositionSide, net_stake: u64, tokens: u64) -> Option<()> {
+        let total_net_stakes = self.total_net_stakes.checked_add(net_stake)?;
+        match side {
+            PositionSide::Yes => {
+                let no_pool = self.no_pool.checked_add(net_stake)?;
+                let issued = self.total_yes_tokens_issued.checked_add(tokens)?;
+                self.no_pool = no_pool;
+                self.yes_pool = self.yes_pool.saturating_sub(tokens);
+                self.total_yes_tokens_issued = issued;
+            }
+            PositionSide::No => {
+                let yes_pool = self.yes_pool.checked_add(net_stake)?;
+                let issued = self.total_no_tokens_issued.checked_add(tokens)?;
+                self.yes_pool = yes_pool;
+                self.no_pool = self.no_pool.saturating_sub(tokens);
+                self.total_no_tokens_issued = issued;
+            }
+        }
+        self.total_net_stakes = total_net_stakes;
+        Some(())
+    }
+
+    /// Whether buying `tokens` on `side` leaves the reserve it draws from at
+    /// or above `min_pool_floor` (and never empty, whatever the floor)
+    pub fn keeps_pool_floor(&self, side: PositionSide, tokens: u64) -> bool {
+        let pool = match side {
+            PositionSide::Yes => self.yes_pool,
+            PositionSide::No => self.no_pool,
+        };
+        pool.saturating_sub(tokens) >= self.min_pool_floor.max(1)
+    }
+
+    /// Split `amount` of matched liquidity across the reserves in their
+    /// current ratio, so adding or removing it leaves the price unchanged.
+    /// Returns (yes, no).
+    pub fn split_liquidity(&self, amount: u64) -> (u64, u64) {
+        let reserves = self.yes_pool as u128 + self.no_pool as u128;
+        if reserves == 0 {
+            return (amount - amount / 2, amount / 2);
+        }
+        let yes = (amount as u128 * self.yes_pool as u128 / reserves) as u64;
+        (yes, amount - yes)
+    }
+
+    /// Deepen both reserves with `amount` of LP liquidity
+    ///
+    /// Returns `None` (leaving the market untouched) if any counter would overflow.
+    pub fn add_lp_liquidity(&mut self, amount: u64) -> Option<()> {
+        let (yes, no) = self.split_liquidity(amount);
+        let yes_pool = self.yes_pool.checked_add(yes)?;
+        let no_pool = self.no_pool.checked_add(no)?;
+        let lp_liquidity = self.lp_liquidity.checked_add(amount)?;
+        self.yes_pool = yes_pool;
+        self.no_pool = no_pool;
+        self.lp_liquidity = lp_liquidity;
+        Some(())
+    }
+
+    /// Take `amount` of LP liquidity back out of the market. While trading is
+    /// open the reserves shrink with it, which fails (leaving the market
+    /// untouched) if either would drop below the pool floor.
+    pub fn remove_lp_liquidity(&mut self, amount: u64) -> bool {
+        let Some(lp_liquidity) = self.lp_liquidity.checked_sub(amount) else {
+            return false;
+        };
+        if self.status == MarketStatus::Open {
+            let (yes, no) = self.split_liquidity(amount);
+            let floor = self.min_pool_floor.max(1);
+            match (self.yes_pool.checked_sub(yes), self.no_pool.checked_sub(no)) {
+                (Some(yes_pool), Some(no_pool)) if yes_pool >= floor && no_pool >= floor => {
+                    self.yes_pool = yes_pool;
+                    self.no_pool = no_pool;
+                }
+                _ => return false,
+            }
+        }
+        self.lp_liquidity = lp_liquidity;
+        true
+    }
+
+    /// Set aside the LPs' part of a trading fee, in proportion to their
+    /// liquidity next to the seed. Returns the part that goes to LPs; the
+    /// rest belongs in `accumulated_fees`.
+    ///
+    /// Returns `None` (leaving the market untouched) if any counter would overflow.
+    pub fn accrue_lp_fees(&mut self, fee: u64) -> Option<u64> {
+        if self.lp_liquidity == 0 {
+            return Some(0);
+        }
+        let depth = self.initial_liquidity as u128 + self.lp_liquidity as u128;
+        let lp_fee = (fee as u128 * self.lp_liquidity as u128 / depth) as u64;
+        let lp_fees = self.lp_fees.checked_add(lp_fee)?;
+        let lp_fee_per_share = self
+            .lp_fee_per_share
+            .checked_add(lp_fee as u128 * LP_FEE_SCALE / self.lp_liquidity as u128)?;
+        self.lp_fees = lp_fees;
+        self.lp_fee_per_share = lp_fee_per_share;
+        Some(lp_fee)
+    }
+
+    /// Everything paid into the market: seed liquidity plus stakes net of fees
+    pub fn total_pot(&self) -> u64 {
+        self.initial_liquidity + self.total_net_stakes
+    }
+
+    /// Whether a position holding `total_staked` would exceed the whale cap
+    pub fn exceeds_position_cap(&self, total_staked: u64) -> bool {
+        self.max_position_bps > 0
+            && total_staked as u128 * 10000 > self.total_pot() as u128 * self.max_position_bps as u128
+    }
+
+    /// Split the pot three ways: (winners, creator-coin burn, protocol)
+    ///
+    /// The burn and protocol shares round down and winners take the
+    /// remainder, so the parts always add up to the whole pot.
+    pub fn pot_split(&self) -> (u64, u64, u64) {
+        let total_pot = self.total_pot();
+        let burn = (total_pot as u128 * self.burn_percentage_bps as u128 / 10000) as u64;
+        let protocol = (total_pot as u128 * self.protocol_fee_bps as u128 / 10000) as u64;
+        (total_pot.saturating_sub(burn).saturating_sub(protocol), burn, protocol)
+    }
+
+    /// What winners share after the burn and protocol fee
+    pub fn distributable_pot(&self) -> u64 {
+        self.pot_split().0
     }
 
     /// Calculate payout for winning position
+    ///
+    /// Winners split the distributable pot pro rata to the tokens issued on
+    /// their side, so the payouts of every winner sum to the pot (less at most
+    /// one lamport of rounding per claim).
     pub fn calculate_payout(&self, position_tokens: u64, is_yes: bool) -> u64 {
-        let (winning_pool, losing_pool) = if is_yes {
-            (self.yes_pool, self.no_pool)
+        let winning_issued = if is_yes {
+            self.total_yes_tokens_issued
         } else {
-            (self.no_pool, self.yes_pool)
+            self.total_no_tokens_issued
         };
 
-        if winning_pool == 0 {
+        if winning_issued == 0 {
             return 0;
         }
 
-        // Winner's share of the losing pool
-        let total_pot = winning_pool + losing_pool;
-        let burn_amount = (total_pot as u128 * self.burn_percentage_bps as u128 / 10000) as u64;
-        let distributable = total_pot - burn_amount - self.accumulated_fees;
+        (self.distributable_pot() as u128 * position_tokens as u128 / winning_issued as u128) as u64
+    }
+
+    /// Largest stake (before fees) that moves the bought side's implied
+    /// probability up by at most `max_impact_bps`
+    ///
+    /// Buying YES adds the net stake to `no_pool` while keeping k = yes * no,
+    /// so the new YES price is no'^2 / (k + no'^2). Solving for a target
+    /// price p gives no' = sqrt(k * p / (1 - p)); NO is symmetric.
+    pub fn max_stake_for_impact(&self, side: PositionSide, max_impact_bps: u16) -> u64 {
+        if self.yes_pool == 0 || self.no_pool == 0 || self.fee_bps >= 10000 {
+            return 0;
+        }
+
+        let (current_bps, counter_pool) = match side {
+            PositionSide::Yes => (self.yes_price_bps(), self.no_pool),
+            PositionSide::No => (self.no_price_bps(), self.yes_pool),
+        };
+        let target_bps = (current_bps as u128 + max_impact_bps as u128).min(9999);
+
+        let k = self.yes_pool as u128 * self.no_pool as u128;
+        let denominator = 10000 - target_bps;
+        let target_sq = k
+            .checked_mul(target_bps)
+            .map(|v| v / denominator)
+            .unwrap_or_else(|| (k / denominator).saturating_mul(target_bps));
+        let new_counter_pool = isqrt(target_sq);
+
+        let net_stake = new_counter_pool.saturating_sub(counter_pool as u128);
+        let gross_stake = net_stake * 10000 / (10000 - self.fee_bps as u128);
 
-        (distributable as u128 * position_tokens as u128 / winning_pool as u128) as u64
+        gross_stake.min(u64::MAX as u128) as u64
+    }
+
+    /// Effective YES probability a buyer of `stake` faces once the trading fee
+    /// is counted (basis points, capped at 10000)
+    ///
+    /// Simulates the post-fee purchase, takes the YES price the pools are
+    /// left at, and grosses it up by what the buyer paid over what reached
+    /// the pool. A zero stake gives the marginal price: `yes_price_bps`
+    /// grossed up by the fee.
+    pub fn yes_price_after_fee_bps(&self, stake: u64) -> u16 {
+        let fee_bps = self.fee_bps.min(10000) as u128;
+        let net_stake = Self::stake_after_fee(stake, self.fee_bps);
+
+        let price_after = if self.yes_pool == 0 && self.no_pool == 0 {
+            self.yes_price_bps() as u128
+        } else {
+            let tokens = self.calculate_yes_tokens(stake, self.fee_bps);
+            let yes_after = self.yes_pool.saturating_sub(tokens) as u128;
+            let no_after = self.no_pool as u128 + net_stake as u128;
+            no_after * 10000 / (yes_after + no_after)
+        };
+
+        let (paid, received) = if stake == 0 {
+            (10000, 10000 - fee_bps)
+        } else {
+            (stake as u128, net_stake as u128)
+        };
+        if received == 0 {
+            return 10000;
+        }
+
+        (price_after * paid / received).min(10000) as u16
+    }
+
+    /// Extra burn owed on a winning payout claimed by the predicted creator
+    /// themselves after being accepted
+    ///
+    /// The creator is recognised by their identity, so a position follows
+    /// the identity to whichever wallet now holds it, as well as by the
+    /// wallet the market was opened against.
+    pub fn self_position_burn(&self, predictor: &Pubkey, predictor_identity: Option<Pubkey>, payout: u64) -> u64 {
+        let is_creator = *predictor == self.creator_wallet
+            || predictor_identity == Some(self.creator_identity);
+        if self.outcome != MarketOutcome::Accepted || !is_creator {
+            return 0;
+        }
+        (payout as u128 * self.self_position_burn_bps as u128 / 10000) as u64
     }
 }
 
+/// Integer square root (floor) via Newton's method
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
 // =============================================================================
 // MARKET POSITION ACCOUNT
 // =============================================================================
@@ -258,6 +748,16 @@ pub struct MarketPosition {
 impl MarketPosition {
     pub const SIZE: usize = 8 + 32 + 32 + 33 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 1;
 
+    /// Whether the position already holds tokens on the side opposite `side`.
+    /// Holding both sides lets a predictor self-trade the price around while
+    /// guaranteeing a winning position, so a position stays on one side.
+    pub fn holds_opposite(&self, side: PositionSide) -> bool {
+        match side {
+            PositionSide::Yes => self.no_tokens > 0,
+            PositionSide::No => self.yes_tokens > 0,
+        }
+    }
+
     /// Calculate unrealized P&L based on current market prices
     pub fn unrealized_pnl(&self, market: &AdmissionMarket) -> i64 {
         let yes_value = self.yes_tokens as u128 * market.yes_price_bps() as u128 / 10000;
@@ -267,6 +767,44 @@ impl MarketPosition {
     }
 }
 
+// =============================================================================
+// LIQUIDITY POSITION ACCOUNT
+// =============================================================================
+// A liquidity provider's matched deposit in a market and the fees it has
+// earned. Fees are only paid once the market is finalized; an expired market
+// refunds every stake in full, fees included, so its LPs get their deposit
+// back but no fees.
+// =============================================================================
+
+#[account]
+pub struct LiquidityPosition {
+    /// The market this liquidity is in
+    pub market: Pubkey,
+    /// The liquidity provider's wallet
+    pub provider: Pubkey,
+    /// Liquidity deposited and not yet removed
+    pub deposited: u64,
+    /// `lp_fee_per_share` when fees were last settled into `fees_owed`
+    pub fee_checkpoint: u128,
+    /// Fees earned and not yet paid
+    pub fees_owed: u64,
+    /// Fees paid out so far
+    pub fees_paid: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LiquidityPosition {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 16 + 8 + 8 + 1;
+
+    /// Move the fees earned since the last checkpoint into `fees_owed`
+    pub fn settle_fees(&mut self, lp_fee_per_share: u128) {
+        let earned = self.deposited as u128 * lp_fee_per_share.saturating_sub(self.fee_checkpoint) / LP_FEE_SCALE;
+        self.fees_owed = self.fees_owed.saturating_add(earned as u64);
+        self.fee_checkpoint = lp_fee_per_share;
+    }
+}
+
 // =============================================================================
 // MARKET FACTORY ACCOUNT
 // =============================================================================
@@ -283,12 +821,30 @@ pub struct MarketFactory {
     pub default_fee_bps: u16,
     /// Default burn percentage (basis points)
     pub default_burn_bps: u16,
+    /// Default share of a resolved pot sent to the protocol (basis points)
+    pub default_protocol_fee_bps: u16,
     /// Minimum initial liquidity required
     pub min_initial_liquidity: u64,
+    /// Minimum directional stake the market creator must take at creation
+    pub min_creator_stake: u64,
+    /// Smallest either AMM reserve may be drawn down to by a trade
+    pub min_pool_floor: u64,
     /// Default expiry period (seconds from creation)
     pub default_expiry_period: i64,
     /// Creator bonus for correct prediction (basis points)
     pub creator_bonus_bps: u16,
+    /// Share of trading fees rebated to market creators for seeding liquidity
+    /// (basis points)
+    pub seeder_fee_share_bps: u16,
+    /// Extra burn on the predicted creator's own winning payout (basis points)
+    pub self_position_burn_bps: u16,
+    /// Minimum DAO prestige tier required to open a market on it (0 = any DAO)
+    pub min_dao_prestige: u8,
+    /// Largest share of a market's pot a single position may stake (basis
+    /// points, 0 = uncapped)
+    pub max_position_bps: u16,
+    /// Seconds a resolved outcome stays disputable before claims open
+    pub challenge_period: i64,
     /// Total markets created
     pub total_markets: u64,
     /// Total volume across all markets
@@ -300,7 +856,20 @@ pub struct MarketFactory {
 }
 
 impl MarketFactory {
-    pub const SIZE: usize = 8 + 32 + 8 + 2 + 2 + 8 + 8 + 2 + 8 + 8 + 8 + 1;
+    pub const SIZE: usize = 8 + 32 + 8 + 2 + 2 + 2 + 8 + 8 + 8 + 8 + 2 + 2 + 2 + 1 + 2 + 8 + 8 + 8 + 8 + 1;
+
+    /// Count a newly created market seeded with `initial_liquidity`
+    ///
+    /// Returns `None` (leaving the factory untouched) if a counter would overflow.
+    pub fn record_market(&mut self, initial_liquidity: u64) -> Option<()> {
+        let market_count = self.market_count.checked_add(1)?;
+        let total_markets = self.total_markets.checked_add(1)?;
+        let total_volume = self.total_volume.checked_add(initial_liquidity)?;
+        self.market_count = market_count;
+        self.total_markets = total_markets;
+        self.total_volume = total_volume;
+        Some(())
+    }
 }
 
 // =============================================================================
@@ -320,7 +889,9 @@ pub struct SurfacingScore {
     pub successful_surfaces: u32,
     /// Total markets created
     pub markets_created: u32,
-    /// Surfacing accuracy (basis points)
+    /// Markets that were cancelled without ever reaching a DAO vote
+    pub markets_cancelled: u32,
+    /// Surfacing accuracy over markets that reached a vote (basis points)
     pub surfacing_accuracy_bps: u16,
     /// Total prediction profit (lamports)
     pub total_profit: i64,
@@ -333,20 +904,49 @@ pub struct SurfacingScore {
 }
 
 impl SurfacingScore {
-    pub const SIZE: usize = 8 + 32 + 4 + 4 + 2 + 8 + 2 + 8 + 1;
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 4 + 2 + 8 + 2 + 8 + 1;
+
+    /// Markets that went to a DAO vote, the only ones a scout can be judged on
+    pub fn markets_decided(&self) -> u32 {
+        self.markets_created.saturating_sub(self.markets_cancelled)
+    }
+
+    /// Credit a market whose creator's own directional position won
+    pub fn record_successful_surface(&mut self, now: i64) {
+        self.successful_surfaces = self.successful_surfaces.saturating_add(1);
+        self.refresh(now);
+    }
+
+    /// Take a cancelled market out of the scout's accuracy denominator
+    pub fn record_cancelled_market(&mut self, now: i64) {
+        self.markets_cancelled = self.markets_cancelled.saturating_add(1);
+        self.refresh(now);
+    }
+
+    fn refresh(&mut self, now: i64) {
+        let decided = self.markets_decided();
+        self.surfacing_accuracy_bps = if decided > 0 {
+            ((self.successful_surfaces as u64 * 10000) / decided as u64).min(10000) as u16
+        } else {
+            0
+        };
+        self.scout_score = self.calculate_scout_score();
+        self.last_updated = now;
+    }
 
     /// Calculate scout score (0-10000)
     /// Good scouts: high accuracy + high volume + profitable
     pub fn calculate_scout_score(&self) -> u16 {
-        if self.markets_created == 0 {
+        let decided = self.markets_decided();
+        if decided == 0 {
             return 0;
         }
 
         // Accuracy component (50%)
         let accuracy = self.surfacing_accuracy_bps as u32 * 50 / 100;
 
-        // Volume component (30%) - more markets = more contribution
-        let volume_tier = match self.markets_created {
+        // Volume component (30%) - more decided markets = more contribution
+        let volume_tier = match decided {
             0..=5 => 2000u32,
             6..=20 => 4000,
             21..=50 => 6000,
@@ -371,3 +971,905 @@ impl SurfacingScore {
         (accuracy + volume + profit).min(10000) as u16
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86400;
+
+    fn market(yes_pool: u64, no_pool: u64) -> AdmissionMarket {
+        AdmissionMarket {
+            market_id: 0,
+            dao: Pubkey::new_unique(),
+            content_type: ContentType::LongFormWriting,
+            style_tag: [0; 32],
+            creator_identity: Pubkey::new_unique(),
+            creator_wallet: Pubkey::new_unique(),
+            nomination: None,
+            market_creator: Pubkey::new_unique(),
+            creator_bonus_bps: 0,
+            seeder_fee_share_bps: 0,
+            yes_pool,
+            no_pool,
+            predictor_count: 0,
+            total_yes_tokens_issued: 0,
+            total_no_tokens_issued: 0,
+            total_net_stakes: 0,
+            initial_liquidity: yes_pool + no_pool,
+            fee_bps: 0,
+            accumulated_fees: 0,
+            seeder_fees_paid: 0,
+            seed_reclaimed: false,
+            lp_liquidity: 0,
+            lp_fees: 0,
+            lp_fee_per_share: 0,
+            cumulative_yes_price: 0,
+            last_price_update_ts: 0,
+            created_at: 0,
+            trading_ends_at: None,
+            expires_at: 0,
+            status: MarketStatus::Resolved,
+            outcome: MarketOutcome::Accepted,
+            resolved_by_nomination: None,
+            resolved_at: None,
+            burn_percentage_bps: 0,
+            protocol_fee_bps: 0,
+            self_position_burn_bps: 0,
+            amount_burned: 0,
+            amount_to_protocol: 0,
+            max_position_bps: 0,
+            min_pool_floor: 0,
+            challenge_period: 0,
+            bump: 0,
+        }
+    }
+
+    /// Mirror take_position for a buy, returning the tokens received
+    fn buy(m: &mut AdmissionMarket, side: PositionSide, stake: u64) -> u64 {
+        let tokens = match side {
+            PositionSide::Yes => m.calculate_yes_tokens(stake, m.fee_bps),
+            PositionSide::No => m.calculate_no_tokens(stake, m.fee_bps),
+        };
+        let net = AdmissionMarket::stake_after_fee(stake, m.fee_bps);
+        m.apply_trade(side, net, tokens).unwrap();
+        tokens
+    }
+
+    fn buy_yes(m: &mut AdmissionMarket, stake: u64) -> u64 {
+        buy(m, PositionSide::Yes, stake)
+    }
+
+    /// Mirror take_position: accumulate at `now`, then trade
+    fn buy_yes_at(m: &mut AdmissionMarket, now: i64, stake: u64) {
+        m.accumulate_price(now);
+        buy_yes(m, stake);
+    }
+
+    #[test]
+    fn a_brief_spike_barely_moves_the_twap() {
+        let mut m = market(1_000_000, 1_000_000);
+        buy_yes_at(&mut m, DAY - 60, 3_000_000);
+        assert!(m.yes_price_bps() > 9000);
+
+        // A day at 50%, then a minute at 90%+
+        let twap = m.twap_bps(DAY, DAY);
+        assert!((5000..5050).contains(&twap), "twap {}", twap);
+    }
+
+    #[test]
+    fn a_sustained_move_carries_the_twap() {
+        let mut m = market(1_000_000, 1_000_000);
+        buy_yes_at(&mut m, 0, 3_000_000);
+        let spot = m.yes_price_bps();
+
+        assert_eq!(m.twap_bps(DAY, DAY), spot);
+
+        // Half the window at 50%, half at the new price
+        let mut half = market(1_000_000, 1_000_000);
+        buy_yes_at(&mut half, DAY / 2, 3_000_000);
+        assert_eq!(half.twap_bps(DAY, DAY), (5000 + spot) / 2);
+    }
+
+    #[test]
+    fn twap_window_is_capped_by_the_market_age() {
+        let mut m = market(1_000_000, 1_000_000);
+        assert_eq!(m.twap_bps(0, DAY), 5000);
+
+        buy_yes_at(&mut m, 100, 3_000_000);
+        let spot = m.yes_price_bps() as u32;
+        // 100s at 50% and 100s at spot, however long the window asked for
+        assert_eq!(m.twap_bps(200, DAY) as u32, (5000 + spot) / 2);
+        // A window inside the time since the last trade is all spot
+        assert_eq!(m.twap_bps(200, 50) as u32, spot);
+    }
+
+    #[test]
+    fn max_stake_for_impact_balanced_pool() {
+        // 50% -> 60%: no' = sqrt(1_000_000 * 6000 / 4000) = 1224
+        let m = market(1_000, 1_000);
+        assert_eq!(m.max_stake_for_impact(PositionSide::Yes, 1000), 224);
+        assert_eq!(m.max_stake_for_impact(PositionSide::No, 1000), 224);
+    }
+
+    #[test]
+    fn max_stake_for_impact_stays_within_bound() {
+        for (yes, no, impact) in [
+            (1_000_000u64, 1_000_000u64, 500u16),
+            (3_000_000, 1_000_000, 1000),
+            (1_000_000, 4_000_000, 250),
+            (50_000_000, 20_000_000, 2000),
+        ] {
+            let mut m = market(yes, no);
+            let before = m.yes_price_bps();
+            let stake = m.max_stake_for_impact(PositionSide::Yes, impact);
+            assert!(stake > 0);
+
+            buy_yes(&mut m, stake);
+            assert!(m.yes_price_bps() - before <= impact);
+
+            // A noticeably larger stake overshoots the bound
+            let mut over = market(yes, no);
+            buy_yes(&mut over, stake + stake / 10 + 10);
+            assert!(over.yes_price_bps() - before > impact);
+        }
+    }
+
+    #[test]
+    fn trades_may_not_draw_a_pool_below_its_floor() {
+        let mut m = market(1_000_000, 1_000_000);
+        m.min_pool_floor = 100_000;
+
+        // Draining stake: the YES reserve would fall to 1e12 / 11e6 = 90_909
+        let drain = m.calculate_yes_tokens(10_000_000, 0);
+        assert!(!m.keeps_pool_floor(PositionSide::Yes, drain));
+
+        // Smaller stake: 1e12 / 5e6 = 200_000 stays above the floor
+        let within = m.calculate_yes_tokens(4_000_000, 0);
+        assert!(m.keeps_pool_floor(PositionSide::Yes, within));
+        assert!(m.keeps_pool_floor(PositionSide::No, m.calculate_no_tokens(4_000_000, 0)));
+
+        // With no floor configured a pool still can't be emptied
+        m.min_pool_floor = 0;
+        assert!(!m.keeps_pool_floor(PositionSide::Yes, m.yes_pool));
+    }
+
+    fn lp(deposited: u64) -> LiquidityPosition {
+        LiquidityPosition {
+            market: Pubkey::default(),
+            provider: Pubkey::default(),
+            deposited,
+            fee_checkpoint: 0,
+            fees_owed: 0,
+            fees_paid: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn lp_liquidity_deepens_the_reserves_without_moving_the_price_or_the_pot() {
+        let mut m = market(3_000_000, 7_000_000);
+        m.status = MarketStatus::Open;
+        let price = m.yes_price_bps();
+        let pot = m.total_pot();
+
+        m.add_lp_liquidity(10_000_000).unwrap();
+        assert_eq!((m.yes_pool, m.no_pool), (6_000_000, 14_000_000));
+        assert_eq!(m.yes_price_bps(), price);
+        assert_eq!(m.lp_liquidity, 10_000_000);
+        assert_eq!(m.total_pot(), pot);
+
+        // Deeper reserves mean less slippage for the same stake
+        let shallow = market(3_000_000, 7_000_000);
+        assert!(m.calculate_yes_tokens(1_000_000, 0) > shallow.calculate_yes_tokens(1_000_000, 0));
+
+        assert!(m.remove_lp_liquidity(10_000_000));
+        assert_eq!((m.yes_pool, m.no_pool), (3_000_000, 7_000_000));
+        assert_eq!(m.lp_liquidity, 0);
+    }
+
+    #[test]
+    fn lp_removal_while_open_respects_the_pool_floor() {
+        let mut m = market(1_000_000, 1_000_000);
+        m.status = MarketStatus::Open;
+        m.min_pool_floor = 100_000;
+        m.add_lp_liquidity(1_000_000).unwrap();
+
+        // Traders drain the YES reserve the LP helped deepen
+        buy_yes(&mut m, 20_000_000);
+        assert!(!m.remove_lp_liquidity(1_000_000));
+        assert_eq!(m.lp_liquidity, 1_000_000);
+
+        // Once trading is over the reserves no longer matter
+        m.status = MarketStatus::Resolved;
+        assert!(m.remove_lp_liquidity(1_000_000));
+        assert_eq!(m.lp_liquidity, 0);
+        assert!(!m.remove_lp_liquidity(1));
+    }
+
+    #[test]
+    fn lps_earn_fees_pro_rata_to_their_liquidity_and_time_in_the_market() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+
+        // No LPs yet: the whole fee is the market's
+        assert_eq!(m.accrue_lp_fees(1_000), Some(0));
+
+        let mut early = lp(0);
+        early.settle_fees(m.lp_fee_per_share);
+        early.deposited = 10_000_000;
+        m.add_lp_liquidity(10_000_000).unwrap();
+
+        // Equal to the seed, so LPs take half of each fee
+        assert_eq!(m.accrue_lp_fees(1_000), Some(500));
+
+        let mut late = lp(0);
+        late.settle_fees(m.lp_fee_per_share);
+        late.deposited = 10_000_000;
+        m.add_lp_liquidity(10_000_000).unwrap();
+
+        // Two thirds of the depth is now LP liquidity, split evenly
+        assert_eq!(m.accrue_lp_fees(3_000), Some(2_000));
+        assert_eq!(m.lp_fees, 2_500);
+
+        early.settle_fees(m.lp_fee_per_share);
+        late.settle_fees(m.lp_fee_per_share);
+        assert_eq!(early.fees_owed, 1_500);
+        assert_eq!(late.fees_owed, 1_000);
+        assert!(early.fees_owed + late.fees_owed <= m.lp_fees);
+
+        // Settling again without new fees earns nothing more
+        early.settle_fees(m.lp_fee_per_share);
+        assert_eq!(early.fees_owed, 1_500);
+    }
+
+    #[test]
+    fn lp_fee_overflow_leaves_the_market_untouched() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.add_lp_liquidity(10_000_000).unwrap();
+        m.lp_fees = u64::MAX;
+        let per_share = m.lp_fee_per_share;
+
+        assert_eq!(m.accrue_lp_fees(1_000), None);
+        assert_eq!((m.lp_fees, m.lp_fee_per_share), (u64::MAX, per_share));
+    }
+
+    #[test]
+    fn lp_liquidity_leaves_winner_payouts_unchanged() {
+        let pay_yes_buyer = |lp_liquidity: u64| {
+            let mut m = market(5_000_000, 5_000_000);
+            m.status = MarketStatus::Open;
+            if lp_liquidity > 0 {
+                m.add_lp_liquidity(lp_liquidity).unwrap();
+            }
+            let tokens = buy_yes(&mut m, 2_000_000);
+            buy(&mut m, PositionSide::No, 1_000_000);
+            m.status = MarketStatus::Resolved;
+            (m.calculate_payout(tokens, true), m.distributable_pot())
+        };
+
+        // A lone YES winner takes the whole pot either way, and the pot
+        // never includes the LPs' deposit
+        assert_eq!(pay_yes_buyer(0), (13_000_000, 13_000_000));
+        assert_eq!(pay_yes_buyer(50_000_000), (13_000_000, 13_000_000));
+    }
+
+    #[test]
+    fn expiry_forfeits_the_lp_fee_share() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.add_lp_liquidity(10_000_000).unwrap();
+        m.accrue_lp_fees(1_000).unwrap();
+        assert_eq!(m.lp_fees, 500);
+
+        m.expire();
+        assert_eq!(m.lp_fees, 0);
+        assert!(m.remove_lp_liquidity(10_000_000));
+    }
+
+    #[test]
+    fn max_stake_for_impact_grosses_up_fees() {
+        let mut m = market(1_000, 1_000);
+        m.fee_bps = 100;
+        // 224 net / 0.99 = 226 gross
+        assert_eq!(m.max_stake_for_impact(PositionSide::Yes, 1000), 226);
+    }
+
+    #[test]
+    fn factory_counters_refuse_to_overflow() {
+        let mut factory = MarketFactory {
+            authority: Pubkey::default(),
+            market_count: 0,
+            default_fee_bps: 0,
+            default_burn_bps: 0,
+            default_protocol_fee_bps: 0,
+            min_initial_liquidity: 0,
+            min_creator_stake: 0,
+            min_pool_floor: 0,
+            default_expiry_period: 0,
+            creator_bonus_bps: 0,
+            seeder_fee_share_bps: 0,
+            self_position_burn_bps: 0,
+            min_dao_prestige: 0,
+            max_position_bps: 0,
+            challenge_period: 0,
+            total_markets: 0,
+            total_volume: 0,
+            total_burned: 0,
+            bump: 0,
+        };
+        assert_eq!(factory.record_market(1_000), Some(()));
+        assert_eq!((factory.market_count, factory.total_markets, factory.total_volume), (1, 1, 1_000));
+
+        factory.total_volume = u64::MAX;
+        assert_eq!(factory.record_market(1), None);
+        assert_eq!((factory.market_count, factory.total_markets), (1, 1));
+
+        factory.total_volume = 0;
+        factory.market_count = u64::MAX;
+        assert_eq!(factory.record_market(1), None);
+        assert_eq!(factory.total_volume, 0);
+    }
+
+    #[test]
+    fn fee_free_marginal_price_matches_the_raw_price() {
+        for (yes, no) in [(1000, 1000), (3000, 1000), (1000, 4000)] {
+            let m = market(yes, no);
+            assert_eq!(m.yes_price_after_fee_bps(0), m.yes_price_bps());
+        }
+    }
+
+    #[test]
+    fn fees_raise_the_price_a_buyer_faces() {
+        for (yes, no) in [(1_000_000, 1_000_000), (3_000_000, 1_000_000), (1_000_000, 4_000_000)] {
+            let mut m = market(yes, no);
+            let raw = m.yes_price_bps();
+            let mut last = raw;
+            for fee_bps in [100, 500, 2000] {
+                m.fee_bps = fee_bps;
+                let marginal = m.yes_price_after_fee_bps(0);
+                assert_eq!(marginal as u32, raw as u32 * 10000 / (10000 - fee_bps as u32));
+                assert!(marginal > last);
+                last = marginal;
+            }
+        }
+
+        // Balanced pool, 1% fee: 5000 / 0.99
+        let mut m = market(1_000_000, 1_000_000);
+        m.fee_bps = 100;
+        assert_eq!(m.yes_price_after_fee_bps(0), 5050);
+    }
+
+    #[test]
+    fn larger_stakes_face_a_higher_fee_adjusted_price() {
+        let mut m = market(1_000_000, 1_000_000);
+        m.fee_bps = 100;
+        let small = m.yes_price_after_fee_bps(1_000);
+        let large = m.yes_price_after_fee_bps(500_000);
+        assert!(small >= m.yes_price_after_fee_bps(0));
+        assert!(large > small);
+        assert!(large <= 10000);
+
+        // The same stake without fees lands strictly lower
+        m.fee_bps = 0;
+        assert!(m.yes_price_after_fee_bps(500_000) < large);
+    }
+
+    #[test]
+    fn a_fee_that_takes_everything_prices_at_certainty() {
+        let mut m = market(1000, 1000);
+        m.fee_bps = 10000;
+        assert_eq!(m.yes_price_after_fee_bps(0), 10000);
+        assert_eq!(m.yes_price_after_fee_bps(500), 10000);
+    }
+
+    #[test]
+    fn max_stake_for_impact_empty_pool() {
+        let m = market(0, 1_000);
+        assert_eq!(m.max_stake_for_impact(PositionSide::Yes, 1000), 0);
+    }
+
+    #[test]
+    fn payouts_sum_to_distributable_pot() {
+        for outcome in [MarketOutcome::Accepted, MarketOutcome::Rejected] {
+            let mut m = market(5_000_000, 5_000_000);
+            m.fee_bps = 100;
+            m.burn_percentage_bps = 500;
+            m.protocol_fee_bps = 200;
+            m.outcome = outcome;
+
+            let trades = [
+                (PositionSide::Yes, 1_000_000u64),
+                (PositionSide::No, 2_500_000),
+                (PositionSide::Yes, 300_000),
+                (PositionSide::Yes, 4_000_000),
+                (PositionSide::No, 750_000),
+                (PositionSide::No, 10_000),
+            ];
+            let positions: Vec<(PositionSide, u64)> = trades
+                .iter()
+                .map(|(side, stake)| (*side, buy(&mut m, *side, *stake)))
+                .collect();
+
+            let winning_side = if outcome == MarketOutcome::Accepted {
+                PositionSide::Yes
+            } else {
+                PositionSide::No
+            };
+            let is_yes = winning_side == PositionSide::Yes;
+            let paid: u64 = positions
+                .iter()
+                .filter(|(side, _)| *side == winning_side)
+                .map(|(_, tokens)| m.calculate_payout(*tokens, is_yes))
+                .sum();
+
+            let winners = positions.iter().filter(|(side, _)| *side == winning_side).count() as u64;
+            let pot = m.distributable_pot();
+            assert!(paid <= pot);
+            assert!(pot - paid <= winners, "lost {} lamports to rounding", pot - paid);
+        }
+    }
+
+    #[test]
+    fn pot_split_adds_up_exactly() {
+        for (yes, no, burn, protocol) in [
+            (5_000_000u64, 5_000_000u64, 500u16, 200u16),
+            (1_234_567, 7_654_321, 333, 777),
+            (1, 2, 5000, 4999),
+            (999, 1, 0, 0),
+            (10_000_000, 3, 10000, 0),
+            (10_000_000, 3, 0, 10000),
+        ] {
+            let mut m = market(yes, no);
+            m.burn_percentage_bps = burn;
+            m.protocol_fee_bps = protocol;
+
+            let (winners, burned, to_protocol) = m.pot_split();
+            assert_eq!(winners + burned + to_protocol, m.total_pot());
+            assert_eq!(winners, m.distributable_pot());
+        }
+
+        // Winners absorb the rounding: 1001 lamports at 5%/2% -> 50 + 20
+        let mut m = market(1_001, 0);
+        m.burn_percentage_bps = 500;
+        m.protocol_fee_bps = 200;
+        assert_eq!(m.pot_split(), (931, 50, 20));
+    }
+
+    #[test]
+    fn only_open_markets_past_expiry_are_stale() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.expires_at = 1_000;
+
+        assert!(!m.is_stale(1_000));
+        assert!(m.is_stale(1_001));
+
+        m.lock_for_voting(Pubkey::new_unique(), 500);
+        assert!(!m.is_stale(1_001));
+    }
+
+    #[test]
+    fn expiring_cancels_the_market() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.outcome = MarketOutcome::Pending;
+
+        m.accumulated_fees = 5_000;
+
+        m.expire();
+        assert!(m.status == MarketStatus::Expired);
+        assert!(m.outcome == MarketOutcome::Cancelled);
+        assert_eq!(m.accumulated_fees, 0);
+    }
+
+    #[test]
+    fn seed_is_reclaimable_only_from_a_cancelled_market() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.outcome = MarketOutcome::Pending;
+        assert_eq!(m.reclaimable_seed(), 0);
+
+        // Resolved markets pay the seed out to winners
+        m.status = MarketStatus::Resolved;
+        m.outcome = MarketOutcome::Accepted;
+        assert_eq!(m.reclaimable_seed(), 0);
+
+        m.expire();
+        assert_eq!(m.reclaimable_seed(), 10_000_000);
+        m.seed_reclaimed = true;
+        assert_eq!(m.reclaimable_seed(), 0);
+    }
+
+    #[test]
+    fn fees_are_swept_exactly_once() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.accumulated_fees = 5_000;
+
+        // Nothing to sweep while the market is live
+        assert_eq!(m.take_withdrawable_fees(), 0);
+        assert_eq!(m.accumulated_fees, 5_000);
+
+        m.status = MarketStatus::Finalized;
+        assert_eq!(m.take_withdrawable_fees(), 5_000);
+        assert_eq!(m.take_withdrawable_fees(), 0);
+
+        // Expiry refunds the fees to traders, leaving nothing to sweep
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.accumulated_fees = 5_000;
+        m.expire();
+        assert_eq!(m.take_withdrawable_fees(), 0);
+    }
+
+    #[test]
+    fn claims_wait_out_the_challenge_period() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.challenge_period = 86400;
+        m.resolved_at = Some(1_000);
+
+        assert!(m.in_challenge_period(1_000));
+        assert!(!m.claims_open(1_000 + 86399));
+        assert!(!m.in_challenge_period(1_000 + 86400));
+        assert!(m.claims_open(1_000 + 86400));
+
+        m.challenge_period = 0;
+        assert!(m.claims_open(1_000));
+
+        m.status = MarketStatus::VotingInProgress;
+        assert!(!m.claims_open(i64::MAX));
+        m.expire();
+        assert!(m.claims_open(0));
+    }
+
+    #[test]
+    fn seeder_rebate_follows_fees_and_seed_share_across_trades() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.fee_bps = 100;
+        m.seeder_fee_share_bps = 5000;
+
+        let mut fees = 0;
+        for (side, stake) in [
+            (PositionSide::Yes, 2_000_000u64),
+            (PositionSide::No, 3_000_000),
+            (PositionSide::Yes, 500_000),
+            (PositionSide::No, 4_500_000),
+        ] {
+            buy(&mut m, side, stake);
+            fees += stake - AdmissionMarket::stake_after_fee(stake, m.fee_bps);
+        }
+        m.accumulated_fees = fees;
+        assert_eq!(fees, 100_000);
+
+        // Without LPs the seed earned every fee, so the rebate is half of them
+        assert_eq!(m.seeder_fee_rebate(), 50_000);
+
+        // The protocol's pot share rides in accumulated_fees but isn't rebated
+        m.amount_to_protocol = 398_000;
+        m.accumulated_fees += m.amount_to_protocol;
+        assert_eq!(m.seeder_fee_rebate(), 50_000);
+
+        let before = m.accumulated_fees;
+        assert_eq!(m.finalize(), 50_000);
+        assert_eq!(m.seeder_fees_paid, 50_000);
+        assert_eq!(m.accumulated_fees + m.seeder_fees_paid, before);
+        assert_eq!(m.status, MarketStatus::Finalized);
+        assert!(m.fees_withdrawable());
+        assert!(m.claims_open(0));
+    }
+
+    #[test]
+    fn seeder_rebate_is_zero_without_a_share_or_fees() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.accumulated_fees = 10_000;
+        assert_eq!(m.seeder_fee_rebate(), 0);
+
+        m.seeder_fee_share_bps = 10000;
+        assert_eq!(m.seeder_fee_rebate(), 10_000);
+
+        let empty = market(0, 0);
+        assert_eq!(empty.seeder_fee_rebate(), 0);
+    }
+
+    #[test]
+    fn seeder_rebate_follows_the_liquidity_split_not_the_stakes() {
+        // Same seed and LP liquidity, same fees paid, very different stakes
+        let rebate = |stakes: &[u64]| {
+            let mut m = market(5_000_000, 5_000_000);
+            m.status = MarketStatus::Open;
+            m.seeder_fee_share_bps = 10000;
+            m.add_lp_liquidity(30_000_000).unwrap();
+            for &stake in stakes {
+                buy(&mut m, PositionSide::Yes, stake);
+            }
+            let lp_fee = m.accrue_lp_fees(40_000).unwrap();
+            m.accumulated_fees += 40_000 - lp_fee;
+            m.seeder_fee_rebate()
+        };
+
+        // The seed is 10M of 40M of liquidity, so a quarter of the fees
+        assert_eq!(rebate(&[1_000_000]), 10_000);
+        assert_eq!(rebate(&[1_000_000, 20_000_000, 50_000_000]), 10_000);
+    }
+
+    #[test]
+    fn unlocked_markets_reopen_until_they_expire() {
+        let mut m = market(5_000_000, 5_000_000);
+        m.status = MarketStatus::Open;
+        m.outcome = MarketOutcome::Pending;
+        m.expires_at = 1_000;
+        m.accumulated_fees = 50;
+        let nomination = Pubkey::new_unique();
+
+        m.lock_for_voting(nomination, 100);
+        assert!(m.unlock_after_nomination(200));
+        assert!(m.status == MarketStatus::Open);
+        assert_eq!((m.nomination, m.trading_ends_at), (None, None));
+        assert_eq!(m.accumulated_fees, 50);
+
+        // A market that ran out of time while locked is cancelled for refunds
+        m.lock_for_voting(nomination, 300);
+        assert!(!m.unlock_after_nomination(1_000));
+        assert!(m.status == MarketStatus::Expired);
+        assert!(m.outcome == MarketOutcome::Cancelled);
+        assert_eq!(m.nomination, None);
+    }
+
+    #[test]
+    fn outcome_follows_the_nominations_result() {
+        assert!(AdmissionMarket::outcome_for(true) == MarketOutcome::Accepted);
+        assert!(AdmissionMarket::outcome_for(false) == MarketOutcome::Rejected);
+    }
+
+    #[test]
+    fn fees_are_withdrawable_only_from_settled_markets() {
+        let mut m = market(5_000_000, 5_000_000);
+        for (status, withdrawable) in [
+            (MarketStatus::Open, false),
+            (MarketStatus::VotingInProgress, false),
+            (MarketStatus::Resolved, false),
+            (MarketStatus::Finalized, true),
+            (MarketStatus::Expired, true),
+        ] {
+            m.status = status;
+            assert_eq!(m.fees_withdrawable(), withdrawable);
+        }
+    }
+
+    #[test]
+    fn only_open_or_voting_markets_can_still_be_resolved() {
+        let mut m = market(5_000_000, 5_000_000);
+        for (status, unsettled) in [
+            (MarketStatus::Open, true),
+            (MarketStatus::VotingInProgress, true),
+            (MarketStatus::Resolved, false),
+            (MarketStatus::Finalized, false),
+            (MarketStatus::Expired, false),
+        ] {
+            m.status = status;
+            assert_eq!(m.is_unsettled(), unsettled);
+        }
+    }
+
+    #[test]
+    fn position_cap_is_a_share_of_the_pot() {
+        let mut m = market(5_000_000, 5_000_000);
+        assert!(!m.exceeds_position_cap(u64::MAX));
+
+        m.max_position_bps = 2500;
+        buy(&mut m, PositionSide::Yes, 2_000_000);
+        // Pot is now 12M, so the cap is 3M
+        assert!(!m.exceeds_position_cap(3_000_000));
+        assert!(m.exceeds_position_cap(3_000_001));
+    }
+
+    #[test]
+    fn stake_consumed_by_fees_buys_nothing() {
+        let mut m = market(1_000_000, 1_000_000);
+        m.fee_bps = 10000;
+        assert_eq!(m.calculate_yes_tokens(1_000, m.fee_bps), 0);
+        assert_eq!(m.calculate_no_tokens(1_000, m.fee_bps), 0);
+    }
+
+    #[test]
+    fn huge_stakes_price_without_panicking_but_cannot_be_applied() {
+        let mut m = market(1_000_000, 1_000_000);
+        m.fee_bps = 100;
+        m.min_pool_floor = 100_000;
+
+        // k / (no_pool + stake) rounds to zero, but one unit stays behind
+        let tokens = m.calculate_yes_tokens(u64::MAX, m.fee_bps);
+        assert_eq!(tokens, 999_999);
+        assert_eq!(m.calculate_no_tokens(u64::MAX, m.fee_bps), 999_999);
+        assert!(!m.keeps_pool_floor(PositionSide::Yes, tokens));
+
+        // Fee-free, the whole stake can't fit in the NO reserve
+        let net = AdmissionMarket::stake_after_fee(u64::MAX, 0);
+        let tokens = m.calculate_yes_tokens(u64::MAX, 0);
+        let before = (m.yes_pool, m.no_pool, m.total_yes_tokens_issued);
+        assert!(m.apply_trade(PositionSide::Yes, net, tokens).is_none());
+        assert_eq!((m.yes_pool, m.no_pool, m.total_yes_tokens_issued), before);
+    }
+
+    #[test]
+    fn late_buyers_are_paid_on_issued_tokens_not_reserves() {
+        let mut m = market(1_000_000, 1_000_000);
+        let early = buy_yes(&mut m, 500_000);
+        let late = buy_yes(&mut m, 500_000);
+
+        // Reserves shrank as YES was bought, but the two positions still
+        // split the whole pot between them
+        assert!(m.yes_pool < early + late);
+        let paid = m.calculate_payout(early, true) + m.calculate_payout(late, true);
+        assert!(m.distributable_pot() - paid <= 2);
+    }
+
+    #[test]
+    fn creator_self_position_pays_less_than_third_party() {
+        let mut m = market(1_000, 3_000);
+        m.self_position_burn_bps = 2_000;
+        let tokens = buy_yes(&mut m, 1_000);
+
+        let payout = m.calculate_payout(tokens, true);
+        assert!(payout > 0);
+        let third_party = Pubkey::new_unique();
+
+        assert_eq!(m.self_position_burn(&third_party, Some(Pubkey::new_unique()), payout), 0);
+
+        let creator_burn = m.self_position_burn(&m.creator_wallet, None, payout);
+        assert_eq!(creator_burn, payout / 5);
+        assert!(payout - creator_burn < payout);
+    }
+
+    #[test]
+    fn self_position_burn_follows_the_creator_identity() {
+        let mut m = market(1_000, 3_000);
+        m.self_position_burn_bps = 2_000;
+        let tokens = buy_yes(&mut m, 1_000);
+        let payout = m.calculate_payout(tokens, true);
+
+        // The creator's identity, moved to another wallet, still pays it
+        let new_wallet = Pubkey::new_unique();
+        assert_eq!(m.self_position_burn(&new_wallet, Some(m.creator_identity), payout), payout / 5);
+    }
+
+    #[test]
+    fn self_position_burn_only_applies_on_acceptance() {
+        let mut m = market(1_000, 3_000);
+        m.self_position_burn_bps = 2_000;
+        m.outcome = MarketOutcome::Rejected;
+        let tokens = buy(&mut m, PositionSide::No, 1_000);
+
+        let payout = m.calculate_payout(tokens, false);
+        assert!(payout > 0);
+        assert_eq!(m.self_position_burn(&m.creator_wallet, Some(m.creator_identity), payout), 0);
+    }
+
+    #[test]
+    fn scout_accuracy_tracks_their_winning_opening_positions() {
+        let mut score = SurfacingScore {
+            identity: Pubkey::default(),
+            successful_surfaces: 0,
+            markets_created: 4,
+            markets_cancelled: 0,
+            surfacing_accuracy_bps: 0,
+            total_profit: 0,
+            scout_score: 0,
+            last_updated: 0,
+            bump: 0,
+        };
+
+        score.record_successful_surface(100);
+        assert_eq!(score.successful_surfaces, 1);
+        assert_eq!(score.surfacing_accuracy_bps, 2_500);
+        assert_eq!(score.scout_score, score.calculate_scout_score());
+        assert_eq!(score.last_updated, 100);
+
+        score.record_successful_surface(200);
+        assert_eq!(score.surfacing_accuracy_bps, 5_000);
+    }
+
+    #[test]
+    fn scouts_opening_no_position_wins_on_rejection() {
+        // Seeded 50/50, then the scout opens NO as create_market does
+        let mut m = market(1_000, 1_000);
+        let tokens = buy(&mut m, PositionSide::No, 500);
+        assert!(m.yes_price_bps() < 5_000);
+        assert_eq!(m.total_yes_tokens_issued, 0);
+
+        m.outcome = MarketOutcome::Rejected;
+        assert!(m.calculate_payout(tokens, false) > 0);
+    }
+
+    #[test]
+    fn seeded_pools_open_at_the_requested_price() {
+        for yes_bps in [MIN_INITIAL_YES_BPS, 3000, DEFAULT_INITIAL_YES_BPS, 7000, MAX_INITIAL_YES_BPS] {
+            let (yes_pool, no_pool) = AdmissionMarket::seed_pools(10_000_000, yes_bps);
+            assert_eq!(yes_pool + no_pool, 10_000_000);
+            assert_eq!(market(yes_pool, no_pool).yes_price_bps(), yes_bps);
+        }
+
+        // The default is the old 50/50 split
+        assert_eq!(AdmissionMarket::seed_pools(10_000_000, DEFAULT_INITIAL_YES_BPS), (5_000_000, 5_000_000));
+    }
+
+    #[test]
+    fn skewed_markets_price_trades_along_the_curve() {
+        let (yes_pool, no_pool) = AdmissionMarket::seed_pools(10_000_000, 7000);
+        let mut m = market(yes_pool, no_pool);
+
+        // Buying YES pushes the price further up from its skewed start
+        buy_yes(&mut m, 1_000_000);
+        assert!(m.yes_price_bps() > 7000);
+    }
+
+    #[test]
+    fn positions_stay_on_one_side() {
+        let mut p = MarketPosition {
+            market: Pubkey::default(),
+            predictor: Pubkey::default(),
+            predictor_identity: None,
+            yes_tokens: 0,
+            no_tokens: 0,
+            total_staked: 0,
+            opened_at: 0,
+            last_modified: 0,
+            claimed: false,
+            payout: 0,
+            bump: 0,
+        };
+        // A fresh position can take either side
+        assert!(!p.holds_opposite(PositionSide::Yes));
+        assert!(!p.holds_opposite(PositionSide::No));
+
+        // Once it holds YES it can add to YES but not buy NO
+        p.yes_tokens = 1;
+        assert!(!p.holds_opposite(PositionSide::Yes));
+        assert!(p.holds_opposite(PositionSide::No));
+    }
+
+    #[test]
+    fn cancelled_markets_do_not_count_against_scouts() {
+        let mut score = SurfacingScore {
+            identity: Pubkey::default(),
+            successful_surfaces: 0,
+            markets_created: 3,
+            markets_cancelled: 0,
+            surfacing_accuracy_bps: 0,
+            total_profit: 0,
+            scout_score: 0,
+            last_updated: 0,
+            bump: 0,
+        };
+
+        // One success, one failure (nothing recorded) and one cancellation
+        score.record_successful_surface(100);
+        assert_eq!(score.surfacing_accuracy_bps, 3_333);
+        score.record_cancelled_market(200);
+        assert_eq!(score.markets_cancelled, 1);
+        assert_eq!(score.markets_decided(), 2);
+        assert_eq!(score.surfacing_accuracy_bps, 5_000);
+        assert_eq!(score.scout_score, score.calculate_scout_score());
+        assert_eq!(score.last_updated, 200);
+    }
+
+    #[test]
+    fn scouts_with_only_cancelled_markets_score_zero() {
+        let mut score = SurfacingScore {
+            identity: Pubkey::default(),
+            successful_surfaces: 0,
+            markets_created: 1,
+            markets_cancelled: 0,
+            surfacing_accuracy_bps: 0,
+            total_profit: 0,
+            scout_score: 0,
+            last_updated: 0,
+            bump: 0,
+        };
+
+        score.record_cancelled_market(100);
+        assert_eq!(score.surfacing_accuracy_bps, 0);
+        assert_eq!(score.scout_score, 0);
+    }
+}
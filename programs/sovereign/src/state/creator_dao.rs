@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::state::SovereignIdentity;
 
 // =============================================================================
 // CREATOR DAO EXTENSION FOR SOVEREIGN
@@ -34,10 +36,46 @@ pub const MAX_DAO_MEMBERS: usize = 200;
 /// Maximum pending nominations at once
 pub const MAX_PENDING_NOMINATIONS: usize = 20;
 
+/// Maximum open self-applications at once
+pub const MAX_PENDING_APPLICATIONS: usize = 20;
+
+/// Highest value returned by `CreatorDAO::prestige_tier`
+pub const MAX_PRESTIGE_TIER: u8 = 4;
+
+/// Maximum DAOs tracked in a wallet's member directory
+pub const MAX_DIRECTORY_DAOS: usize = 16;
+
+/// DAOs listed per registry page (a full page must fit in return data)
+pub const DAO_REGISTRY_PAGE_SIZE: usize = 12;
+
+/// Seconds after voting closes during which committed votes can be revealed
+pub const REVEAL_PERIOD: i64 = 86400;
+
+/// Seconds a creator can go without activity before their DAO reputation
+/// starts to decay
+pub const REPUTATION_GRACE_PERIOD: i64 = 90 * 86400;
+
+/// Daily decay of idle DAO reputation past the grace period (basis points,
+/// roughly halving it every 140 days)
+pub const REPUTATION_DECAY_BPS_PER_DAY: u16 = 50;
+
+/// Seconds an accepted nominee has to accept (or decline) their membership
+pub const MEMBERSHIP_CONSENT_WINDOW: i64 = 7 * 86400;
+
+/// Creator score deducted per committed vote left unrevealed (basis points)
+pub const UNREVEALED_VOTE_PENALTY_BPS: u16 = 50;
+
+/// Most creator score unrevealed votes can cost in total (basis points)
+pub const MAX_UNREVEALED_VOTE_PENALTY_BPS: u16 = 500;
+
+/// Members a DAO needs before it can open nominations, unless the founder
+/// picks another minimum
+pub const DEFAULT_MIN_MEMBERS_FOR_NOMINATIONS: u8 = 3;
+
 /// Content type classification
 /// Vitalik: "Be okay with having a dominant type of content (long-form writing,
 /// music, short-form video, long-form video, fiction, educational...)"
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ContentType {
     LongFormWriting,    // Essays, articles, newsletters (like Substack)
     ShortFormWriting,   // Tweets, threads, short posts
@@ -51,6 +89,77 @@ pub enum ContentType {
     Code,               // Open source, developer content
 }
 
+/// Region/culture affinity a DAO can declare
+/// Vitalik: "be okay with having a dominant style (eg. country or region of
+/// origin...)"
+///
+/// Stored on the DAO as its `u16` code to keep the account small.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Region {
+    Global,         // 0: no regional focus
+    NorthAmerica,   // 1
+    LatinAmerica,   // 2
+    Europe,         // 3
+    MiddleEast,     // 4
+    Africa,         // 5
+    SouthAsia,      // 6
+    EastAsia,       // 7
+    SoutheastAsia,  // 8
+    Oceania,        // 9
+}
+
+impl Region {
+    /// The region for a stored code, if it is a known one
+    pub fn from_code(code: u16) -> Option<Region> {
+        match code {
+            0 => Some(Region::Global),
+            1 => Some(Region::NorthAmerica),
+            2 => Some(Region::LatinAmerica),
+            3 => Some(Region::Europe),
+            4 => Some(Region::MiddleEast),
+            5 => Some(Region::Africa),
+            6 => Some(Region::SouthAsia),
+            7 => Some(Region::EastAsia),
+            8 => Some(Region::SoutheastAsia),
+            9 => Some(Region::Oceania),
+            _ => None,
+        }
+    }
+
+    /// The code stored in `CreatorDAO::region_code`
+    pub fn to_code(self) -> u16 {
+        self as u16
+    }
+}
+
+/// How much each member's vote counts toward a nomination's tallies
+/// Vitalik: "we make the assumption that good creators are also good judges
+/// of quality"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoteWeighting {
+    Equal,           // One member, one vote
+    ByTenure,        // Longer-standing members count more
+    ByCreatorScore,  // Higher creator reputation counts more
+}
+
+/// Tenure weighting adds one vote per 30 days of membership, up to this cap
+pub const MAX_TENURE_WEIGHT: u32 = 12;
+
+impl VoteWeighting {
+    /// Weight of a single vote (always at least 1)
+    pub fn weight(&self, tenure_seconds: i64, creator_score: Option<u16>) -> u32 {
+        match self {
+            VoteWeighting::Equal => 1,
+            VoteWeighting::ByTenure => {
+                let months = (tenure_seconds.max(0) / (30 * 86400)) as u32;
+                1 + months.min(MAX_TENURE_WEIGHT)
+            }
+            // 1 at score 0 up to 11 at 10000
+            VoteWeighting::ByCreatorScore => 1 + creator_score.unwrap_or(0).min(10000) as u32 / 1000,
+        }
+    }
+}
+
 /// Vote choice for member admission
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum VoteChoice {
@@ -86,7 +195,7 @@ pub struct CreatorDAO {
     pub content_type: ContentType,
     /// Style tag - opinionated niche identifier (e.g., "solana-defi", "ea-rationalist")
     pub style_tag: [u8; 32],
-    /// Region/culture affinity (optional, 0 = global)
+    /// Region/culture affinity as a `Region` code (0 = global)
     pub region_code: u16,
 
     // === Membership ===
@@ -106,18 +215,51 @@ pub struct CreatorDAO {
     pub voting_period: i64,
     /// Minimum members required to vote for quorum
     pub quorum: u8,
+    /// Distinct members who must vote accept on top of quorum and
+    /// threshold, so a low quorum can't admit on a handful of votes
+    pub min_accept_votes: u16,
+    /// How votes are weighted in nomination tallies
+    pub vote_weighting: VoteWeighting,
+    /// If set, nominees must hold an attestation from this authority
+    pub required_attestor: Option<Pubkey>,
+    /// Creator score a member needs on their identity to nominate
+    pub min_nominator_creator_score: u16,
+    /// Seconds a member must wait between their own nominations
+    pub nomination_cooldown: i64,
+    /// Lamports a nominator pays into the DAO treasury per nomination,
+    /// refunded if the nominee is accepted (0 = free)
+    pub nomination_fee: u64,
+    /// Seconds a rejected creator must wait before being nominated again
+    pub rejection_cooldown: i64,
+    /// Members the DAO needs before nominations can be opened, so a lone
+    /// founder can't run admission votes
+    pub min_members_for_nominations: u8,
 
     // === State ===
     /// Number of pending nominations
     pub pending_nominations: u8,
+    /// Number of open self-applications awaiting a sponsor
+    pub pending_applications: u8,
     /// Total creators ever admitted
     pub total_admitted: u64,
     /// Total creators ever removed
     pub total_removed: u64,
+    /// Total nominations ever opened (direct or sponsored)
+    pub total_nominations: u64,
+    /// Total nominations ever resolved as acceptances (whether or not the
+    /// nominee then consented)
+    pub total_accepted: u64,
+    /// Total nominations ever resolved as rejections
+    pub total_rejected: u64,
+    /// Sum of active members' counted creator scores (each recorded on their
+    /// membership), for the quality metric behind the admission bonus
+    pub total_member_creator_score: u64,
     /// Whether the DAO is active
     pub is_active: bool,
     /// Nonce for generating unique nomination IDs
     pub nomination_nonce: u64,
+    /// Whether the founder can still hand-pick members (closed permanently)
+    pub founder_phase_open: bool,
 
     // === Auto-split tracking ===
     // Vitalik: "If N gets above ~200, consider auto-splitting it"
@@ -145,19 +287,111 @@ impl CreatorDAO {
         1 +                          // admission_threshold
         8 +                          // voting_period
         1 +                          // quorum
+        2 +                          // min_accept_votes
+        1 +                          // vote_weighting
+        33 +                         // required_attestor (Option<Pubkey>)
+        2 +                          // min_nominator_creator_score
+        8 +                          // nomination_cooldown
+        8 +                          // nomination_fee
+        8 +                          // rejection_cooldown
+        1 +                          // min_members_for_nominations
         1 +                          // pending_nominations
+        1 +                          // pending_applications
         8 +                          // total_admitted
         8 +                          // total_removed
+        8 +                          // total_nominations
+        8 +                          // total_accepted
+        8 +                          // total_rejected
+        8 +                          // total_member_creator_score
         1 +                          // is_active
         8 +                          // nomination_nonce
+        1 +                          // founder_phase_open
         33 +                         // parent_dao (Option<Pubkey>)
         1 +                          // split_count
         1;                           // bump
 
+    /// Whether the DAO has enough members to open nominations
+    pub fn can_open_nominations(&self) -> bool {
+        self.member_count >= self.min_members_for_nominations as u16
+    }
+
     /// Check if DAO should consider splitting
     pub fn should_consider_split(&self) -> bool {
         self.member_count as usize >= MAX_DAO_MEMBERS
     }
+
+    /// Prestige tier from membership size (0 = new, 4 = elite).
+    /// Higher member count = more established = more prestige.
+    pub fn prestige_tier(&self) -> u8 {
+        match self.member_count {
+            0..=10 => 0,      // New DAO
+            11..=50 => 1,     // Growing DAO
+            51..=100 => 2,    // Established DAO
+            101..=150 => 3,   // Prestigious DAO
+            _ => 4,           // Elite DAO
+        }
+    }
+
+    /// Count a joining member's creator score towards the DAO's quality
+    ///
+    /// Returns `None` (leaving the DAO untouched) if the total would overflow.
+    pub fn add_member_score(&mut self, creator_score: u16) -> Option<()> {
+        self.total_member_creator_score = self.total_member_creator_score.checked_add(creator_score as u64)?;
+        Some(())
+    }
+
+    /// Stop counting a departing member's recorded creator score
+    pub fn remove_member_score(&mut self, creator_score: u16) {
+        self.total_member_creator_score = self.total_member_creator_score.saturating_sub(creator_score as u64);
+    }
+
+    /// Replace a member's recorded creator score with their current one
+    ///
+    /// Returns `None` (leaving the DAO untouched) if the total would overflow.
+    pub fn resync_member_score(&mut self, recorded: u16, current: u16) -> Option<()> {
+        self.total_member_creator_score = self
+            .total_member_creator_score
+            .saturating_sub(recorded as u64)
+            .checked_add(current as u64)?;
+        Some(())
+    }
+
+    /// Average creator score of the members (0 with no members)
+    pub fn average_member_creator_score(&self) -> u16 {
+        if self.member_count == 0 {
+            return 0;
+        }
+        (self.total_member_creator_score / self.member_count as u64).min(10000) as u16
+    }
+
+    /// Quality tier from the members' average creator score (0-4), so a
+    /// small DAO of strong creators outranks a large mediocre one
+    pub fn quality_tier(&self) -> u8 {
+        match self.average_member_creator_score() {
+            0..=1999 => 0,
+            2000..=3999 => 1,
+            4000..=5999 => 2,
+            6000..=7999 => 3,
+            _ => 4,
+        }
+    }
+
+    /// Share of resolved admission votes that accepted the nominee, in basis
+    /// points (0 before the first resolution). Withdrawn, expired and pending
+    /// nominations don't count. Lower means more selective.
+    pub fn acceptance_rate_bps(&self) -> u16 {
+        let resolved = self.total_accepted as u128 + self.total_rejected as u128;
+        if resolved == 0 {
+            return 0;
+        }
+        (self.total_accepted as u128 * 10000 / resolved) as u16
+    }
+
+    /// Reputation points an admission awards: 100 for the weakest DAOs up
+    /// to 500 for the strongest
+    pub fn admission_bonus(&self) -> u32 {
+        100 * (self.quality_tier() as u32 + 1)
+    }
 }
 
 // =============================================================================
@@ -175,7 +409,7 @@ pub struct DAOMembership {
     pub member_identity: Pubkey,
     /// The member's wallet
     pub member_wallet: Pubkey,
-    /// When they were admitted
+    /// When they were admitted (0 while awaiting their consent)
     pub admitted_at: i64,
     /// Who nominated them (null for founders)
     pub nominated_by: Option<Pubkey>,
@@ -183,14 +417,133 @@ pub struct DAOMembership {
     pub successful_nominations: u16,
     /// Number of votes cast
     pub votes_cast: u64,
+    /// When they last nominated someone (0 = never)
+    pub last_nominated_at: i64,
     /// Whether membership is active
     pub is_active: bool,
+    /// Whether the DAO accepted them and is waiting on their consent
+    pub pending_consent: bool,
+    /// Last moment they can accept the membership (0 if never pending)
+    pub consent_deadline: i64,
+    /// Fellow member who may cast this member's votes (None = no delegation)
+    pub delegate: Option<Pubkey>,
+    /// Nomination the delegation is limited to (None = every nomination)
+    pub delegation_scope: Option<Pubkey>,
+    /// Creator score this member contributes to the DAO's
+    /// total_member_creator_score, refreshed by sync_member_score
+    pub counted_creator_score: u16,
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl DAOMembership {
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 33 + 2 + 8 + 1 + 1;
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 33 + 2 + 8 + 8 + 1 + 1 + 8 + 33 + 33 + 2 + 1;
+
+    /// Whether the nominee can still accept the membership at `now`
+    pub fn can_accept(&self, now: i64) -> bool {
+        self.pending_consent && now <= self.consent_deadline
+    }
+
+    /// Whether `caster` may cast this member's vote on `nomination`: the
+    /// member themselves, or their delegate if the delegation covers it
+    pub fn can_cast_for(&self, caster: &Pubkey, nomination: &Pubkey) -> bool {
+        *caster == self.member_wallet
+            || (self.delegate == Some(*caster)
+                && self.delegation_scope.is_none_or(|scope| scope == *nomination))
+    }
+}
+
+// =============================================================================
+// MEMBER DIRECTORY
+// =============================================================================
+// Memberships are seeded by (dao, wallet), so they can't be listed for a
+// wallet. The directory records every DAO a wallet has been admitted to, so
+// a creator's cross-DAO standing can be read in one account.
+// =============================================================================
+
+#[account]
+pub struct MemberDirectory {
+    /// The member's wallet
+    pub wallet: Pubkey,
+    /// DAOs the wallet is an active member of (up to MAX_DIRECTORY_DAOS)
+    pub daos: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MemberDirectory {
+    pub const SIZE: usize = 8 + 32 + 4 + 32 * MAX_DIRECTORY_DAOS + 1;
+
+    /// Record membership of `dao`. Returns false if the directory is full;
+    /// admission itself is never blocked by the directory.
+    pub fn add(&mut self, dao: Pubkey) -> bool {
+        if self.daos.contains(&dao) {
+            return true;
+        }
+        if self.daos.len() >= MAX_DIRECTORY_DAOS {
+            return false;
+        }
+        self.daos.push(dao);
+        true
+    }
+
+    /// Drop `dao` when the wallet leaves it
+    pub fn remove(&mut self, dao: &Pubkey) {
+        self.daos.retain(|d| d != dao);
+    }
+}
+
+// =============================================================================
+// DAO REGISTRY
+// =============================================================================
+// DAO PDAs are seeded by founder and ID, so they can't be listed without
+// scanning program accounts. create_dao appends each new DAO to the registry
+// page for its ID, DAO_REGISTRY_PAGE_SIZE per page, so clients can page
+// through every DAO and filter by content type or style tag.
+// =============================================================================
+
+/// One DAO as listed in the registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DAORegistryEntry {
+    pub dao: Pubkey,
+    pub content_type: ContentType,
+    pub style_tag: [u8; 32],
+}
+
+impl DAORegistryEntry {
+    pub const SIZE: usize = 32 + 1 + 32;
+}
+
+// A full page fits in the 1024-byte return data limit
+const _: () = assert!(4 + DAORegistryEntry::SIZE * DAO_REGISTRY_PAGE_SIZE <= 1024);
+
+#[account]
+pub struct DAORegistryPage {
+    /// Page index (DAO IDs page * DAO_REGISTRY_PAGE_SIZE onwards)
+    pub page: u32,
+    /// DAOs on this page, in creation order
+    pub entries: Vec<DAORegistryEntry>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DAORegistryPage {
+    pub const SIZE: usize = 8 + 4 + 4 + DAORegistryEntry::SIZE * DAO_REGISTRY_PAGE_SIZE + 1;
+
+    /// Page a DAO with `dao_id` is listed on
+    pub fn page_for(dao_id: u64) -> u32 {
+        (dao_id / DAO_REGISTRY_PAGE_SIZE as u64) as u32
+    }
+
+    /// Entries matching every filter passed (None matches anything)
+    pub fn matching(&self, content_type: Option<ContentType>, style_tag: Option<[u8; 32]>) -> Vec<DAORegistryEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| content_type.is_none_or(|ct| entry.content_type == ct))
+            .filter(|entry| style_tag.is_none_or(|tag| entry.style_tag == tag))
+            .cloned()
+            .collect()
+    }
 }
 
 // =============================================================================
@@ -217,24 +570,47 @@ pub struct Nomination {
     // === Voting State ===
     /// When nomination was created
     pub created_at: i64,
-    /// When voting ends
+    /// When voting ends. Snapshotted from the DAO's voting_period at creation;
+    /// later governance changes never move an in-flight deadline.
     pub voting_ends_at: i64,
-    /// Number of accept votes
-    pub votes_accept: u16,
-    /// Number of reject votes
-    pub votes_reject: u16,
-    /// Number of abstentions
-    pub votes_abstain: u16,
+    /// Weighted accept votes
+    pub votes_accept: u32,
+    /// Weighted reject votes
+    pub votes_reject: u32,
+    /// Weighted abstentions
+    pub votes_abstain: u32,
+    /// Number of members who committed a vote (for quorum, independent of
+    /// weighting)
+    pub voter_count: u16,
+    /// Number of committed votes revealed so far
+    pub revealed_count: u16,
+    /// Number of members whose revealed vote was accept, whatever its weight
+    pub accept_voter_count: u16,
     /// Total members at time of nomination (for quorum calculation)
     pub total_members_snapshot: u16,
+    /// DAO admission threshold at time of nomination
+    pub admission_threshold_snapshot: u8,
+    /// DAO quorum at time of nomination
+    pub quorum_snapshot: u8,
+    /// DAO minimum accept votes at time of nomination
+    pub min_accept_votes_snapshot: u16,
 
     // === Resolution ===
     /// Whether voting has been resolved
     pub is_resolved: bool,
     /// Whether nominee was accepted (only valid if resolved)
     pub was_accepted: bool,
+    /// Whether the nominator withdrew the nomination before it resolved
+    pub was_withdrawn: bool,
+    /// Whether voting ended without quorum and the nomination was cancelled
+    pub timed_out: bool,
     /// When resolved
     pub resolved_at: Option<i64>,
+    /// Nomination fee the nominator paid into the DAO treasury
+    pub fee_paid: u64,
+    /// The prediction market locked to this nomination when it was opened.
+    /// Instructions that settle or read the market must then be passed it.
+    pub linked_market: Option<Pubkey>,
 
     /// PDA bump seed
     pub bump: u8,
@@ -250,57 +626,359 @@ impl Nomination {
         256 +                        // reason
         8 +                          // created_at
         8 +                          // voting_ends_at
-        2 +                          // votes_accept
-        2 +                          // votes_reject
-        2 +                          // votes_abstain
+        4 +                          // votes_accept
+        4 +                          // votes_reject
+        4 +                          // votes_abstain
+        2 +                          // voter_count
+        2 +                          // revealed_count
+        2 +                          // accept_voter_count
         2 +                          // total_members_snapshot
+        1 +                          // admission_threshold_snapshot
+        1 +                          // quorum_snapshot
+        2 +                          // min_accept_votes_snapshot
         1 +                          // is_resolved
         1 +                          // was_accepted
+        1 +                          // was_withdrawn
+        1 +                          // timed_out
         9 +                          // resolved_at (Option<i64>)
+        8 +                          // fee_paid
+        33 +                         // linked_market
         1;                           // bump
 
-    /// Check if quorum is reached
-    pub fn has_quorum(&self, quorum_threshold: u8) -> bool {
-        let total_votes = self.votes_accept + self.votes_reject + self.votes_abstain;
-        let required = (self.total_members_snapshot as u32 * quorum_threshold as u32) / 100;
-        total_votes as u32 >= required
+    /// Whether `market` is the market linked to this nomination (`None`
+    /// when no market was linked), so a linked market can't be left out
+    pub fn links_market(&self, market: Option<Pubkey>) -> bool {
+        self.linked_market == market
     }
 
-    /// Check if admission threshold is met
+    /// Fee returned to the nominator on resolution: all of it if the
+    /// nominee was accepted, none if rejected
+    pub fn fee_refund(&self) -> u64 {
+        if self.is_resolved && self.was_accepted {
+            self.fee_paid
+        } else {
+            0
+        }
+    }
+
+    /// Whether votes can still be cast or changed at `now`
+    pub fn voting_open(&self, now: i64) -> bool {
+        now <= self.voting_ends_at
+    }
+
+    /// Whether committed votes can be revealed at `now`
+    pub fn reveal_open(&self, now: i64) -> bool {
+        !self.voting_open(now) && now <= self.voting_ends_at + REVEAL_PERIOD
+    }
+
+    /// Whether the tallies are final at `now`: voting has closed and either
+    /// every committed vote was revealed or the reveal period is over
+    pub fn tally_final(&self, now: i64) -> bool {
+        !self.voting_open(now)
+            && (self.revealed_count >= self.voter_count || !self.reveal_open(now))
+    }
+
+    /// Whether the vote ran its course: the nomination resolved or timed
+    /// out rather than being withdrawn or left open
+    pub fn settled_by_vote(&self) -> bool {
+        self.is_resolved && !self.was_withdrawn
+    }
+
+    /// Add one voter's `weight` to the tally for `choice`
+    ///
+    /// Returns `None` (leaving the tallies untouched) if it would overflow.
+    pub fn add_vote(&mut self, choice: VoteChoice, weight: u32) -> Option<()> {
+        if choice == VoteChoice::Accept {
+            let accept_voters = self.accept_voter_count.checked_add(1)?;
+            self.votes_accept = self.votes_accept.checked_add(weight)?;
+            self.accept_voter_count = accept_voters;
+            return Some(());
+        }
+        let tally = match choice {
+            VoteChoice::Reject => &mut self.votes_reject,
+            _ => &mut self.votes_abstain,
+        };
+        *tally = tally.checked_add(weight)?;
+        Some(())
+    }
+
+    /// Members quorum is measured against. With the DAO's current
+    /// `member_count`, the larger of it and the snapshot is used: members
+    /// leaving (or being removed) mid-vote can't lower the bar to push a
+    /// nomination through, and members admitted since still count.
+    pub fn quorum_base(&self, current_members: Option<u16>) -> u16 {
+        current_members.map_or(self.total_members_snapshot, |current| {
+            current.max(self.total_members_snapshot)
+        })
+    }
+
+    /// Check if quorum is reached (counts committed voters, not vote weight).
+    /// A commitment that is never revealed still counts, as an abstention,
+    /// so withholding a reveal can't sink quorum.
+    pub fn has_quorum(&self, quorum_threshold: u8, current_members: Option<u16>) -> bool {
+        let required = (self.quorum_base(current_members) as u32 * quorum_threshold as u32) / 100;
+        self.voter_count as u32 >= required
+    }
+
+    /// Whether the tallies are final at `now` without reaching the snapshotted
+    /// quorum, so the nomination can never resolve and may be cancelled instead
+    pub fn can_time_out(&self, now: i64, current_members: Option<u16>) -> bool {
+        self.tally_final(now) && !self.has_quorum(self.quorum_snapshot, current_members)
+    }
+
+    /// Check if admission threshold is met by the weighted tallies
     pub fn meets_threshold(&self, threshold: u8) -> bool {
-        let total_decisive = self.votes_accept + self.votes_reject;
+        let total_decisive = self.votes_accept as u64 + self.votes_reject as u64;
         if total_decisive == 0 {
             return false;
         }
-        let accept_pct = (self.votes_accept as u32 * 100) / total_decisive as u32;
-        accept_pct >= threshold as u32
+        let accept_pct = (self.votes_accept as u64 * 100) / total_decisive;
+        accept_pct >= threshold as u64
+    }
+
+    /// Check enough distinct members accepted: counts voters, not vote
+    /// weight, so one heavily weighted member can't meet the minimum alone
+    pub fn meets_min_accept_votes(&self, min_accept_votes: u16) -> bool {
+        self.accept_voter_count >= min_accept_votes
+    }
+
+    /// Whether the tallies admit the nominee under the snapshotted
+    /// threshold and minimum accept votes (quorum is checked separately)
+    pub fn admits(&self) -> bool {
+        self.meets_threshold(self.admission_threshold_snapshot)
+            && self.meets_min_accept_votes(self.min_accept_votes_snapshot)
+    }
+}
+
+// =============================================================================
+// NOMINEE LOCK
+// =============================================================================
+// One open nomination per (DAO, nominee). Created by nominate_creator and
+// closed when the nomination resolves or is withdrawn, so concurrent
+// nominations can't split votes or waste rent.
+// =============================================================================
+
+#[account]
+pub struct NomineeLock {
+    /// The DAO the nominee is pending in
+    pub dao: Pubkey,
+    /// The nominee's wallet
+    pub nominee_wallet: Pubkey,
+    /// The open nomination holding the lock
+    pub nomination: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl NomineeLock {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 1;
+}
+
+// =============================================================================
+// REJECTION RECORD
+// =============================================================================
+// When a (DAO, nominee) pair was last rejected. Written by
+// resolve_nomination and checked when nominating, so a determined member
+// can't re-run a failed vote until it passes by attrition.
+// =============================================================================
+
+#[account]
+pub struct RejectionRecord {
+    /// The DAO that rejected the nominee
+    pub dao: Pubkey,
+    /// The rejected nominee's wallet
+    pub nominee_wallet: Pubkey,
+    /// When the latest rejection was resolved
+    pub last_rejected_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RejectionRecord {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+
+    /// Whether `cooldown` seconds have passed since the last rejection
+    pub fn cooldown_elapsed(&self, cooldown: i64, now: i64) -> bool {
+        now.saturating_sub(self.last_rejected_at) >= cooldown
     }
 }
 
 // =============================================================================
-// VOTE RECORD (for anonymity tracking without revealing vote)
+// APPLICATION
 // =============================================================================
-// Vitalik: "(anonymously) vote"
-// Note: For true anonymity, integrate with VEIL's ZK infrastructure
-// This is a simplified version that hides vote choice but not participation
+// A creator's own request to join a DAO. It carries no vote; a member has to
+// sponsor it, which turns it into a regular nomination, or reject it. Either
+// way the account is closed and its rent returned to the applicant.
+// =============================================================================
+
+#[account]
+pub struct Application {
+    /// The DAO applied to
+    pub dao: Pubkey,
+    /// The applicant's SOVEREIGN identity
+    pub applicant_identity: Pubkey,
+    /// The applicant's wallet
+    pub applicant_wallet: Pubkey,
+    /// Why the applicant fits the DAO (max 256 bytes), used as the
+    /// nomination reason when sponsored
+    pub pitch: [u8; 256],
+    /// When the application was made
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Application {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 256 + 8 + 1;
+}
+
+// =============================================================================
+// VOTE RECORD (commit-reveal)
+// =============================================================================
+// While voting is open a record holds only keccak(choice || salt), so neither
+// the record nor the nomination's tallies (which stay untouched until reveal)
+// show how anyone voted; with a random 32-byte salt the three choices can't
+// be brute-forced. After voting closes each voter reveals (choice, salt) to
+// have their weight counted.
+//
+// Votes are not anonymous: the record is seeded by the voter, so once it is
+// revealed its choice is tied to them. Counting choices apart from their
+// voters needs VEIL's ZK infrastructure and is not implemented.
 // =============================================================================
 
 #[account]
 pub struct VoteRecord {
     /// The nomination this vote is for
     pub nomination: Pubkey,
-    /// Hash of (voter_identity + nomination_id + salt) - hides who voted how
-    pub voter_hash: [u8; 32],
-    /// The actual vote (encrypted or committed for ZK version)
-    pub vote: VoteChoice,
-    /// When vote was cast
+    /// Member whose vote this is (the record is seeded by their wallet, so
+    /// they and their delegate share one vote)
+    pub voter: Pubkey,
+    /// Delegate who committed it on the voter's behalf (None if the voter did)
+    pub delegate: Option<Pubkey>,
+    /// keccak(choice || salt), with choice as its enum index
+    pub commitment: [u8; 32],
+    /// Whether the vote was revealed and added to the tallies
+    pub revealed: bool,
+    /// When the vote was last committed
     pub voted_at: i64,
+    /// Linked market's implied YES probability when the vote was cast, if a
+    /// market was passed (for later analysis of anchoring bias)
+    pub market_prob_at_vote_bps: Option<u16>,
+    /// Weight this vote added to the nomination's tallies
+    pub weight: u32,
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl VoteRecord {
-    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 1;
+    pub const SIZE: usize = 8 + 32 + 32 + 33 + 32 + 1 + 8 + 3 + 4 + 1;
+
+    /// Whoever committed the vote holds its salt, so they reveal it
+    pub fn can_reveal(&self, signer: &Pubkey) -> bool {
+        match self.delegate {
+            Some(delegate) => *signer == delegate,
+            None => *signer == self.voter,
+        }
+    }
+
+    /// The commitment a voter submits for `choice` under `salt`
+    pub fn commitment_for(choice: VoteChoice, salt: &[u8; 32]) -> [u8; 32] {
+        keccak::hashv(&[&[choice as u8], salt]).0
+    }
+
+    /// How vote events identify `voter`
+    pub fn voter_hash(voter: &Pubkey) -> [u8; 32] {
+        keccak::hash(voter.as_ref()).0
+    }
+}
+
+// =============================================================================
+// CONTENT RECORDS
+// =============================================================================
+// Pieces a creator has published, as attested by a fellow DAO member. Peer
+// upvotes hang off each record so a member can only upvote a piece once.
+// =============================================================================
+
+#[account]
+pub struct ContentRecord {
+    /// The creator's SOVEREIGN identity
+    pub creator_identity: Pubkey,
+    /// Index of this piece among the creator's content (from content_count)
+    pub index: u32,
+    /// Hash of the content's canonical URI or body
+    pub content_hash: [u8; 32],
+    /// DAO member who recorded the piece
+    pub submitted_by: Pubkey,
+    /// When it was recorded
+    pub submitted_at: i64,
+    /// Upvotes on this piece
+    pub upvotes: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ContentRecord {
+    pub const SIZE: usize = 8 + 32 + 4 + 32 + 32 + 8 + 4 + 1;
+}
+
+/// Marks that a member upvoted a content record (one per member per piece)
+#[account]
+pub struct ContentUpvote {
+    pub content: Pubkey,
+    pub voter: Pubkey,
+    pub upvoted_at: i64,
+    pub bump: u8,
+}
+
+impl ContentUpvote {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+}
+
+// =============================================================================
+// DAO TREASURY
+// =============================================================================
+// Vitalik: "a portion of their proceeds from the DAO are used to burn their
+// creator coins"
+//
+// Holds lamports paid into a DAO (membership dues, content revenue shares).
+// When proceeds are paid out for a creator, `burn_bps` of them are burned.
+// =============================================================================
+
+#[account]
+pub struct DAOTreasury {
+    /// The DAO this treasury belongs to
+    pub dao: Pubkey,
+    /// Share of each creator's proceeds that is burned (basis points)
+    pub burn_bps: u16,
+    /// Lamports ever deposited
+    pub total_deposited: u64,
+    /// Lamports ever burned
+    pub total_burned: u64,
+    /// Nomination fees held for nominations still pending, refunded if the
+    /// nominee is accepted and so not the treasury's to spend yet
+    pub reserved_fees: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DAOTreasury {
+    pub const SIZE: usize = 8 + 32 + 2 + 8 + 8 + 8 + 1;
+
+    /// Portion of `proceeds` to burn
+    pub fn burn_share(&self, proceeds: u64) -> u64 {
+        (proceeds as u128 * self.burn_bps as u128 / 10000) as u64
+    }
+
+    /// Lamports the treasury can spend out of `balance`, keeping `rent_floor`
+    /// and the reserved nomination fees
+    pub fn spendable(&self, balance: u64, rent_floor: u64) -> u64 {
+        balance.saturating_sub(rent_floor).saturating_sub(self.reserved_fees)
+    }
+
+    /// Stop holding a closed nomination's fee
+    pub fn release_fee(&mut self, fee: u64) {
+        self.reserved_fees = self.reserved_fees.saturating_sub(fee);
+    }
 }
 
 // =============================================================================
@@ -324,6 +1002,8 @@ pub struct CreatorScoreDetails {
     pub daos_accepted: u16,
     /// Weighted reputation from DAO acceptances (higher-tier DAOs = more weight)
     pub dao_reputation_points: u32,
+    /// DAO votes the creator committed but never revealed
+    pub unrevealed_votes: u16,
 
     // === Judgment Quality Metrics ===
     // Vitalik: "good creators are also good judges of quality"
@@ -358,8 +1038,11 @@ pub struct CreatorScoreDetails {
     // Vitalik: "a portion of their proceeds from the DAO are used to burn
     // their creator coins"
 
-    /// Total amount burned from DAO proceeds (in lamports)
+    /// Total amount burned from DAO proceeds (lamports, plus creator-coin
+    /// base units once `creator_mint` is set)
     pub total_burned: u64,
+    /// SPL mint of the creator's coin, registered by its mint authority
+    pub creator_mint: Option<Pubkey>,
 
     // === Timestamps ===
     pub first_dao_acceptance: Option<i64>,
@@ -374,6 +1057,7 @@ impl CreatorScoreDetails {
         32 +                         // identity
         2 +                          // daos_accepted
         4 +                          // dao_reputation_points
+        2 +                          // unrevealed_votes
         2 +                          // successful_nominations
         2 +                          // failed_nominations
         2 +                          // nomination_accuracy_bps
@@ -384,21 +1068,26 @@ impl CreatorScoreDetails {
         8 +                          // peer_upvotes
         4 +                          // content_count
         8 +                          // total_burned
+        33 +                         // creator_mint
         9 +                          // first_dao_acceptance
         8 +                          // last_updated
         1;                           // bump
 
-    /// Calculate creator score (0-10000 basis points)
+    /// Calculate creator score (0-10000 basis points) as of `now`
     ///
     /// Weighting rationale (aligned with Vitalik's hierarchy):
     /// - DAO acceptance (40%): Primary signal - accepted by quality peers
     /// - Judgment quality (25%): Good creators judge well
     /// - Prediction accuracy (20%): Market participants who predict well
     /// - Peer upvotes (15%): Content quality signal
-    pub fn calculate_score(&self) -> u16 {
+    ///
+    /// Declined admissions then cost a small flat penalty, so a creator can't
+    /// keep the DAO voting on them for free, and so do votes committed but
+    /// never revealed.
+    pub fn calculate_score(&self, now: i64) -> u16 {
         // DAO acceptance component (40%)
-        // More DAOs + higher tier DAOs = higher score
-        let dao_component = self.dao_acceptance_score() as u32 * 40 / 100;
+        // More DAOs + higher tier DAOs = higher score, eroding while idle
+        let dao_component = self.dao_acceptance_score(now) as u32 * 40 / 100;
 
         // Judgment quality component (25%)
         // Good nominators = good judges of quality
@@ -412,10 +1101,82 @@ impl CreatorScoreDetails {
         let upvote_component = self.upvote_tier() as u32 * 15 / 100;
 
         (dao_component + judgment_component + prediction_component + upvote_component)
-            .min(10000) as u16
+            .min(10000)
+            .saturating_sub(self.unrevealed_vote_penalty() as u32) as u16
+    }
+
+    /// Record settled predictions and the summed P&L of the correct ones
+    pub fn record_predictions(&mut self, correct: u32, incorrect: u32, pnl_bps: i32, now: i64) {
+        self.predictions_correct = self.predictions_correct.saturating_add(correct);
+        self.predictions_incorrect = self.predictions_incorrect.saturating_add(incorrect);
+        let total = self.predictions_correct as u64 + self.predictions_incorrect as u64;
+        self.prediction_accuracy_bps = (self.predictions_correct as u64 * 10000)
+            .checked_div(total)
+            .unwrap_or(0) as u16;
+        self.prediction_pnl_bps = self.prediction_pnl_bps.saturating_add(pnl_bps);
+        self.last_updated = now;
+    }
+
+    /// Count a committed vote the creator never revealed
+    pub fn record_unrevealed_vote(&mut self) {
+        self.unrevealed_votes = self.unrevealed_votes.saturating_add(1);
+    }
+
+    /// Score deducted for unrevealed votes (basis points, capped)
+    pub fn unrevealed_vote_penalty(&self) -> u16 {
+        (self.unrevealed_votes as u32 * UNREVEALED_VOTE_PENALTY_BPS as u32)
+            .min(MAX_UNREVEALED_VOTE_PENALTY_BPS as u32) as u16
+    }
+
+    /// Record an admission worth `reputation_points` into a DAO
+    ///
+    /// Returns `None` (leaving the details untouched) if a counter would overflow.
+    pub fn record_acceptance(&mut self, reputation_points: u32, now: i64) -> Option<()> {
+        let daos_accepted = self.daos_accepted.checked_add(1)?;
+        let dao_reputation_points = self.dao_reputation_points.checked_add(reputation_points)?;
+        self.daos_accepted = daos_accepted;
+        self.dao_reputation_points = dao_reputation_points;
+        if self.first_dao_acceptance.is_none() {
+            self.first_dao_acceptance = Some(now);
+        }
+        self.last_updated = now;
+        Some(())
     }
 
-    fn dao_acceptance_score(&self) -> u16 {
+    /// Record the outcome of a nomination this creator made
+    pub fn record_nomination(&mut self, accepted: bool) {
+        if accepted {
+            self.successful_nominations = self.successful_nominations.saturating_add(1);
+        } else {
+            self.failed_nominations = self.failed_nominations.saturating_add(1);
+        }
+        let total = self.successful_nominations as u32 + self.failed_nominations as u32;
+        self.nomination_accuracy_bps = ((self.successful_nominations as u32 * 10000) / total) as u16;
+    }
+
+    /// DAO reputation after idle decay: untouched for `REPUTATION_GRACE_PERIOD`
+    /// after `last_updated`, then decaying by `REPUTATION_DECAY_BPS_PER_DAY`.
+    /// Applied on read; the stored points are never reduced.
+    pub fn effective_reputation_points(&self, now: i64) -> u32 {
+        let idle = now.saturating_sub(self.last_updated);
+        if idle <= REPUTATION_GRACE_PERIOD {
+            return self.dao_reputation_points;
+        }
+        let days = ((idle - REPUTATION_GRACE_PERIOD) / 86400) as u64;
+        let factor = SovereignIdentity::decay_factor_bps(REPUTATION_DECAY_BPS_PER_DAY, days);
+        (self.dao_reputation_points as u64 * factor / 10000) as u32
+    }
+
+    fn dao_acceptance_score(&self, now: i64) -> u16 {
+        let tier_score = self.dao_tier_score() as u64;
+        if self.dao_reputation_points == 0 {
+            return tier_score as u16;
+        }
+        // Scaled by how much of the reputation survives idle decay
+        (tier_score * self.effective_reputation_points(now) as u64 / self.dao_reputation_points as u64) as u16
+    }
+
+    fn dao_tier_score(&self) -> u16 {
         // Logarithmic scaling: 1 DAO = 4000, 3 DAOs = 6000, 10 DAOs = 8000, 30+ = 10000
         match self.daos_accepted {
             0 => 0,
@@ -439,3 +1200,762 @@ impl CreatorScoreDetails {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86400;
+
+    #[test]
+    fn region_codes_round_trip() {
+        for code in 0..=9 {
+            let region = Region::from_code(code).unwrap();
+            assert_eq!(region.to_code(), code);
+        }
+        assert_eq!(Region::from_code(0), Some(Region::Global));
+        assert_eq!(Region::from_code(10), None);
+        assert_eq!(Region::from_code(u16::MAX), None);
+    }
+
+    fn nomination(accept: u32, reject: u32, abstain: u32, voters: u16, members: u16) -> Nomination {
+        Nomination {
+            dao: Pubkey::default(),
+            nomination_id: 0,
+            nominee_identity: Pubkey::default(),
+            nominee_wallet: Pubkey::default(),
+            nominator: Pubkey::default(),
+            reason: [0; 256],
+            created_at: 0,
+            voting_ends_at: DAY,
+            votes_accept: accept,
+            votes_reject: reject,
+            votes_abstain: abstain,
+            voter_count: voters,
+            revealed_count: voters,
+            // Unit-weight voters unless a test sets otherwise
+            accept_voter_count: accept.min(voters as u32) as u16,
+            total_members_snapshot: members,
+            admission_threshold_snapshot: 60,
+            quorum_snapshot: 50,
+            min_accept_votes_snapshot: 1,
+            is_resolved: false,
+            was_accepted: false,
+            was_withdrawn: false,
+            timed_out: false,
+            resolved_at: None,
+            fee_paid: 0,
+            linked_market: None,
+            bump: 0,
+        }
+    }
+
+    fn creator_details() -> CreatorScoreDetails {
+        CreatorScoreDetails {
+            identity: Pubkey::default(),
+            daos_accepted: 0,
+            dao_reputation_points: 0,
+            unrevealed_votes: 0,
+            successful_nominations: 0,
+            failed_nominations: 0,
+            nomination_accuracy_bps: 0,
+            prediction_pnl_bps: 0,
+            predictions_correct: 0,
+            predictions_incorrect: 0,
+            prediction_accuracy_bps: 0,
+            peer_upvotes: 0,
+            content_count: 0,
+            total_burned: 0,
+            creator_mint: None,
+            first_dao_acceptance: None,
+            last_updated: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn vote_tallies_refuse_to_overflow() {
+        let mut n = nomination(u32::MAX - 1, 0, 0, 1, 3);
+        assert_eq!(n.add_vote(VoteChoice::Accept, 1), Some(()));
+        assert_eq!(n.votes_accept, u32::MAX);
+
+        assert_eq!(n.add_vote(VoteChoice::Accept, 1), None);
+        assert_eq!((n.votes_accept, n.accept_voter_count), (u32::MAX, 2));
+        assert_eq!(n.add_vote(VoteChoice::Reject, 1), Some(()));
+    }
+
+    #[test]
+    fn acceptance_counters_refuse_to_overflow() {
+        let mut details = creator_details();
+        assert_eq!(details.record_acceptance(100, 5), Some(()));
+        assert_eq!((details.daos_accepted, details.dao_reputation_points), (1, 100));
+        assert_eq!(details.first_dao_acceptance, Some(5));
+
+        details.daos_accepted = u16::MAX;
+        assert_eq!(details.record_acceptance(100, 6), None);
+        assert_eq!((details.daos_accepted, details.dao_reputation_points), (u16::MAX, 100));
+
+        details.daos_accepted = 1;
+        details.dao_reputation_points = u32::MAX - 50;
+        assert_eq!(details.record_acceptance(100, 7), None);
+        assert_eq!(details.daos_accepted, 1);
+        assert_eq!(details.last_updated, 5);
+    }
+
+    #[test]
+    fn idle_dao_reputation_decays_after_the_grace_period() {
+        let mut details = creator_details();
+        details.record_acceptance(500, 0).unwrap();
+
+        assert_eq!(details.effective_reputation_points(REPUTATION_GRACE_PERIOD), 500);
+        let year_later = details.effective_reputation_points(REPUTATION_GRACE_PERIOD + 365 * DAY);
+        assert!(year_later < 500 / 4);
+
+        // The stored points are untouched
+        assert_eq!(details.dao_reputation_points, 500);
+    }
+
+    #[test]
+    fn stale_acceptances_score_below_fresh_activity() {
+        let now = 1_000 * DAY;
+
+        // Farmed many acceptances long ago and went quiet
+        let mut stale = creator_details();
+        for _ in 0..12 {
+            stale.record_acceptance(500, 0).unwrap();
+        }
+
+        // A single recent acceptance
+        let mut fresh = creator_details();
+        fresh.record_acceptance(500, now - DAY).unwrap();
+
+        assert!(stale.calculate_score(0) > fresh.calculate_score(now));
+        assert!(stale.calculate_score(now) < fresh.calculate_score(now));
+    }
+
+    #[test]
+    fn unrevealed_votes_lower_the_creator_score_up_to_a_cap() {
+        let mut details = creator_details();
+        details.record_acceptance(500, 0).unwrap();
+        let before = details.calculate_score(0);
+
+        details.record_unrevealed_vote();
+        assert_eq!(details.unrevealed_votes, 1);
+        assert_eq!(details.calculate_score(0), before - UNREVEALED_VOTE_PENALTY_BPS);
+
+        for _ in 0..100 {
+            details.record_unrevealed_vote();
+        }
+        assert_eq!(details.calculate_score(0), before - MAX_UNREVEALED_VOTE_PENALTY_BPS);
+    }
+
+    #[test]
+    fn batched_predictions_match_one_at_a_time() {
+        let mut one_by_one = creator_details();
+        one_by_one.record_predictions(1, 0, 2500, 10);
+        one_by_one.record_predictions(0, 1, 0, 10);
+        one_by_one.record_predictions(1, 0, 500, 10);
+
+        let mut batched = creator_details();
+        batched.record_predictions(2, 1, 3000, 10);
+
+        assert_eq!(batched.predictions_correct, 2);
+        assert_eq!(batched.predictions_incorrect, 1);
+        assert_eq!(batched.prediction_accuracy_bps, 6666);
+        assert_eq!(batched.prediction_pnl_bps, 3000);
+        assert_eq!(batched.prediction_accuracy_bps, one_by_one.prediction_accuracy_bps);
+        assert_eq!(batched.prediction_pnl_bps, one_by_one.prediction_pnl_bps);
+        assert_eq!(batched.last_updated, 10);
+    }
+
+    #[test]
+    fn unrevealed_vote_penalty_never_takes_the_score_below_zero() {
+        let mut details = creator_details();
+        details.unrevealed_votes = u16::MAX;
+        // Only the 15% upvote floor (300) is left to deduct from
+        assert_eq!(details.calculate_score(0), 0);
+    }
+
+    #[test]
+    fn directory_tracks_each_dao_once_up_to_the_cap() {
+        let mut directory = MemberDirectory {
+            wallet: Pubkey::new_unique(),
+            daos: Vec::new(),
+            bump: 0,
+        };
+        let first = Pubkey::new_unique();
+
+        assert!(directory.add(first));
+        assert!(directory.add(first));
+        assert_eq!(directory.daos, vec![first]);
+
+        for _ in 1..MAX_DIRECTORY_DAOS {
+            assert!(directory.add(Pubkey::new_unique()));
+        }
+        assert!(!directory.add(Pubkey::new_unique()));
+        assert_eq!(directory.daos.len(), MAX_DIRECTORY_DAOS);
+
+        directory.remove(&first);
+        assert!(!directory.daos.contains(&first));
+        assert!(directory.add(Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn registry_pages_filter_by_content_type_and_style() {
+        let tag = |name: &str| {
+            let mut bytes = [0u8; 32];
+            bytes[..name.len()].copy_from_slice(name.as_bytes());
+            bytes
+        };
+        let entry = |content_type, style: &str| DAORegistryEntry {
+            dao: Pubkey::new_unique(),
+            content_type,
+            style_tag: tag(style),
+        };
+        let page = DAORegistryPage {
+            page: 0,
+            entries: vec![
+                entry(ContentType::Music, "lofi"),
+                entry(ContentType::Code, "solana-defi"),
+                entry(ContentType::Music, "jazz"),
+                entry(ContentType::Music, "lofi"),
+            ],
+            bump: 0,
+        };
+
+        assert_eq!(page.matching(None, None), page.entries);
+        assert_eq!(page.matching(Some(ContentType::Music), None).len(), 3);
+        assert_eq!(page.matching(Some(ContentType::Music), Some(tag("lofi"))).len(), 2);
+        assert_eq!(page.matching(Some(ContentType::Code), Some(tag("lofi"))), vec![]);
+        assert_eq!(page.matching(None, Some(tag("solana-defi"))), vec![page.entries[1].clone()]);
+    }
+
+    #[test]
+    fn registry_pages_hold_consecutive_dao_ids() {
+        assert_eq!(DAORegistryPage::page_for(0), 0);
+        assert_eq!(DAORegistryPage::page_for(DAO_REGISTRY_PAGE_SIZE as u64 - 1), 0);
+        assert_eq!(DAORegistryPage::page_for(DAO_REGISTRY_PAGE_SIZE as u64), 1);
+    }
+
+    #[test]
+    fn treasury_keeps_pending_nomination_fees_out_of_reach() {
+        let mut treasury = DAOTreasury {
+            dao: Pubkey::default(),
+            burn_bps: 0,
+            total_deposited: 0,
+            total_burned: 0,
+            reserved_fees: 300,
+            bump: 0,
+        };
+        assert_eq!(treasury.spendable(1_000, 100), 600);
+        assert_eq!(treasury.spendable(350, 100), 0);
+
+        treasury.release_fee(300);
+        assert_eq!(treasury.spendable(1_000, 100), 900);
+        treasury.release_fee(1);
+        assert_eq!(treasury.reserved_fees, 0);
+    }
+
+    #[test]
+    fn treasury_burns_its_configured_share_of_proceeds() {
+        let treasury = DAOTreasury {
+            dao: Pubkey::default(),
+            burn_bps: 2500,
+            total_deposited: 0,
+            total_burned: 0,
+            reserved_fees: 0,
+            bump: 0,
+        };
+        assert_eq!(treasury.burn_share(400_000_000), 100_000_000);
+        assert_eq!(treasury.burn_share(3), 0);
+        assert_eq!(treasury.burn_share(u64::MAX), u64::MAX / 4);
+
+        let none = DAOTreasury { burn_bps: 0, ..treasury.clone() };
+        assert_eq!(none.burn_share(1_000), 0);
+        let all = DAOTreasury { burn_bps: 10000, ..treasury };
+        assert_eq!(all.burn_share(1_000), 1_000);
+    }
+
+    #[test]
+    fn a_linked_market_must_be_passed() {
+        let mut n = nomination(0, 0, 0, 0, 10);
+        let market = Pubkey::new_unique();
+        assert!(n.links_market(None));
+        assert!(!n.links_market(Some(market)));
+
+        n.linked_market = Some(market);
+        assert!(n.links_market(Some(market)));
+        assert!(!n.links_market(None));
+        assert!(!n.links_market(Some(Pubkey::new_unique())));
+    }
+
+    #[test]
+    fn nomination_fee_is_refunded_only_on_acceptance() {
+        let mut n = nomination(0, 0, 0, 0, 10);
+        n.fee_paid = 50_000_000;
+        assert_eq!(n.fee_refund(), 0);
+
+        n.is_resolved = true;
+        assert_eq!(n.fee_refund(), 0);
+
+        n.was_accepted = true;
+        assert_eq!(n.fee_refund(), 50_000_000);
+    }
+
+    #[test]
+    fn nomination_accuracy_tracks_the_nominators_own_record() {
+        let mut nominator = creator_details();
+
+        nominator.record_nomination(true);
+        assert_eq!(nominator.nomination_accuracy_bps, 10000);
+
+        nominator.record_nomination(false);
+        assert_eq!(nominator.successful_nominations, 1);
+        assert_eq!(nominator.failed_nominations, 1);
+        assert_eq!(nominator.nomination_accuracy_bps, 5000);
+    }
+
+    #[test]
+    fn only_ended_nominations_without_quorum_can_time_out() {
+        // 50% quorum of 4 members needs 2 voters
+        let stuck = nomination(1, 0, 0, 1, 4);
+        assert!(!stuck.can_time_out(DAY, None));
+        assert!(stuck.can_time_out(DAY + 1, None));
+
+        let quorate = nomination(1, 1, 0, 2, 4);
+        assert!(!quorate.can_time_out(DAY + 1, None));
+    }
+
+    #[test]
+    fn voting_closes_after_the_deadline() {
+        let n = nomination(0, 0, 0, 0, 3);
+        assert!(n.voting_open(0));
+        assert!(n.voting_open(DAY));
+        assert!(!n.voting_open(DAY + 1));
+    }
+
+    #[test]
+    fn unrevealed_votes_hold_the_tally_until_the_reveal_period_ends() {
+        // Two committed, one revealed
+        let mut n = nomination(1, 0, 0, 2, 4);
+        n.revealed_count = 1;
+        assert!(!n.tally_final(DAY));
+        assert!(n.reveal_open(DAY + 1));
+        assert!(!n.tally_final(DAY + 1));
+
+        // Revealing the last vote finalizes it early
+        n.revealed_count = 2;
+        assert!(n.tally_final(DAY + 1));
+
+        // Otherwise the reveal period running out does
+        n.revealed_count = 1;
+        assert!(!n.reveal_open(DAY + REVEAL_PERIOD + 1));
+        assert!(n.tally_final(DAY + REVEAL_PERIOD + 1));
+    }
+
+    #[test]
+    fn only_nominations_that_ran_their_course_are_settled_by_vote() {
+        let mut n = nomination(1, 0, 0, 2, 4);
+        assert!(!n.settled_by_vote());
+
+        n.is_resolved = true;
+        assert!(n.settled_by_vote());
+
+        n.timed_out = true;
+        assert!(n.settled_by_vote());
+
+        // Withdrawn after a vote was committed
+        n.timed_out = false;
+        n.was_withdrawn = true;
+        assert!(!n.settled_by_vote());
+    }
+
+    #[test]
+    fn withheld_reveals_still_count_toward_quorum() {
+        // Two of four committed, only one revealed
+        let mut n = nomination(1, 0, 0, 2, 4);
+        n.revealed_count = 1;
+        assert!(n.has_quorum(50, None));
+        assert!(!n.can_time_out(DAY + REVEAL_PERIOD + 1, None));
+
+        // A vote that was never committed still doesn't
+        n.voter_count = 1;
+        assert!(!n.has_quorum(50, None));
+        assert!(n.can_time_out(DAY + REVEAL_PERIOD + 1, None));
+    }
+
+    #[test]
+    fn a_commitment_hides_the_choice_without_its_salt() {
+        let salt = [7u8; 32];
+        let committed = VoteRecord::commitment_for(VoteChoice::Accept, &salt);
+
+        // Only the (choice, salt) pair that was committed matches
+        assert_eq!(committed, VoteRecord::commitment_for(VoteChoice::Accept, &salt));
+        for choice in [VoteChoice::Abstain, VoteChoice::Reject] {
+            assert_ne!(committed, VoteRecord::commitment_for(choice, &salt));
+        }
+
+        // Guessing the choice without the salt never reproduces the commitment
+        for choice in [VoteChoice::Abstain, VoteChoice::Accept, VoteChoice::Reject] {
+            assert_ne!(committed, VoteRecord::commitment_for(choice, &[0u8; 32]));
+            assert_ne!(committed, keccak::hash(&[choice as u8]).0);
+        }
+    }
+
+    #[test]
+    fn equal_weighting_counts_every_vote_once() {
+        assert_eq!(VoteWeighting::Equal.weight(0, None), 1);
+        assert_eq!(VoteWeighting::Equal.weight(365 * DAY, Some(10000)), 1);
+    }
+
+    #[test]
+    fn tenure_weighting_adds_a_vote_per_month_up_to_the_cap() {
+        assert_eq!(VoteWeighting::ByTenure.weight(0, None), 1);
+        assert_eq!(VoteWeighting::ByTenure.weight(29 * DAY, None), 1);
+        assert_eq!(VoteWeighting::ByTenure.weight(30 * DAY, None), 2);
+        assert_eq!(VoteWeighting::ByTenure.weight(10 * 365 * DAY, None), 1 + MAX_TENURE_WEIGHT);
+        // Clock skew never produces a sub-minimum weight
+        assert_eq!(VoteWeighting::ByTenure.weight(-DAY, None), 1);
+    }
+
+    #[test]
+    fn creator_score_weighting_scales_with_reputation() {
+        assert_eq!(VoteWeighting::ByCreatorScore.weight(0, None), 1);
+        assert_eq!(VoteWeighting::ByCreatorScore.weight(0, Some(999)), 1);
+        assert_eq!(VoteWeighting::ByCreatorScore.weight(0, Some(5000)), 6);
+        assert_eq!(VoteWeighting::ByCreatorScore.weight(0, Some(10000)), 11);
+    }
+
+    #[test]
+    fn threshold_uses_weighted_tallies() {
+        // Two light accepts lose to one heavy reject
+        assert!(!nomination(2, 11, 0, 3, 3).meets_threshold(60));
+        // One heavy accept beats two light rejects
+        assert!(nomination(11, 2, 0, 3, 3).meets_threshold(60));
+        assert!(!nomination(0, 0, 5, 1, 3).meets_threshold(1));
+    }
+
+    #[test]
+    fn admission_needs_min_accept_votes_beyond_quorum_and_threshold() {
+        // 2 accepts / 0 rejects from 2 of 3 members: quorum and threshold pass
+        let mut n = nomination(2, 0, 0, 2, 3);
+        assert!(n.has_quorum(50, None));
+        assert!(n.meets_threshold(60));
+
+        n.min_accept_votes_snapshot = 3;
+        assert!(!n.admits());
+
+        n.min_accept_votes_snapshot = 2;
+        assert!(n.admits());
+    }
+
+    #[test]
+    fn min_accept_votes_counts_voters_not_weight() {
+        let mut n = nomination(0, 0, 0, 1, 10);
+        n.add_vote(VoteChoice::Accept, 11).unwrap();
+        assert_eq!((n.votes_accept, n.accept_voter_count), (11, 1));
+        assert!(!n.meets_min_accept_votes(3));
+
+        n.add_vote(VoteChoice::Accept, 1).unwrap();
+        n.add_vote(VoteChoice::Reject, 1).unwrap();
+        assert!(!n.meets_min_accept_votes(3));
+        n.add_vote(VoteChoice::Accept, 1).unwrap();
+        assert!(n.meets_min_accept_votes(3));
+    }
+
+    #[test]
+    fn min_accept_votes_alone_does_not_admit() {
+        // Plenty of accepts, but rejects carry the threshold
+        let mut n = nomination(5, 10, 0, 15, 15);
+        n.min_accept_votes_snapshot = 1;
+        assert!(n.meets_min_accept_votes(5));
+        assert!(!n.admits());
+    }
+
+    #[test]
+    fn quorum_counts_members_not_weight() {
+        // A single heavy voter can't reach a 50% quorum of 10 members alone
+        assert!(!nomination(13, 0, 0, 1, 10).has_quorum(50, None));
+        assert!(nomination(5, 0, 0, 5, 10).has_quorum(50, None));
+    }
+
+    #[test]
+    fn members_leaving_mid_vote_never_lower_the_quorum() {
+        // 10 members at nomination, 4 voted, then 2 left
+        let n = nomination(4, 0, 0, 4, 10);
+
+        // A 50% quorum still needs 5 of the snapshotted 10
+        assert_eq!(n.quorum_base(Some(8)), 10);
+        assert!(!n.has_quorum(50, Some(8)));
+        assert!(n.can_time_out(DAY + 1, Some(8)));
+    }
+
+    #[test]
+    fn members_admitted_mid_vote_raise_the_quorum() {
+        let n = nomination(5, 0, 0, 5, 10);
+        assert!(n.has_quorum(50, None));
+
+        // 12 members now, so a 50% quorum needs 6
+        assert_eq!(n.quorum_base(Some(12)), 12);
+        assert!(!n.has_quorum(50, Some(12)));
+    }
+
+    #[test]
+    fn rejected_nominees_wait_out_the_rejection_cooldown() {
+        let record = RejectionRecord {
+            dao: Pubkey::default(),
+            nominee_wallet: Pubkey::default(),
+            last_rejected_at: 10 * DAY,
+            bump: 0,
+        };
+
+        // Blocked within the window, allowed once it has passed
+        assert!(!record.cooldown_elapsed(7 * DAY, 10 * DAY));
+        assert!(!record.cooldown_elapsed(7 * DAY, 17 * DAY - 1));
+        assert!(record.cooldown_elapsed(7 * DAY, 17 * DAY));
+
+        // No cooldown means immediate re-nomination
+        assert!(record.cooldown_elapsed(0, 10 * DAY));
+    }
+
+    fn dao() -> CreatorDAO {
+        CreatorDAO {
+            dao_id: 0,
+            name: [0; 32],
+            description: [0; 128],
+            content_type: ContentType::LongFormWriting,
+            style_tag: [0; 32],
+            region_code: 0,
+            member_count: 0,
+            founder: Pubkey::default(),
+            created_at: 0,
+            admission_threshold: 60,
+            voting_period: DAY,
+            quorum: 50,
+            min_accept_votes: 1,
+            vote_weighting: VoteWeighting::Equal,
+            required_attestor: None,
+            min_nominator_creator_score: 0,
+            nomination_cooldown: 0,
+            nomination_fee: 0,
+            rejection_cooldown: 0,
+            min_members_for_nominations: DEFAULT_MIN_MEMBERS_FOR_NOMINATIONS,
+            pending_nominations: 0,
+            pending_applications: 0,
+            total_admitted: 0,
+            total_removed: 0,
+            total_nominations: 0,
+            total_accepted: 0,
+            total_rejected: 0,
+            total_member_creator_score: 0,
+            is_active: true,
+            nomination_nonce: 0,
+            founder_phase_open: true,
+            parent_dao: None,
+            split_count: 0,
+            bump: 0,
+        }
+    }
+
+    impl CreatorDAO {
+        /// Add `count` members who each joined with `creator_score`
+        fn with_members(mut self, count: u16, creator_score: u16) -> Self {
+            for _ in 0..count {
+                self.add_member_score(creator_score).unwrap();
+                self.member_count += 1;
+            }
+            self
+        }
+    }
+
+    #[test]
+    fn acceptance_rate_reflects_mixed_outcomes() {
+        let mut d = dao();
+        assert_eq!(d.acceptance_rate_bps(), 0);
+
+        // Five nominations: two accepted, two rejected, one still pending
+        d.total_nominations = 5;
+        d.total_accepted = 2;
+        d.total_rejected = 2;
+        assert_eq!(d.acceptance_rate_bps(), 5000);
+
+        // The pending one is accepted
+        d.total_accepted = 3;
+        assert_eq!(d.acceptance_rate_bps(), 6000);
+
+        // A selective DAO: one acceptance in eight votes
+        d.total_nominations = 8;
+        d.total_accepted = 1;
+        d.total_rejected = 7;
+        assert_eq!(d.acceptance_rate_bps(), 1250);
+    }
+
+    #[test]
+    fn acceptance_rate_ignores_unresolved_nominations_and_consent() {
+        let mut d = dao();
+
+        // Withdrawn or timed-out nominations never reach a vote
+        d.total_nominations = 10;
+        assert_eq!(d.acceptance_rate_bps(), 0);
+        d.total_accepted = 1;
+        d.total_rejected = 1;
+        assert_eq!(d.acceptance_rate_bps(), 5000);
+
+        // A nominee declining the seat doesn't change how the vote went
+        d.total_admitted = 0;
+        assert_eq!(d.acceptance_rate_bps(), 5000);
+    }
+
+    #[test]
+    fn member_quality_outweighs_member_count() {
+        let elite = dao().with_members(8, 9_000);
+        let mediocre = dao().with_members(180, 1_000);
+
+        // By size alone the large DAO would rank far above the small one
+        assert!(mediocre.prestige_tier() > elite.prestige_tier());
+
+        assert_eq!(elite.average_member_creator_score(), 9_000);
+        assert_eq!(mediocre.average_member_creator_score(), 1_000);
+        assert_eq!(elite.admission_bonus(), 500);
+        assert_eq!(mediocre.admission_bonus(), 100);
+    }
+
+    #[test]
+    fn nominations_open_once_the_dao_has_enough_members() {
+        assert!(!dao().can_open_nominations());
+        assert!(!dao().with_members(2, 0).can_open_nominations());
+        assert!(dao().with_members(3, 0).can_open_nominations());
+
+        let mut solo = dao().with_members(1, 0);
+        solo.min_members_for_nominations = 1;
+        assert!(solo.can_open_nominations());
+    }
+
+    #[test]
+    fn quality_averages_members_as_they_join() {
+        let mut d = dao();
+        assert_eq!(d.average_member_creator_score(), 0);
+        assert_eq!(d.admission_bonus(), 100);
+
+        d = d.with_members(1, 7_000).with_members(1, 3_000);
+        assert_eq!(d.total_member_creator_score, 10_000);
+        assert_eq!(d.average_member_creator_score(), 5_000);
+        assert_eq!(d.quality_tier(), 2);
+    }
+
+    #[test]
+    fn quality_follows_members_leaving_and_resyncing() {
+        let mut d = dao().with_members(1, 7_000).with_members(1, 3_000);
+
+        d.member_count -= 1;
+        d.remove_member_score(7_000);
+        assert_eq!(d.average_member_creator_score(), 3_000);
+
+        assert_eq!(d.resync_member_score(3_000, 5_000), Some(()));
+        assert_eq!(d.average_member_creator_score(), 5_000);
+
+        // A stale record can't take the total below zero
+        d.remove_member_score(9_000);
+        assert_eq!(d.total_member_creator_score, 0);
+    }
+
+    #[test]
+    fn member_score_total_refuses_to_overflow() {
+        let mut d = dao();
+        d.total_member_creator_score = u64::MAX;
+        assert_eq!(d.add_member_score(1), None);
+        assert_eq!(d.total_member_creator_score, u64::MAX);
+        assert_eq!(d.add_member_score(0), Some(()));
+    }
+
+    #[test]
+    fn accepted_nominees_must_consent_within_the_window() {
+        let mut m = DAOMembership {
+            dao: Pubkey::default(),
+            member_identity: Pubkey::default(),
+            member_wallet: Pubkey::default(),
+            admitted_at: 0,
+            nominated_by: Some(Pubkey::default()),
+            successful_nominations: 0,
+            votes_cast: 0,
+            last_nominated_at: 0,
+            is_active: false,
+            pending_consent: true,
+            consent_deadline: 1000 + MEMBERSHIP_CONSENT_WINDOW,
+            delegate: None,
+            delegation_scope: None,
+            counted_creator_score: 0,
+            bump: 0,
+        };
+        assert!(m.can_accept(1000));
+        assert!(m.can_accept(1000 + MEMBERSHIP_CONSENT_WINDOW));
+        assert!(!m.can_accept(1001 + MEMBERSHIP_CONSENT_WINDOW));
+
+        // Once accepted (or for founder-added members) there is nothing to accept
+        m.pending_consent = false;
+        assert!(!m.can_accept(1000));
+    }
+
+    #[test]
+    fn delegates_cast_only_within_the_delegation_scope() {
+        let member = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let (scoped, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut m = DAOMembership {
+            dao: Pubkey::default(),
+            member_identity: Pubkey::default(),
+            member_wallet: member,
+            admitted_at: 0,
+            nominated_by: None,
+            successful_nominations: 0,
+            votes_cast: 0,
+            last_nominated_at: 0,
+            is_active: true,
+            pending_consent: false,
+            consent_deadline: 0,
+            delegate: None,
+            delegation_scope: None,
+            counted_creator_score: 0,
+            bump: 0,
+        };
+        assert!(m.can_cast_for(&member, &scoped));
+        assert!(!m.can_cast_for(&delegate, &scoped));
+
+        m.delegate = Some(delegate);
+        assert!(m.can_cast_for(&delegate, &scoped));
+        assert!(m.can_cast_for(&delegate, &other));
+
+        m.delegation_scope = Some(scoped);
+        assert!(m.can_cast_for(&delegate, &scoped));
+        assert!(!m.can_cast_for(&delegate, &other));
+        // Delegating never locks the member out of their own vote
+        assert!(m.can_cast_for(&member, &other));
+
+        m.delegate = None;
+        m.delegation_scope = None;
+        assert!(!m.can_cast_for(&delegate, &scoped));
+    }
+
+    #[test]
+    fn votes_are_revealed_by_whoever_committed_them() {
+        let voter = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let mut record = VoteRecord {
+            nomination: Pubkey::default(),
+            voter,
+            delegate: None,
+            commitment: [0; 32],
+            revealed: false,
+            voted_at: 0,
+            market_prob_at_vote_bps: None,
+            weight: 1,
+            bump: 0,
+        };
+        assert!(record.can_reveal(&voter));
+        assert!(!record.can_reveal(&delegate));
+
+        record.delegate = Some(delegate);
+        assert!(record.can_reveal(&delegate));
+        assert!(!record.can_reveal(&voter));
+    }
+}
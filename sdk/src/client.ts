@@ -1,7 +1,9 @@
 import { Program, AnchorProvider, BN, Idl } from '@coral-xyz/anchor';
 import { PublicKey, SystemProgram, Keypair } from '@solana/web3.js';
+import { keccak_256 } from '@noble/hashes/sha3';
 import {
   SOVEREIGN_PROGRAM_ID,
+  INCINERATOR,
   getIdentityPda,
   getTradingDetailsPda,
   getCivicDetailsPda,
@@ -9,12 +11,18 @@ import {
   getDaoCounterPda,
   getDaoPda,
   getDaoMembershipPda,
+  getMemberDirectoryPda,
+  getDaoRegistryPagePda,
   getNominationPda,
   getVoteRecordPda,
   getAdmissionMarketPda,
   getMarketPositionPda,
+  getLiquidityPositionPda,
   getMarketFactoryPda,
   getSurfacingScorePda,
+  getTierConfigPda,
+  getScoreHistoryPda,
+  getProtocolConfigPda,
 } from './pda';
 import {
   SovereignIdentity,
@@ -29,6 +37,7 @@ import {
   AdmissionMarket,
   MarketPosition,
   SurfacingScore,
+  InitAuthorities,
   CreateDAOParams,
   NominateCreatorParams,
   CreateAdmissionMarketParams,
@@ -56,6 +65,11 @@ export class SovereignClient {
     return this.program;
   }
 
+  /** `address` if an account exists there, for optional accounts */
+  private async ifExists(address: PublicKey): Promise<PublicKey | null> {
+    return (await this.provider.connection.getAccountInfo(address)) !== null ? address : null;
+  }
+
   // ============================================
   // Static PDA helpers (re-exported for convenience)
   // ============================================
@@ -70,6 +84,7 @@ export class SovereignClient {
   static getVoteRecordPda = getVoteRecordPda;
   static getAdmissionMarketPda = getAdmissionMarketPda;
   static getMarketPositionPda = getMarketPositionPda;
+  static getLiquidityPositionPda = getLiquidityPositionPda;
   static getSurfacingScorePda = getSurfacingScorePda;
   static PROGRAM_ID = SOVEREIGN_PROGRAM_ID;
 
@@ -215,9 +230,10 @@ export class SovereignClient {
    */
   async getAdmissionMarket(
     dao: PublicKey,
-    predictedCreatorIdentity: PublicKey
+    predictedCreatorIdentity: PublicKey,
+    marketId: BN | number
   ): Promise<AdmissionMarket | null> {
-    const [pda] = getAdmissionMarketPda(dao, predictedCreatorIdentity);
+    const [pda] = getAdmissionMarketPda(dao, predictedCreatorIdentity, marketId);
     try {
       return await this.requireProgram().account.admissionMarket.fetch(pda);
     } catch {
@@ -265,17 +281,29 @@ export class SovereignClient {
 
   /**
    * Create a new SOVEREIGN identity for the connected wallet
+   * @param authorities - Optional dimension authorities to delegate up front
    * @returns Transaction signature
    */
-  async createIdentity(): Promise<string> {
+  async createIdentity(authorities?: InitAuthorities): Promise<string> {
     const owner = this.provider.wallet.publicKey;
     const [identityPda] = getIdentityPda(owner);
 
     return this.requireProgram().methods
-      .createIdentity()
+      .createIdentity(
+        authorities
+          ? {
+              trading: authorities.trading ?? null,
+              civic: authorities.civic ?? null,
+              developer: authorities.developer ?? null,
+              infra: authorities.infra ?? null,
+              creator: authorities.creator ?? null,
+            }
+          : null
+      )
       .accounts({
         owner,
         identity: identityPda,
+        scoreHistory: getScoreHistoryPda(identityPda)[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -369,6 +397,40 @@ export class SovereignClient {
       .rpc();
   }
 
+  /**
+   * Reset every dimension authority back to the connected wallet, e.g.
+   * after an oracle key is compromised
+   */
+  async revokeAllAuthorities(): Promise<string> {
+    const owner = this.provider.wallet.publicKey;
+    const [identityPda] = getIdentityPda(owner);
+
+    return this.requireProgram().methods
+      .revokeAllAuthorities()
+      .accounts({
+        owner,
+        identity: identityPda,
+      })
+      .rpc();
+  }
+
+  /**
+   * Set the connected wallet's display name (up to 32 bytes) and metadata
+   * URI (up to 96 bytes); longer values are truncated on-chain
+   */
+  async setIdentityMetadata(displayName: string, metadataUri: string): Promise<string> {
+    const owner = this.provider.wallet.publicKey;
+    const [identityPda] = getIdentityPda(owner);
+
+    return this.requireProgram().methods
+      .setIdentityMetadata(displayName, metadataUri)
+      .accounts({
+        owner,
+        identity: identityPda,
+      })
+      .rpc();
+  }
+
   // ============================================
   // Authority Write Operations
   // (Called by authorized oracles/programs)
@@ -379,16 +441,18 @@ export class SovereignClient {
    * @param identityOwner - The identity owner's wallet
    * @param score - New score (0-10000)
    * @param authority - Authority keypair (optional, uses wallet if not provided)
+   * @param updateSeq - Oracle sequence number; must exceed the last one accepted (optional)
    * @returns Transaction signature
    */
   async updateTradingScore(
     identityOwner: PublicKey,
     score: number,
-    authority?: Keypair
+    authority?: Keypair,
+    updateSeq?: BN
   ): Promise<string> {
     const [identityPda] = getIdentityPda(identityOwner);
 
-    const tx = this.requireProgram().methods.updateTradingScore(score).accounts({
+    const tx = this.requireProgram().methods.updateTradingScore(score, updateSeq ?? null).accounts({
       authority: authority?.publicKey ?? this.provider.wallet.publicKey,
       identity: identityPda,
     });
@@ -404,16 +468,18 @@ export class SovereignClient {
    * @param identityOwner - The identity owner's wallet
    * @param score - New score (0-10000)
    * @param authority - Authority keypair (optional, uses wallet if not provided)
+   * @param updateSeq - Oracle sequence number; must exceed the last one accepted (optional)
    * @returns Transaction signature
    */
   async updateCivicScore(
     identityOwner: PublicKey,
     score: number,
-    authority?: Keypair
+    authority?: Keypair,
+    updateSeq?: BN
   ): Promise<string> {
     const [identityPda] = getIdentityPda(identityOwner);
 
-    const tx = this.requireProgram().methods.updateCivicScore(score).accounts({
+    const tx = this.requireProgram().methods.updateCivicScore(score, updateSeq ?? null).accounts({
       authority: authority?.publicKey ?? this.provider.wallet.publicKey,
       identity: identityPda,
     });
@@ -429,16 +495,18 @@ export class SovereignClient {
    * @param identityOwner - The identity owner's wallet
    * @param score - New score (0-10000)
    * @param authority - Authority keypair (optional, uses wallet if not provided)
+   * @param updateSeq - Oracle sequence number; must exceed the last one accepted (optional)
    * @returns Transaction signature
    */
   async updateDeveloperScore(
     identityOwner: PublicKey,
     score: number,
-    authority?: Keypair
+    authority?: Keypair,
+    updateSeq?: BN
   ): Promise<string> {
     const [identityPda] = getIdentityPda(identityOwner);
 
-    const tx = this.requireProgram().methods.updateDeveloperScore(score).accounts({
+    const tx = this.requireProgram().methods.updateDeveloperScore(score, updateSeq ?? null).accounts({
       authority: authority?.publicKey ?? this.provider.wallet.publicKey,
       identity: identityPda,
     });
@@ -454,16 +522,18 @@ export class SovereignClient {
    * @param identityOwner - The identity owner's wallet
    * @param score - New score (0-10000)
    * @param authority - Authority keypair (optional, uses wallet if not provided)
+   * @param updateSeq - Oracle sequence number; must exceed the last one accepted (optional)
    * @returns Transaction signature
    */
   async updateInfraScore(
     identityOwner: PublicKey,
     score: number,
-    authority?: Keypair
+    authority?: Keypair,
+    updateSeq?: BN
   ): Promise<string> {
     const [identityPda] = getIdentityPda(identityOwner);
 
-    const tx = this.requireProgram().methods.updateInfraScore(score).accounts({
+    const tx = this.requireProgram().methods.updateInfraScore(score, updateSeq ?? null).accounts({
       authority: authority?.publicKey ?? this.provider.wallet.publicKey,
       identity: identityPda,
     });
@@ -476,15 +546,21 @@ export class SovereignClient {
 
   /**
    * Update creator score (called by creator authority)
+   * @param identityOwner - The identity owner's wallet
+   * @param score - New score (0-10000)
+   * @param authority - Authority keypair (optional, uses wallet if not provided)
+   * @param updateSeq - Oracle sequence number; must exceed the last one accepted (optional)
+   * @returns Transaction signature
    */
   async updateCreatorScore(
     identityOwner: PublicKey,
     score: number,
-    authority?: Keypair
+    authority?: Keypair,
+    updateSeq?: BN
   ): Promise<string> {
     const [identityPda] = getIdentityPda(identityOwner);
 
-    const tx = this.requireProgram().methods.updateCreatorScore(score).accounts({
+    const tx = this.requireProgram().methods.updateCreatorScore(score, updateSeq ?? null).accounts({
       authority: authority?.publicKey ?? this.provider.wallet.publicKey,
       identity: identityPda,
     });
@@ -495,6 +571,30 @@ export class SovereignClient {
     return tx.rpc();
   }
 
+  /**
+   * Sync the creator score from the identity's creator details (called by
+   * creator authority)
+   */
+  async syncCreatorScore(identityOwner: PublicKey, authority?: Keypair): Promise<string> {
+    const [identityPda] = getIdentityPda(identityOwner);
+    const [creatorDetails] = getCreatorDetailsPda(identityPda);
+
+    const tx = this.requireProgram().methods.syncCreatorScore().accounts({
+      authority: authority?.publicKey ?? this.provider.wallet.publicKey,
+      identity: identityPda,
+      creatorDetails,
+      tierConfig: await this.ifExists(getTierConfigPda()[0]),
+      protocolConfig: await this.ifExists(getProtocolConfigPda()[0]),
+      // Identities created before score histories may not have one yet
+      scoreHistory: await this.ifExists(getScoreHistoryPda(identityPda)[0]),
+    });
+
+    if (authority) {
+      return tx.signers([authority]).rpc();
+    }
+    return tx.rpc();
+  }
+
   // ============================================
   // Creator DAO Operations
   // ============================================
@@ -510,6 +610,7 @@ export class SovereignClient {
     // Read current counter to derive DAO PDA
     const counterAccount = await this.requireProgram().account.daoCounter.fetch(daoCounter);
     const [daoPda] = getDaoPda(founder, counterAccount.count);
+    const [registryPage] = getDaoRegistryPagePda(counterAccount.count);
 
     return this.requireProgram().methods
       .createDao({
@@ -518,15 +619,20 @@ export class SovereignClient {
         contentType: { [ContentTypeToAnchor[params.contentType]]: {} },
         styleTag: params.styleTag,
         regionCode: params.regionCode,
+        strictRegion: params.strictRegion ?? true,
         admissionThreshold: params.admissionThreshold,
         votingPeriod: params.votingPeriod,
         quorum: params.quorum,
+        minAcceptVotes: params.minAcceptVotes ?? 1,
+        minMembersForNominations: params.minMembersForNominations ?? null,
       })
       .accounts({
         founder,
         founderIdentity,
         dao: daoPda,
         daoCounter,
+        registryPage,
+        protocolConfig: getProtocolConfigPda()[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -581,24 +687,69 @@ export class SovereignClient {
         nomineeIdentity,
         nomineeWallet,
         nomination,
+        protocolConfig: getProtocolConfigPda()[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
   /**
-   * Cast a vote on a nomination
-   * @param salt - 32-byte random salt for semi-anonymous voting
+   * Commitment submitted for a vote: keccak(on-chain choice index || salt)
+   */
+  static voteCommitment(vote: VoteChoice, salt: Uint8Array): number[] {
+    const choice = vote === VoteChoice.Abstain ? 0 : vote === VoteChoice.Accept ? 1 : 2;
+    return Array.from(keccak_256(new Uint8Array([choice, ...salt])));
+  }
+
+  /**
+   * Commit a vote on a nomination. Keep `salt` private: it is needed to
+   * reveal the vote after voting ends, and anyone holding it can tell how
+   * you voted.
+   * @param salt - 32-byte random salt hiding the choice until reveal
+   * @param onBehalfOf - Member who delegated their vote to the connected wallet
    */
   async castVote(
     daoPda: PublicKey,
     nominationPda: PublicKey,
     vote: VoteChoice,
-    salt: Uint8Array
+    salt: Uint8Array,
+    onBehalfOf?: PublicKey
   ): Promise<string> {
     const voter = this.provider.wallet.publicKey;
-    const [voterMembership] = getDaoMembershipPda(daoPda, voter);
-    const [voteRecord] = getVoteRecordPda(nominationPda, voter);
+    const member = onBehalfOf ?? voter;
+    const [voterMembership] = getDaoMembershipPda(daoPda, member);
+    const [voteRecord] = getVoteRecordPda(nominationPda, member);
+
+    // A nomination's linked market must be passed along
+    const nomination = await this.requireProgram().account.nomination.fetch(nominationPda);
+
+    return this.requireProgram().methods
+      .castVote(SovereignClient.voteCommitment(vote, salt))
+      .accounts({
+        voter,
+        voterMembership,
+        dao: daoPda,
+        nomination: nominationPda,
+        voteRecord,
+        predictionMarket: nomination.linkedMarket,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Reveal a committed vote once voting has ended so it counts
+   * @param salt - The salt the vote was committed with
+   * @param onBehalfOf - Member whose vote the connected wallet cast as delegate
+   */
+  async revealVote(
+    nominationPda: PublicKey,
+    vote: VoteChoice,
+    salt: Uint8Array,
+    onBehalfOf?: PublicKey
+  ): Promise<string> {
+    const voter = this.provider.wallet.publicKey;
+    const [voteRecord] = getVoteRecordPda(nominationPda, onBehalfOf ?? voter);
 
     const voteArg =
       vote === VoteChoice.Accept
@@ -608,27 +759,73 @@ export class SovereignClient {
           : { abstain: {} };
 
     return this.requireProgram().methods
-      .castVote(voteArg, Array.from(salt))
+      .revealVote(voteArg, Array.from(salt))
+      .accounts({ voter, nomination: nominationPda, voteRecord })
+      .rpc();
+  }
+
+  /**
+   * Penalize a member's vote left unrevealed past the reveal period
+   * (anyone can call this; the record's rent goes back to the member)
+   * @param voter - Wallet of the member whose vote went unrevealed
+   */
+  async penalizeUnrevealedVote(nominationPda: PublicKey, voter: PublicKey): Promise<string> {
+    const [voteRecord] = getVoteRecordPda(nominationPda, voter);
+    const [voterIdentity] = getIdentityPda(voter);
+
+    return this.requireProgram().methods
+      .penalizeUnrevealedVote()
       .accounts({
-        voter,
-        voterMembership,
-        dao: daoPda,
+        caller: this.provider.wallet.publicKey,
         nomination: nominationPda,
         voteRecord,
-        systemProgram: SystemProgram.programId,
+        voter,
+        voterIdentity,
+        voterCreatorDetails: getCreatorDetailsPda(voterIdentity)[0],
+      })
+      .rpc();
+  }
+
+  /**
+   * Let a fellow member of the DAO cast the connected wallet's votes
+   * @param nomination - Limit the delegation to this nomination (default: all)
+   */
+  async delegateVote(daoPda: PublicKey, delegate: PublicKey, nomination: PublicKey | null = null): Promise<string> {
+    const member = this.provider.wallet.publicKey;
+
+    return this.requireProgram().methods
+      .delegateVote(nomination)
+      .accounts({
+        member,
+        membership: getDaoMembershipPda(daoPda, member)[0],
+        delegateMembership: getDaoMembershipPda(daoPda, delegate)[0],
+      })
+      .rpc();
+  }
+
+  /**
+   * Take back the connected wallet's vote delegation in a DAO
+   */
+  async revokeDelegation(daoPda: PublicKey): Promise<string> {
+    const member = this.provider.wallet.publicKey;
+
+    return this.requireProgram().methods
+      .revokeDelegation()
+      .accounts({
+        member,
+        membership: getDaoMembershipPda(daoPda, member)[0],
       })
       .rpc();
   }
 
   /**
-   * Resolve a nomination after voting period ends
-   * @param predictionMarket - Optional admission market to resolve alongside
+   * Resolve a nomination after voting period ends, along with the admission
+   * market linked to it (if any)
    */
   async resolveNomination(
     daoPda: PublicKey,
     nominationPda: PublicKey,
-    nomineeWallet: PublicKey,
-    predictionMarket?: PublicKey
+    nomineeWallet: PublicKey
   ): Promise<string> {
     const resolver = this.provider.wallet.publicKey;
     const [nomineeIdentity] = getIdentityPda(nomineeWallet);
@@ -648,19 +845,105 @@ export class SovereignClient {
         nomineeIdentity,
         creatorScore,
         nominatorMembership,
-        predictionMarket: predictionMarket ?? null,
+        predictionMarket: nomination.linkedMarket,
         newMembership,
+        burnAddress: INCINERATOR,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
+  /**
+   * Accept a membership the DAO voted to admit the connected wallet to.
+   * Must be called before the membership's consentDeadline.
+   */
+  async acceptMembership(daoPda: PublicKey): Promise<string> {
+    const creator = this.provider.wallet.publicKey;
+    const [creatorIdentity] = getIdentityPda(creator);
+    const [creatorScore] = getCreatorDetailsPda(creatorIdentity);
+    const [membership] = getDaoMembershipPda(daoPda, creator);
+    const [memberDirectory] = getMemberDirectoryPda(creator);
+
+    return this.requireProgram().methods
+      .acceptMembership()
+      .accounts({
+        creator,
+        dao: daoPda,
+        membership,
+        creatorIdentity,
+        creatorScore,
+        memberDirectory,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Decline a membership the DAO voted to admit the connected wallet to.
+   */
+  async declineMembership(daoPda: PublicKey): Promise<string> {
+    const creator = this.provider.wallet.publicKey;
+    const [membership] = getDaoMembershipPda(daoPda, creator);
+
+    return this.requireProgram().methods
+      .declineMembership()
+      .accounts({ creator, dao: daoPda, membership })
+      .rpc();
+  }
+
+  /**
+   * Leave a DAO the connected wallet is an active member of.
+   * The founder can't leave without transferring the DAO first.
+   */
+  async leaveDao(daoPda: PublicKey): Promise<string> {
+    const member = this.provider.wallet.publicKey;
+    const [membership] = getDaoMembershipPda(daoPda, member);
+    const [memberDirectory] = getMemberDirectoryPda(member);
+
+    return this.requireProgram().methods
+      .leaveDao()
+      .accounts({ member, dao: daoPda, membership, memberDirectory })
+      .rpc();
+  }
+
+  /**
+   * Refresh the creator score a member counts towards their DAO's quality
+   */
+  async syncMemberScore(daoPda: PublicKey, memberWallet: PublicKey): Promise<string> {
+    const [membership] = getDaoMembershipPda(daoPda, memberWallet);
+    const [memberIdentity] = getIdentityPda(memberWallet);
+
+    return this.requireProgram().methods
+      .syncMemberScore()
+      .accounts({ dao: daoPda, membership, memberIdentity })
+      .rpc();
+  }
+
   // ============================================
   // Admission Market Operations
   // ============================================
 
   /**
-   * Create an admission market (prediction market on creator admission)
+   * Create the connected wallet's surfacing score (required once before
+   * its first createMarket)
+   */
+  async initSurfacingScore(): Promise<string> {
+    const scout = this.provider.wallet.publicKey;
+    const [surfacingScore] = getSurfacingScorePda(scout);
+
+    return this.requireProgram().methods
+      .initSurfacingScore()
+      .accounts({
+        scout,
+        surfacingScore,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Create an admission market (prediction market on creator admission).
+   * The scout's surfacing score must exist (see initSurfacingScore).
    */
   async createMarket(
     daoPda: PublicKey,
@@ -670,14 +953,20 @@ export class SovereignClient {
     const creator = this.provider.wallet.publicKey;
     const [creatorIdentity] = getIdentityPda(creator);
     const [predictedCreatorIdentity] = getIdentityPda(predictedCreatorWallet);
-    const [market] = getAdmissionMarketPda(daoPda, predictedCreatorIdentity);
     const [factory] = getMarketFactoryPda();
+    // The new market is seeded by the factory's running market count
+    const factoryAccount = await this.requireProgram().account.marketFactory.fetch(factory);
+    const [market] = getAdmissionMarketPda(daoPda, predictedCreatorIdentity, factoryAccount.marketCount);
     const [surfacingScore] = getSurfacingScorePda(creator);
+    const [creatorPosition] = getMarketPositionPda(market, creator);
 
     return this.requireProgram().methods
       .createMarket({
         initialLiquidity: params.initialLiquidity,
         expiryDays: params.expiryDays,
+        creatorInitialSide: params.creatorInitialSide === 0 ? { yes: {} } : { no: {} },
+        creatorInitialStake: params.creatorInitialStake,
+        initialYesBps: params.initialYesBps ?? null,
       })
       .accounts({
         creator,
@@ -686,8 +975,10 @@ export class SovereignClient {
         predictedCreatorIdentity,
         predictedCreatorWallet,
         market,
+        creatorPosition,
         factory,
         surfacingScore,
+        protocolConfig: getProtocolConfigPda()[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -717,21 +1008,73 @@ export class SovereignClient {
         predictorIdentity,
         market: marketPda,
         position,
+        protocolConfig: getProtocolConfigPda()[0],
         systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
 
+  /**
+   * Add matched YES/NO liquidity to an open market (doesn't move the price)
+   */
+  async addLiquidity(marketPda: PublicKey, amount: BN): Promise<string> {
+    const provider = this.provider.wallet.publicKey;
+    const [liquidityPosition] = getLiquidityPositionPda(marketPda, provider);
+
+    return this.requireProgram().methods
+      .addLiquidity(amount)
+      .accounts({
+        provider,
+        market: marketPda,
+        liquidityPosition,
+        protocolConfig: getProtocolConfigPda()[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Withdraw the connected wallet's liquidity from a market, plus its fee
+   * share once the market is finalized or has expired
+   */
+  async removeLiquidity(marketPda: PublicKey): Promise<string> {
+    const provider = this.provider.wallet.publicKey;
+    const [liquidityPosition] = getLiquidityPositionPda(marketPda, provider);
+
+    return this.requireProgram().methods
+      .removeLiquidity()
+      .accounts({
+        provider,
+        market: marketPda,
+        liquidityPosition,
+      })
+      .rpc();
+  }
+
+  /**
+   * Take back the seed liquidity of a cancelled market the connected wallet
+   * created
+   */
+  async reclaimSeedLiquidity(marketPda: PublicKey): Promise<string> {
+    return this.requireProgram().methods
+      .reclaimSeedLiquidity()
+      .accounts({
+        creator: this.provider.wallet.publicKey,
+        market: marketPda,
+      })
+      .rpc();
+  }
+
   /**
    * Claim winnings from a resolved admission market
    */
-  async claimWinnings(
-    marketPda: PublicKey,
-    burnTreasury: PublicKey
-  ): Promise<string> {
+  async claimWinnings(marketPda: PublicKey): Promise<string> {
     const predictor = this.provider.wallet.publicKey;
     const [position] = getMarketPositionPda(marketPda, predictor);
-    const [creatorScore] = getCreatorDetailsPda(predictor);
+
+    // The position's recorded identity decides whose creator details are updated
+    const { predictorIdentity } = await this.requireProgram().account.marketPosition.fetch(position);
+    const creatorScore = predictorIdentity ? getCreatorDetailsPda(predictorIdentity)[0] : null;
 
     // Read market to get market_creator for surfacing score
     const market = await this.requireProgram().account.admissionMarket.fetch(marketPda);
@@ -745,11 +1088,41 @@ export class SovereignClient {
         position,
         creatorScore,
         surfacingScore,
-        burnTreasury,
+        burnTreasury: INCINERATOR,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
+
+  /**
+   * Claim winnings from several resolved markets in one transaction.
+   * Positions that aren't claimable yet are skipped by the program.
+   */
+  async claimAllWinnings(marketPdas: PublicKey[]): Promise<string> {
+    const predictor = this.provider.wallet.publicKey;
+    const [identity] = getIdentityPda(predictor);
+    const [creatorScore] = getCreatorDetailsPda(identity);
+    const [surfacingScore] = getSurfacingScorePda(predictor);
+    // Positions taken with an identity must settle against its creator details
+    const tracked = (await this.provider.connection.getAccountInfo(identity)) !== null;
+    const hasSurfacingScore = (await this.provider.connection.getAccountInfo(surfacingScore)) !== null;
+
+    return this.requireProgram().methods
+      .claimAllWinnings()
+      .accounts({
+        predictor,
+        creatorScore: tracked ? creatorScore : null,
+        surfacingScore: hasSurfacingScore ? surfacingScore : null,
+        burnTreasury: INCINERATOR,
+      })
+      .remainingAccounts(
+        marketPdas.flatMap((market) => [
+          { pubkey: market, isSigner: false, isWritable: true },
+          { pubkey: getMarketPositionPda(market, predictor)[0], isSigner: false, isWritable: true },
+        ])
+      )
+      .rpc();
+  }
 }
 
 // ============================================================================
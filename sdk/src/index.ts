@@ -4,6 +4,8 @@ export { SovereignClient } from './client';
 // PDA utilities
 export {
   SOVEREIGN_PROGRAM_ID,
+  DAO_REGISTRY_PAGE_SIZE,
+  INCINERATOR,
   getIdentityPda,
   getTradingDetailsPda,
   getCivicDetailsPda,
@@ -11,12 +13,17 @@ export {
   getDaoCounterPda,
   getDaoPda,
   getDaoMembershipPda,
+  getMemberDirectoryPda,
+  getDaoRegistryPagePda,
   getNominationPda,
   getVoteRecordPda,
   getAdmissionMarketPda,
   getMarketPositionPda,
+  getLiquidityPositionPda,
   getMarketFactoryPda,
   getSurfacingScorePda,
+  getTierConfigPda,
+  getScoreHistoryPda,
 } from './pda';
 
 // Account types
@@ -33,6 +40,7 @@ export type {
   VoteRecord,
   AdmissionMarket,
   MarketPosition,
+  LiquidityPosition,
   MarketFactory,
   SurfacingScore,
   TierConfig,
@@ -40,6 +48,7 @@ export type {
 
 // Param types
 export type {
+  InitAuthorities,
   CreateDAOParams,
   NominateCreatorParams,
   CreateAdmissionMarketParams,
@@ -61,6 +70,7 @@ export {
   calculateCompositeScore,
   calculateCreatorScore,
   calculateScoutScore,
+  calculateAcceptanceRate,
   TIER_CONFIGS,
   getTierName,
   getPointsToNextTier,
@@ -8,6 +8,18 @@ export const SOVEREIGN_PROGRAM_ID = new PublicKey(
   '2UAZc1jj4QTSkgrC8U9d4a7EM9AQunxMvW5g7rX7Af9T'
 );
 
+/**
+ * DAOs listed per DAO registry page
+ */
+export const DAO_REGISTRY_PAGE_SIZE = 12;
+
+/**
+ * Solana's incinerator; lamports sent here are burned
+ */
+export const INCINERATOR = new PublicKey(
+  '1nc1nerator11111111111111111111111111111111'
+);
+
 /**
  * Derive the PDA for a user's SOVEREIGN identity
  * @param owner - The wallet address of the identity owner
@@ -93,6 +105,27 @@ export function getDaoMembershipPda(dao: PublicKey, memberWallet: PublicKey): [P
   );
 }
 
+/**
+ * Derive the PDA for a wallet's directory of DAOs
+ * @param memberWallet - The member's wallet
+ */
+export function getMemberDirectoryPda(memberWallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('member_directory'), memberWallet.toBuffer()],
+    SOVEREIGN_PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the PDA for the DAO registry page listing a DAO ID
+ * @param daoId - The DAO's ID from the DAO counter
+ */
+export function getDaoRegistryPagePda(daoId: BN | number): [PublicKey, number] {
+  const id = typeof daoId === 'number' ? new BN(daoId) : daoId;
+  const page = id.divn(DAO_REGISTRY_PAGE_SIZE).toArrayLike(Buffer, 'le', 4);
+  return PublicKey.findProgramAddressSync([Buffer.from('dao_registry'), page], SOVEREIGN_PROGRAM_ID);
+}
+
 /**
  * Derive the PDA for a nomination
  * @param dao - The DAO PDA
@@ -109,7 +142,8 @@ export function getNominationPda(dao: PublicKey, nonce: BN | number): [PublicKey
 /**
  * Derive the PDA for a vote record
  * @param nomination - The nomination PDA
- * @param voter - The voter's wallet
+ * @param voter - The wallet of the member whose vote it is (even when a
+ *   delegate casts it)
  */
 export function getVoteRecordPda(nomination: PublicKey, voter: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
@@ -126,13 +160,21 @@ export function getVoteRecordPda(nomination: PublicKey, voter: PublicKey): [Publ
  * Derive the PDA for an admission market
  * @param dao - The DAO PDA
  * @param predictedCreatorIdentity - The predicted creator's identity PDA
+ * @param marketId - The market's ID (the factory's market count at creation)
  */
 export function getAdmissionMarketPda(
   dao: PublicKey,
-  predictedCreatorIdentity: PublicKey
+  predictedCreatorIdentity: PublicKey,
+  marketId: BN | number
 ): [PublicKey, number] {
+  const id = typeof marketId === 'number' ? new BN(marketId) : marketId;
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('admission_market'), dao.toBuffer(), predictedCreatorIdentity.toBuffer()],
+    [
+      Buffer.from('admission_market'),
+      dao.toBuffer(),
+      predictedCreatorIdentity.toBuffer(),
+      id.toArrayLike(Buffer, 'le', 8),
+    ],
     SOVEREIGN_PROGRAM_ID
   );
 }
@@ -149,6 +191,18 @@ export function getMarketPositionPda(market: PublicKey, predictor: PublicKey): [
   );
 }
 
+/**
+ * Derive the PDA for a liquidity provider's position in a market
+ * @param market - The admission market PDA
+ * @param provider - The liquidity provider's wallet
+ */
+export function getLiquidityPositionPda(market: PublicKey, provider: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('liquidity_position'), market.toBuffer(), provider.toBuffer()],
+    SOVEREIGN_PROGRAM_ID
+  );
+}
+
 /**
  * Derive the PDA for the market factory (singleton)
  */
@@ -159,6 +213,38 @@ export function getMarketFactoryPda(): [PublicKey, number] {
   );
 }
 
+/**
+ * Derive the PDA for an identity's score history
+ * @param identity - The identity PDA
+ * @returns [PDA, bump]
+ */
+export function getScoreHistoryPda(identity: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('score_history'), identity.toBuffer()],
+    SOVEREIGN_PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the PDA for the tier config (singleton tier curve)
+ */
+export function getTierConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('tier_config')],
+    SOVEREIGN_PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the PDA for the protocol config (singleton pause switch)
+ */
+export function getProtocolConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('protocol_config')],
+    SOVEREIGN_PROGRAM_ID
+  );
+}
+
 /**
  * Derive the PDA for a surfacing score
  * @param creator - The talent scout's wallet
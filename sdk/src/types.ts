@@ -37,6 +37,14 @@ export interface SovereignIdentity {
   /** Tier level (1-5) */
   tier: number;
 
+  /** Display name (UTF-8, zero-padded to 32 bytes) */
+  displayName: number[];
+  /** Metadata URI (UTF-8, zero-padded to 96 bytes) */
+  metadataUri: number[];
+
+  /** Identity this one was transferred to (set on the frozen tombstone) */
+  transferredTo: PublicKey | null;
+
   /** Last time any score was updated */
   lastUpdated: BN;
   /** PDA bump seed */
@@ -102,6 +110,22 @@ export enum ContentType {
   Other = 9,
 }
 
+/**
+ * Known region codes for CreatorDAO.regionCode (mirrors the program's Region)
+ */
+export enum Region {
+  Global = 0,
+  NorthAmerica = 1,
+  LatinAmerica = 2,
+  Europe = 3,
+  MiddleEast = 4,
+  Africa = 5,
+  SouthAsia = 6,
+  EastAsia = 7,
+  SoutheastAsia = 8,
+  Oceania = 9,
+}
+
 export enum VoteChoice {
   Accept = 0,
   Reject = 1,
@@ -135,6 +159,8 @@ export interface CreatorScoreDetails {
   daosAccepted: number;
   firstDaoAcceptance: BN | null;
   daoReputationPoints: number;
+  /** DAO votes committed but never revealed, each costing a little creator score */
+  unrevealedVotes: number;
   failedNominations: number;
   predictionsCorrect: number;
   predictionsIncorrect: number;
@@ -143,6 +169,7 @@ export interface CreatorScoreDetails {
   peerUpvotes: BN;
   peerDownvotes: BN;
   totalBurned: BN;
+  creatorMint: PublicKey | null;
   lastUpdated: BN;
   bump: number;
 }
@@ -164,16 +191,21 @@ export interface CreatorDAO {
   description: number[]; // Fixed 256-byte array
   contentType: ContentType;
   styleTag: number[]; // Fixed 32-byte array
-  regionCode: number[]; // Fixed 8-byte array
+  regionCode: number; // Region code (0 = global)
   admissionThreshold: number;
   votingPeriod: BN;
   quorum: number;
+  minAcceptVotes: number;
+  minMembersForNominations: number;
   memberCount: number;
   founder: PublicKey;
   createdAt: BN;
   pendingNominations: number;
   totalAdmitted: BN;
   totalRemoved: BN;
+  totalNominations: BN;
+  totalAccepted: BN;
+  totalRejected: BN;
   isActive: boolean;
   nominationNonce: BN;
   bump: number;
@@ -191,6 +223,14 @@ export interface DAOMembership {
   successfulNominations: number;
   votesCast: number;
   isActive: boolean;
+  /** Accepted by the DAO, waiting on the creator's acceptMembership */
+  pendingConsent: boolean;
+  /** Last moment the creator can accept (0 if never pending) */
+  consentDeadline: BN;
+  /** Fellow member who may cast this member's votes */
+  delegate: PublicKey | null;
+  /** Nomination the delegation is limited to (null = every nomination) */
+  delegationScope: PublicKey | null;
   bump: number;
 }
 
@@ -213,17 +253,25 @@ export interface Nomination {
   isResolved: boolean;
   wasAccepted: boolean;
   resolvedAt: BN | null;
+  feePaid: BN;
+  linkedMarket: PublicKey | null;
   bump: number;
 }
 
 /**
- * Vote record account (semi-anonymous via hash)
+ * Vote record account (commit-reveal; the choice is hidden until revealed)
  */
 export interface VoteRecord {
   nomination: PublicKey;
-  voterHash: number[]; // 32-byte keccak hash
-  vote: VoteChoice;
+  /** Member whose vote this is */
+  voter: PublicKey;
+  /** Delegate who committed it on the voter's behalf */
+  delegate: PublicKey | null;
+  commitment: number[]; // keccak(choice index || salt)
+  revealed: boolean;
   votedAt: BN;
+  marketProbAtVoteBps: number | null;
+  weight: number;
   bump: number;
 }
 
@@ -237,16 +285,30 @@ export interface VoteRecord {
 export interface AdmissionMarket {
   marketId: BN;
   dao: PublicKey;
+  contentType: ContentType; // Copied from the DAO at creation
+  styleTag: number[]; // Fixed 32-byte array, copied from the DAO
   creatorIdentity: PublicKey;
   creatorWallet: PublicKey;
   marketCreator: PublicKey;
   creatorBonusBps: number;
+  /** Share of trading fees rebated to the market creator at finalization */
+  seederFeeShareBps: number;
   yesPool: BN;
   noPool: BN;
   totalPool: BN;
   predictorCount: number;
   feeBps: number;
   accumulatedFees: BN;
+  seederFeesPaid: BN;
+  seedReclaimed: boolean;
+  /** Matched liquidity added by LPs (deepens the reserves, not part of the pot) */
+  lpLiquidity: BN;
+  /** Trading fees set aside for LPs and not yet paid */
+  lpFees: BN;
+  /** LP fees per lamport of LP liquidity, scaled by 1e12 */
+  lpFeePerShare: BN;
+  cumulativeYesPrice: BN;
+  lastPriceUpdateTs: BN;
   createdAt: BN;
   tradingEndsAt: BN | null;
   expiresAt: BN;
@@ -255,7 +317,9 @@ export interface AdmissionMarket {
   resolvedByNomination: PublicKey | null;
   resolvedAt: BN | null;
   burnPercentageBps: number;
+  protocolFeeBps: number;
   amountBurned: BN;
+  amountToProtocol: BN;
   bump: number;
 }
 
@@ -275,6 +339,21 @@ export interface MarketPosition {
   bump: number;
 }
 
+/**
+ * Liquidity provider's position in a market
+ */
+export interface LiquidityPosition {
+  market: PublicKey;
+  provider: PublicKey;
+  /** Liquidity deposited and not yet removed */
+  deposited: BN;
+  feeCheckpoint: BN;
+  /** Fees earned and not yet paid (paid once the market is finalized) */
+  feesOwed: BN;
+  feesPaid: BN;
+  bump: number;
+}
+
 /**
  * Market factory configuration
  */
@@ -283,6 +362,9 @@ export interface MarketFactory {
   totalMarkets: BN;
   totalVolume: BN;
   minInitialLiquidity: BN;
+  minCreatorStake: BN;
+  /** Smallest either AMM reserve may be drawn down to by a trade */
+  minPoolFloor: BN;
   bump: number;
 }
 
@@ -292,6 +374,8 @@ export interface MarketFactory {
 export interface SurfacingScore {
   identity: PublicKey;
   marketsCreated: number;
+  /** Markets cancelled before a DAO vote, left out of the accuracy */
+  marketsCancelled: number;
   successfulSurfaces: number;
   surfacingAccuracyBps: number;
   scoutScore: number;
@@ -303,15 +387,35 @@ export interface SurfacingScore {
 // Instruction Params
 // ============================================================================
 
+/** Dimension authorities to delegate at identity creation */
+export interface InitAuthorities {
+  /** Trading oracle (default owner) */
+  trading?: PublicKey | null;
+  /** Civic program (default owner) */
+  civic?: PublicKey | null;
+  /** Developer oracle (default owner) */
+  developer?: PublicKey | null;
+  /** Infra program (default owner) */
+  infra?: PublicKey | null;
+  /** Creator authority (default unset) */
+  creator?: PublicKey | null;
+}
+
 export interface CreateDAOParams {
   name: string;
   description: string;
   contentType: ContentType;
   styleTag: string;
-  regionCode: string;
+  regionCode: Region | number;
+  /** Reject codes that aren't a known Region (default true) */
+  strictRegion?: boolean;
   admissionThreshold: number;
   votingPeriod: BN;
   quorum: number;
+  /** Distinct accept voters needed to admit (default 1) */
+  minAcceptVotes?: number;
+  /** Members needed before nominations open (default 3) */
+  minMembersForNominations?: number;
 }
 
 export interface NominateCreatorParams {
@@ -321,6 +425,12 @@ export interface NominateCreatorParams {
 export interface CreateAdmissionMarketParams {
   initialLiquidity: BN;
   expiryDays: number;
+  /** Side of the scout's own opening position */
+  creatorInitialSide: PositionSide;
+  /** Scout's opening stake (at least the factory's minCreatorStake) */
+  creatorInitialStake: BN;
+  /** Starting YES price in basis points, 1000-9000 (default 5000) */
+  initialYesBps?: number;
 }
 
 export interface TakePositionParams {
@@ -403,6 +513,18 @@ export function calculateScoutScore(details: {
   return Math.floor((details.successfulSurfaces * 10000) / details.marketsCreated);
 }
 
+/**
+ * DAO acceptance rate in basis points (mirrors Rust CreatorDAO::acceptance_rate_bps)
+ */
+export function calculateAcceptanceRate(dao: {
+  totalAccepted: number;
+  totalRejected: number;
+}): number {
+  const resolved = dao.totalAccepted + dao.totalRejected;
+  if (resolved === 0) return 0;
+  return Math.floor((dao.totalAccepted * 10000) / resolved);
+}
+
 // ============================================================================
 // Tier Configuration
 // ============================================================================
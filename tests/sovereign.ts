@@ -1,7 +1,10 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
 import { PublicKey, Keypair, SystemProgram } from '@solana/web3.js';
+import { keccak_256 } from '@noble/hashes/sha3';
+import { createMint, getMint, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { expect } from 'chai';
+import * as fs from 'fs';
 
 describe('sovereign', () => {
   // Configure the client to use the local cluster
@@ -35,15 +38,430 @@ describe('sovereign', () => {
     await provider.connection.confirmTransaction(
       await provider.connection.requestAirdrop(civicProgram.publicKey, airdropAmount)
     );
+
+    // Markets, positions and nominations all check the pause switch
+    await program.methods
+      .initializeProtocolConfig()
+      .accounts({ admin: owner, protocolConfig: protocolConfigPda, systemProgram: SystemProgram.programId })
+      .rpc();
+
+    // Score recalculations read the configured tier curve when it is passed
+    await program.methods
+      .initializeTierConfig({ cutoffs: [2000, 4000, 6000, 8000], names: ['Bronze', 'Silver', 'Gold', 'Platinum', 'Diamond'] })
+      .accounts({ authority: owner, protocolConfig: protocolConfigPda, tierConfig: tierConfigPda, systemProgram: SystemProgram.programId })
+      .rpc();
   });
 
+  // Create a fresh funded wallet with its own identity (authorities default to the wallet)
+  const createFundedIdentity = async (): Promise<{ wallet: Keypair; identity: PublicKey }> => {
+    const wallet = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL)
+    );
+
+    const [identity] = PublicKey.findProgramAddressSync(
+      [Buffer.from('identity'), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createIdentity(null)
+      .accounts({
+        owner: wallet.publicKey,
+        identity,
+        scoreHistory: scoreHistoryPda(identity),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([wallet])
+      .rpc();
+
+    return { wallet, identity };
+  };
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // ------------------------------------------------------------------------
+  // Creator DAO / Admission Market fixtures
+  // ------------------------------------------------------------------------
+
+  type Member = { wallet: Keypair; identity: PublicKey };
+
+  // Solana's incinerator; lamports sent here are burned
+  const burnAddress = new PublicKey('1nc1nerator11111111111111111111111111111111');
+
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const u64Le = (value: number | anchor.BN) => new anchor.BN(value).toArrayLike(Buffer, 'le', 8);
+
+  const daoCounterPda = pda(Buffer.from('dao_counter'));
+  const factoryPda = pda(Buffer.from('market_factory'));
+  const protocolConfigPda = pda(Buffer.from('protocol_config'));
+  const tierConfigPda = pda(Buffer.from('tier_config'));
+  const scoreHistoryPda = (identity: PublicKey) => pda(Buffer.from('score_history'), identity.toBuffer());
+  const membershipPda = (dao: PublicKey, wallet: PublicKey) =>
+    pda(Buffer.from('dao_membership'), dao.toBuffer(), wallet.toBuffer());
+  const voteRecordPda = (nomination: PublicKey, voter: PublicKey) =>
+    pda(Buffer.from('vote_record'), nomination.toBuffer(), voter.toBuffer());
+  const nomineeLockPda = (dao: PublicKey, nominee: PublicKey) =>
+    pda(Buffer.from('nominee_lock'), dao.toBuffer(), nominee.toBuffer());
+  const rejectionRecordPda = (dao: PublicKey, nominee: PublicKey) =>
+    pda(Buffer.from('rejection_record'), dao.toBuffer(), nominee.toBuffer());
+  // DAOs are listed DAO_REGISTRY_PAGE_SIZE (12) to a registry page by ID
+  const registryPagePda = (daoId: number | anchor.BN) => {
+    const page = Buffer.alloc(4);
+    page.writeUInt32LE(Math.floor(new anchor.BN(daoId).toNumber() / 12));
+    return pda(Buffer.from('dao_registry'), page);
+  };
+  const positionPda = (market: PublicKey, predictor: PublicKey) =>
+    pda(Buffer.from('market_position'), market.toBuffer(), predictor.toBuffer());
+
+  const ensureDaoCounter = async () => {
+    if (await provider.connection.getAccountInfo(daoCounterPda)) return;
+    await program.methods
+      .initializeDaoCounter()
+      .accounts({ payer: owner, daoCounter: daoCounterPda, systemProgram: SystemProgram.programId })
+      .rpc();
+  };
+
+  const ensureMarketFactory = async () => {
+    if (await provider.connection.getAccountInfo(factoryPda)) return;
+    await program.methods
+      .initializeMarketFactory({
+        defaultFeeBps: 100,
+        defaultBurnBps: 500,
+        defaultProtocolFeeBps: 200,
+        minInitialLiquidity: new anchor.BN(1_000_000),
+        minCreatorStake: new anchor.BN(100_000),
+        minPoolFloor: new anchor.BN(100_000),
+        defaultExpiryPeriod: new anchor.BN(30 * 86400),
+        creatorBonusBps: 200,
+        seederFeeShareBps: 2000,
+        selfPositionBurnBps: 2000,
+        minDaoPrestige: 0,
+        maxPositionBps: 0,
+        challengePeriod: new anchor.BN(0),
+      })
+      .accounts({ authority: owner, factory: factoryPda, systemProgram: SystemProgram.programId })
+      .rpc();
+  };
+
+  const updateFactoryConfig = (params: Record<string, any>, authority: Keypair | null = null) =>
+    program.methods
+      .updateFactoryConfig({
+        defaultFeeBps: null,
+        defaultBurnBps: null,
+        defaultProtocolFeeBps: null,
+        minInitialLiquidity: null,
+        minCreatorStake: null,
+        minPoolFloor: null,
+        defaultExpiryPeriod: null,
+        creatorBonusBps: null,
+        seederFeeShareBps: null,
+        selfPositionBurnBps: null,
+        minDaoPrestige: null,
+        maxPositionBps: null,
+        challengePeriod: null,
+        ...params,
+      })
+      .accounts({ authority: authority ? authority.publicKey : owner, factory: factoryPda })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+  const initAllDetails = async (member: Member): Promise<PublicKey> => {
+    const creatorDetails = pda(Buffer.from('creator_details'), member.identity.toBuffer());
+
+    await program.methods
+      .initAllDetails()
+      .accounts({
+        owner: member.wallet.publicKey,
+        identity: member.identity,
+        tradingDetails: pda(Buffer.from('trading_details'), member.identity.toBuffer()),
+        civicDetails: pda(Buffer.from('civic_details'), member.identity.toBuffer()),
+        developerDetails: pda(Buffer.from('developer_details'), member.identity.toBuffer()),
+        infraDetails: pda(Buffer.from('infra_details'), member.identity.toBuffer()),
+        creatorDetails,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([member.wallet])
+      .rpc();
+
+    return creatorDetails;
+  };
+
+  const ensureCreatorDetails = async (member: Member): Promise<PublicKey> => {
+    const creatorDetails = pda(Buffer.from('creator_details'), member.identity.toBuffer());
+    if (await provider.connection.getAccountInfo(creatorDetails)) return creatorDetails;
+    return initAllDetails(member);
+  };
+
+  const createDao = async (
+    founder: Member,
+    overrides: Record<string, any> = {},
+    founderIdentity: PublicKey = founder.identity
+  ): Promise<PublicKey> => {
+    await ensureDaoCounter();
+    const counter = await program.account.daoCounter.fetch(daoCounterPda);
+    const dao = pda(Buffer.from('creator_dao'), founder.wallet.publicKey.toBuffer(), u64Le(counter.count));
+
+    await program.methods
+      .createDao({
+        name: 'Test DAO',
+        description: 'Opinionated long-form writers',
+        contentType: { longFormWriting: {} },
+        styleTag: 'solana-defi',
+        regionCode: 0,
+        strictRegion: true,
+        admissionThreshold: 60,
+        votingPeriod: new anchor.BN(86400),
+        quorum: 50,
+        minAcceptVotes: 1,
+        voteWeighting: { equal: {} },
+        requiredAttestor: null,
+        minNominatorCreatorScore: 0,
+        nominationCooldown: new anchor.BN(0),
+        nominationFee: new anchor.BN(0),
+        rejectionCooldown: new anchor.BN(0),
+        // Most tests nominate from a founder-only DAO
+        minMembersForNominations: 1,
+        ...overrides,
+      })
+      .accounts({
+        founder: founder.wallet.publicKey,
+        founderIdentity,
+        dao,
+        daoCounter: daoCounterPda,
+        registryPage: registryPagePda(counter.count),
+        protocolConfig: protocolConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([founder.wallet])
+      .rpc();
+
+    return dao;
+  };
+
+  const addFounderMember = (founder: Member, dao: PublicKey, member: Member) =>
+    program.methods
+      .addFounderMember()
+      .accounts({
+        founder: founder.wallet.publicKey,
+        dao,
+        memberIdentity: member.identity,
+        memberWallet: member.wallet.publicKey,
+        membership: membershipPda(dao, member.wallet.publicKey),
+        memberDirectory: pda(Buffer.from('member_directory'), member.wallet.publicKey.toBuffer()),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([founder.wallet])
+      .rpc();
+
+  const closeFounderPhase = (founder: Member, dao: PublicKey) =>
+    program.methods
+      .closeFounderPhase()
+      .accounts({ founder: founder.wallet.publicKey, dao })
+      .signers([founder.wallet])
+      .rpc();
+
+  const updateDaoGovernance = (founder: Member, dao: PublicKey, params: Record<string, any>) =>
+    program.methods
+      .updateDaoGovernance({
+        admissionThreshold: null,
+        votingPeriod: null,
+        quorum: null,
+        minAcceptVotes: null,
+        minNominatorCreatorScore: null,
+        nominationCooldown: null,
+        nominationFee: null,
+        rejectionCooldown: null,
+        minMembersForNominations: null,
+        ...params,
+      })
+      .accounts({ founder: founder.wallet.publicKey, dao, protocolConfig: protocolConfigPda })
+      .signers([founder.wallet])
+      .rpc();
+
+  const nominate = async (
+    nominator: Member,
+    dao: PublicKey,
+    nominee: Member,
+    predictionMarket: PublicKey | null = null,
+    treasury: PublicKey | null = null
+  ): Promise<PublicKey> => {
+    const daoAccount = await program.account.creatorDao.fetch(dao);
+    const nomination = pda(Buffer.from('nomination'), dao.toBuffer(), u64Le(daoAccount.nominationNonce));
+
+    await program.methods
+      .nominateCreator({ reason: 'Consistently excellent essays' })
+      .accounts({
+        nominator: nominator.wallet.publicKey,
+        nominatorMembership: membershipPda(dao, nominator.wallet.publicKey),
+        nominatorIdentity: nominator.identity,
+        dao,
+        nomineeIdentity: nominee.identity,
+        nomineeWallet: nominee.wallet.publicKey,
+        nomineeMembership: membershipPda(dao, nominee.wallet.publicKey),
+        nomination,
+        nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+        rejectionRecord: rejectionRecordPda(dao, nominee.wallet.publicKey),
+        predictionMarket,
+        treasury,
+        protocolConfig: protocolConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([nominator.wallet])
+      .rpc();
+
+    return nomination;
+  };
+
+  const withdrawNomination = (
+    nominator: Member,
+    dao: PublicKey,
+    nomination: PublicKey,
+    nominee: Member,
+    predictionMarket: PublicKey | null = null,
+    treasury: PublicKey | null = null
+  ) =>
+    program.methods
+      .withdrawNomination()
+      .accounts({
+        nominator: nominator.wallet.publicKey,
+        dao,
+        nomination,
+        nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+        predictionMarket,
+        treasury,
+      })
+      .signers([nominator.wallet])
+      .rpc();
+
+  // keccak(choice || salt), with choice as its VoteChoice index
+  const voteCommitment = (vote: object, salt: Uint8Array) => {
+    const choice = ['abstain', 'accept', 'reject'].indexOf(Object.keys(vote)[0]);
+    return Array.from(keccak_256(Buffer.concat([Buffer.from([choice]), Buffer.from(salt)])));
+  };
+
+  const castVote = (
+    voter: Member,
+    dao: PublicKey,
+    nomination: PublicKey,
+    vote: object,
+    predictionMarket: PublicKey | null = null,
+    salt: Uint8Array = Keypair.generate().publicKey.toBytes()
+  ) =>
+    program.methods
+      .castVote(voteCommitment(vote, salt))
+      .accounts({
+        voter: voter.wallet.publicKey,
+        voterMembership: membershipPda(dao, voter.wallet.publicKey),
+        dao,
+        nomination,
+        voteRecord: voteRecordPda(nomination, voter.wallet.publicKey),
+        predictionMarket,
+        voterCreatorDetails: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([voter.wallet])
+      .rpc();
+
+  const eventParser = new anchor.EventParser(program.programId, program.coder);
+
+  // Events a confirmed transaction emitted, by (case-insensitive) name
+  const eventsIn = async (signature: string, name: string) => {
+    await provider.connection.confirmTransaction(signature, 'confirmed');
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    return Array.from(eventParser.parseLogs(tx!.meta!.logMessages!))
+      .filter((event) => event.name.toLowerCase() === name.toLowerCase())
+      .map((event) => event.data as any);
+  };
+
+  const surfacingScorePda = (scout: Member) =>
+    pda(Buffer.from('surfacing_score'), scout.wallet.publicKey.toBuffer());
+
+  const ensureSurfacingScore = async (scout: Member) => {
+    if (await provider.connection.getAccountInfo(surfacingScorePda(scout))) return;
+    await program.methods
+      .initSurfacingScore()
+      .accounts({
+        scout: scout.wallet.publicKey,
+        surfacingScore: surfacingScorePda(scout),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([scout.wallet])
+      .rpc();
+  };
+
+  // Markets are seeded by the factory's running market count
+  const nextMarketPda = async (dao: PublicKey, nominee: Member): Promise<PublicKey> => {
+    const { marketCount } = await program.account.marketFactory.fetch(factoryPda);
+    return pda(Buffer.from('admission_market'), dao.toBuffer(), nominee.identity.toBuffer(), u64Le(marketCount));
+  };
+
+  const createMarket = async (
+    scout: Member,
+    dao: PublicKey,
+    nominee: Member,
+    expiryDays = 30,
+    creatorInitialSide: object = { yes: {} },
+    creatorInitialStake = 1_000_000,
+    initialYesBps: number | null = null
+  ): Promise<PublicKey> => {
+    await ensureMarketFactory();
+    await ensureSurfacingScore(scout);
+    const market = await nextMarketPda(dao, nominee);
+
+    await program.methods
+      .createMarket({
+        initialLiquidity: new anchor.BN(10_000_000),
+        expiryDays,
+        creatorInitialSide,
+        creatorInitialStake: new anchor.BN(creatorInitialStake),
+        initialYesBps,
+      })
+      .accounts({
+        creator: scout.wallet.publicKey,
+        creatorIdentity: scout.identity,
+        dao,
+        predictedCreatorIdentity: nominee.identity,
+        predictedCreatorWallet: nominee.wallet.publicKey,
+        market,
+        creatorPosition: positionPda(market, scout.wallet.publicKey),
+        factory: factoryPda,
+        surfacingScore: surfacingScorePda(scout),
+        protocolConfig: protocolConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([scout.wallet])
+      .rpc();
+
+    return market;
+  };
+
+  const takePosition = (predictor: Member, market: PublicKey, side: object, amount: number) =>
+    program.methods
+      .takePosition({ amount: new anchor.BN(amount), side, minTokens: new anchor.BN(0) })
+      .accounts({
+        predictor: predictor.wallet.publicKey,
+        predictorIdentity: predictor.identity,
+        market,
+        position: positionPda(market, predictor.wallet.publicKey),
+        protocolConfig: protocolConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([predictor.wallet])
+      .rpc();
+
   describe('Identity Creation', () => {
     it('creates a new identity', async () => {
       await program.methods
-        .createIdentity()
+        .createIdentity(null)
         .accounts({
           owner,
           identity: identityPda,
+          scoreHistory: scoreHistoryPda(identityPda),
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -66,10 +484,11 @@ describe('sovereign', () => {
     it('fails to create duplicate identity', async () => {
       try {
         await program.methods
-          .createIdentity()
+          .createIdentity(null)
           .accounts({
             owner,
             identity: identityPda,
+            scoreHistory: scoreHistoryPda(identityPda),
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -144,10 +563,13 @@ describe('sovereign', () => {
       const score = 7500;
 
       await program.methods
-        .updateTradingScore(score)
+        .updateTradingScore(score, null)
         .accounts({
           authority: tradingOracle.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([tradingOracle])
         .rpc();
@@ -164,10 +586,13 @@ describe('sovereign', () => {
       const score = 8000;
 
       await program.methods
-        .updateCivicScore(score)
+        .updateCivicScore(score, null)
         .accounts({
           authority: civicProgram.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([civicProgram])
         .rpc();
@@ -192,10 +617,13 @@ describe('sovereign', () => {
 
       try {
         await program.methods
-          .updateTradingScore(9000)
+          .updateTradingScore(9000, null)
           .accounts({
             authority: unauthorized.publicKey,
             identity: identityPda,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: scoreHistoryPda(identityPda),
           })
           .signers([unauthorized])
           .rpc();
@@ -208,10 +636,13 @@ describe('sovereign', () => {
     it('rejects score above 10000', async () => {
       try {
         await program.methods
-          .updateTradingScore(15000)
+          .updateTradingScore(15000, null)
           .accounts({
             authority: tradingOracle.publicKey,
             identity: identityPda,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: scoreHistoryPda(identityPda),
           })
           .signers([tradingOracle])
           .rpc();
@@ -262,20 +693,26 @@ describe('sovereign', () => {
 
       // Developer: 6000
       await program.methods
-        .updateDeveloperScore(6000)
+        .updateDeveloperScore(6000, null)
         .accounts({
           authority: developerOracle.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([developerOracle])
         .rpc();
 
       // Infra: 4000
       await program.methods
-        .updateInfraScore(4000)
+        .updateInfraScore(4000, null)
         .accounts({
           authority: infraProgram.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([infraProgram])
         .rpc();
@@ -295,37 +732,49 @@ describe('sovereign', () => {
     it('reaches tier 5 with high scores', async () => {
       // Update all scores to max
       await program.methods
-        .updateTradingScore(10000)
+        .updateTradingScore(10000, null)
         .accounts({
           authority: tradingOracle.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([tradingOracle])
         .rpc();
 
       await program.methods
-        .updateCivicScore(10000)
+        .updateCivicScore(10000, null)
         .accounts({
           authority: civicProgram.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([civicProgram])
         .rpc();
 
       await program.methods
-        .updateDeveloperScore(10000)
+        .updateDeveloperScore(10000, null)
         .accounts({
           authority: developerOracle.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([developerOracle])
         .rpc();
 
       await program.methods
-        .updateInfraScore(10000)
+        .updateInfraScore(10000, null)
         .accounts({
           authority: infraProgram.publicKey,
           identity: identityPda,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identityPda),
         })
         .signers([infraProgram])
         .rpc();
@@ -550,29 +999,45 @@ describe('sovereign', () => {
   // ========================================================================
 
   describe('Admission Market PDAs', () => {
-    it('derives market PDA from DAO and creator identity', () => {
+    it('derives market PDA from DAO, creator identity and market id', () => {
       const dao = Keypair.generate().publicKey;
       const creatorIdentity = Keypair.generate().publicKey;
 
       const [marketPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('admission_market'), dao.toBuffer(), creatorIdentity.toBuffer()],
+        [Buffer.from('admission_market'), dao.toBuffer(), creatorIdentity.toBuffer(), u64Le(0)],
         program.programId
       );
 
       expect(marketPda).to.not.be.null;
     });
 
+    it('different market ids for the same pair produce unique market PDAs', () => {
+      const dao = Keypair.generate().publicKey;
+      const creator = Keypair.generate().publicKey;
+
+      const [market1] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admission_market'), dao.toBuffer(), creator.toBuffer(), u64Le(0)],
+        program.programId
+      );
+      const [market2] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admission_market'), dao.toBuffer(), creator.toBuffer(), u64Le(1)],
+        program.programId
+      );
+
+      expect(market1.toBase58()).to.not.equal(market2.toBase58());
+    });
+
     it('different creators in same DAO produce unique market PDAs', () => {
       const dao = Keypair.generate().publicKey;
       const creator1 = Keypair.generate().publicKey;
       const creator2 = Keypair.generate().publicKey;
 
       const [market1] = PublicKey.findProgramAddressSync(
-        [Buffer.from('admission_market'), dao.toBuffer(), creator1.toBuffer()],
+        [Buffer.from('admission_market'), dao.toBuffer(), creator1.toBuffer(), u64Le(0)],
         program.programId
       );
       const [market2] = PublicKey.findProgramAddressSync(
-        [Buffer.from('admission_market'), dao.toBuffer(), creator2.toBuffer()],
+        [Buffer.from('admission_market'), dao.toBuffer(), creator2.toBuffer(), u64Le(0)],
         program.programId
       );
 
@@ -585,11 +1050,11 @@ describe('sovereign', () => {
       const creator = Keypair.generate().publicKey;
 
       const [market1] = PublicKey.findProgramAddressSync(
-        [Buffer.from('admission_market'), dao1.toBuffer(), creator.toBuffer()],
+        [Buffer.from('admission_market'), dao1.toBuffer(), creator.toBuffer(), u64Le(0)],
         program.programId
       );
       const [market2] = PublicKey.findProgramAddressSync(
-        [Buffer.from('admission_market'), dao2.toBuffer(), creator.toBuffer()],
+        [Buffer.from('admission_market'), dao2.toBuffer(), creator.toBuffer(), u64Le(0)],
         program.programId
       );
 
@@ -674,4 +1139,5505 @@ describe('sovereign', () => {
       expect(score1.toBase58()).to.not.equal(score2.toBase58());
     });
   });
+
+  // ========================================================================
+  // Tier Demotion Grace
+  // ========================================================================
+
+  describe('Tier Demotion Grace', () => {
+    const setTrading = (wallet: Keypair, identity: PublicKey, score: number) =>
+      program.methods
+        .updateTradingScore(score, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+    it('does not demote on a brief dip', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      // Trading 10000 * 30% = 3000 composite -> tier 2
+      await setTrading(wallet, identity, 10000);
+      await setTrading(wallet, identity, 0);
+
+      let account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.compositeScore).to.equal(0);
+      expect(account.tier).to.equal(2);
+      expect(account.tierDemotionPendingSince).to.not.be.null;
+
+      // Recovering before the grace period ends clears the pending demotion
+      await setTrading(wallet, identity, 10000);
+
+      account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.tier).to.equal(2);
+      expect(account.tierDemotionPendingSince).to.be.null;
+    });
+
+    it('demotes once the dip outlasts the grace period', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      await program.methods
+        .setTierGracePeriod(new anchor.BN(1))
+        .accounts({ owner: wallet.publicKey, identity })
+        .signers([wallet])
+        .rpc();
+
+      await setTrading(wallet, identity, 10000);
+      await setTrading(wallet, identity, 0);
+
+      let account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.tier).to.equal(2);
+
+      await sleep(2500);
+      await setTrading(wallet, identity, 0);
+
+      account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.tier).to.equal(1);
+      expect(account.tierDemotionPendingSince).to.be.null;
+    });
+
+    it('rejects a negative grace period', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .setTierGracePeriod(new anchor.BN(-1))
+          .accounts({ owner: wallet.publicKey, identity })
+          .signers([wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidGracePeriod');
+      }
+    });
+  });
+
+  // ========================================================================
+  // Score Details Initialization
+  // ========================================================================
+
+  describe('Score Details Initialization', () => {
+    const detailsPda = (seed: string, identity: PublicKey) =>
+      PublicKey.findProgramAddressSync([Buffer.from(seed), identity.toBuffer()], program.programId)[0];
+
+    it('initializes every detail account in one call', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      const accounts = {
+        owner: wallet.publicKey,
+        identity,
+        tradingDetails: detailsPda('trading_details', identity),
+        civicDetails: detailsPda('civic_details', identity),
+        developerDetails: detailsPda('developer_details', identity),
+        infraDetails: detailsPda('infra_details', identity),
+        creatorDetails: detailsPda('creator_details', identity),
+        systemProgram: SystemProgram.programId,
+      };
+
+      await program.methods.initAllDetails().accounts(accounts).signers([wallet]).rpc();
+
+      const trading = await program.account.tradingScoreDetails.fetch(accounts.tradingDetails);
+      const civic = await program.account.civicScoreDetails.fetch(accounts.civicDetails);
+      const developer = await program.account.developerScoreDetails.fetch(accounts.developerDetails);
+      const infra = await program.account.infraScoreDetails.fetch(accounts.infraDetails);
+      const creator = await program.account.creatorScoreDetails.fetch(accounts.creatorDetails);
+
+      for (const details of [trading, civic, developer, infra, creator]) {
+        expect(details.identity.toString()).to.equal(identity.toString());
+      }
+
+      // Calling again skips the already-initialized accounts
+      await program.methods.initAllDetails().accounts(accounts).signers([wallet]).rpc();
+    });
+  });
+
+  // ========================================================================
+  // Score Decay
+  // ========================================================================
+  // The local validator clock can't be advanced by whole days, so these cover
+  // configuration and the sub-day no-op.
+
+  describe('Score Decay', () => {
+    it('defaults to no decay', async () => {
+      const { identity } = await createFundedIdentity();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.decayBpsPerDay).to.equal(0);
+    });
+
+    it('sets a decay rate and leaves same-day scores untouched', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      await program.methods
+        .setDecayRate(100)
+        .accounts({ owner: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      await program.methods.decayScore().accounts({ identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) }).rpc();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.decayBpsPerDay).to.equal(100);
+      // Less than a day has elapsed, so nothing decays yet
+      expect(account.tradingScore).to.equal(8000);
+    });
+
+    it('rejects a decay rate above 10000 bps', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .setDecayRate(10001)
+          .accounts({ owner: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+          .signers([wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidDecayRate');
+      }
+    });
+  });
+
+  // ========================================================================
+  // Voting — Market Anchoring Record
+  // ========================================================================
+
+  describe('Vote Market Probability', () => {
+    let founder: Member;
+    let voter: Member;
+    let nominee: Member;
+    let dao: PublicKey;
+    let nomination: PublicKey;
+    let market: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      voter = await createFundedIdentity();
+      nominee = await createFundedIdentity();
+      const scout = await createFundedIdentity();
+
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, voter);
+
+      market = await createMarket(scout, dao, nominee);
+
+      // Move the price away from 50/50, then lock it to the nomination
+      await takePosition(scout, market, { yes: {} }, 2_000_000);
+      nomination = await nominate(founder, dao, nominee, market);
+    });
+
+    it('records the market-implied probability at vote time', async () => {
+      const marketAccount = await program.account.admissionMarket.fetch(market);
+      const yesPool = marketAccount.yesPool.toNumber();
+      const noPool = marketAccount.noPool.toNumber();
+      const expectedBps = Math.floor((noPool * 10000) / (yesPool + noPool));
+
+      await castVote(voter, dao, nomination, { accept: {} }, market);
+
+      const record = await program.account.voteRecord.fetch(
+        voteRecordPda(nomination, voter.wallet.publicKey)
+      );
+      expect(record.marketProbAtVoteBps).to.equal(expectedBps);
+      expect(expectedBps).to.not.equal(5000);
+    });
+
+    it('requires the nomination\'s linked market', async () => {
+      try {
+        await castVote(founder, dao, nomination, { accept: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('LinkedMarketRequired');
+      }
+    });
+
+    it('leaves the probability empty on a nomination without a market', async () => {
+      const unlinked = await nominate(founder, dao, await createFundedIdentity());
+      await castVote(founder, dao, unlinked, { accept: {} });
+
+      const record = await program.account.voteRecord.fetch(
+        voteRecordPda(unlinked, founder.wallet.publicKey)
+      );
+      expect(record.marketProbAtVoteBps).to.be.null;
+    });
+  });
+
+  // ========================================================================
+  // Configurable Score Weights
+  // ========================================================================
+
+  describe('Score Weights', () => {
+    it('defaults to 30/20/15/10/25', async () => {
+      const { identity } = await createFundedIdentity();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.weights).to.deep.equal([30, 20, 15, 10, 25]);
+    });
+
+    it('recomputes the composite when weights change', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      await program.methods
+        .updateTradingScore(10000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      let account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.compositeScore).to.equal(3000);
+
+      // Infra-heavy ecosystem keeps trading at 50%
+      await program.methods
+        .setScoreWeights([50, 10, 10, 20, 10])
+        .accounts({ owner: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.weights).to.deep.equal([50, 10, 10, 20, 10]);
+      expect(account.compositeScore).to.equal(5000);
+      expect(account.tier).to.equal(3);
+    });
+
+    it('rejects weights that do not sum to 100', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .setScoreWeights([50, 50, 50, 0, 0])
+          .accounts({ owner: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+          .signers([wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidWeights');
+      }
+    });
+  });
+
+  // ========================================================================
+  // Trading Details
+  // ========================================================================
+
+  describe('Trading Details', () => {
+    it('derives the trading score from raw metrics', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+      const tradingDetails = pda(Buffer.from('trading_details'), identity.toBuffer());
+
+      await program.methods
+        .updateTradingDetails({
+          winRateBps: 6000,
+          profitFactorBps: 15000,
+          totalTrades: new anchor.BN(120),
+          totalVolume: new anchor.BN(5_000_000_000),
+          maxDrawdownBps: 2000,
+        })
+        .accounts({
+          authority: wallet.publicKey,
+          identity,
+          tradingDetails,
+          systemProgram: SystemProgram.programId,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identity),
+        })
+        .signers([wallet])
+        .rpc();
+
+      // Win rate:  6000 * 30% = 1800
+      // PF:        7500 * 25% = 1875
+      // Volume:    4000 * 20% = 800
+      // Drawdown:  8000 * 15% = 1200
+      // Trades:    6000 * 10% = 600
+      const details = await program.account.tradingScoreDetails.fetch(tradingDetails);
+      expect(details.winRateBps).to.equal(6000);
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.tradingScore).to.equal(6275);
+    });
+
+    it('rejects updates from a non-trading authority', async () => {
+      const { identity } = await createFundedIdentity();
+      const { wallet: stranger } = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .updateTradingDetails({
+            winRateBps: 6000,
+            profitFactorBps: 15000,
+            totalTrades: new anchor.BN(120),
+            totalVolume: new anchor.BN(5_000_000_000),
+            maxDrawdownBps: 2000,
+          })
+          .accounts({
+            authority: stranger.publicKey,
+            identity,
+            tradingDetails: pda(Buffer.from('trading_details'), identity.toBuffer()),
+            systemProgram: SystemProgram.programId,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: scoreHistoryPda(identity),
+          })
+          .signers([stranger])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+  });
+
+  // ========================================================================
+  // Nomination Deadlines
+  // ========================================================================
+
+  describe('Nomination Deadlines', () => {
+    it('snapshots the voting deadline on the nomination', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+
+      const dao = await createDao(founder, { votingPeriod: new anchor.BN(2 * 86400) });
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.votingEndsAt.sub(account.createdAt).toNumber()).to.equal(2 * 86400);
+    });
+
+    it('keeps in-flight deadlines and thresholds when governance changes', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+
+      const dao = await createDao(founder, { votingPeriod: new anchor.BN(2 * 86400) });
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+      const before = await program.account.nomination.fetch(nomination);
+
+      await updateDaoGovernance(founder, dao, {
+        admissionThreshold: 90,
+        votingPeriod: new anchor.BN(7 * 86400),
+        quorum: 80,
+      });
+
+      const after = await program.account.nomination.fetch(nomination);
+      expect(after.votingEndsAt.toNumber()).to.equal(before.votingEndsAt.toNumber());
+      expect(after.admissionThresholdSnapshot).to.equal(60);
+      expect(after.quorumSnapshot).to.equal(50);
+    });
+  });
+
+  // ========================================================================
+  // Civic Details
+  // ========================================================================
+
+  describe('Civic Details', () => {
+    const civicParams = {
+      problemsSolved: new anchor.BN(12),
+      predictionAccuracyBps: 7000,
+      directionsProposed: new anchor.BN(10),
+      directionsWon: new anchor.BN(4),
+      currentStreak: 4,
+      communityTrust: 8000,
+    };
+
+    it('initializes details and derives the civic score', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+      const civicDetails = pda(Buffer.from('civic_details'), identity.toBuffer());
+
+      await program.methods
+        .initCivicDetails()
+        .accounts({
+          authority: wallet.publicKey,
+          identity,
+          civicDetails,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+      await program.methods
+        .updateCivicDetails(civicParams)
+        .accounts({ authority: wallet.publicKey, identity, civicDetails, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      // Accuracy: 7000 * 40% = 2800
+      // Solved:   6000 * 25% = 1500
+      // Trust:    8000 * 25% = 2000
+      // Streak:   4000 * 10% = 400
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.civicScore).to.equal(6700);
+
+      const details = await program.account.civicScoreDetails.fetch(civicDetails);
+      expect(details.communityTrust).to.equal(8000);
+    });
+
+    it('rejects updates from a non-civic authority', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+      const { wallet: stranger } = await createFundedIdentity();
+      const civicDetails = pda(Buffer.from('civic_details'), identity.toBuffer());
+
+      await program.methods
+        .initCivicDetails()
+        .accounts({
+          authority: wallet.publicKey,
+          identity,
+          civicDetails,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+      try {
+        await program.methods
+          .updateCivicDetails(civicParams)
+          .accounts({ authority: stranger.publicKey, identity, civicDetails, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+          .signers([stranger])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+  });
+
+  describe('Duplicate Nominations', () => {
+    it('rejects a second nomination while one is pending', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      await nominate(founder, dao, nominee);
+
+      try {
+        await nominate(founder, dao, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NominationAlreadyPending');
+      }
+
+      const daoAccount = await program.account.creatorDao.fetch(dao);
+      expect(daoAccount.pendingNominations).to.equal(1);
+    });
+
+    it('allows re-nomination once the pending nomination is withdrawn', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      const first = await nominate(founder, dao, nominee);
+      await withdrawNomination(founder, dao, first, nominee);
+
+      const withdrawn = await program.account.nomination.fetch(first);
+      expect(withdrawn.isResolved).to.be.true;
+      expect(withdrawn.wasWithdrawn).to.be.true;
+      expect(withdrawn.wasAccepted).to.be.false;
+
+      const second = await nominate(founder, dao, nominee);
+      const lock = await program.account.nomineeLock.fetch(nomineeLockPda(dao, nominee.wallet.publicKey));
+      expect(lock.nomination.toBase58()).to.equal(second.toBase58());
+    });
+
+    it('only lets the nominator withdraw', async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      const nomination = await nominate(founder, dao, nominee);
+
+      try {
+        await withdrawNomination(member, dao, nomination, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotNominator');
+      }
+    });
+
+    it('rejects nominating a creator who is already an active member', async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      try {
+        await nominate(founder, dao, member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AlreadyMember');
+      }
+
+      const daoAccount = await program.account.creatorDao.fetch(dao);
+      expect(daoAccount.pendingNominations).to.equal(0);
+    });
+  });
+
+  describe('Market Prestige Gate', () => {
+    const setMinDaoPrestige = (minDaoPrestige: number) =>
+      program.methods
+        .setMinDaoPrestige(minDaoPrestige)
+        .accounts({ authority: owner, factory: factoryPda })
+        .rpc();
+
+    before(async () => {
+      await ensureMarketFactory();
+      await setMinDaoPrestige(1);
+    });
+
+    after(async () => {
+      await setMinDaoPrestige(0);
+    });
+
+    it('rejects a market on a DAO below the minimum prestige tier', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      try {
+        await createMarket(founder, dao, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('DAOPrestigeTooLow');
+      }
+    });
+
+    it('allows a market once the DAO reaches the minimum prestige tier', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      // Tier 1 ("growing") starts at 11 members
+      for (let i = 0; i < 10; i++) {
+        await addFounderMember(founder, dao, await createFundedIdentity());
+      }
+
+      const market = await createMarket(founder, dao, nominee);
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.dao.toBase58()).to.equal(dao.toBase58());
+    });
+
+    it('rejects prestige tiers above the maximum', async () => {
+      try {
+        await setMinDaoPrestige(5);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidPrestigeTier');
+      }
+    });
+  });
+
+  describe('Founder Phase', () => {
+    it('lets the founder hand-pick members while the phase is open', async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      expect((await program.account.creatorDao.fetch(dao)).founderPhaseOpen).to.be.true;
+
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      const daoAccount = await program.account.creatorDao.fetch(dao);
+      expect(daoAccount.memberCount).to.equal(2);
+    });
+
+    it('rejects hand-picked members after the phase is closed', async () => {
+      const founder = await createFundedIdentity();
+      const late = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      await closeFounderPhase(founder, dao);
+      expect((await program.account.creatorDao.fetch(dao)).founderPhaseOpen).to.be.false;
+
+      try {
+        await addFounderMember(founder, dao, late);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('FounderPhaseClosed');
+      }
+
+      // Nominations remain the way in
+      await nominate(founder, dao, late);
+    });
+
+    it('only lets the founder close the phase', async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      try {
+        await closeFounderPhase(member, dao);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotFounder');
+      }
+    });
+
+    it('refuses to close the phase of an empty DAO', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      try {
+        await closeFounderPhase(founder, dao);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NoMembers');
+      }
+    });
+  });
+
+  describe('DAO Governance', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+    });
+
+    it('applies only the values that are provided', async () => {
+      await updateDaoGovernance(founder, dao, { admissionThreshold: 75, votingPeriod: new anchor.BN(3 * 86400) });
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.admissionThreshold).to.equal(75);
+      expect(account.votingPeriod.toNumber()).to.equal(3 * 86400);
+      expect(account.quorum).to.equal(50);
+    });
+
+    const rejections: [string, Record<string, any>, string][] = [
+      ['a zero threshold', { admissionThreshold: 0 }, 'InvalidThreshold'],
+      ['a threshold above 100', { admissionThreshold: 101 }, 'InvalidThreshold'],
+      ['a zero quorum', { quorum: 0 }, 'InvalidQuorum'],
+      ['a quorum above 100', { quorum: 101 }, 'InvalidQuorum'],
+      ['a voting period under a day', { votingPeriod: new anchor.BN(86399) }, 'VotingPeriodTooShort'],
+      ['a voting period over 30 days', { votingPeriod: new anchor.BN(30 * 86400 + 1) }, 'VotingPeriodTooLong'],
+    ];
+
+    for (const [label, params, error] of rejections) {
+      it(`rejects ${label}`, async () => {
+        try {
+          await updateDaoGovernance(founder, dao, params);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include(error);
+        }
+      });
+    }
+
+    it('rejects updates from anyone but the founder', async () => {
+      const stranger = await createFundedIdentity();
+
+      try {
+        await updateDaoGovernance(stranger, dao, { quorum: 10 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotFounder');
+      }
+    });
+  });
+
+  describe('Founder Transfer', () => {
+    const transferFounder = (founder: Member, dao: PublicKey, newFounder: PublicKey) =>
+      program.methods
+        .transferFounder(newFounder)
+        .accounts({ founder: founder.wallet.publicKey, dao })
+        .signers([founder.wallet])
+        .rpc();
+
+    it('moves founder-only powers to the new founder', async () => {
+      const founder = await createFundedIdentity();
+      const successor = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      await transferFounder(founder, dao, successor.wallet.publicKey);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.founder.toBase58()).to.equal(successor.wallet.publicKey.toBase58());
+
+      try {
+        await addFounderMember(founder, dao, await createFundedIdentity());
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotFounder');
+      }
+
+      await addFounderMember(successor, dao, successor);
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(2);
+    });
+
+    it('rejects the default public key as the new founder', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      try {
+        await transferFounder(founder, dao, PublicKey.default);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidFounder');
+      }
+    });
+  });
+
+  describe('Self Votes', () => {
+    it('rejects a nominee voting on their own admission', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      // The founder phase is still open, so the nominee can be hand-picked
+      // while their nomination is pending
+      const nomination = await nominate(founder, dao, nominee);
+      await addFounderMember(founder, dao, nominee);
+
+      try {
+        await castVote(nominee, dao, nomination, { accept: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CannotVoteOnSelf');
+      }
+
+      await castVote(founder, dao, nomination, { accept: {} });
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.voterCount).to.equal(1);
+    });
+  });
+
+  describe('Vote Weighting', () => {
+    const setup = async (voteWeighting: object) => {
+      const founder = await createFundedIdentity();
+      const voter = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { voteWeighting });
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, voter);
+      const nomination = await nominate(founder, dao, nominee);
+      return { founder, voter, dao, nomination };
+    };
+
+    it('counts each vote once under equal weighting', async () => {
+      const { founder, voter, dao, nomination } = await setup({ equal: {} });
+
+      await castVote(founder, dao, nomination, { accept: {} });
+      await castVote(voter, dao, nomination, { reject: {} });
+
+      for (const member of [founder, voter]) {
+        const record = await program.account.voteRecord.fetch(voteRecordPda(nomination, member.wallet.publicKey));
+        expect(record.weight).to.equal(1);
+      }
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.voterCount).to.equal(2);
+    });
+
+    it('weights brand-new members at the minimum under tenure weighting', async () => {
+      const { founder, dao, nomination } = await setup({ byTenure: {} });
+      expect((await program.account.creatorDao.fetch(dao)).voteWeighting).to.deep.equal({ byTenure: {} });
+
+      await castVote(founder, dao, nomination, { accept: {} });
+
+      const record = await program.account.voteRecord.fetch(voteRecordPda(nomination, founder.wallet.publicKey));
+      expect(record.weight).to.equal(1);
+    });
+
+    it('weights by the creator score in the voter\'s details', async () => {
+      const { voter, dao, nomination } = await setup({ byCreatorScore: {} });
+      const creatorDetails = await initAllDetails(voter);
+
+      await program.methods
+        .castVote(voteCommitment({ reject: {} }, Keypair.generate().publicKey.toBytes()))
+        .accounts({
+          voter: voter.wallet.publicKey,
+          voterMembership: membershipPda(dao, voter.wallet.publicKey),
+          dao,
+          nomination,
+          voteRecord: voteRecordPda(nomination, voter.wallet.publicKey),
+          predictionMarket: null,
+          voterCreatorDetails: creatorDetails,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter.wallet])
+        .rpc();
+
+      // Fresh details score 300 (upvote floor only), which is weight 1
+      const record = await program.account.voteRecord.fetch(voteRecordPda(nomination, voter.wallet.publicKey));
+      expect(record.weight).to.equal(1);
+      expect((await program.account.nomination.fetch(nomination)).voterCount).to.equal(1);
+    });
+  });
+
+  describe('Vote Changes', () => {
+    const changeVote = (voter: Member, dao: PublicKey, nomination: PublicKey, vote: object) =>
+      program.methods
+        .changeVote(voteCommitment(vote, Keypair.generate().publicKey.toBytes()))
+        .accounts({
+          voter: voter.wallet.publicKey,
+          voterMembership: membershipPda(dao, voter.wallet.publicKey),
+          dao,
+          nomination,
+          voteRecord: voteRecordPda(nomination, voter.wallet.publicKey),
+        })
+        .signers([voter.wallet])
+        .rpc();
+
+    const tally = async (nomination: PublicKey) => {
+      const account = await program.account.nomination.fetch(nomination);
+      return [account.votesAccept, account.votesReject, account.votesAbstain];
+    };
+
+    it('replaces the commitment without touching the tallies', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+      const recordPda = voteRecordPda(nomination, founder.wallet.publicKey);
+
+      await castVote(founder, dao, nomination, { accept: {} });
+      const commitments = [(await program.account.voteRecord.fetch(recordPda)).commitment];
+
+      for (const vote of [{ reject: {} }, { abstain: {} }, { accept: {} }]) {
+        await changeVote(founder, dao, nomination, vote);
+        commitments.push((await program.account.voteRecord.fetch(recordPda)).commitment);
+        expect(await tally(nomination)).to.deep.equal([0, 0, 0]);
+      }
+
+      // A fresh salt each time, so even the return to accept looks new
+      expect(new Set(commitments.map((c: number[]) => Buffer.from(c).toString('hex'))).size).to.equal(4);
+      expect((await program.account.nomination.fetch(nomination)).voterCount).to.equal(1);
+    });
+
+    it('rejects changes to a vote that was never cast', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+
+      try {
+        await changeVote(founder, dao, nomination, { reject: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AccountNotInitialized');
+      }
+    });
+  });
+
+  describe('Reputation Summary', () => {
+    it('aggregates identity scores and creator details into one view', async () => {
+      const member = await createFundedIdentity();
+      const { wallet, identity } = member;
+
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      const creatorDetails = await initAllDetails(member);
+
+      const summary = await program.methods
+        .getReputationSummary()
+        .accounts({ identity, creatorDetails, surfacingScore: null, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda })
+        .view();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(summary.owner.toBase58()).to.equal(wallet.publicKey.toBase58());
+      expect(summary.tradingScore).to.equal(8000);
+      // 8000 * 30% = 2400 -> Silver
+      expect(summary.compositeScore).to.equal(2400);
+      expect(summary.compositeScore).to.equal(account.compositeScore);
+      expect(summary.tier).to.equal(account.tier);
+      expect(summary.daosAccepted).to.equal(0);
+      expect(summary.predictionAccuracyBps).to.equal(0);
+      expect(summary.scoutScore).to.equal(0);
+    });
+
+    it('works without the optional creator accounts', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      const summary = await program.methods
+        .getReputationSummary()
+        .accounts({ identity, creatorDetails: null, surfacingScore: null, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda })
+        .view();
+
+      expect(summary.owner.toBase58()).to.equal(wallet.publicKey.toBase58());
+      expect(summary.compositeScore).to.equal(0);
+      expect(summary.tier).to.equal(1);
+    });
+  });
+
+  describe('Content', () => {
+    const contentPda = (creator: Member, index: number) =>
+      pda(Buffer.from('content'), creator.identity.toBuffer(), (() => {
+        const buf = Buffer.alloc(4);
+        buf.writeUInt32LE(index);
+        return buf;
+      })());
+
+    const submitContent = (submitter: Member, dao: PublicKey, creator: Member, index: number) =>
+      program.methods
+        .submitContent(Array.from(Keypair.generate().publicKey.toBytes()))
+        .accounts({
+          submitter: submitter.wallet.publicKey,
+          dao,
+          submitterMembership: membershipPda(dao, submitter.wallet.publicKey),
+          creatorMembership: membershipPda(dao, creator.wallet.publicKey),
+          creatorDetails: pda(Buffer.from('creator_details'), creator.identity.toBuffer()),
+          content: contentPda(creator, index),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([submitter.wallet])
+        .rpc();
+
+    const upvoteContent = (voter: Member, dao: PublicKey, creator: Member, content: PublicKey) =>
+      program.methods
+        .upvoteContent()
+        .accounts({
+          voter: voter.wallet.publicKey,
+          dao,
+          voterMembership: membershipPda(dao, voter.wallet.publicKey),
+          creatorMembership: membershipPda(dao, creator.wallet.publicKey),
+          creatorDetails: pda(Buffer.from('creator_details'), creator.identity.toBuffer()),
+          content,
+          upvote: pda(Buffer.from('content_upvote'), content.toBuffer(), voter.wallet.publicKey.toBuffer()),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter.wallet])
+        .rpc();
+
+    let founder: Member;
+    let creator: Member;
+    let dao: PublicKey;
+    let creatorDetails: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      creator = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, creator);
+      creatorDetails = await initAllDetails(creator);
+    });
+
+    it('counts content recorded by fellow members', async () => {
+      await submitContent(founder, dao, creator, 0);
+      await submitContent(creator, dao, creator, 1);
+
+      const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+      expect(details.contentCount).to.equal(2);
+
+      const record = await program.account.contentRecord.fetch(contentPda(creator, 1));
+      expect(record.index).to.equal(1);
+      expect(record.submittedBy.toBase58()).to.equal(creator.wallet.publicKey.toBase58());
+    });
+
+    it('adds peer upvotes once per member per piece', async () => {
+      const content = contentPda(creator, 0);
+      await upvoteContent(founder, dao, creator, content);
+
+      try {
+        await upvoteContent(founder, dao, creator, content);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/already in use|custom program error: 0x0/);
+      }
+
+      const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+      expect(details.peerUpvotes.toNumber()).to.equal(1);
+      expect((await program.account.contentRecord.fetch(content)).upvotes).to.equal(1);
+    });
+
+    it('rejects creators upvoting their own content', async () => {
+      try {
+        await upvoteContent(creator, dao, creator, contentPda(creator, 0));
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CannotUpvoteOwnContent');
+      }
+    });
+
+    it('rejects submissions from non-members', async () => {
+      const outsider = await createFundedIdentity();
+
+      try {
+        await submitContent(outsider, dao, creator, 2);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/NotMember|AccountNotInitialized/);
+      }
+    });
+
+    describe('Creator Score Sync', () => {
+      const creatorAuthority = Keypair.generate();
+
+      const syncCreatorScore = (authority: Keypair) =>
+        program.methods
+          .syncCreatorScore()
+          .accounts({
+            authority: authority.publicKey,
+            identity: creator.identity,
+            creatorDetails,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: scoreHistoryPda(creator.identity),
+          })
+          .signers([authority])
+          .rpc();
+
+      before(async () => {
+        await program.methods
+          .setCreatorAuthority(creatorAuthority.publicKey)
+          .accounts({ owner: creator.wallet.publicKey, identity: creator.identity })
+          .signers([creator.wallet])
+          .rpc();
+      });
+
+      it('writes the accrued creator details into the identity', async () => {
+        const before = await program.account.sovereignIdentity.fetch(creator.identity);
+        expect(before.creatorScore).to.equal(0);
+
+        // The creator has content and a peer upvote on record
+        const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+        expect(details.peerUpvotes.toNumber()).to.be.greaterThan(0);
+
+        await syncCreatorScore(creatorAuthority);
+
+        // Only the upvote tier scores so far: 2000 * 15%
+        const after = await program.account.sovereignIdentity.fetch(creator.identity);
+        expect(after.creatorScore).to.equal(300);
+        expect(after.compositeScore).to.be.greaterThan(before.compositeScore);
+        expect(after.creatorUpdatedAt.toNumber()).to.be.greaterThan(0);
+      });
+
+      it('rejects syncing by anyone but the creator authority', async () => {
+        try {
+          await syncCreatorScore(creator.wallet);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include('Unauthorized');
+        }
+      });
+    });
+  });
+
+  describe('Attestations', () => {
+    const bindAttestation = (member: Member, authority: Keypair, hash = Keypair.generate().publicKey.toBytes()) =>
+      program.methods
+        .bindAttestation(Array.from(hash))
+        .accounts({
+          owner: member.wallet.publicKey,
+          attestationAuthority: authority.publicKey,
+          identity: member.identity,
+        })
+        .signers([member.wallet, authority])
+        .rpc();
+
+    it('marks an identity verified when the authority co-signs', async () => {
+      const member = await createFundedIdentity();
+      const attestor = Keypair.generate();
+      const hash = Keypair.generate().publicKey.toBytes();
+
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).isVerified).to.be.false;
+
+      await bindAttestation(member, attestor, hash);
+
+      const account = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(account.isVerified).to.be.true;
+      expect(account.attestationAuthority.toBase58()).to.equal(attestor.publicKey.toBase58());
+      expect(Buffer.from(account.attestationHash)).to.deep.equal(Buffer.from(hash));
+    });
+
+    it('rejects an empty attestation hash', async () => {
+      const member = await createFundedIdentity();
+
+      try {
+        await bindAttestation(member, Keypair.generate(), new Uint8Array(32));
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidAttestation');
+      }
+    });
+
+    describe('nomination gating', () => {
+      const attestor = Keypair.generate();
+      let founder: Member;
+      let dao: PublicKey;
+
+      before(async () => {
+        founder = await createFundedIdentity();
+        dao = await createDao(founder, { requiredAttestor: attestor.publicKey });
+        await addFounderMember(founder, dao, founder);
+      });
+
+      it('rejects unverified nominees', async () => {
+        const nominee = await createFundedIdentity();
+
+        try {
+          await nominate(founder, dao, nominee);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include('NomineeNotVerified');
+        }
+      });
+
+      it('rejects nominees verified by a different authority', async () => {
+        const nominee = await createFundedIdentity();
+        await bindAttestation(nominee, Keypair.generate());
+
+        try {
+          await nominate(founder, dao, nominee);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include('NomineeNotVerified');
+        }
+      });
+
+      it('accepts nominees verified by the DAO attestor', async () => {
+        const nominee = await createFundedIdentity();
+        await bindAttestation(nominee, attestor);
+
+        const nomination = await nominate(founder, dao, nominee);
+        const account = await program.account.nomination.fetch(nomination);
+        expect(account.nomineeWallet.toBase58()).to.equal(nominee.wallet.publicKey.toBase58());
+      });
+    });
+  });
+
+  describe('Trade Bounds', () => {
+    let scout: Member;
+    let market: PublicKey;
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      scout = await createFundedIdentity();
+
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, nominee);
+    });
+
+    it('rejects a stake that would overflow the pools', async () => {
+      const before = await program.account.admissionMarket.fetch(market);
+
+      try {
+        await program.methods
+          .takePosition({
+            amount: new anchor.BN('18446744073709551615'),
+            side: { yes: {} },
+            minTokens: new anchor.BN(0),
+          })
+          .accounts({
+            predictor: scout.wallet.publicKey,
+            predictorIdentity: scout.identity,
+            market,
+            position: positionPda(market, scout.wallet.publicKey),
+            protocolConfig: protocolConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([scout.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ArithmeticOverflow');
+      }
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.totalYesTokensIssued.toNumber()).to.equal(before.totalYesTokensIssued.toNumber());
+    });
+
+    it('still accepts a one-lamport stake that buys at least one token', async () => {
+      const before = await program.account.marketPosition.fetch(positionPda(market, scout.wallet.publicKey));
+      await takePosition(scout, market, { yes: {} }, 1);
+
+      const position = await program.account.marketPosition.fetch(
+        positionPda(market, scout.wallet.publicKey)
+      );
+      expect(position.yesTokens.toNumber()).to.be.greaterThan(before.yesTokens.toNumber());
+    });
+  });
+
+  describe('Position Cap', () => {
+    const setMaxPositionBps = (maxPositionBps: number) =>
+      program.methods
+        .setMaxPositionBps(maxPositionBps)
+        .accounts({ authority: owner, factory: factoryPda })
+        .rpc();
+
+    let market: PublicKey;
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      await ensureMarketFactory();
+      await setMaxPositionBps(5000);
+      market = await createMarket(scout, dao, nominee);
+      // Only markets created while the cap is set inherit it
+      await setMaxPositionBps(0);
+    });
+
+    it('copies the factory cap into new markets', async () => {
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.maxPositionBps).to.equal(5000);
+    });
+
+    it('accepts a position within the cap', async () => {
+      const predictor = await createFundedIdentity();
+      await takePosition(predictor, market, { yes: {} }, 2_000_000);
+
+      const position = await program.account.marketPosition.fetch(
+        positionPda(market, predictor.wallet.publicKey)
+      );
+      expect(position.totalStaked.toNumber()).to.equal(2_000_000);
+    });
+
+    it('rejects a position that would take over half the pot', async () => {
+      const whale = await createFundedIdentity();
+
+      try {
+        await takePosition(whale, market, { no: {} }, 20_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('PositionCapExceeded');
+      }
+    });
+
+    it('rejects caps above 100%', async () => {
+      try {
+        await setMaxPositionBps(10001);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidFeeConfig');
+      }
+    });
+  });
+
+  describe('Market Trading Window', () => {
+    let founder: Member;
+    let nominee: Member;
+    let scout: Member;
+    let dao: PublicKey;
+    let market: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      nominee = await createFundedIdentity();
+      scout = await createFundedIdentity();
+
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, nominee);
+    });
+
+    it('trades freely before the nomination', async () => {
+      await takePosition(scout, market, { yes: {} }, 1_000_000);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ open: {} });
+      expect(account.tradingEndsAt).to.be.null;
+    });
+
+    it('locks the market when the nominee is nominated', async () => {
+      await nominate(founder, dao, nominee, market);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ votingInProgress: {} });
+      expect(account.tradingEndsAt).to.not.be.null;
+    });
+
+    it('rejects new positions once voting has started', async () => {
+      const latecomer = await createFundedIdentity();
+
+      try {
+        await takePosition(latecomer, market, { yes: {} }, 1_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotOpen');
+      }
+    });
+
+    it('rejects locking a market on a different nominee', async () => {
+      const other = await createFundedIdentity();
+      const otherMarket = await createMarket(scout, dao, other);
+      const someoneElse = await createFundedIdentity();
+
+      try {
+        await nominate(founder, dao, someoneElse, otherMarket);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketMismatch');
+      }
+    });
+  });
+
+  describe('Market Nomination Link', () => {
+    let founder: Member;
+    let scout: Member;
+    let dao: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('links the market to the nomination it was attached to', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee);
+      const nomination = await nominate(founder, dao, nominee, market);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.nomination.toBase58()).to.equal(nomination.toBase58());
+      const nominationAccount = await program.account.nomination.fetch(nomination);
+      expect(nominationAccount.linkedMarket.toBase58()).to.equal(market.toBase58());
+    });
+
+    it('refuses to close a nomination without its linked market', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee);
+      const nomination = await nominate(founder, dao, nominee, market);
+
+      try {
+        await withdrawNomination(founder, dao, nomination, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('LinkedMarketRequired');
+      }
+
+      try {
+        await program.methods
+          .cancelExpiredNomination()
+          .accounts({
+            caller: founder.wallet.publicKey,
+            dao,
+            nomination,
+            nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+            predictionMarket: null,
+            treasury: null,
+          })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('LinkedMarketRequired');
+      }
+
+      try {
+        await program.methods
+          .resolveNomination()
+          .accounts({
+            resolver: founder.wallet.publicKey,
+            dao,
+            nomination,
+            nomineeIdentity: nominee.identity,
+            creatorScore: await initAllDetails(nominee),
+            nominatorMembership: membershipPda(dao, founder.wallet.publicKey),
+            nominatorCreatorScore: pda(Buffer.from('creator_details'), founder.identity.toBuffer()),
+            predictionMarket: null,
+            newMembership: membershipPda(dao, nominee.wallet.publicKey),
+            nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+            rejectionRecord: rejectionRecordPda(dao, nominee.wallet.publicKey),
+            treasury: null,
+            nominatorWallet: null,
+            burnAddress,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('LinkedMarketRequired');
+      }
+
+      // With the market it withdraws and the market reopens
+      await withdrawNomination(founder, dao, nomination, nominee, market);
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ open: {} });
+    });
+
+    it('rejects resolving a nomination with a market that is not linked to it', async () => {
+      const nominee = await createFundedIdentity();
+      const creatorScore = await initAllDetails(nominee);
+      const unlinkedMarket = await createMarket(scout, dao, nominee);
+      const nomination = await nominate(founder, dao, nominee);
+
+      try {
+        await program.methods
+          .resolveNomination()
+          .accounts({
+            resolver: founder.wallet.publicKey,
+            dao,
+            nomination,
+            nomineeIdentity: nominee.identity,
+            creatorScore,
+            nominatorMembership: membershipPda(dao, founder.wallet.publicKey),
+            nominatorCreatorScore: pda(Buffer.from('creator_details'), founder.identity.toBuffer()),
+            predictionMarket: unlinkedMarket,
+            newMembership: membershipPda(dao, nominee.wallet.publicKey),
+            nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+            rejectionRecord: rejectionRecordPda(dao, nominee.wallet.publicKey),
+            treasury: null,
+            nominatorWallet: null,
+            burnAddress,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketMismatch');
+      }
+    });
+  });
+
+  describe('Market Escrow and Expiry', () => {
+    let scout: Member;
+    let dao: PublicKey;
+
+    const expireStaleMarket = (keeper: Member, market: PublicKey) =>
+      program.methods
+        .expireStaleMarket()
+        .accounts({ keeper: keeper.wallet.publicKey, market })
+        .signers([keeper.wallet])
+        .rpc();
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('escrows seed liquidity and stakes in the market account', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee);
+      const afterCreate = await provider.connection.getBalance(market);
+
+      const predictor = await createFundedIdentity();
+      await takePosition(predictor, market, { yes: {} }, 3_000_000);
+
+      const afterTrade = await provider.connection.getBalance(market);
+      expect(afterTrade - afterCreate).to.equal(3_000_000);
+
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        (await provider.connection.getAccountInfo(market))!.data.length
+      );
+      // Seed liquidity plus the scout's opening stake
+      expect(afterCreate - rent).to.equal(11_000_000);
+    });
+
+    it('refuses to expire a market before its expiry', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee);
+
+      try {
+        await expireStaleMarket(scout, market);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotExpired');
+      }
+    });
+
+    it('lets anyone cancel a market past its expiry', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee, 0);
+      const keeper = await createFundedIdentity();
+
+      await sleep(2500);
+      await expireStaleMarket(keeper, market);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ expired: {} });
+      expect(account.outcome).to.deep.equal({ cancelled: {} });
+
+      try {
+        await expireStaleMarket(keeper, market);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotOpen');
+      }
+    });
+
+    it('opens a fresh market for a pair whose earlier market expired', async () => {
+      const nominee = await createFundedIdentity();
+      const first = await createMarket(scout, dao, nominee, 0);
+      await sleep(2500);
+      await expireStaleMarket(scout, first);
+
+      const second = await createMarket(scout, dao, nominee);
+      expect(second.toBase58()).to.not.equal(first.toBase58());
+
+      const [old, fresh] = await Promise.all([
+        program.account.admissionMarket.fetch(first),
+        program.account.admissionMarket.fetch(second),
+      ]);
+      expect(old.status).to.deep.equal({ expired: {} });
+      expect(fresh.status).to.deep.equal({ open: {} });
+      expect(fresh.creatorIdentity.toBase58()).to.equal(nominee.identity.toBase58());
+      expect(fresh.marketId.toNumber()).to.be.greaterThan(old.marketId.toNumber());
+    });
+
+    it('returns a cancelled market\'s seed liquidity to its scout once', async () => {
+      const reclaim = (creator: Member, market: PublicKey) =>
+        program.methods
+          .reclaimSeedLiquidity()
+          .accounts({ creator: creator.wallet.publicKey, market })
+          .signers([creator.wallet])
+          .rpc();
+
+      const open = await createMarket(scout, dao, await createFundedIdentity());
+      try {
+        await reclaim(scout, open);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotResolved');
+      }
+
+      const market = await createMarket(scout, dao, await createFundedIdentity(), 0);
+      await sleep(2500);
+      await expireStaleMarket(scout, market);
+
+      try {
+        await reclaim(await createFundedIdentity(), market);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketCreatorMismatch');
+      }
+
+      const before = await provider.connection.getBalance(market);
+      await reclaim(scout, market);
+      expect(before - (await provider.connection.getBalance(market))).to.equal(10_000_000);
+
+      try {
+        await reclaim(scout, market);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('SeedNotReclaimable');
+      }
+    });
+
+    it('refunds each predictor their full stake once the market expires', async () => {
+      const market = await createMarket(scout, dao, await createFundedIdentity(), 0);
+      await sleep(2500);
+      await expireStaleMarket(scout, market);
+
+      const position = positionPda(market, scout.wallet.publicKey);
+      const staked = (await program.account.marketPosition.fetch(position)).totalStaked.toNumber();
+      expect(staked).to.equal(1_000_000);
+
+      const before = await provider.connection.getBalance(market);
+      await program.methods
+        .claimWinnings(null)
+        .accounts({
+          predictor: scout.wallet.publicKey,
+          market,
+          position,
+          creatorScore: await ensureCreatorDetails(scout),
+          surfacingScore: surfacingScorePda(scout),
+          burnTreasury: burnAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([scout.wallet])
+        .rpc();
+
+      // The stake comes back whole, the trading fee it paid included
+      expect(before - (await provider.connection.getBalance(market))).to.equal(staked);
+      expect((await program.account.marketPosition.fetch(position)).payout.toNumber()).to.equal(staked);
+    });
+
+    it('takes a cancelled market out of the scout surfacing accuracy', async () => {
+      const market = await createMarket(scout, dao, await createFundedIdentity(), 0);
+      await sleep(2500);
+      await expireStaleMarket(scout, market);
+
+      const before = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      await program.methods
+        .claimWinnings(null)
+        .accounts({
+          predictor: scout.wallet.publicKey,
+          market,
+          position: positionPda(market, scout.wallet.publicKey),
+          creatorScore: await ensureCreatorDetails(scout),
+          surfacingScore: surfacingScorePda(scout),
+          burnTreasury: burnAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([scout.wallet])
+        .rpc();
+
+      // Resolved markets can't be produced on localnet, so the 1 win / 1 loss /
+      // 1 cancelled split is covered by the program's unit tests
+      const after = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      expect(after.marketsCancelled).to.equal(before.marketsCancelled + 1);
+      expect(after.marketsCreated).to.equal(before.marketsCreated);
+      expect(after.surfacingAccuracyBps).to.equal(0);
+    });
+  });
+
+  describe('Fee Withdrawal', () => {
+    let market: PublicKey;
+
+    const withdrawFees = (authority: PublicKey, signers: Keypair[] = []) =>
+      program.methods
+        .withdrawFees()
+        .accounts({ authority, factory: factoryPda, market, destination: owner })
+        .signers(signers)
+        .rpc();
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, nominee);
+    });
+
+    it('accrues fees across trades', async () => {
+      const trades: [object, number][] = [
+        [{ yes: {} }, 1_000_000],
+        [{ no: {} }, 2_500_000],
+        [{ yes: {} }, 333_333],
+      ];
+      for (const [side, amount] of trades) {
+        await takePosition(await createFundedIdentity(), market, side, amount);
+      }
+
+      const account = await program.account.admissionMarket.fetch(market);
+      // The scout's 1_000_000 opening stake pays the fee too
+      const expected = trades.reduce(
+        (sum, [, amount]) => sum + Math.floor((amount * 100) / 10000),
+        Math.floor((1_000_000 * 100) / 10000)
+      );
+      expect(account.accumulatedFees.toNumber()).to.equal(expected);
+    });
+
+    it('refuses to sweep a live market', async () => {
+      try {
+        await withdrawFees(owner);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketStillActive');
+      }
+    });
+
+    it('rejects withdrawals by anyone but the factory authority', async () => {
+      const { wallet: stranger } = await createFundedIdentity();
+
+      try {
+        await withdrawFees(stranger.publicKey, [stranger]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+
+    it('leaves nothing to sweep once a market expires', async () => {
+      const founder = await createFundedIdentity();
+      const scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const expired = await createMarket(scout, dao, await createFundedIdentity(), 0);
+      expect((await program.account.admissionMarket.fetch(expired)).accumulatedFees.toNumber()).to.be.greaterThan(0);
+
+      await sleep(2500);
+      await program.methods
+        .expireStaleMarket()
+        .accounts({ keeper: scout.wallet.publicKey, market: expired })
+        .signers([scout.wallet])
+        .rpc();
+
+      // Expiry refunds the fees with the stakes; sweeping a finalized
+      // market exactly once is covered by the program's unit tests
+      expect((await program.account.admissionMarket.fetch(expired)).accumulatedFees.toNumber()).to.equal(0);
+      try {
+        await program.methods
+          .withdrawFees()
+          .accounts({ authority: owner, factory: factoryPda, market: expired, destination: owner })
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NoFeesToWithdraw');
+      }
+    });
+  });
+
+  describe('Factory Config', () => {
+    before(async () => {
+      await ensureMarketFactory();
+    });
+
+    it('updates only the values that are passed', async () => {
+      const before = await program.account.marketFactory.fetch(factoryPda);
+
+      await updateFactoryConfig({ defaultFeeBps: 150, creatorBonusBps: 300 });
+
+      const after = await program.account.marketFactory.fetch(factoryPda);
+      expect(after.defaultFeeBps).to.equal(150);
+      expect(after.creatorBonusBps).to.equal(300);
+      expect(after.defaultBurnBps).to.equal(before.defaultBurnBps);
+      expect(after.minInitialLiquidity.toNumber()).to.equal(before.minInitialLiquidity.toNumber());
+
+      await updateFactoryConfig({ defaultFeeBps: before.defaultFeeBps, creatorBonusBps: before.creatorBonusBps });
+    });
+
+    it('leaves existing markets on the values they were created with', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const market = await createMarket(founder, dao, nominee);
+      const { feeBps } = await program.account.admissionMarket.fetch(market);
+
+      await updateFactoryConfig({ defaultFeeBps: feeBps + 50 });
+      try {
+        const account = await program.account.admissionMarket.fetch(market);
+        expect(account.feeBps).to.equal(feeBps);
+      } finally {
+        await updateFactoryConfig({ defaultFeeBps: feeBps });
+      }
+    });
+
+    it('rejects a fee and burn that together exceed 100%', async () => {
+      try {
+        await updateFactoryConfig({ defaultFeeBps: 6000, defaultBurnBps: 5000 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidFeeConfig');
+      }
+    });
+
+    it('rejects a burn and protocol share that together exceed 100%', async () => {
+      try {
+        await updateFactoryConfig({ defaultBurnBps: 5000, defaultProtocolFeeBps: 5001 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidFeeConfig');
+      }
+    });
+
+    it('copies the protocol share onto new markets', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      const { defaultProtocolFeeBps } = await program.account.marketFactory.fetch(factoryPda);
+      await updateFactoryConfig({ defaultProtocolFeeBps: 300 });
+      try {
+        const market = await createMarket(founder, dao, nominee);
+        const account = await program.account.admissionMarket.fetch(market);
+        expect(account.protocolFeeBps).to.equal(300);
+        expect(account.amountToProtocol.toNumber()).to.equal(0);
+      } finally {
+        await updateFactoryConfig({ defaultProtocolFeeBps });
+      }
+    });
+
+    it('rejects updates from anyone but the factory authority', async () => {
+      const { wallet: stranger } = await createFundedIdentity();
+
+      try {
+        await updateFactoryConfig({ defaultFeeBps: 0 }, stranger);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+
+      try {
+        await program.methods
+          .setMinDaoPrestige(2)
+          .accounts({ authority: stranger.publicKey, factory: factoryPda })
+          .signers([stranger])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+  });
+
+  describe('Nomination Timeouts', () => {
+    it('refuses to cancel a nomination while voting is open', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+      const caller = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .cancelExpiredNomination()
+          .accounts({
+            caller: caller.wallet.publicKey,
+            dao,
+            nomination,
+            nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+            predictionMarket: null,
+            treasury: null,
+          })
+          .signers([caller.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('VotingNotEnded');
+      }
+
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.isResolved).to.be.false;
+      expect(account.timedOut).to.be.false;
+    });
+  });
+
+  describe('Member Directory', () => {
+    it('lists every DAO a creator has been admitted to', async () => {
+      const creator = await createFundedIdentity();
+      const founderA = await createFundedIdentity();
+      const founderB = await createFundedIdentity();
+      const daoA = await createDao(founderA);
+      const daoB = await createDao(founderB);
+      await addFounderMember(founderA, daoA, founderA);
+      await addFounderMember(founderB, daoB, founderB);
+
+      await addFounderMember(founderA, daoA, creator);
+      await addFounderMember(founderB, daoB, creator);
+
+      const daos: PublicKey[] = await program.methods
+        .getMemberDaos()
+        .accounts({
+          wallet: creator.wallet.publicKey,
+          memberDirectory: pda(Buffer.from('member_directory'), creator.wallet.publicKey.toBuffer()),
+        })
+        .view();
+
+      expect(daos.map((dao) => dao.toBase58())).to.have.members([daoA.toBase58(), daoB.toBase58()]);
+    });
+  });
+
+  describe('Tier Verification', () => {
+    const cpiMock = anchor.workspace.SovereignCpiMock as Program;
+    let member: Member;
+
+    before(async () => {
+      // A fresh identity scores 0, which is Bronze (tier 1)
+      member = await createFundedIdentity();
+    });
+
+    it('returns whether the identity meets the minimum tier', async () => {
+      const pass = await program.methods.verifyTier(1).accounts({ identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda }).view();
+      expect(pass.meetsMinTier).to.be.true;
+      expect(pass.tier).to.equal(1);
+      expect(pass.compositeScore).to.equal(0);
+      expect(pass.owner.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+
+      const fail = await program.methods.verifyTier(2).accounts({ identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda }).view();
+      expect(fail.meetsMinTier).to.be.false;
+    });
+
+    it('lets another program gate on the tier over CPI', async () => {
+      await cpiMock.methods
+        .gateOnTier(1)
+        .accounts({ user: member.wallet.publicKey, identity: member.identity, sovereignProgram: program.programId, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda })
+        .signers([member.wallet])
+        .rpc();
+
+      try {
+        await cpiMock.methods
+          .gateOnTier(5)
+          .accounts({ user: member.wallet.publicKey, identity: member.identity, sovereignProgram: program.programId, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda })
+          .signers([member.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('TierTooLow');
+      }
+    });
+
+    it("rejects gating on someone else's identity", async () => {
+      const other = await createFundedIdentity();
+      try {
+        await cpiMock.methods
+          .gateOnTier(1)
+          .accounts({ user: other.wallet.publicKey, identity: member.identity, sovereignProgram: program.programId, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda })
+          .signers([other.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotIdentityOwner');
+      }
+    });
+  });
+
+  describe('Settled Market Guard', () => {
+    it('rejects resolving a nomination against an already-settled market', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const creatorScore = await initAllDetails(nominee);
+
+      // Settle the market by letting it expire before the nomination
+      const market = await createMarket(founder, dao, nominee, 0);
+      await sleep(2500);
+      await program.methods
+        .expireStaleMarket()
+        .accounts({ keeper: founder.wallet.publicKey, market })
+        .signers([founder.wallet])
+        .rpc();
+
+      const nomination = await nominate(founder, dao, nominee);
+
+      try {
+        await program.methods
+          .resolveNomination()
+          .accounts({
+            resolver: founder.wallet.publicKey,
+            dao,
+            nomination,
+            nomineeIdentity: nominee.identity,
+            creatorScore,
+            nominatorMembership: membershipPda(dao, founder.wallet.publicKey),
+            nominatorCreatorScore: pda(Buffer.from('creator_details'), founder.identity.toBuffer()),
+            predictionMarket: market,
+            newMembership: membershipPda(dao, nominee.wallet.publicKey),
+            nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+            rejectionRecord: rejectionRecordPda(dao, nominee.wallet.publicKey),
+            treasury: null,
+            nominatorWallet: null,
+            burnAddress,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        // A settled market can never have been linked to the nomination
+        expect(e.message).to.include('MarketMismatch');
+      }
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ expired: {} });
+    });
+  });
+
+  describe('Tier Config', () => {
+    const tierConfig = pda(Buffer.from('tier_config'));
+    const names = ['Bronze', 'Silver', 'Gold', 'Platinum', 'Diamond'];
+    const setCutoffs = (cutoffs: number[]) =>
+      program.methods.setTierConfig({ cutoffs, names }).accounts({ authority: owner, tierConfig }).rpc();
+
+    after(async () => {
+      await setCutoffs([2000, 4000, 6000, 8000]);
+    });
+
+    it('places the same composite in a different tier under a custom curve', async () => {
+      await setCutoffs([1, 2, 3, 4]);
+
+      const custom = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: custom.wallet.publicKey, identity: custom.identity, tierConfig, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(custom.identity) })
+        .signers([custom.wallet])
+        .rpc();
+
+      // The same score under the default curve
+      await setCutoffs([2000, 4000, 6000, 8000]);
+      const legacy = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: legacy.wallet.publicKey, identity: legacy.identity, tierConfig, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(legacy.identity) })
+        .signers([legacy.wallet])
+        .rpc();
+
+      const customAccount = await program.account.sovereignIdentity.fetch(custom.identity);
+      const legacyAccount = await program.account.sovereignIdentity.fetch(legacy.identity);
+      expect(customAccount.compositeScore).to.equal(legacyAccount.compositeScore);
+      expect(customAccount.tier).to.equal(5);
+      expect(legacyAccount.tier).to.be.lessThan(5);
+    });
+
+    it('falls back to the default cutoffs without the tier config', async () => {
+      await setCutoffs([1, 2, 3, 4]);
+
+      const member = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({
+          authority: member.wallet.publicKey,
+          identity: member.identity,
+          tierConfig: null,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(member.identity),
+        })
+        .signers([member.wallet])
+        .rpc();
+
+      // 8000 * 30% = 2400, Silver under the default curve
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).tier).to.equal(2);
+    });
+
+    it('rejects cutoffs that do not strictly increase', async () => {
+      try {
+        await setCutoffs([4000, 2000, 6000, 8000]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidTierCutoffs');
+      }
+    });
+
+    it('rejects updates from anyone but the config authority', async () => {
+      const { wallet } = await createFundedIdentity();
+      try {
+        await program.methods
+          .setTierConfig({ cutoffs: [1000, 2000, 3000, 4000], names })
+          .accounts({ authority: wallet.publicKey, tierConfig })
+          .signers([wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+
+    it('refuses to initialize the tier config for anyone but the protocol admin', async () => {
+      const { wallet } = await createFundedIdentity();
+      let refused = false;
+      try {
+        await program.methods
+          .initializeTierConfig({ cutoffs: [1, 2, 3, 4], names })
+          .accounts({ authority: wallet.publicKey, protocolConfig: protocolConfigPda, tierConfig, systemProgram: SystemProgram.programId })
+          .signers([wallet])
+          .rpc();
+      } catch (e: any) {
+        refused = true;
+      }
+      expect(refused).to.be.true;
+
+      const account = await program.account.tierConfig.fetch(tierConfig);
+      expect(account.authority.toBase58()).to.equal(owner.toBase58());
+    });
+  });
+
+  describe('Score History', () => {
+    const SCORE_HISTORY_LEN = 32;
+
+    // create_identity opens the history
+    const setUp = async () => {
+      const member = await createFundedIdentity();
+      return { ...member, scoreHistory: scoreHistoryPda(member.identity) };
+    };
+
+    // Apply a trading score and return the composite it produced
+    const update = async (member: Awaited<ReturnType<typeof setUp>>, score: number) => {
+      await program.methods
+        .updateTradingScore(score, null)
+        .accounts({
+          authority: member.wallet.publicKey,
+          identity: member.identity,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: member.scoreHistory,
+        })
+        .signers([member.wallet])
+        .rpc();
+      const account = await program.account.sovereignIdentity.fetch(member.identity);
+      return account.compositeScore as number;
+    };
+
+    const history = (member: Awaited<ReturnType<typeof setUp>>) =>
+      program.methods
+        .getScoreHistory()
+        .accounts({ identity: member.identity, scoreHistory: member.scoreHistory })
+        .view();
+
+    it('records a snapshot for each update, oldest first', async () => {
+      const member = await setUp();
+      expect(await history(member)).to.have.length(0);
+
+      const composites: number[] = [];
+      for (const score of [3000, 9000, 6000]) {
+        composites.push(await update(member, score));
+      }
+
+      const snapshots = await history(member);
+      expect(snapshots.map((s: any) => s.compositeScore)).to.deep.equal(composites);
+      for (let i = 1; i < snapshots.length; i++) {
+        expect(snapshots[i].timestamp.gte(snapshots[i - 1].timestamp)).to.be.true;
+      }
+    });
+
+    it('overwrites the oldest snapshots once full', async () => {
+      const member = await setUp();
+
+      const composites: number[] = [];
+      for (let i = 0; i < SCORE_HISTORY_LEN + 3; i++) {
+        composites.push(await update(member, (i + 1) * 250));
+      }
+
+      const snapshots = await history(member);
+      expect(snapshots).to.have.length(SCORE_HISTORY_LEN);
+      expect(snapshots.map((s: any) => s.compositeScore)).to.deep.equal(composites.slice(3));
+    });
+
+    it("refuses a score update that passes another identity's history", async () => {
+      const member = await setUp();
+      const other = await setUp();
+
+      try {
+        await program.methods
+          .updateTradingScore(7000, null)
+          .accounts({
+            authority: member.wallet.publicKey,
+            identity: member.identity,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: other.scoreHistory,
+          })
+          .signers([member.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+      expect(await history(member)).to.have.length(0);
+    });
+
+    it('still moves scores when the history is left out', async () => {
+      const member = await setUp();
+
+      await program.methods
+        .updateTradingScore(7000, null)
+        .accounts({
+          authority: member.wallet.publicKey,
+          identity: member.identity,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: null,
+        })
+        .signers([member.wallet])
+        .rpc();
+
+      // Anyone can run decay without it too
+      await program.methods
+        .decayScore()
+        .accounts({ identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: null })
+        .rpc();
+
+      const account = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(account.tradingScore).to.equal(7000);
+      expect(await history(member)).to.have.length(0);
+    });
+  });
+
+  describe('Identity Freeze', () => {
+    const updates = ['updateTradingScore', 'updateCivicScore', 'updateDeveloperScore', 'updateInfraScore'];
+
+    const update = (member: { wallet: Keypair; identity: PublicKey }, method: string, score: number) =>
+      program.methods[method](score, null)
+        .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+        .signers([member.wallet])
+        .rpc();
+
+    it('rejects every score update while frozen and accepts them once unfrozen', async () => {
+      const member = await createFundedIdentity();
+
+      await program.methods
+        .freezeIdentity()
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).isFrozen).to.be.true;
+
+      for (const method of updates) {
+        try {
+          await update(member, method, 5000);
+          expect.fail(`${method} should have thrown error`);
+        } catch (e: any) {
+          expect(e.message).to.include('IdentityFrozen');
+        }
+      }
+
+      await program.methods
+        .unfreezeIdentity()
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+
+      for (const method of updates) {
+        await update(member, method, 5000);
+      }
+
+      const account = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(account.isFrozen).to.be.false;
+      expect(account.tradingScore).to.equal(5000);
+      expect(account.civicScore).to.equal(5000);
+      expect(account.developerScore).to.equal(5000);
+      expect(account.infraScore).to.equal(5000);
+    });
+
+    it('fails tier verification and DAO eligibility while frozen', async () => {
+      const member = await createFundedIdentity();
+      await program.methods
+        .freezeIdentity()
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+
+      const verification = await program.methods.verifyTier(1).accounts({ identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda }).view();
+      expect(verification.tier).to.equal(1);
+      expect(verification.meetsMinTier).to.be.false;
+
+      try {
+        await createDao(member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('IdentityFrozen');
+      }
+    });
+  });
+
+  describe('Identity Transfer', () => {
+    const fundedWallet = async () => {
+      const wallet = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(wallet.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+      return wallet;
+    };
+
+    const transfer = (from: { wallet: Keypair; identity: PublicKey }, to: Keypair, keepAuthorities: boolean, signers: Keypair[]) =>
+      program.methods
+        .transferIdentity(keepAuthorities)
+        .accounts({
+          oldOwner: from.wallet.publicKey,
+          newOwner: to.publicKey,
+          oldIdentity: from.identity,
+          newIdentity: pda(Buffer.from('identity'), to.publicKey.toBuffer()),
+          oldScoreHistory: scoreHistoryPda(from.identity),
+          newScoreHistory: scoreHistoryPda(pda(Buffer.from('identity'), to.publicKey.toBuffer())),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers(signers)
+        .rpc();
+
+    it('migrates every field to the new owner and resets authorities', async () => {
+      const member = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(7000, null)
+        .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+        .signers([member.wallet])
+        .rpc();
+      const before = await program.account.sovereignIdentity.fetch(member.identity);
+
+      const newOwner = await fundedWallet();
+      await transfer(member, newOwner, false, [member.wallet, newOwner]);
+
+      const newIdentity = pda(Buffer.from('identity'), newOwner.publicKey.toBuffer());
+      const after = await program.account.sovereignIdentity.fetch(newIdentity);
+      expect(after.owner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      expect(after.transferredTo).to.be.null;
+      expect(after.tradingScore).to.equal(7000);
+      expect(after.compositeScore).to.equal(before.compositeScore);
+      expect(after.tier).to.equal(before.tier);
+      expect(after.createdAt.toString()).to.equal(before.createdAt.toString());
+      for (const authority of [after.tradingAuthority, after.civicAuthority, after.developerAuthority, after.infraAuthority]) {
+        expect(authority.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      }
+
+      // The score history moves with the identity
+      const snapshots = await program.methods
+        .getScoreHistory()
+        .accounts({ identity: newIdentity, scoreHistory: scoreHistoryPda(newIdentity) })
+        .view();
+      expect(snapshots).to.have.length(1);
+      expect(await provider.connection.getAccountInfo(scoreHistoryPda(member.identity))).to.be.null;
+    });
+
+    it('leaves a frozen, zeroed tombstone the old owner cannot re-create or revive', async () => {
+      const member = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(7000, null)
+        .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+        .signers([member.wallet])
+        .rpc();
+
+      const newOwner = await fundedWallet();
+      await transfer(member, newOwner, false, [member.wallet, newOwner]);
+
+      const tombstone = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(tombstone.transferredTo!.toBase58()).to.equal(
+        pda(Buffer.from('identity'), newOwner.publicKey.toBuffer()).toBase58()
+      );
+      expect(tombstone.isFrozen).to.be.true;
+      expect(tombstone.tradingScore).to.equal(0);
+      expect(tombstone.compositeScore).to.equal(0);
+
+      try {
+        await program.methods
+          .createIdentity(null)
+          .accounts({
+            owner: member.wallet.publicKey,
+            identity: member.identity,
+            scoreHistory: scoreHistoryPda(member.identity),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('already in use');
+      }
+
+      try {
+        await program.methods
+          .unfreezeIdentity()
+          .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+          .signers([member.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('IdentityTransferred');
+      }
+    });
+
+    it('keeps existing authorities when asked to', async () => {
+      const member = await createFundedIdentity();
+      const oracle = Keypair.generate();
+      await program.methods
+        .setTradingAuthority(oracle.publicKey)
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+
+      const newOwner = await fundedWallet();
+      await transfer(member, newOwner, true, [member.wallet, newOwner]);
+
+      const after = await program.account.sovereignIdentity.fetch(pda(Buffer.from('identity'), newOwner.publicKey.toBuffer()));
+      expect(after.tradingAuthority.toBase58()).to.equal(oracle.publicKey.toBase58());
+      expect(after.civicAuthority.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+    });
+
+    it('requires both the old and the new owner to sign', async () => {
+      const member = await createFundedIdentity();
+      const newOwner = await fundedWallet();
+
+      for (const signers of [[member.wallet], [newOwner]]) {
+        try {
+          await transfer(member, newOwner, false, signers);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.match(/[Ss]ignature|unknown signer/);
+        }
+      }
+
+      expect(await provider.connection.getAccountInfo(member.identity)).to.not.be.null;
+    });
+  });
+
+  describe('Per-Dimension Freshness', () => {
+    it('stamps only the dimension that was updated', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      const created = await program.account.sovereignIdentity.fetch(identity);
+      expect(created.tradingUpdatedAt.toNumber()).to.equal(0);
+      expect(created.civicUpdatedAt.toNumber()).to.equal(0);
+
+      await program.methods
+        .updateTradingScore(6000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+      const afterTrading = await program.account.sovereignIdentity.fetch(identity);
+      expect(afterTrading.tradingUpdatedAt.toNumber()).to.be.greaterThan(0);
+      expect(afterTrading.civicUpdatedAt.toNumber()).to.equal(0);
+
+      await sleep(2000);
+
+      await program.methods
+        .updateCivicScore(4000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+      const afterCivic = await program.account.sovereignIdentity.fetch(identity);
+      expect(afterCivic.tradingUpdatedAt.toNumber()).to.equal(afterTrading.tradingUpdatedAt.toNumber());
+      expect(afterCivic.civicUpdatedAt.toNumber()).to.be.greaterThan(afterTrading.tradingUpdatedAt.toNumber());
+      expect(afterCivic.developerUpdatedAt.toNumber()).to.equal(0);
+      expect(afterCivic.infraUpdatedAt.toNumber()).to.equal(0);
+      expect(afterCivic.creatorUpdatedAt.toNumber()).to.equal(0);
+    });
+  });
+
+  describe('Nominator Score Gate', () => {
+    it('defaults to letting any member nominate', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      expect((await program.account.creatorDao.fetch(dao)).minNominatorCreatorScore).to.equal(0);
+    });
+
+    it('rejects nominators below the minimum creator score', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { minNominatorCreatorScore: 1 });
+      await addFounderMember(founder, dao, founder);
+
+      // Fresh identities have a creator score of 0
+      try {
+        await nominate(founder, dao, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NominatorScoreTooLow');
+      }
+
+      // Once the nominator meets the minimum the nomination goes through
+      await updateDaoGovernance(founder, dao, { minNominatorCreatorScore: 0 });
+      const nomination = await nominate(founder, dao, nominee);
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.nominator.toBase58()).to.equal(founder.wallet.publicKey.toBase58());
+    });
+
+    it('rejects a minimum above 10000', async () => {
+      const founder = await createFundedIdentity();
+      try {
+        await createDao(founder, { minNominatorCreatorScore: 10001 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidMinNominatorScore');
+      }
+    });
+  });
+
+  describe('Nomination Cooldown', () => {
+    it('makes a member wait out the cooldown between nominations', async () => {
+      const founder = await createFundedIdentity();
+      const first = await createFundedIdentity();
+      const second = await createFundedIdentity();
+      const dao = await createDao(founder, { nominationCooldown: new anchor.BN(3) });
+      await addFounderMember(founder, dao, founder);
+
+      await nominate(founder, dao, first);
+
+      try {
+        await nominate(founder, dao, second);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NominationCooldownActive');
+      }
+
+      await sleep(4000);
+      await nominate(founder, dao, second);
+
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, founder.wallet.publicKey));
+      expect(membership.lastNominatedAt.toNumber()).to.be.greaterThan(0);
+      expect((await program.account.creatorDao.fetch(dao)).pendingNominations).to.equal(2);
+    });
+
+    it('rejects a negative cooldown', async () => {
+      const founder = await createFundedIdentity();
+      try {
+        await createDao(founder, { nominationCooldown: new anchor.BN(-1) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidNominationCooldown');
+      }
+    });
+  });
+
+  describe('Vote Commitments', () => {
+    it('stores only a commitment that does not reveal the choice', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+
+      const salt = Keypair.generate().publicKey.toBytes();
+      await castVote(founder, dao, nomination, { accept: {} }, null, salt);
+
+      const record = await program.account.voteRecord.fetch(voteRecordPda(nomination, founder.wallet.publicKey));
+      expect(record).to.not.have.property('vote');
+      expect(record.revealed).to.be.false;
+      expect(record.commitment).to.deep.equal(voteCommitment({ accept: {} }, salt));
+
+      // Without the salt, no choice reproduces the commitment
+      const unsalted = new Uint8Array(32);
+      for (const vote of [{ abstain: {} }, { accept: {} }, { reject: {} }]) {
+        expect(record.commitment).to.not.deep.equal(voteCommitment(vote, unsalted));
+      }
+
+      // Nor do the tallies give it away before the reveal
+      const account = await program.account.nomination.fetch(nomination);
+      expect([account.votesAccept, account.votesReject, account.votesAbstain]).to.deep.equal([0, 0, 0]);
+      expect(account.voterCount).to.equal(1);
+      expect(account.revealedCount).to.equal(0);
+    });
+
+    it('refuses reveals while voting is still open', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const nomination = await nominate(founder, dao, nominee);
+
+      const salt = Keypair.generate().publicKey.toBytes();
+      await castVote(founder, dao, nomination, { accept: {} }, null, salt);
+
+      try {
+        await program.methods
+          .revealVote({ accept: {} }, Array.from(salt))
+          .accounts({
+            voter: founder.wallet.publicKey,
+            nomination,
+            voteRecord: voteRecordPda(nomination, founder.wallet.publicKey),
+          })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('RevealNotOpen');
+      }
+    });
+
+    it('refuses to penalize a vote before the reveal period ends', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const creatorDetails = await initAllDetails(founder);
+      const nomination = await nominate(founder, dao, nominee);
+      await castVote(founder, dao, nomination, { accept: {} });
+
+      // The commitment already counts toward quorum
+      expect((await program.account.nomination.fetch(nomination)).voterCount).to.equal(1);
+
+      try {
+        await program.methods
+          .penalizeUnrevealedVote()
+          .accounts({
+            caller: owner,
+            nomination,
+            voteRecord: voteRecordPda(nomination, founder.wallet.publicKey),
+            voter: founder.wallet.publicKey,
+            voterIdentity: founder.identity,
+            voterCreatorDetails: creatorDetails,
+          })
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('RevealNotClosed');
+      }
+      expect((await program.account.creatorScoreDetails.fetch(creatorDetails)).unrevealedVotes).to.equal(0);
+    });
+
+    it('refuses to penalize a vote on a withdrawn nomination', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const creatorDetails = await initAllDetails(founder);
+      const nomination = await nominate(founder, dao, nominee);
+      await castVote(founder, dao, nomination, { accept: {} });
+
+      // Withdrawn nominations can't be revealed on, so the vote never could be
+      await withdrawNomination(founder, dao, nomination, nominee);
+
+      try {
+        await program.methods
+          .penalizeUnrevealedVote()
+          .accounts({
+            caller: owner,
+            nomination,
+            voteRecord: voteRecordPda(nomination, founder.wallet.publicKey),
+            voter: founder.wallet.publicKey,
+            voterIdentity: founder.identity,
+            voterCreatorDetails: creatorDetails,
+          })
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NominationWithdrawn');
+      }
+      expect((await program.account.creatorScoreDetails.fetch(creatorDetails)).unrevealedVotes).to.equal(0);
+    });
+  });
+
+  describe('Composite Recompute', () => {
+    const tierConfig = pda(Buffer.from('tier_config'));
+    const names = ['Bronze', 'Silver', 'Gold', 'Platinum', 'Diamond'];
+    const setCutoffs = (cutoffs: number[]) =>
+      program.methods.setTierConfig({ cutoffs, names }).accounts({ authority: owner, tierConfig }).rpc();
+    const recompute = (identity: PublicKey) =>
+      program.methods.recomputeComposite().accounts({ identity, tierConfig, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) }).rpc();
+
+    after(async () => {
+      await setCutoffs([2000, 4000, 6000, 8000]);
+    });
+
+    it('moves an identity to its tier under a changed tier config', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+      const before = await program.account.sovereignIdentity.fetch(identity);
+      expect(before.tier).to.be.lessThan(5);
+
+      await setCutoffs([1, 2, 3, 4]);
+      expect((await program.account.sovereignIdentity.fetch(identity)).tier).to.equal(before.tier);
+
+      await recompute(identity);
+
+      const after = await program.account.sovereignIdentity.fetch(identity);
+      expect(after.tier).to.equal(5);
+      expect(after.compositeScore).to.equal(before.compositeScore);
+      expect(after.lastUpdated.toString()).to.equal(before.lastUpdated.toString());
+    });
+
+    it('leaves an up-to-date identity unchanged', async () => {
+      const { identity } = await createFundedIdentity();
+      await setCutoffs([2000, 4000, 6000, 8000]);
+      const before = await program.account.sovereignIdentity.fetch(identity);
+
+      await recompute(identity);
+
+      const after = await program.account.sovereignIdentity.fetch(identity);
+      expect(after.compositeScore).to.equal(before.compositeScore);
+      expect(after.tier).to.equal(before.tier);
+    });
+  });
+
+  describe('Schema Migration', () => {
+    it('creates identities on schema v2 with the 5-dimension weighting', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+      await program.methods
+        .updateTradingScore(8000, null)
+        .accounts({ authority: wallet.publicKey, identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .signers([wallet])
+        .rpc();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.schemaVersion).to.equal(2);
+      expect(account.compositeScore).to.equal(2400); // 30% of 8000, not the legacy 40%
+    });
+
+    it('migrates a v1 identity to the v2 layout and weighting', async () => {
+      // Preloaded by Anchor.toml: trading 8000 under the legacy weighting
+      const wallet = Keypair.fromSecretKey(
+        Uint8Array.from(JSON.parse(fs.readFileSync('tests/fixtures/legacy-identity-owner.json', 'utf8')))
+      );
+      const identity = pda(Buffer.from('identity'), wallet.publicKey.toBuffer());
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(wallet.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+      expect((await provider.connection.getAccountInfo(identity))!.data.length).to.equal(230);
+
+      const creatorAuthority = Keypair.generate().publicKey;
+      await program.methods
+        .migrateToV2(creatorAuthority)
+        .accounts({
+          owner: wallet.publicKey,
+          identity,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(identity),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+      const account = await program.account.sovereignIdentity.fetch(identity);
+      expect(account.schemaVersion).to.equal(2);
+      expect(account.owner.toBase58()).to.equal(wallet.publicKey.toBase58());
+      expect(account.creatorAuthority.toBase58()).to.equal(creatorAuthority.toBase58());
+      expect(account.tradingScore).to.equal(8000);
+      expect(account.compositeScore).to.equal(2400); // 30% of 8000, down from the legacy 3200
+
+      const history = await program.account.scoreHistory.fetch(scoreHistoryPda(identity));
+      expect(history.count).to.equal(1);
+    });
+
+    it('rejects migrating an identity that is already on v2', async () => {
+      const { wallet, identity } = await createFundedIdentity();
+
+      try {
+        await program.methods
+          .migrateToV2(Keypair.generate().publicKey)
+          .accounts({
+            owner: wallet.publicKey,
+            identity,
+            tierConfig: tierConfigPda,
+            protocolConfig: protocolConfigPda,
+            scoreHistory: scoreHistoryPda(identity),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AlreadyMigrated');
+      }
+    });
+  });
+
+  describe('DAO Treasury', () => {
+    let founder: Member;
+    let creator: Member;
+    let dao: PublicKey;
+    let treasury: PublicKey;
+    let creatorDetails: PublicKey;
+
+    const burn = (signer: Member, proceeds: number) =>
+      program.methods
+        .burnFromTreasury(new anchor.BN(proceeds))
+        .accounts({
+          founder: signer.wallet.publicKey,
+          dao,
+          treasury,
+          creatorMembership: membershipPda(dao, creator.wallet.publicKey),
+          creatorDetails,
+          burnAddress,
+        })
+        .signers([signer.wallet])
+        .rpc();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      creator = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, creator);
+      creatorDetails = await initAllDetails(creator);
+      treasury = pda(Buffer.from('dao_treasury'), dao.toBuffer());
+
+      await program.methods
+        .initDaoTreasury(2500)
+        .accounts({ founder: founder.wallet.publicKey, dao, treasury, systemProgram: SystemProgram.programId })
+        .signers([founder.wallet])
+        .rpc();
+    });
+
+    it('accepts deposits from anyone', async () => {
+      const depositor = await createFundedIdentity();
+      const before = await provider.connection.getBalance(treasury);
+
+      await program.methods
+        .depositToTreasury(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL))
+        .accounts({ depositor: depositor.wallet.publicKey, treasury, systemProgram: SystemProgram.programId })
+        .signers([depositor.wallet])
+        .rpc();
+
+      expect(await provider.connection.getBalance(treasury)).to.equal(before + anchor.web3.LAMPORTS_PER_SOL);
+      const account = await program.account.daoTreasury.fetch(treasury);
+      expect(account.totalDeposited.toNumber()).to.equal(anchor.web3.LAMPORTS_PER_SOL);
+    });
+
+    it("burns the configured share of a creator's proceeds and credits their score", async () => {
+      const proceeds = 0.4 * anchor.web3.LAMPORTS_PER_SOL;
+      const before = await provider.connection.getBalance(treasury);
+
+      await burn(founder, proceeds);
+
+      const burned = proceeds / 4; // 2500 bps
+      expect(await provider.connection.getBalance(treasury)).to.equal(before - burned);
+      expect((await program.account.daoTreasury.fetch(treasury)).totalBurned.toNumber()).to.equal(burned);
+      const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+      expect(details.totalBurned.toNumber()).to.equal(burned);
+    });
+
+    it('rejects proceeds the treasury cannot cover', async () => {
+      try {
+        await burn(founder, 5 * anchor.web3.LAMPORTS_PER_SOL);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InsufficientTreasury');
+      }
+    });
+
+    it('rejects burns from anyone but the founder', async () => {
+      try {
+        await burn(creator, 1000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotFounder');
+      }
+    });
+  });
+
+  describe('Scout Rank', () => {
+    let scouts: Member[];
+    const asRemaining = (accounts: PublicKey[]) =>
+      accounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }));
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      scouts = [];
+      for (let i = 0; i < 3; i++) {
+        const scout = await createFundedIdentity();
+        await createMarket(scout, dao, await createFundedIdentity());
+        scouts.push(scout);
+      }
+    });
+
+    it('ranks a scout against the others using freshly computed scores', async () => {
+      const [scout, ...others] = scouts;
+      const stored = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      expect(stored.scoutScore).to.equal(0); // not refreshed by create_market
+
+      const rank = await program.methods
+        .getScoutRank(1)
+        .accounts({ surfacingScore: surfacingScorePda(scout) })
+        .remainingAccounts(asRemaining([surfacingScorePda(scout), ...others.map(surfacingScorePda)]))
+        .view();
+
+      // One market, no resolved surfaces: 20% volume tier * 30% + 20% profit tier * 20%
+      expect(rank.scoutScore).to.equal(1000);
+      expect(rank.compared).to.equal(2);
+      expect(rank.rank).to.equal(1);
+      expect(rank.percentileBps).to.equal(10000);
+      expect(rank.isTopN).to.be.true;
+    });
+
+    it('rejects comparison accounts that are not surfacing scores', async () => {
+      try {
+        await program.methods
+          .getScoutRank(1)
+          .accounts({ surfacingScore: surfacingScorePda(scouts[0]) })
+          .remainingAccounts(asRemaining([scouts[1].wallet.publicKey]))
+          .view();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidScoutAccount');
+      }
+    });
+  });
+
+  describe('Surfacing Score Setup', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('lets a scout create their score and then open a market', async () => {
+      const scout = await createFundedIdentity();
+      await ensureSurfacingScore(scout);
+
+      const initialized = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      expect(initialized.identity.toBase58()).to.equal(scout.wallet.publicKey.toBase58());
+      expect(initialized.marketsCreated).to.equal(0);
+
+      await createMarket(scout, dao, await createFundedIdentity());
+
+      const after = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      expect(after.marketsCreated).to.equal(1);
+    });
+
+    it('rejects market creation when the scout has no surfacing score', async () => {
+      await ensureMarketFactory();
+      const scout = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+
+      const market = await nextMarketPda(dao, nominee);
+
+      try {
+        await program.methods
+          .createMarket({
+            initialLiquidity: new anchor.BN(10_000_000),
+            expiryDays: 30,
+            creatorInitialSide: { yes: {} },
+            creatorInitialStake: new anchor.BN(1_000_000),
+            initialYesBps: null,
+          })
+          .accounts({
+            creator: scout.wallet.publicKey,
+            creatorIdentity: scout.identity,
+            dao,
+            predictedCreatorIdentity: nominee.identity,
+            predictedCreatorWallet: nominee.wallet.publicKey,
+            market,
+            creatorPosition: positionPda(market, scout.wallet.publicKey),
+            factory: factoryPda,
+            surfacingScore: surfacingScorePda(scout),
+            protocolConfig: protocolConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([scout.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AccountNotInitialized');
+      }
+    });
+  });
+
+  describe('Creator Coin Burns', () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+
+    let founder: Member;
+    let creator: Member;
+    let dao: PublicKey;
+    let treasury: PublicKey;
+    let creatorDetails: PublicKey;
+    let mint: PublicKey;
+    let treasuryTokenAccount: PublicKey;
+
+    const setCreatorMint = (member: Member, mintKey: PublicKey) =>
+      program.methods
+        .setCreatorMint()
+        .accounts({
+          owner: member.wallet.publicKey,
+          identity: member.identity,
+          creatorDetails: pda(Buffer.from('creator_details'), member.identity.toBuffer()),
+          mint: mintKey,
+        })
+        .signers([member.wallet])
+        .rpc();
+
+    const burnCreatorCoins = (proceeds: number, mintKey = mint) =>
+      program.methods
+        .burnCreatorCoins(new anchor.BN(proceeds))
+        .accounts({
+          founder: founder.wallet.publicKey,
+          dao,
+          treasury,
+          creatorMembership: membershipPda(dao, creator.wallet.publicKey),
+          creatorDetails,
+          mint: mintKey,
+          treasuryTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([founder.wallet])
+        .rpc();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      creator = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, creator);
+      creatorDetails = await initAllDetails(creator);
+      treasury = pda(Buffer.from('dao_treasury'), dao.toBuffer());
+
+      await program.methods
+        .initDaoTreasury(2500)
+        .accounts({ founder: founder.wallet.publicKey, dao, treasury, systemProgram: SystemProgram.programId })
+        .signers([founder.wallet])
+        .rpc();
+
+      mint = await createMint(provider.connection, payer, creator.wallet.publicKey, null, 0);
+      treasuryTokenAccount = (
+        await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, treasury, true)
+      ).address;
+      await mintTo(provider.connection, payer, mint, treasuryTokenAccount, creator.wallet, 1000);
+    });
+
+    it('only lets the mint authority register a creator mint', async () => {
+      const impostor = await createFundedIdentity();
+      await initAllDetails(impostor);
+
+      try {
+        await setCreatorMint(impostor, mint);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotMintAuthority');
+      }
+
+      await setCreatorMint(creator, mint);
+      const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+      expect(details.creatorMint.toBase58()).to.equal(mint.toBase58());
+    });
+
+    it("burns the treasury's share of the creator's coins and reduces supply", async () => {
+      await burnCreatorCoins(400);
+
+      // 2500 bps of 400
+      expect(Number((await getMint(provider.connection, mint)).supply)).to.equal(900);
+      const details = await program.account.creatorScoreDetails.fetch(creatorDetails);
+      expect(details.totalBurned.toNumber()).to.equal(100);
+    });
+
+    it("rejects burns of a mint other than the creator's coin", async () => {
+      const otherMint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+
+      try {
+        await burnCreatorCoins(400, otherMint);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CreatorMintMismatch');
+      }
+    });
+
+    it('rejects proceeds beyond what the treasury holds', async () => {
+      try {
+        await burnCreatorCoins(10_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InsufficientCreatorCoins');
+      }
+    });
+  });
+
+  describe('Keeper Settlement', () => {
+    let market: PublicKey;
+    let predictors: Member[];
+    let keeper: Member;
+
+    const claimFor = (predictor: Member, position: PublicKey) =>
+      program.methods
+        .claimWinningsFor()
+        .accounts({
+          keeper: keeper.wallet.publicKey,
+          predictor: predictor.wallet.publicKey,
+          market,
+          position,
+          creatorScore: pda(Buffer.from('creator_details'), predictor.identity.toBuffer()),
+          surfacingScore: null,
+          burnTreasury: burnAddress,
+        })
+        .instruction();
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, await createFundedIdentity());
+
+      predictors = [];
+      for (const side of [{ yes: {} }, { no: {} }, { yes: {} }]) {
+        const predictor = await createFundedIdentity();
+        await takePosition(predictor, market, side, 1_000_000);
+        await ensureCreatorDetails(predictor);
+        predictors.push(predictor);
+      }
+      keeper = await createFundedIdentity();
+    });
+
+    it('refuses to pay a position out to anyone but its predictor', async () => {
+      const [victim, thief] = predictors;
+      const ix = await claimFor(thief, positionPda(market, victim.wallet.publicKey));
+
+      try {
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(ix), [keeper.wallet]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(JSON.stringify(e.logs ?? e.message)).to.include('PredictorMismatch');
+      }
+    });
+
+    it('refuses to settle a market that has not resolved', async () => {
+      // Resolution needs the voting and reveal periods to pass, which localnet
+      // can't fast-forward; payout settlement itself is covered by the
+      // payouts_sum_to_distributable_pot unit test.
+      const tx = new anchor.web3.Transaction();
+      for (const predictor of predictors) {
+        tx.add(await claimFor(predictor, positionPda(market, predictor.wallet.publicKey)));
+      }
+
+      try {
+        await provider.sendAndConfirm(tx, [keeper.wallet]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(JSON.stringify(e.logs ?? e.message)).to.include('MarketNotResolved');
+      }
+    });
+  });
+
+  describe('Resolution Challenge Period', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    before(async () => {
+      await ensureMarketFactory();
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    after(async () => {
+      await updateFactoryConfig({ challengePeriod: new anchor.BN(0) });
+    });
+
+    it('copies the factory challenge period onto new markets', async () => {
+      await updateFactoryConfig({ challengePeriod: new anchor.BN(3600) });
+      const market = await createMarket(founder, dao, await createFundedIdentity());
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.challengePeriod.toNumber()).to.equal(3600);
+    });
+
+    it('rejects a negative challenge period', async () => {
+      try {
+        await updateFactoryConfig({ challengePeriod: new anchor.BN(-1) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidChallengePeriod');
+      }
+    });
+
+    it('rejects disputes on a market that has not resolved', async () => {
+      // Resolving needs the voting and reveal periods to pass, which localnet
+      // can't fast-forward; the window itself is covered by unit tests.
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(founder, dao, nominee);
+      const nomination = await nominate(founder, dao, nominee, market);
+
+      try {
+        await program.methods
+          .disputeResolution()
+          .accounts({ disputer: founder.wallet.publicKey, market, nomination })
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotResolved');
+      }
+    });
+  });
+
+  describe('Nomination Fee', () => {
+    const fee = 0.1 * anchor.web3.LAMPORTS_PER_SOL;
+
+    let founder: Member;
+    let dao: PublicKey;
+    let treasury: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder, { nominationFee: new anchor.BN(fee) });
+      await addFounderMember(founder, dao, founder);
+      treasury = pda(Buffer.from('dao_treasury'), dao.toBuffer());
+
+      await program.methods
+        .initDaoTreasury(0)
+        .accounts({ founder: founder.wallet.publicKey, dao, treasury, systemProgram: SystemProgram.programId })
+        .signers([founder.wallet])
+        .rpc();
+    });
+
+    it('charges the fee into the treasury and records it on the nomination', async () => {
+      const nominee = await createFundedIdentity();
+      const before = await provider.connection.getBalance(treasury);
+
+      const nomination = await nominate(founder, dao, nominee, null, treasury);
+
+      expect(await provider.connection.getBalance(treasury)).to.equal(before + fee);
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.feePaid.toNumber()).to.equal(fee);
+      const treasuryAccount = await program.account.daoTreasury.fetch(treasury);
+      expect(treasuryAccount.totalDeposited.toNumber()).to.equal(fee);
+      expect(treasuryAccount.reservedFees.toNumber()).to.equal(fee);
+    });
+
+    it('holds a pending fee until the nomination closes', async () => {
+      const nominee = await createFundedIdentity();
+      const nomination = await nominate(founder, dao, nominee, null, treasury);
+      expect((await program.account.daoTreasury.fetch(treasury)).reservedFees.toNumber()).to.equal(2 * fee);
+
+      try {
+        await withdrawNomination(founder, dao, nomination, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('TreasuryRequired');
+      }
+
+      // Withdrawing forfeits the fee, so the treasury no longer holds it
+      await withdrawNomination(founder, dao, nomination, nominee, null, treasury);
+      expect((await program.account.daoTreasury.fetch(treasury)).reservedFees.toNumber()).to.equal(fee);
+    });
+
+    it('rejects nominations without the treasury when a fee is set', async () => {
+      const nominee = await createFundedIdentity();
+      try {
+        await nominate(founder, dao, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('TreasuryRequired');
+      }
+    });
+
+    it('lets the founder change the fee', async () => {
+      await updateDaoGovernance(founder, dao, { nominationFee: new anchor.BN(0) });
+      expect((await program.account.creatorDao.fetch(dao)).nominationFee.toNumber()).to.equal(0);
+
+      const nominee = await createFundedIdentity();
+      const nomination = await nominate(founder, dao, nominee);
+      expect((await program.account.nomination.fetch(nomination)).feePaid.toNumber()).to.equal(0);
+    });
+  });
+
+  describe('DAO Regions', () => {
+    let founder: Member;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+    });
+
+    it('stores a known region code', async () => {
+      const dao = await createDao(founder, { regionCode: 3 }); // Europe
+      expect((await program.account.creatorDao.fetch(dao)).regionCode).to.equal(3);
+    });
+
+    it('defaults to the global region', async () => {
+      const dao = await createDao(founder);
+      expect((await program.account.creatorDao.fetch(dao)).regionCode).to.equal(0);
+    });
+
+    it('rejects an unknown region code under strict validation', async () => {
+      try {
+        await createDao(founder, { regionCode: 999 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('UnknownRegion');
+      }
+    });
+
+    it('keeps an unknown region code when strict validation is off', async () => {
+      const dao = await createDao(founder, { regionCode: 999, strictRegion: false });
+      expect((await program.account.creatorDao.fetch(dao)).regionCode).to.equal(999);
+    });
+  });
+
+  describe('Market Segmentation', () => {
+    it("copies the DAO's content type and style tag onto the market", async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { contentType: { music: {} }, styleTag: 'lofi-beats' });
+      await addFounderMember(founder, dao, founder);
+
+      const market = await createMarket(founder, dao, nominee);
+
+      const daoAccount = await program.account.creatorDao.fetch(dao);
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.contentType).to.deep.equal({ music: {} });
+      expect(account.contentType).to.deep.equal(daoAccount.contentType);
+      expect(account.styleTag).to.deep.equal(daoAccount.styleTag);
+    });
+  });
+
+  describe('Protocol Pause', () => {
+    let founder: Member;
+    let dao: PublicKey;
+    let market: PublicKey;
+
+    const setPaused = (paused: boolean, admin: Keypair | null = null) => {
+      const builder = program.methods
+        .setPaused(paused)
+        .accounts({ admin: admin ? admin.publicKey : owner, protocolConfig: protocolConfigPda });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    const expectPaused = async (action: () => Promise<unknown>) => {
+      try {
+        await action();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ProtocolPaused');
+      }
+    };
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(founder, dao, await createFundedIdentity());
+    });
+
+    afterEach(async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      if (config.paused) await setPaused(false);
+    });
+
+    it('blocks new markets, positions and nominations while paused', async () => {
+      const nominee = await createFundedIdentity();
+      await setPaused(true);
+
+      await expectPaused(() => createMarket(founder, dao, nominee));
+      await expectPaused(() => takePosition(founder, market, { yes: {} }, 1_000_000));
+      await expectPaused(() => nominate(founder, dao, nominee));
+    });
+
+    it('restores them once unpaused', async () => {
+      const nominee = await createFundedIdentity();
+      await setPaused(true);
+      await setPaused(false);
+
+      await createMarket(founder, dao, nominee);
+      await takePosition(founder, market, { yes: {} }, 1_000_000);
+      await nominate(founder, dao, nominee);
+    });
+
+    it('rejects pausing by anyone but the admin', async () => {
+      const stranger = await createFundedIdentity();
+      try {
+        await setPaused(true, stranger.wallet);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+  });
+
+  describe('Minimum Accept Votes', () => {
+    let founder: Member;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+    });
+
+    it('stores the minimum and snapshots it on new nominations', async () => {
+      const dao = await createDao(founder, { minAcceptVotes: 3 });
+      await addFounderMember(founder, dao, founder);
+      expect((await program.account.creatorDao.fetch(dao)).minAcceptVotes).to.equal(3);
+
+      const nomination = await nominate(founder, dao, await createFundedIdentity());
+      await updateDaoGovernance(founder, dao, { minAcceptVotes: 5 });
+
+      expect((await program.account.creatorDao.fetch(dao)).minAcceptVotes).to.equal(5);
+      expect((await program.account.nomination.fetch(nomination)).minAcceptVotesSnapshot).to.equal(3);
+    });
+
+    it('rejects a minimum of zero', async () => {
+      try {
+        await createDao(founder, { minAcceptVotes: 0 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidMinAcceptVotes');
+      }
+
+      const dao = await createDao(founder);
+      try {
+        await updateDaoGovernance(founder, dao, { minAcceptVotes: 0 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidMinAcceptVotes');
+      }
+    });
+  });
+
+  describe('Founder Membership', () => {
+    it('requires the founder to be added as member #1', async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      try {
+        await addFounderMember(founder, dao, member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('FounderMustJoinFirst');
+      }
+
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.memberCount).to.equal(2);
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, founder.wallet.publicKey));
+      expect(membership.memberWallet.toBase58()).to.equal(founder.wallet.publicKey.toBase58());
+      expect(membership.isActive).to.be.true;
+    });
+
+    it('never adds the founder twice', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      try {
+        await addFounderMember(founder, dao, founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/already in use|custom program error: 0x0/);
+      }
+
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(1);
+    });
+  });
+
+  describe('Market Reopening', () => {
+    it('reopens a locked market for trading when its nomination is withdrawn', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const predictor = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      const market = await createMarket(founder, dao, nominee);
+      const nomination = await nominate(founder, dao, nominee, market);
+
+      let account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ votingInProgress: {} });
+      try {
+        await takePosition(predictor, market, { yes: {} }, 1_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotOpen');
+      }
+
+      await withdrawNomination(founder, dao, nomination, nominee, market);
+
+      account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ open: {} });
+      expect(account.nomination).to.be.null;
+      expect(account.tradingEndsAt).to.be.null;
+
+      await takePosition(predictor, market, { yes: {} }, 1_000_000);
+      const position = await program.account.marketPosition.fetch(positionPda(market, predictor.wallet.publicKey));
+      expect(position.totalStaked.toNumber()).to.equal(1_000_000);
+
+      // The creator can be nominated again with the same market
+      await nominate(founder, dao, nominee, market);
+      account = await program.account.admissionMarket.fetch(market);
+      expect(account.status).to.deep.equal({ votingInProgress: {} });
+    });
+  });
+
+  describe('Admission Applications', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    const applicationPda = (dao: PublicKey, applicant: Member) =>
+      pda(Buffer.from('application'), dao.toBuffer(), applicant.wallet.publicKey.toBuffer());
+
+    const apply = (applicant: Member, dao: PublicKey, pitch = 'Weekly essays on Solana DeFi') =>
+      program.methods
+        .applyForAdmission({ pitch })
+        .accounts({
+          applicant: applicant.wallet.publicKey,
+          applicantIdentity: applicant.identity,
+          dao,
+          applicantMembership: membershipPda(dao, applicant.wallet.publicKey),
+          application: applicationPda(dao, applicant),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([applicant.wallet])
+        .rpc();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('lets a creator with an identity apply', async () => {
+      const applicant = await createFundedIdentity();
+      await apply(applicant, dao);
+
+      const account = await program.account.application.fetch(applicationPda(dao, applicant));
+      expect(account.applicantWallet.toBase58()).to.equal(applicant.wallet.publicKey.toBase58());
+      expect(account.applicantIdentity.toBase58()).to.equal(applicant.identity.toBase58());
+      expect(Buffer.from(account.pitch).toString().replace(/\0+$/, '')).to.equal('Weekly essays on Solana DeFi');
+      expect((await program.account.creatorDao.fetch(dao)).pendingApplications).to.equal(1);
+    });
+
+    it('rejects applicants without an identity and existing members', async () => {
+      const wallet = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(wallet.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+      const stranger = { wallet, identity: pda(Buffer.from('identity'), wallet.publicKey.toBuffer()) };
+      try {
+        await apply(stranger, dao);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AccountNotInitialized');
+      }
+
+      try {
+        await apply(founder, dao);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AlreadyMember');
+      }
+    });
+
+    it('turns a sponsored application into a nomination', async () => {
+      const applicant = await createFundedIdentity();
+      await apply(applicant, dao);
+      const daoBefore = await program.account.creatorDao.fetch(dao);
+      const nomination = pda(Buffer.from('nomination'), dao.toBuffer(), u64Le(daoBefore.nominationNonce));
+
+      await program.methods
+        .sponsorApplication()
+        .accounts({
+          sponsor: founder.wallet.publicKey,
+          sponsorMembership: membershipPda(dao, founder.wallet.publicKey),
+          sponsorIdentity: founder.identity,
+          dao,
+          application: applicationPda(dao, applicant),
+          applicantIdentity: applicant.identity,
+          applicantWallet: applicant.wallet.publicKey,
+          applicantMembership: membershipPda(dao, applicant.wallet.publicKey),
+          nomination,
+          nomineeLock: nomineeLockPda(dao, applicant.wallet.publicKey),
+          rejectionRecord: rejectionRecordPda(dao, applicant.wallet.publicKey),
+          predictionMarket: null,
+          treasury: null,
+          protocolConfig: protocolConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([founder.wallet])
+        .rpc();
+
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.nomineeWallet.toBase58()).to.equal(applicant.wallet.publicKey.toBase58());
+      expect(account.nominator.toBase58()).to.equal(founder.wallet.publicKey.toBase58());
+      expect(Buffer.from(account.reason).toString().replace(/\0+$/, '')).to.equal('Weekly essays on Solana DeFi');
+
+      expect(await provider.connection.getAccountInfo(applicationPda(dao, applicant))).to.be.null;
+      const daoAfter = await program.account.creatorDao.fetch(dao);
+      expect(daoAfter.pendingApplications).to.equal(daoBefore.pendingApplications - 1);
+      expect(daoAfter.pendingNominations).to.equal(daoBefore.pendingNominations + 1);
+    });
+
+    it('lets a member reject an application and refunds its rent', async () => {
+      const applicant = await createFundedIdentity();
+      await apply(applicant, dao);
+      const pending = (await program.account.creatorDao.fetch(dao)).pendingApplications;
+      const balanceBefore = await provider.connection.getBalance(applicant.wallet.publicKey);
+
+      await program.methods
+        .rejectApplication()
+        .accounts({
+          member: founder.wallet.publicKey,
+          memberMembership: membershipPda(dao, founder.wallet.publicKey),
+          dao,
+          application: applicationPda(dao, applicant),
+          applicantWallet: applicant.wallet.publicKey,
+        })
+        .signers([founder.wallet])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(applicationPda(dao, applicant))).to.be.null;
+      expect(await provider.connection.getBalance(applicant.wallet.publicKey)).to.be.greaterThan(balanceBefore);
+      expect((await program.account.creatorDao.fetch(dao)).pendingApplications).to.equal(pending - 1);
+    });
+  });
+
+  describe('Market Creator Stake', () => {
+    let scout: Member;
+    let dao: PublicKey;
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it("opens the scout's directional position at creation", async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee, 30, { yes: {} }, 2_000_000);
+
+      const position = await program.account.marketPosition.fetch(
+        positionPda(market, scout.wallet.publicKey)
+      );
+      expect(position.market.toBase58()).to.equal(market.toBase58());
+      expect(position.predictor.toBase58()).to.equal(scout.wallet.publicKey.toBase58());
+      expect(position.predictorIdentity!.toBase58()).to.equal(scout.identity.toBase58());
+      expect(position.totalStaked.toNumber()).to.equal(2_000_000);
+      expect(position.yesTokens.toNumber()).to.be.greaterThan(0);
+      expect(position.noTokens.toNumber()).to.equal(0);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.totalYesTokensIssued.toNumber()).to.equal(position.yesTokens.toNumber());
+      expect(account.predictorCount).to.equal(1);
+    });
+
+    it("refuses to track a position against someone else's identity", async () => {
+      const market = await createMarket(scout, dao, await createFundedIdentity());
+      const predictor = await createFundedIdentity();
+
+      try {
+        await takePosition({ ...predictor, identity: scout.identity }, market, { yes: {} }, 1_000_000);
+        expect.fail('Should have thrown');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+    });
+
+    it('lets the scout open on the NO side', async () => {
+      const nominee = await createFundedIdentity();
+      const market = await createMarket(scout, dao, nominee, 30, { no: {} });
+
+      const position = await program.account.marketPosition.fetch(
+        positionPda(market, scout.wallet.publicKey)
+      );
+      expect(position.noTokens.toNumber()).to.be.greaterThan(0);
+      expect(position.yesTokens.toNumber()).to.equal(0);
+    });
+
+    it('rejects an opening stake below the factory minimum', async () => {
+      await ensureMarketFactory();
+      await updateFactoryConfig({ minCreatorStake: new anchor.BN(5_000_000) });
+
+      try {
+        await createMarket(scout, dao, await createFundedIdentity(), 30, { yes: {} }, 4_999_999);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CreatorStakeTooLow');
+      } finally {
+        await updateFactoryConfig({ minCreatorStake: new anchor.BN(100_000) });
+      }
+    });
+
+    it('rejects a zero opening stake even without a minimum', async () => {
+      await updateFactoryConfig({ minCreatorStake: new anchor.BN(0) });
+
+      try {
+        await createMarket(scout, dao, await createFundedIdentity(), 30, { yes: {} }, 0);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CreatorStakeTooLow');
+      } finally {
+        await updateFactoryConfig({ minCreatorStake: new anchor.BN(100_000) });
+      }
+    });
+  });
+
+  // Rejections need a resolved vote, which takes at least a day, so the
+  // cooldown window itself is covered by the RejectionRecord unit tests
+  describe('Rejection Cooldown', () => {
+    const WEEK = 7 * 86400;
+
+    it('stores the rejection cooldown and lets the founder change it', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder, { rejectionCooldown: new anchor.BN(WEEK) });
+      expect((await program.account.creatorDao.fetch(dao)).rejectionCooldown.toNumber()).to.equal(WEEK);
+
+      await updateDaoGovernance(founder, dao, { rejectionCooldown: new anchor.BN(2 * WEEK) });
+      expect((await program.account.creatorDao.fetch(dao)).rejectionCooldown.toNumber()).to.equal(2 * WEEK);
+    });
+
+    it('nominates creators the DAO never rejected', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { rejectionCooldown: new anchor.BN(WEEK) });
+      await addFounderMember(founder, dao, founder);
+
+      await nominate(founder, dao, nominee);
+
+      expect(await provider.connection.getAccountInfo(rejectionRecordPda(dao, nominee.wallet.publicKey))).to.be.null;
+      expect((await program.account.creatorDao.fetch(dao)).pendingNominations).to.equal(1);
+    });
+
+    it('rejects a negative cooldown', async () => {
+      const founder = await createFundedIdentity();
+      try {
+        await createDao(founder, { rejectionCooldown: new anchor.BN(-1) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidRejectionCooldown');
+      }
+
+      const dao = await createDao(founder);
+      try {
+        await updateDaoGovernance(founder, dao, { rejectionCooldown: new anchor.BN(-1) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidRejectionCooldown');
+      }
+    });
+  });
+
+  describe('Founder Reputation', () => {
+    const setMinFounderComposite = (min: number, admin: Keypair | null = null) => {
+      const builder = program.methods
+        .setMinFounderComposite(min)
+        .accounts({ admin: admin ? admin.publicKey : owner, protocolConfig: protocolConfigPda });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    afterEach(async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      if (config.minFounderComposite !== 0) await setMinFounderComposite(0);
+    });
+
+    it('rejects founders below the minimum composite score', async () => {
+      // Fresh identities start with a composite score of 0
+      const founder = await createFundedIdentity();
+      await setMinFounderComposite(1);
+
+      try {
+        await createDao(founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('FounderScoreTooLow');
+      }
+
+      await setMinFounderComposite(0);
+      await createDao(founder);
+    });
+
+    it("rejects another wallet's identity as the founder's", async () => {
+      const founder = await createFundedIdentity();
+      const other = await createFundedIdentity();
+
+      try {
+        await createDao(founder, {}, other.identity);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+    });
+
+    it('rejects a founder without an identity', async () => {
+      const wallet = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(wallet.publicKey, anchor.web3.LAMPORTS_PER_SOL)
+      );
+      const founder = { wallet, identity: pda(Buffer.from('identity'), wallet.publicKey.toBuffer()) };
+
+      try {
+        await createDao(founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AccountNotInitialized');
+      }
+    });
+
+    it('restricts the minimum to the admin and valid scores', async () => {
+      const stranger = await createFundedIdentity();
+      try {
+        await setMinFounderComposite(100, stranger.wallet);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+
+      try {
+        await setMinFounderComposite(10001);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidScore');
+      }
+    });
+  });
+
+  describe('Nominee Identity', () => {
+    it("rejects a nominee wallet paired with someone else's identity", async () => {
+      const founder = await createFundedIdentity();
+      const walletOwner = await createFundedIdentity();
+      const identityOwner = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      try {
+        await nominate(founder, dao, { wallet: walletOwner.wallet, identity: identityOwner.identity });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/NomineeIdentityMismatch|ConstraintSeeds/);
+      }
+
+      expect((await program.account.creatorDao.fetch(dao)).pendingNominations).to.equal(0);
+      expect(await provider.connection.getAccountInfo(nomineeLockPda(dao, walletOwner.wallet.publicKey))).to.be.null;
+    });
+  });
+
+  // Creator scores can't be raised on localnet yet, so the bonus comparison
+  // between DAOs of different quality lives in the CreatorDAO unit tests
+  describe('DAO Member Quality', () => {
+    it("sums founder members' creator scores as they join", async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.memberCount).to.equal(2);
+      // Fresh identities have a creator score of 0
+      expect(account.totalMemberCreatorScore.toNumber()).to.equal(0);
+    });
+
+    it("rejects a founder member paired with someone else's identity", async () => {
+      const founder = await createFundedIdentity();
+      const member = await createFundedIdentity();
+      const other = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      try {
+        await addFounderMember(founder, dao, { wallet: member.wallet, identity: other.identity });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(1);
+    });
+  });
+
+  describe('Batch Founder Members', () => {
+    const memberDirectoryPda = (wallet: PublicKey) =>
+      pda(Buffer.from('member_directory'), wallet.toBuffer());
+
+    const addFounderMembersBatch = (founder: Member, dao: PublicKey, members: Member[]) =>
+      program.methods
+        .addFounderMembersBatch()
+        .accounts({ founder: founder.wallet.publicKey, dao, systemProgram: SystemProgram.programId })
+        .remainingAccounts(
+          members.flatMap((member) => [
+            { pubkey: member.identity, isSigner: false, isWritable: false },
+            { pubkey: membershipPda(dao, member.wallet.publicKey), isSigner: false, isWritable: true },
+            { pubkey: memberDirectoryPda(member.wallet.publicKey), isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([founder.wallet])
+        .rpc();
+
+    it('adds the founder and several members in one transaction', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      const members = [founder];
+      for (let i = 0; i < 4; i++) members.push(await createFundedIdentity());
+
+      await addFounderMembersBatch(founder, dao, members);
+
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(members.length);
+      for (const member of members) {
+        const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+        expect(membership.memberWallet.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+        expect(membership.memberIdentity.toBase58()).to.equal(member.identity.toBase58());
+        expect(membership.isActive).to.be.true;
+
+        const directory = await program.account.memberDirectory.fetch(memberDirectoryPda(member.wallet.publicKey));
+        expect(directory.daos.map((d: PublicKey) => d.toBase58())).to.include(dao.toBase58());
+      }
+
+      // Later batches extend the same DAO
+      const late = await createFundedIdentity();
+      await addFounderMembersBatch(founder, dao, [late]);
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(members.length + 1);
+    });
+
+    it('requires the founder to be in the first batch', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      try {
+        await addFounderMembersBatch(founder, dao, [await createFundedIdentity(), founder]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('FounderMustJoinFirst');
+      }
+      expect((await program.account.creatorDao.fetch(dao)).memberCount).to.equal(0);
+    });
+
+    it('rejects members who already joined', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      try {
+        await addFounderMembersBatch(founder, dao, [founder]);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AlreadyMember');
+      }
+    });
+
+    it('rejects malformed batches', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+
+      try {
+        await program.methods
+          .addFounderMembersBatch()
+          .accounts({ founder: founder.wallet.publicKey, dao, systemProgram: SystemProgram.programId })
+          .remainingAccounts([
+            { pubkey: founder.identity, isSigner: false, isWritable: false },
+            { pubkey: membershipPda(dao, founder.wallet.publicKey), isSigner: false, isWritable: true },
+          ])
+          .signers([founder.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidMemberBatch');
+      }
+    });
+  });
+
+  // `.view()` simulates the instruction and reads its return data, exactly
+  // what a CPI caller gets back. No instruction deactivates a membership
+  // yet, so the removed-member case is covered by unit tests.
+  describe('Active Member Check', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    const isActiveMember = (wallet: PublicKey): Promise<boolean> =>
+      program.methods
+        .assertActiveMember()
+        .accounts({ dao, wallet, membership: membershipPda(dao, wallet) })
+        .view();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('returns true for an active member', async () => {
+      expect(await isActiveMember(founder.wallet.publicKey)).to.be.true;
+    });
+
+    it('returns false for a non-member without erroring', async () => {
+      const stranger = await createFundedIdentity();
+      expect(await isActiveMember(stranger.wallet.publicKey)).to.be.false;
+    });
+
+    it('returns false for a member of a different DAO', async () => {
+      const otherFounder = await createFundedIdentity();
+      const otherDao = await createDao(otherFounder);
+      await addFounderMember(otherFounder, otherDao, otherFounder);
+
+      expect(await isActiveMember(otherFounder.wallet.publicKey)).to.be.false;
+    });
+  });
+
+  describe('Initial Market Price', () => {
+    let scout: Member;
+    let dao: PublicKey;
+
+    // The scout's minimum opening stake nudges the price a little off the
+    // seeded one, towards their side
+    const openingPrice = async (side: object, initialYesBps: number | null) => {
+      const market = await createMarket(scout, dao, await createFundedIdentity(), 30, side, 100_000, initialYesBps);
+      const account = await program.account.admissionMarket.fetch(market);
+      return Math.floor((account.noPool.toNumber() * 10000) / (account.yesPool.toNumber() + account.noPool.toNumber()));
+    };
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('opens a market at the requested YES price', async () => {
+      const skewedUp = await openingPrice({ yes: {} }, 7000);
+      expect(skewedUp).to.be.at.least(7000).and.below(7100);
+
+      const skewedDown = await openingPrice({ no: {} }, 3000);
+      expect(skewedDown).to.be.at.most(3000).and.above(2900);
+    });
+
+    it('defaults to 50/50', async () => {
+      const price = await openingPrice({ yes: {} }, null);
+      expect(price).to.be.at.least(5000).and.below(5100);
+    });
+
+    it('rejects starting prices outside 10-90%', async () => {
+      for (const initialYesBps of [999, 9001]) {
+        try {
+          await openingPrice({ yes: {} }, initialYesBps);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include('InvalidInitialPrice');
+        }
+      }
+    });
+  });
+
+  describe('Membership Consent', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    const acceptMembership = async (creator: Member) =>
+      program.methods
+        .acceptMembership()
+        .accounts({
+          creator: creator.wallet.publicKey,
+          dao,
+          membership: membershipPda(dao, creator.wallet.publicKey),
+          creatorIdentity: creator.identity,
+          creatorScore: await initAllDetails(creator),
+          memberDirectory: pda(Buffer.from('member_directory'), creator.wallet.publicKey.toBuffer()),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator.wallet])
+        .rpc();
+
+    const declineMembership = async (creator: Member) =>
+      program.methods
+        .declineMembership()
+        .accounts({
+          creator: creator.wallet.publicKey,
+          dao,
+          membership: membershipPda(dao, creator.wallet.publicKey),
+        })
+        .signers([creator.wallet])
+        .rpc();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    // Pending memberships only come out of resolve_nomination, which can't
+    // run on localnet; the consent window is covered by the program's unit
+    // tests
+    it('rejects accepting a membership that is already active', async () => {
+      try {
+        await acceptMembership(founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotPendingConsent');
+      }
+    });
+
+    it('rejects declining a membership that is already active', async () => {
+      try {
+        await declineMembership(founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotPendingConsent');
+      }
+
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, founder.wallet.publicKey));
+      expect(membership.isActive).to.be.true;
+    });
+
+    it('rejects accepting without having been admitted', async () => {
+      const stranger = await createFundedIdentity();
+      try {
+        await acceptMembership(stranger);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('AccountNotInitialized');
+      }
+    });
+  });
+
+  describe('One-Sided Positions', () => {
+    let scout: Member;
+    let market: PublicKey;
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, await createFundedIdentity());
+    });
+
+    it('lets a predictor add to the side they already hold', async () => {
+      const predictor = await createFundedIdentity();
+      await takePosition(predictor, market, { no: {} }, 1_000_000);
+      await takePosition(predictor, market, { no: {} }, 1_000_000);
+
+      const position = await program.account.marketPosition.fetch(positionPda(market, predictor.wallet.publicKey));
+      expect(position.noTokens.toNumber()).to.be.greaterThan(0);
+      expect(position.yesTokens.toNumber()).to.equal(0);
+    });
+
+    it('rejects a predictor hedging into the opposite side', async () => {
+      const predictor = await createFundedIdentity();
+      await takePosition(predictor, market, { yes: {} }, 1_000_000);
+
+      try {
+        await takePosition(predictor, market, { no: {} }, 1_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('OpposingPositionHeld');
+      }
+
+      const position = await program.account.marketPosition.fetch(positionPda(market, predictor.wallet.publicKey));
+      expect(position.noTokens.toNumber()).to.equal(0);
+    });
+
+    it('holds the market creator to their opening side', async () => {
+      // The scout opened the market on YES
+      try {
+        await takePosition(scout, market, { no: {} }, 1_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('OpposingPositionHeld');
+      }
+    });
+  });
+
+  describe('Vote Audit Trail', () => {
+    const voterHash = (voter: Member) => Array.from(keccak_256(voter.wallet.publicKey.toBuffer()));
+
+    let founder: Member;
+    let members: Member[];
+    let dao: PublicKey;
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      members = [await createFundedIdentity(), await createFundedIdentity()];
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      for (const member of members) {
+        await addFounderMember(founder, dao, member);
+      }
+    });
+
+    it('emits NominationOpened when a nomination opens', async () => {
+      const nominee = await createFundedIdentity();
+      const daoAccount = await program.account.creatorDao.fetch(dao);
+      const nomination = pda(Buffer.from('nomination'), dao.toBuffer(), u64Le(daoAccount.nominationNonce));
+
+      const signature = await program.methods
+        .nominateCreator({ reason: 'Consistently excellent essays' })
+        .accounts({
+          nominator: founder.wallet.publicKey,
+          nominatorMembership: membershipPda(dao, founder.wallet.publicKey),
+          nominatorIdentity: founder.identity,
+          dao,
+          nomineeIdentity: nominee.identity,
+          nomineeWallet: nominee.wallet.publicKey,
+          nomineeMembership: membershipPda(dao, nominee.wallet.publicKey),
+          nomination,
+          nomineeLock: nomineeLockPda(dao, nominee.wallet.publicKey),
+          rejectionRecord: rejectionRecordPda(dao, nominee.wallet.publicKey),
+          predictionMarket: null,
+          treasury: null,
+          protocolConfig: protocolConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([founder.wallet])
+        .rpc();
+
+      const [event] = await eventsIn(signature, 'NominationOpened');
+      const account = await program.account.nomination.fetch(nomination);
+      expect(event.nomination.toBase58()).to.equal(nomination.toBase58());
+      expect(event.nominationId.toNumber()).to.equal(account.nominationId.toNumber());
+      expect(event.nomineeWallet.toBase58()).to.equal(nominee.wallet.publicKey.toBase58());
+      expect(event.nominator.toBase58()).to.equal(founder.wallet.publicKey.toBase58());
+      expect(event.membersSnapshot).to.equal(3);
+      expect(event.votingEndsAt.toNumber()).to.equal(account.votingEndsAt.toNumber());
+    });
+
+    it('emits VoteCast with running tallies matching the nomination after each vote', async () => {
+      const nomination = await nominate(founder, dao, await createFundedIdentity());
+
+      const voters = [founder, ...members];
+      for (const [i, voter] of voters.entries()) {
+        const salt = Keypair.generate().publicKey.toBytes();
+        const signature = await castVote(voter, dao, nomination, { accept: {} }, null, salt);
+
+        const [event] = await eventsIn(signature, 'VoteCast');
+        const account = await program.account.nomination.fetch(nomination);
+        expect(event.nomination.toBase58()).to.equal(nomination.toBase58());
+        expect(Array.from(event.voterHash)).to.deep.equal(voterHash(voter));
+        expect(Array.from(event.choiceCommitted)).to.deep.equal(voteCommitment({ accept: {} }, salt));
+        expect(event.voterCount).to.equal(i + 1);
+        expect(event.voterCount).to.equal(account.voterCount);
+        expect([event.runningAccept, event.runningReject, event.runningAbstain]).to.deep.equal([
+          account.votesAccept,
+          account.votesReject,
+          account.votesAbstain,
+        ]);
+      }
+    });
+  });
+
+  describe('Tier Change Events', () => {
+    let member: Member;
+
+    const updateTradingScore = (score: number) =>
+      program.methods
+        .updateTradingScore(score, null)
+        .accounts({
+          authority: member.wallet.publicKey,
+          identity: member.identity,
+          tierConfig: tierConfigPda,
+          protocolConfig: protocolConfigPda,
+          scoreHistory: scoreHistoryPda(member.identity),
+        })
+        .signers([member.wallet])
+        .rpc();
+
+    before(async () => {
+      // New identities are their own trading authority
+      member = await createFundedIdentity();
+    });
+
+    it('stays quiet for an update that keeps the tier', async () => {
+      // 5000 * 30% = 1500, still Bronze
+      const signature = await updateTradingScore(5000);
+      expect(await eventsIn(signature, 'TierChanged')).to.be.empty;
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).tier).to.equal(1);
+    });
+
+    it('emits TierChanged for an update that crosses a boundary', async () => {
+      // 8000 * 30% = 2400, Silver
+      const signature = await updateTradingScore(8000);
+      const events = await eventsIn(signature, 'TierChanged');
+      expect(events).to.have.lengthOf(1);
+      expect(events[0].identity.toBase58()).to.equal(member.identity.toBase58());
+      expect(events[0].oldTier).to.equal(1);
+      expect(events[0].newTier).to.equal(2);
+      expect(events[0].compositeScore).to.equal(2400);
+    });
+  });
+
+  describe('Minimum Members for Nominations', () => {
+    it('defaults to three members before a DAO can nominate', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder, { minMembersForNominations: null });
+      await addFounderMember(founder, dao, founder);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.minMembersForNominations).to.equal(3);
+    });
+
+    it('refuses nominations until enough founders are added', async () => {
+      const founder = await createFundedIdentity();
+      const second = await createFundedIdentity();
+      const third = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { minMembersForNominations: 3 });
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, second);
+
+      try {
+        await nominate(founder, dao, nominee);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('DAOTooSmall');
+      }
+
+      await addFounderMember(founder, dao, third);
+      const nomination = await nominate(founder, dao, nominee);
+
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.nomineeWallet.toBase58()).to.equal(nominee.wallet.publicKey.toBase58());
+    });
+
+    it('lets the founder lower the minimum', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const dao = await createDao(founder, { minMembersForNominations: 3 });
+      await addFounderMember(founder, dao, founder);
+
+      await updateDaoGovernance(founder, dao, { minMembersForNominations: 1 });
+      await nominate(founder, dao, nominee);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.pendingNominations).to.equal(1);
+    });
+  });
+
+  describe('Pre-Delegated Authorities', () => {
+    const createIdentityWith = async (authorities: Record<string, PublicKey | null>): Promise<Member> => {
+      const wallet = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL)
+      );
+      const identity = pda(Buffer.from('identity'), wallet.publicKey.toBuffer());
+
+      await program.methods
+        .createIdentity({ trading: null, civic: null, developer: null, infra: null, creator: null, ...authorities })
+        .accounts({ owner: wallet.publicKey, identity, scoreHistory: scoreHistoryPda(identity), systemProgram: SystemProgram.programId })
+        .signers([wallet])
+        .rpc();
+
+      return { wallet, identity };
+    };
+
+    it('sets each passed authority and defaults the rest to the owner', async () => {
+      const trading = Keypair.generate().publicKey;
+      const member = await createIdentityWith({ trading });
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tradingAuthority.toBase58()).to.equal(trading.toBase58());
+      expect(identity.civicAuthority.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+      expect(identity.developerAuthority.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+      expect(identity.infraAuthority.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+    });
+
+    it('lets every delegated authority update straight away', async () => {
+      const trading = Keypair.generate();
+      const civic = Keypair.generate();
+      const developer = Keypair.generate();
+      const infra = Keypair.generate();
+      const creator = Keypair.generate();
+      const member = await createIdentityWith({
+        trading: trading.publicKey,
+        civic: civic.publicKey,
+        developer: developer.publicKey,
+        infra: infra.publicKey,
+        creator: creator.publicKey,
+      });
+
+      const accounts = (authority: Keypair) => ({
+        authority: authority.publicKey,
+        identity: member.identity,
+        tierConfig: tierConfigPda,
+        protocolConfig: protocolConfigPda,
+        scoreHistory: scoreHistoryPda(member.identity),
+      });
+      await program.methods.updateTradingScore(5000, null).accounts(accounts(trading)).signers([trading]).rpc();
+      await program.methods.updateCivicScore(6000, null).accounts(accounts(civic)).signers([civic]).rpc();
+      await program.methods.updateDeveloperScore(7000, null).accounts(accounts(developer)).signers([developer]).rpc();
+      await program.methods.updateInfraScore(8000, null).accounts(accounts(infra)).signers([infra]).rpc();
+
+      const creatorDetails = await initAllDetails(member);
+      await program.methods
+        .syncCreatorScore()
+        .accounts({ ...accounts(creator), creatorDetails })
+        .signers([creator])
+        .rpc();
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tradingScore).to.equal(5000);
+      expect(identity.civicScore).to.equal(6000);
+      expect(identity.developerScore).to.equal(7000);
+      expect(identity.infraScore).to.equal(8000);
+      expect(identity.creatorAuthority.toBase58()).to.equal(creator.publicKey.toBase58());
+    });
+
+    it('rejects the default key as an authority', async () => {
+      try {
+        await createIdentityWith({ civic: PublicKey.default });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidAuthority');
+      }
+    });
+  });
+
+  describe('Market State View', () => {
+    it('returns the odds, depth and a sample quote matching the pools', async () => {
+      const founder = await createFundedIdentity();
+      const nominee = await createFundedIdentity();
+      const predictor = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const market = await createMarket(founder, dao, nominee);
+      await takePosition(predictor, market, { no: {} }, 2_000_000);
+
+      const sampleStake = new anchor.BN(500_000);
+      const state = await program.methods.getMarketState(sampleStake).accounts({ market }).view();
+      const account = await program.account.admissionMarket.fetch(market);
+
+      // Recompute the constant-product quote off the fetched reserves
+      const yes = BigInt(account.yesPool.toString());
+      const no = BigInt(account.noPool.toString());
+      const net = 500_000n - (500_000n * BigInt(account.feeBps)) / 10_000n;
+      const expectedYes = yes - (yes * no) / (no + net);
+      const expectedNo = no - (yes * no) / (yes + net);
+      const yesPriceBps = Number((no * 10_000n) / (yes + no));
+
+      expect(state.yesPriceBps).to.equal(yesPriceBps);
+      expect(state.noPriceBps).to.equal(10_000 - yesPriceBps);
+      expect(state.yesPool.toString()).to.equal(account.yesPool.toString());
+      expect(state.noPool.toString()).to.equal(account.noPool.toString());
+      expect(state.predictorCount).to.equal(account.predictorCount);
+      expect(state.accumulatedFees.toString()).to.equal(account.accumulatedFees.toString());
+      expect(state.sampleStake.toString()).to.equal(sampleStake.toString());
+      expect(state.sampleYesTokens.toString()).to.equal(expectedYes.toString());
+      expect(state.sampleNoTokens.toString()).to.equal(expectedNo.toString());
+
+      // The NO buy moved the odds towards rejection
+      expect(state.yesPriceBps).to.be.lessThan(5000);
+    });
+  });
+
+  describe('DAO Registry', () => {
+    const readRegistry = async (daoIds: anchor.BN[], contentType: object | null, styleTag: string | null) => {
+      const pages = [...new Set(daoIds.map((id) => registryPagePda(id).toBase58()))];
+      const entries = [];
+      for (const page of pages) {
+        entries.push(
+          ...(await program.methods
+            .getDaoRegistryPage(contentType, styleTag)
+            .accounts({ registryPage: new PublicKey(page) })
+            .view())
+        );
+      }
+      return entries.map((entry: any) => entry.dao.toBase58());
+    };
+
+    it('lists new DAOs and filters them by content type and style tag', async () => {
+      const styleTag = `registry-${Date.now()}`;
+      const created: { dao: PublicKey; daoId: anchor.BN }[] = [];
+      for (const contentType of [{ music: {} }, { code: {} }, { music: {} }]) {
+        const founder = await createFundedIdentity();
+        const dao = await createDao(founder, { contentType, styleTag });
+        const { daoId } = await program.account.creatorDao.fetch(dao);
+        created.push({ dao, daoId });
+      }
+      const ids = created.map(({ daoId }) => daoId);
+      const [music1, code, music2] = created.map(({ dao }) => dao.toBase58());
+
+      expect(await readRegistry(ids, null, styleTag)).to.deep.equal([music1, code, music2]);
+      expect(await readRegistry(ids, { music: {} }, styleTag)).to.deep.equal([music1, music2]);
+      expect(await readRegistry(ids, { code: {} }, styleTag)).to.deep.equal([code]);
+      expect(await readRegistry(ids, { podcasts: {} }, styleTag)).to.deep.equal([]);
+    });
+
+    it('records each DAO on the page for its ID', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      const { daoId, contentType } = await program.account.creatorDao.fetch(dao);
+
+      const page = await program.account.daoRegistryPage.fetch(registryPagePda(daoId));
+      expect(page.page).to.equal(Math.floor(daoId.toNumber() / 12));
+      const entry = page.entries.find((e: any) => e.dao.equals(dao));
+      expect(entry).to.not.be.undefined;
+      expect(entry.contentType).to.deep.equal(contentType);
+    });
+  });
+
+  // Finalization needs a resolved market, which can't be reached on
+  // localnet; the rebate math is covered by the program's unit tests
+  describe('Seeder Fee Rebate', () => {
+    let scout: Member;
+    let market: PublicKey;
+
+    const finalizeMarket = (keeper: Member, marketCreator: PublicKey) =>
+      program.methods
+        .finalizeMarket()
+        .accounts({ keeper: keeper.wallet.publicKey, market, marketCreator })
+        .signers([keeper.wallet])
+        .rpc();
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      scout = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(scout, dao, await createFundedIdentity());
+    });
+
+    it('copies the factory fee share onto new markets', async () => {
+      const factory = await program.account.marketFactory.fetch(factoryPda);
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.seederFeeShareBps).to.equal(factory.seederFeeShareBps);
+      expect(account.seederFeesPaid.toNumber()).to.equal(0);
+    });
+
+    it('accumulates fees across trades without paying the seeder early', async () => {
+      const before = await program.account.admissionMarket.fetch(market);
+      for (const [side, amount] of [
+        [{ yes: {} }, 2_000_000],
+        [{ no: {} }, 3_000_000],
+      ] as [object, number][]) {
+        await takePosition(await createFundedIdentity(), market, side, amount);
+      }
+
+      const after = await program.account.admissionMarket.fetch(market);
+      const fees = after.accumulatedFees.sub(before.accumulatedFees).toNumber();
+      expect(fees).to.equal(Math.floor((5_000_000 * after.feeBps) / 10_000));
+      expect(after.seederFeesPaid.toNumber()).to.equal(0);
+    });
+
+    it('refuses to finalize a market that has not resolved', async () => {
+      try {
+        await finalizeMarket(scout, scout.wallet.publicKey);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('MarketNotResolved');
+      }
+    });
+
+    it('rejects a fee share above 100%', async () => {
+      try {
+        await updateFactoryConfig({ seederFeeShareBps: 10001 });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidFeeConfig');
+      }
+    });
+  });
+
+  describe('Revoke All Authorities', () => {
+    it('hands every dimension back to the owner in one transaction', async () => {
+      const member = await createFundedIdentity();
+      const owner = member.wallet;
+      const oracles = Array.from({ length: 5 }, () => Keypair.generate().publicKey);
+      const [trading, civic, developer, infra, creator] = oracles;
+
+      for (const [method, authority] of [
+        ['setTradingAuthority', trading],
+        ['setCivicAuthority', civic],
+        ['setDeveloperAuthority', developer],
+        ['setInfraAuthority', infra],
+        ['setCreatorAuthority', creator],
+      ] as [string, PublicKey][]) {
+        await (program.methods as any)
+          [method](authority)
+          .accounts({ owner: owner.publicKey, identity: member.identity })
+          .signers([owner])
+          .rpc();
+      }
+      const delegated = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(delegated.creatorAuthority.toBase58()).to.equal(creator.toBase58());
+
+      await program.methods
+        .revokeAllAuthorities()
+        .accounts({ owner: owner.publicKey, identity: member.identity })
+        .signers([owner])
+        .rpc();
+
+      const accounts = { authority: owner.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) };
+      await program.methods.updateTradingScore(5000, null).accounts(accounts).signers([owner]).rpc();
+      await program.methods.updateCivicScore(6000, null).accounts(accounts).signers([owner]).rpc();
+      await program.methods.updateDeveloperScore(7000, null).accounts(accounts).signers([owner]).rpc();
+      await program.methods.updateInfraScore(8000, null).accounts(accounts).signers([owner]).rpc();
+      const creatorDetails = await initAllDetails(member);
+      await program.methods
+        .syncCreatorScore()
+        .accounts({ ...accounts, creatorDetails })
+        .signers([owner])
+        .rpc();
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      for (const authority of [
+        identity.tradingAuthority,
+        identity.civicAuthority,
+        identity.developerAuthority,
+        identity.infraAuthority,
+        identity.creatorAuthority,
+      ]) {
+        expect(authority.toBase58()).to.equal(owner.publicKey.toBase58());
+      }
+      expect(identity.tradingScore).to.equal(5000);
+      expect(identity.civicScore).to.equal(6000);
+      expect(identity.developerScore).to.equal(7000);
+      expect(identity.infraScore).to.equal(8000);
+    });
+
+    it('rejects a revoke signed by anyone but the owner', async () => {
+      const member = await createFundedIdentity();
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .revokeAllAuthorities()
+          .accounts({ owner: stranger.publicKey, identity: member.identity })
+          .signers([stranger])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+    });
+  });
+
+  describe('Voting Period Bounds', () => {
+    const THIRTY_DAYS = 30 * 86400;
+
+    const setMaxVotingPeriod = (max: number, admin: Keypair | null = null) => {
+      const builder = program.methods
+        .setMaxVotingPeriod(new anchor.BN(max))
+        .accounts({ admin: admin ? admin.publicKey : owner, protocolConfig: protocolConfigPda });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    afterEach(async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      if (config.maxVotingPeriod.toNumber() !== THIRTY_DAYS) await setMaxVotingPeriod(THIRTY_DAYS);
+    });
+
+    it('defaults the maximum to 30 days', async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      expect(config.maxVotingPeriod.toNumber()).to.equal(THIRTY_DAYS);
+    });
+
+    it('accepts a voting period up to the maximum', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder, { votingPeriod: new anchor.BN(THIRTY_DAYS) });
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.votingPeriod.toNumber()).to.equal(THIRTY_DAYS);
+    });
+
+    it('rejects a DAO whose voting period exceeds the maximum', async () => {
+      const founder = await createFundedIdentity();
+
+      try {
+        await createDao(founder, { votingPeriod: new anchor.BN(10 * 365 * 86400) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('VotingPeriodTooLong');
+      }
+    });
+
+    it('applies a lowered maximum to new DAOs and governance updates', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder, { votingPeriod: new anchor.BN(7 * 86400) });
+      await setMaxVotingPeriod(3 * 86400);
+
+      try {
+        await createDao(founder, { votingPeriod: new anchor.BN(7 * 86400) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('VotingPeriodTooLong');
+      }
+
+      try {
+        await updateDaoGovernance(founder, dao, { votingPeriod: new anchor.BN(4 * 86400) });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('VotingPeriodTooLong');
+      }
+
+      await updateDaoGovernance(founder, dao, { votingPeriod: new anchor.BN(3 * 86400) });
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.votingPeriod.toNumber()).to.equal(3 * 86400);
+    });
+
+    it('restricts the maximum to the admin and at least one day', async () => {
+      const stranger = await createFundedIdentity();
+      try {
+        await setMaxVotingPeriod(THIRTY_DAYS * 2, stranger.wallet);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+
+      try {
+        await setMaxVotingPeriod(86399);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidVotingPeriodBound');
+      }
+    });
+  });
+
+  describe('Claim All Winnings', () => {
+    let founder: Member;
+    let dao: PublicKey;
+
+    const expireStaleMarket = (keeper: Member, market: PublicKey) =>
+      program.methods
+        .expireStaleMarket()
+        .accounts({ keeper: keeper.wallet.publicKey, market })
+        .signers([keeper.wallet])
+        .rpc();
+
+    const claimAllWinnings = async (
+      predictor: Member,
+      markets: PublicKey[],
+      withSurfacingScore = true,
+      withCreatorScore = true
+    ) =>
+      program.methods
+        .claimAllWinnings()
+        .accounts({
+          predictor: predictor.wallet.publicKey,
+          creatorScore: withCreatorScore ? await ensureCreatorDetails(predictor) : null,
+          surfacingScore: withSurfacingScore ? surfacingScorePda(predictor) : null,
+          burnTreasury: burnAddress,
+        })
+        .remainingAccounts(
+          markets.flatMap((market) => [
+            { pubkey: market, isSigner: false, isWritable: true },
+            { pubkey: positionPda(market, predictor.wallet.publicKey), isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([predictor.wallet])
+        .rpc();
+
+    // Expired markets are the only ones claimable on localnet, so the scout's
+    // opening positions stand in for a predictor's
+    const expiredMarkets = async (scout: Member, count: number): Promise<PublicKey[]> => {
+      const markets: PublicKey[] = [];
+      for (let i = 0; i < count; i++) {
+        markets.push(await createMarket(scout, dao, await createFundedIdentity(), 0));
+      }
+      await sleep(2500);
+      for (const market of markets) await expireStaleMarket(scout, market);
+      return markets;
+    };
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+    });
+
+    it('settles three markets in one call', async () => {
+      const scout = await createFundedIdentity();
+      const markets = await expiredMarkets(scout, 3);
+      const before = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      const balanceBefore = await provider.connection.getBalance(scout.wallet.publicKey);
+
+      await claimAllWinnings(scout, markets);
+
+      for (const market of markets) {
+        const position = await program.account.marketPosition.fetch(positionPda(market, scout.wallet.publicKey));
+        expect(position.claimed).to.be.true;
+        expect(position.payout.toNumber()).to.equal(1_000_000);
+      }
+      const balanceAfter = await provider.connection.getBalance(scout.wallet.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(3_000_000);
+
+      const after = await program.account.surfacingScore.fetch(surfacingScorePda(scout));
+      expect(after.marketsCancelled).to.equal(before.marketsCancelled + 3);
+    });
+
+    it('skips unclaimable and already claimed positions instead of failing', async () => {
+      const scout = await createFundedIdentity();
+      const [claimed, fresh] = await expiredMarkets(scout, 2);
+      const open = await createMarket(scout, dao, await createFundedIdentity());
+      await claimAllWinnings(scout, [claimed]);
+
+      await claimAllWinnings(scout, [claimed, open, fresh]);
+
+      const [openPosition, freshPosition] = await Promise.all([
+        program.account.marketPosition.fetch(positionPda(open, scout.wallet.publicKey)),
+        program.account.marketPosition.fetch(positionPda(fresh, scout.wallet.publicKey)),
+      ]);
+      expect(openPosition.claimed).to.be.false;
+      expect(freshPosition.claimed).to.be.true;
+    });
+
+    it("leaves a scout's own markets alone without their surfacing score", async () => {
+      const scout = await createFundedIdentity();
+      const [market] = await expiredMarkets(scout, 1);
+
+      await claimAllWinnings(scout, [market], false);
+
+      const position = await program.account.marketPosition.fetch(positionPda(market, scout.wallet.publicKey));
+      expect(position.claimed).to.be.false;
+    });
+
+    it('refuses to leave an identity-backed position untracked', async () => {
+      const scout = await createFundedIdentity();
+      const [market] = await expiredMarkets(scout, 1);
+
+      try {
+        await claimAllWinnings(scout, [market], true, false);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('CreatorDetailsNotInitialized');
+      }
+    });
+
+    it('rejects an empty batch', async () => {
+      const scout = await createFundedIdentity();
+      await ensureSurfacingScore(scout);
+
+      try {
+        await claimAllWinnings(scout, []);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidClaimBatch');
+      }
+    });
+  });
+
+  describe('Oracle Update Ordering', () => {
+    const updateTradingScore = (member: Member, score: number, updateSeq: number | null) =>
+      program.methods
+        .updateTradingScore(score, updateSeq === null ? null : new anchor.BN(updateSeq))
+        .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+        .signers([member.wallet])
+        .rpc();
+
+    it('accepts sequenced updates in order', async () => {
+      const member = await createFundedIdentity();
+
+      await updateTradingScore(member, 4000, 1);
+      await updateTradingScore(member, 5000, 2);
+      await updateTradingScore(member, 6000, 10);
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tradingScore).to.equal(6000);
+      expect(identity.tradingUpdateSeq.toNumber()).to.equal(10);
+    });
+
+    it('rejects replayed and out-of-order updates', async () => {
+      const member = await createFundedIdentity();
+      await updateTradingScore(member, 7000, 5);
+
+      for (const stale of [5, 4]) {
+        try {
+          await updateTradingScore(member, 1000, stale);
+          expect.fail('Should have thrown error');
+        } catch (e: any) {
+          expect(e.message).to.include('StaleUpdate');
+        }
+      }
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tradingScore).to.equal(7000);
+      expect(identity.tradingUpdateSeq.toNumber()).to.equal(5);
+    });
+
+    it('keeps unsequenced updates working and tracks each dimension separately', async () => {
+      const member = await createFundedIdentity();
+      await updateTradingScore(member, 7000, 5);
+      await updateTradingScore(member, 3000, null);
+
+      // The civic oracle has its own sequence
+      await program.methods
+        .updateCivicScore(4000, new anchor.BN(1))
+        .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+        .signers([member.wallet])
+        .rpc();
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tradingScore).to.equal(3000);
+      expect(identity.tradingUpdateSeq.toNumber()).to.equal(5);
+      expect(identity.civicScore).to.equal(4000);
+      expect(identity.civicUpdateSeq.toNumber()).to.equal(1);
+    });
+
+    it('orders creator score updates by their own sequence', async () => {
+      const member = await createFundedIdentity();
+      await program.methods
+        .setCreatorAuthority(member.wallet.publicKey)
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+      const updateCreatorScore = (score: number, updateSeq: number) =>
+        program.methods
+          .updateCreatorScore(score, new anchor.BN(updateSeq))
+          .accounts({ authority: member.wallet.publicKey, identity: member.identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(member.identity) })
+          .signers([member.wallet])
+          .rpc();
+
+      await updateTradingScore(member, 7000, 5);
+      await updateCreatorScore(6000, 2);
+
+      try {
+        await updateCreatorScore(1000, 1);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('StaleUpdate');
+      }
+
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.creatorScore).to.equal(6000);
+      expect(identity.creatorUpdateSeq.toNumber()).to.equal(2);
+      expect(identity.tradingUpdateSeq.toNumber()).to.equal(5);
+    });
+  });
+
+  describe('DAO Acceptance Counters', () => {
+    it('starts every counter at zero and counts each opened nomination', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+
+      let account = await program.account.creatorDao.fetch(dao);
+      expect(account.totalNominations.toNumber()).to.equal(0);
+      expect(account.totalAccepted.toNumber()).to.equal(0);
+      expect(account.totalRejected.toNumber()).to.equal(0);
+
+      for (let i = 0; i < 3; i++) {
+        await nominate(founder, dao, await createFundedIdentity());
+      }
+
+      // Resolutions can't run on localnet; the acceptance rate over mixed
+      // outcomes is covered by the program's unit tests
+      account = await program.account.creatorDao.fetch(dao);
+      expect(account.totalNominations.toNumber()).to.equal(3);
+      expect(account.totalAccepted.toNumber()).to.equal(0);
+      expect(account.totalRejected.toNumber()).to.equal(0);
+      expect(account.totalAdmitted.toNumber()).to.equal(0);
+    });
+  });
+
+  describe('Market TWAP', () => {
+    it('accumulates the price on every trade and lags a sudden move', async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      const market = await createMarket(founder, dao, await createFundedIdentity());
+
+      const created = await program.account.admissionMarket.fetch(market);
+      expect(created.cumulativeYesPrice.toString()).to.equal('0');
+      expect(created.lastPriceUpdateTs.toNumber()).to.equal(created.createdAt.toNumber());
+
+      await sleep(3000);
+      await takePosition(await createFundedIdentity(), market, { no: {} }, 30_000_000);
+
+      const traded = await program.account.admissionMarket.fetch(market);
+      const elapsed = traded.lastPriceUpdateTs.toNumber() - created.createdAt.toNumber();
+      expect(elapsed).to.be.greaterThan(0);
+      const openingPrice = Number(
+        (BigInt(created.noPool.toString()) * 10_000n) /
+          (BigInt(created.yesPool.toString()) + BigInt(created.noPool.toString()))
+      );
+      expect(traded.cumulativeYesPrice.toString()).to.equal((BigInt(openingPrice) * BigInt(elapsed)).toString());
+
+      // The large NO buy crashed the spot price, but it has barely stood
+      const state = await program.methods.getMarketState(new anchor.BN(0)).accounts({ market }).view();
+      const twap = await program.methods.getMarketTwap(new anchor.BN(86400)).accounts({ market }).view();
+      expect(state.yesPriceBps).to.be.lessThan(2000);
+      expect(twap).to.be.greaterThan(state.yesPriceBps);
+    });
+  });
+
+  describe('Pool Floor', () => {
+    let market: PublicKey;
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(founder, dao, await createFundedIdentity());
+    });
+
+    it('copies the factory floor onto new markets', async () => {
+      const factory = await program.account.marketFactory.fetch(factoryPda);
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.minPoolFloor.toString()).to.equal(factory.minPoolFloor.toString());
+    });
+
+    it('rejects a trade that would drain a pool below the floor', async () => {
+      const before = await program.account.admissionMarket.fetch(market);
+
+      try {
+        await takePosition(await createFundedIdentity(), market, { yes: {} }, 500_000_000);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InsufficientLiquidity');
+      }
+
+      const after = await program.account.admissionMarket.fetch(market);
+      expect(after.yesPool.toString()).to.equal(before.yesPool.toString());
+    });
+
+    it('accepts a smaller trade that stays above the floor', async () => {
+      await takePosition(await createFundedIdentity(), market, { yes: {} }, 50_000_000);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.yesPool.gte(account.minPoolFloor)).to.be.true;
+    });
+  });
+
+  describe('Identity Metadata', () => {
+    const setMetadata = (member: Member, displayName: string, metadataUri: string) =>
+      program.methods
+        .setIdentityMetadata(displayName, metadataUri)
+        .accounts({ owner: member.wallet.publicKey, identity: member.identity })
+        .signers([member.wallet])
+        .rpc();
+
+    const readMetadata = async (member: Member) => {
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      const text = (bytes: number[]) => Buffer.from(bytes).toString('utf8').replace(/\0+$/, '');
+      return {
+        displayName: text(identity.displayName as number[]),
+        metadataUri: text(identity.metadataUri as number[]),
+      };
+    };
+
+    it('starts empty, then sets and overwrites the metadata', async () => {
+      const member = await createFundedIdentity();
+      expect(await readMetadata(member)).to.deep.equal({ displayName: '', metadataUri: '' });
+
+      await setMetadata(member, 'Ada Writes', 'https://example.com/ada.json');
+      expect(await readMetadata(member)).to.deep.equal({
+        displayName: 'Ada Writes',
+        metadataUri: 'https://example.com/ada.json',
+      });
+
+      // A shorter value leaves nothing of the old one behind
+      await setMetadata(member, 'Ada', '');
+      expect(await readMetadata(member)).to.deep.equal({ displayName: 'Ada', metadataUri: '' });
+    });
+
+    it('truncates over-long input to the field size', async () => {
+      const member = await createFundedIdentity();
+      const longName = 'A'.repeat(40);
+      const longUri = 'ipfs://' + 'b'.repeat(120);
+
+      await setMetadata(member, longName, longUri);
+
+      const metadata = await readMetadata(member);
+      expect(metadata.displayName).to.equal(longName.slice(0, 32));
+      expect(metadata.metadataUri).to.equal(longUri.slice(0, 96));
+    });
+
+    it('rejects metadata set by anyone but the owner', async () => {
+      const member = await createFundedIdentity();
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .setIdentityMetadata('Impostor', '')
+          .accounts({ owner: stranger.publicKey, identity: member.identity })
+          .signers([stranger])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+    });
+  });
+
+  describe('Balanced Tier 5', () => {
+    const setRequireBalancedTier5 = (required: boolean, admin: Keypair | null = null) => {
+      const builder = program.methods
+        .setRequireBalancedTier5(required)
+        .accounts({ admin: admin ? admin.publicKey : owner, protocolConfig: protocolConfigPda });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    // Trading-only weights let a single maxed dimension carry the composite to 10000
+    const createLopsidedIdentity = async (): Promise<Member> => {
+      const member = await createFundedIdentity();
+      const accounts = {
+        identity: member.identity,
+        tierConfig: tierConfigPda,
+        protocolConfig: protocolConfigPda,
+        scoreHistory: scoreHistoryPda(member.identity),
+      };
+      await program.methods
+        .setScoreWeights([100, 0, 0, 0, 0])
+        .accounts({ ...accounts, owner: member.wallet.publicKey })
+        .signers([member.wallet])
+        .rpc();
+      await program.methods
+        .updateTradingScore(10000, null)
+        .accounts({ ...accounts, authority: member.wallet.publicKey })
+        .signers([member.wallet])
+        .rpc();
+      return member;
+    };
+
+    const recompute = (identity: PublicKey) =>
+      program.methods
+        .recomputeComposite()
+        .accounts({ identity, tierConfig: tierConfigPda, protocolConfig: protocolConfigPda, scoreHistory: scoreHistoryPda(identity) })
+        .rpc();
+
+    afterEach(async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      if (config.requireBalancedTier5) await setRequireBalancedTier5(false);
+    });
+
+    it('is off by default, so a single maxed dimension reaches tier 5', async () => {
+      const config = await program.account.protocolConfig.fetch(protocolConfigPda);
+      expect(config.requireBalancedTier5).to.be.false;
+
+      const member = await createLopsidedIdentity();
+      const identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.compositeScore).to.equal(10000);
+      expect(identity.tier).to.equal(5);
+    });
+
+    it('caps a lopsided identity at tier 4 when required', async () => {
+      await setRequireBalancedTier5(true);
+
+      const member = await createLopsidedIdentity();
+      let identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.compositeScore).to.equal(10000);
+      expect(identity.tier).to.equal(4);
+
+      // Lifting the rule lets the next recalculation promote it
+      await setRequireBalancedTier5(false);
+      await recompute(member.identity);
+      identity = await program.account.sovereignIdentity.fetch(member.identity);
+      expect(identity.tier).to.equal(5);
+    });
+
+    it('treats a left-out protocol config as the rule being off', async () => {
+      await setRequireBalancedTier5(true);
+      const member = await createLopsidedIdentity();
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).tier).to.equal(4);
+
+      await program.methods
+        .recomputeComposite()
+        .accounts({ identity: member.identity, tierConfig: tierConfigPda, protocolConfig: null, scoreHistory: scoreHistoryPda(member.identity) })
+        .rpc();
+      expect((await program.account.sovereignIdentity.fetch(member.identity)).tier).to.equal(5);
+    });
+
+    it('rejects the setting from anyone but the admin', async () => {
+      const stranger = Keypair.generate();
+
+      try {
+        await setRequireBalancedTier5(true, stranger);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('Unauthorized');
+      }
+    });
+  });
+
+  describe('Liquidity Providers', () => {
+    let market: PublicKey;
+    let lp: Member;
+
+    const liquidityPositionPda = (market: PublicKey, provider: PublicKey) =>
+      pda(Buffer.from('liquidity_position'), market.toBuffer(), provider.toBuffer());
+
+    const addLiquidity = (provider: Member, market: PublicKey, amount: number) =>
+      program.methods
+        .addLiquidity(new anchor.BN(amount))
+        .accounts({
+          provider: provider.wallet.publicKey,
+          market,
+          liquidityPosition: liquidityPositionPda(market, provider.wallet.publicKey),
+          protocolConfig: protocolConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([provider.wallet])
+        .rpc();
+
+    const removeLiquidity = (provider: Member, market: PublicKey, position?: PublicKey) =>
+      program.methods
+        .removeLiquidity()
+        .accounts({
+          provider: provider.wallet.publicKey,
+          market,
+          liquidityPosition: position ?? liquidityPositionPda(market, provider.wallet.publicKey),
+        })
+        .signers([provider.wallet])
+        .rpc();
+
+    before(async () => {
+      const founder = await createFundedIdentity();
+      const dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      market = await createMarket(founder, dao, await createFundedIdentity());
+      lp = await createFundedIdentity();
+    });
+
+    it('adds matched liquidity without moving the price or the pot', async () => {
+      const before = await program.account.admissionMarket.fetch(market);
+
+      await addLiquidity(lp, market, 10_000_000);
+
+      const after = await program.account.admissionMarket.fetch(market);
+      const reserves = (m: typeof after) => m.yesPool.add(m.noPool).toNumber();
+      const yesPrice = (m: typeof after) => m.noPool.toNumber() / reserves(m);
+      expect(reserves(after) - reserves(before)).to.equal(10_000_000);
+      expect(yesPrice(after)).to.be.closeTo(yesPrice(before), 0.0001);
+      expect(after.lpLiquidity.toNumber()).to.equal(10_000_000);
+      expect(after.initialLiquidity.toString()).to.equal(before.initialLiquidity.toString());
+      expect(after.totalNetStakes.toString()).to.equal(before.totalNetStakes.toString());
+
+      const position = await program.account.liquidityPosition.fetch(
+        liquidityPositionPda(market, lp.wallet.publicKey)
+      );
+      expect(position.deposited.toNumber()).to.equal(10_000_000);
+    });
+
+    it('sets aside a share of each trading fee for LPs', async () => {
+      const before = await program.account.admissionMarket.fetch(market);
+
+      // The 1% fee on 5M is 50_000; the LPs' 10M sits next to the 10M seed, so half is theirs
+      await takePosition(await createFundedIdentity(), market, { no: {} }, 5_000_000);
+
+      const after = await program.account.admissionMarket.fetch(market);
+      expect(after.lpFees.sub(before.lpFees).toNumber()).to.equal(25_000);
+      expect(after.accumulatedFees.sub(before.accumulatedFees).toNumber()).to.equal(25_000);
+    });
+
+    it('returns the deposit on removal, keeping the fees owed until finalization', async () => {
+      const balanceBefore = await provider.connection.getBalance(lp.wallet.publicKey);
+
+      await removeLiquidity(lp, market);
+
+      const balanceAfter = await provider.connection.getBalance(lp.wallet.publicKey);
+      expect(balanceAfter - balanceBefore).to.be.within(10_000_000 - 10_000, 10_000_000);
+
+      const account = await program.account.admissionMarket.fetch(market);
+      expect(account.lpLiquidity.toNumber()).to.equal(0);
+      expect(account.lpFees.toNumber()).to.equal(25_000);
+
+      const position = await program.account.liquidityPosition.fetch(
+        liquidityPositionPda(market, lp.wallet.publicKey)
+      );
+      expect(position.deposited.toNumber()).to.equal(0);
+      expect(position.feesOwed.toNumber()).to.equal(25_000);
+      expect(position.feesPaid.toNumber()).to.equal(0);
+
+      // Nothing more to take out while the market is still open
+      try {
+        await removeLiquidity(lp, market);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NoLiquidityToRemove');
+      }
+    });
+
+    it("rejects removing another provider's liquidity", async () => {
+      const other = await createFundedIdentity();
+      await addLiquidity(other, market, 1_000_000);
+      const stranger = await createFundedIdentity();
+
+      try {
+        await removeLiquidity(stranger, market, liquidityPositionPda(market, other.wallet.publicKey));
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('ConstraintSeeds');
+      }
+    });
+  });
+
+  describe('Vote Delegation', () => {
+    let founder: Member;
+    let dao: PublicKey;
+    let member: Member;
+    let delegate: Member;
+
+    const delegateVote = (from: Member, to: Member, nomination: PublicKey | null = null) =>
+      program.methods
+        .delegateVote(nomination)
+        .accounts({
+          member: from.wallet.publicKey,
+          membership: membershipPda(dao, from.wallet.publicKey),
+          delegateMembership: membershipPda(dao, to.wallet.publicKey),
+        })
+        .signers([from.wallet])
+        .rpc();
+
+    const revokeDelegation = (from: Member) =>
+      program.methods
+        .revokeDelegation()
+        .accounts({ member: from.wallet.publicKey, membership: membershipPda(dao, from.wallet.publicKey) })
+        .signers([from.wallet])
+        .rpc();
+
+    // Cast `onBehalfOf`'s vote, signed by `caster`
+    const castFor = (caster: Member, onBehalfOf: Member, nomination: PublicKey, vote: object) =>
+      program.methods
+        .castVote(voteCommitment(vote, Keypair.generate().publicKey.toBytes()))
+        .accounts({
+          voter: caster.wallet.publicKey,
+          voterMembership: membershipPda(dao, onBehalfOf.wallet.publicKey),
+          dao,
+          nomination,
+          voteRecord: voteRecordPda(nomination, onBehalfOf.wallet.publicKey),
+          predictionMarket: null,
+          voterCreatorDetails: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([caster.wallet])
+        .rpc();
+
+    const freshNomination = async () => nominate(founder, dao, await createFundedIdentity());
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      member = await createFundedIdentity();
+      delegate = await createFundedIdentity();
+      await addFounderMember(founder, dao, member);
+      await addFounderMember(founder, dao, delegate);
+    });
+
+    afterEach(async () => {
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+      if (membership.delegate) await revokeDelegation(member);
+    });
+
+    it("lets a delegate cast the member's vote, recording both", async () => {
+      const nomination = await freshNomination();
+      await delegateVote(member, delegate);
+
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+      expect(membership.delegate!.toBase58()).to.equal(delegate.wallet.publicKey.toBase58());
+      expect(membership.delegationScope).to.be.null;
+
+      await castFor(delegate, member, nomination, { accept: {} });
+
+      const record = await program.account.voteRecord.fetch(voteRecordPda(nomination, member.wallet.publicKey));
+      expect(record.voter.toBase58()).to.equal(member.wallet.publicKey.toBase58());
+      expect(record.delegate!.toBase58()).to.equal(delegate.wallet.publicKey.toBase58());
+
+      // The delegate still has their own vote
+      await castVote(delegate, dao, nomination, { reject: {} });
+      const account = await program.account.nomination.fetch(nomination);
+      expect(account.voterCount).to.equal(2);
+    });
+
+    it('gives the member and their delegate a single vote between them', async () => {
+      await delegateVote(member, delegate);
+
+      // Delegate first: the member can no longer vote
+      const first = await freshNomination();
+      await castFor(delegate, member, first, { accept: {} });
+      try {
+        await castVote(member, dao, first, { reject: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/already in use|custom program error: 0x0/);
+      }
+
+      // Member first: the delegate can no longer vote for them
+      const second = await freshNomination();
+      await castVote(member, dao, second, { reject: {} });
+      try {
+        await castFor(delegate, member, second, { accept: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.match(/already in use|custom program error: 0x0/);
+      }
+
+      const record = await program.account.voteRecord.fetch(voteRecordPda(second, member.wallet.publicKey));
+      expect(record.delegate).to.be.null;
+    });
+
+    it('limits a per-nomination delegation to that nomination', async () => {
+      const scoped = await freshNomination();
+      const other = await freshNomination();
+      await delegateVote(member, delegate, scoped);
+
+      await castFor(delegate, member, scoped, { accept: {} });
+      try {
+        await castFor(delegate, member, other, { accept: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotDelegate');
+      }
+    });
+
+    it('stops the delegate voting once revoked', async () => {
+      await delegateVote(member, delegate);
+      await revokeDelegation(member);
+
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+      expect(membership.delegate).to.be.null;
+
+      const nomination = await freshNomination();
+      try {
+        await castFor(delegate, member, nomination, { accept: {} });
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotDelegate');
+      }
+
+      // The member votes for themselves again
+      await castVote(member, dao, nomination, { accept: {} });
+
+      try {
+        await revokeDelegation(member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NoDelegation');
+      }
+    });
+
+    it('only delegates to another active member of the same DAO', async () => {
+      try {
+        await delegateVote(member, member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidDelegate');
+      }
+
+      const outsider = await createFundedIdentity();
+      const otherDao = await createDao(outsider);
+      await addFounderMember(outsider, otherDao, outsider);
+      try {
+        await program.methods
+          .delegateVote(null)
+          .accounts({
+            member: member.wallet.publicKey,
+            membership: membershipPda(dao, member.wallet.publicKey),
+            delegateMembership: membershipPda(otherDao, outsider.wallet.publicKey),
+          })
+          .signers([member.wallet])
+          .rpc();
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('InvalidDelegate');
+      }
+    });
+  });
+
+  describe('Leaving a DAO', () => {
+    let founder: Member;
+    let member: Member;
+    let dao: PublicKey;
+
+    const leaveDao = (leaver: Member) =>
+      program.methods
+        .leaveDao()
+        .accounts({
+          member: leaver.wallet.publicKey,
+          dao,
+          membership: membershipPda(dao, leaver.wallet.publicKey),
+          memberDirectory: pda(Buffer.from('member_directory'), leaver.wallet.publicKey.toBuffer()),
+        })
+        .signers([leaver.wallet])
+        .rpc();
+
+    before(async () => {
+      founder = await createFundedIdentity();
+      member = await createFundedIdentity();
+      dao = await createDao(founder);
+      await addFounderMember(founder, dao, founder);
+      await addFounderMember(founder, dao, member);
+    });
+
+    it('resyncs a member score without changing the member count', async () => {
+      await program.methods
+        .syncMemberScore()
+        .accounts({ dao, membership: membershipPda(dao, member.wallet.publicKey), memberIdentity: member.identity })
+        .rpc();
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.memberCount).to.equal(2);
+      const { creatorScore } = await program.account.sovereignIdentity.fetch(member.identity);
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+      expect(membership.countedCreatorScore).to.equal(creatorScore);
+    });
+
+    it('refuses to let the founder leave', async () => {
+      try {
+        await leaveDao(founder);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('FounderCannotLeave');
+      }
+    });
+
+    it('deactivates the membership and takes the member out of the totals', async () => {
+      await leaveDao(member);
+
+      const account = await program.account.creatorDao.fetch(dao);
+      expect(account.memberCount).to.equal(1);
+      const { creatorScore } = await program.account.sovereignIdentity.fetch(founder.identity);
+      expect(account.totalMemberCreatorScore.toNumber()).to.equal(creatorScore);
+
+      const membership = await program.account.daoMembership.fetch(membershipPda(dao, member.wallet.publicKey));
+      expect(membership.isActive).to.be.false;
+      const directory = await program.account.memberDirectory.fetch(
+        pda(Buffer.from('member_directory'), member.wallet.publicKey.toBuffer())
+      );
+      expect(directory.daos.map((d: PublicKey) => d.toBase58())).to.not.include(dao.toBase58());
+
+      try {
+        await leaveDao(member);
+        expect.fail('Should have thrown error');
+      } catch (e: any) {
+        expect(e.message).to.include('NotMember');
+      }
+    });
+  });
 });